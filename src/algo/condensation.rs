@@ -0,0 +1,98 @@
+use crate::{
+	algo::TarjanScc,
+	common::AdjListGraph,
+	core::{
+		property::{AcyclicGraph, AddEdge, NewVertex, Subgraph},
+		Directed, EnsureUnloaded, Graph,
+	},
+};
+use std::{collections::HashMap, hash::Hash};
+
+/// The condensation of a graph whose vertices are of type `V`.
+///
+/// See [`condensation`](fn.condensation.html).
+pub type Condensation<V> = AcyclicGraph<AdjListGraph<Vec<V>, Vec<(V, V)>>>;
+
+/// Constructs the [condensation](https://en.wikipedia.org/wiki/Strongly_connected_component#Definitions)
+/// of the given graph.
+///
+/// The condensation has a vertex for each [strongly connected component](https://mathworld.wolfram.com/StronglyConnectedComponent.html)
+/// (SCC) of the graph, with a weight holding the vertices of the component.
+/// Each edge of the condensation represents all edges between two components,
+/// holding them as its weight. Since it can't have cycles, the condensation is
+/// returned as an [`AcyclicGraph`](../core/property/struct.AcyclicGraph.html).
+///
+/// The vertices of the condensation (the component IDs) are in reverse
+/// topological order, as the components are found using
+/// [`TarjanScc`](struct.TarjanScc.html).
+///
+/// Also returns which component each vertex of the original graph belongs to.
+///
+/// ### Usage
+///
+/// ```
+/// # use graphene::{
+/// # 	algo::condensation,
+/// # 	common::AdjListGraph,
+/// # 	core::{Graph, property::{AddEdge, NewVertex}},
+/// # };
+/// let mut graph = AdjListGraph::<(), ()>::new();
+///
+/// let v0 = graph.new_vertex().unwrap();
+/// let v1 = graph.new_vertex().unwrap();
+/// let v2 = graph.new_vertex().unwrap();
+///
+/// // First SCC has v0 and v1, second SCC has only v2
+/// graph.add_edge(&v0, &v1).unwrap();
+/// graph.add_edge(&v1, &v0).unwrap();
+/// graph.add_edge(&v1, &v2).unwrap();
+///
+/// let (condensed, components) = condensation(&graph);
+///
+/// assert_eq!(condensed.all_vertices().count(), 2);
+/// assert_eq!(components[&v0], components[&v1]);
+/// assert_eq!(
+/// 	condensed.edges_between(components[&v0], components[&v2]).next(),
+/// 	Some(&vec![(v1, v2)])
+/// );
+/// ```
+pub fn condensation<G>(graph: &G) -> (Condensation<G::Vertex>, HashMap<G::Vertex, usize>)
+where
+	G: Graph<Directedness = Directed>,
+	G::Vertex: Hash,
+{
+	let mut condensed: AdjListGraph<Vec<_>, Vec<_>> = AdjListGraph::new();
+	let mut components = HashMap::new();
+	let mut exit_edges = Vec::new();
+
+	for scc in TarjanScc::new(graph)
+	{
+		let members: Vec<_> = scc.all_vertices().collect();
+		let component = condensed.new_vertex_weighted(members.clone()).unwrap();
+		components.extend(members.into_iter().map(|v| (v, component)));
+		exit_edges.extend(scc.exit_edges().map(|e| (component, e)));
+	}
+
+	// Aggregate the edges between each pair of components, in the order the
+	// pairs are first found
+	let mut pairs = HashMap::new();
+	let mut aggregated: Vec<(usize, usize, Vec<_>)> = Vec::new();
+	for (source, (v1, v2)) in exit_edges
+	{
+		let sink = components[&v2];
+		let i = *pairs.entry((source, sink)).or_insert_with(|| {
+			aggregated.push((source, sink, Vec::new()));
+			aggregated.len() - 1
+		});
+		aggregated[i].2.push((v1, v2));
+	}
+	for (source, sink, edges) in aggregated
+	{
+		condensed.add_edge_weighted(source, sink, edges).unwrap();
+	}
+
+	(
+		AcyclicGraph::ensure(condensed).expect("Condensation of graph has a cycle"),
+		components,
+	)
+}
@@ -0,0 +1,603 @@
+//! An implementation of Contraction Hierarchies for answering repeated
+//! shortest-path queries.
+//!
+//! The algorithm:
+//!
+//! Preprocessing orders all vertices by "importance" and then contracts them
+//! one by one, from least to most important. Contracting a vertex `v` means
+//! removing it from the remaining graph while preserving all shortest paths
+//! between the remaining vertices. For every pair of remaining neighbors
+//! `u -> v -> x`, we check whether the path through `v` is the only shortest
+//! path from `u` to `x`. This is done by a local Dijkstra search from `u` that
+//! isn't allowed to use `v` (called a "witness search"). If no path as short
+//! as `u -> v -> x` is found, a shortcut edge `u -> x` is added, recording `v`
+//! as its middle vertex.
+//!
+//! The order in which a vertex is contracted is called its rank.
+//! After preprocessing, any shortest path in the original graph has a
+//! counterpart in the augmented graph (original edges plus shortcuts) that
+//! first only goes "up" in rank and then only goes "down".
+//! A query therefore runs a Dijkstra search from the source using only upward
+//! edges and another from the sink using only downward edges in reverse.
+//! The two searches meet at the highest ranked vertex on the shortest path.
+//! Lastly, shortcuts on the found path are recursively replaced by the two
+//! edges they bypass, producing a path in the original graph.
+//!
+//! Implementation:
+//!
+//! Vertex importance is estimated by the edge difference (the number of
+//! shortcuts that contracting the vertex would add minus the number of edges
+//! that would be removed) plus the number of neighbors that have already been
+//! contracted. Priorities are updated lazily: when the least important vertex
+//! is about to be contracted, its priority is recalculated, and if it is no
+//! longer the least important, it is put back in the queue.
+//!
+//! Witness searches are limited to a fixed number of settled vertices.
+//! If a witness search gives up, a shortcut is added even though it might not
+//! be needed. This doesn't affect the correctness of queries, only the size of
+//! the augmented graph.
+use crate::{
+	common::AdjListGraph,
+	core::{
+		property::{AddEdge, NewVertex},
+		Directed, Graph,
+	},
+};
+use num_traits::{PrimInt, Unsigned};
+use std::{
+	borrow::Borrow,
+	cmp::Reverse,
+	collections::{BinaryHeap, HashMap},
+	hash::Hash,
+};
+
+/// The maximum number of vertices a witness search may settle before giving
+/// up.
+const WITNESS_SEARCH_LIMIT: usize = 500;
+
+/// An edge in the augmented graph produced by
+/// [`ContractionHierarchies`](struct.ContractionHierarchies.html).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ContractionEdge<W>
+{
+	/// The weight of the edge.
+	///
+	/// For shortcuts, this is the sum of the weights of the two edges being
+	/// bypassed.
+	pub weight: W,
+
+	/// The vertex bypassed by a shortcut edge.
+	///
+	/// The vertex refers to the augmented graph and not the original one.
+	/// If the edge is one of the original edges, this is `None`.
+	pub middle: Option<usize>,
+}
+
+/// An edge as it is stored during and after preprocessing: the other vertex,
+/// the weight, and the middle vertex if it is a shortcut.
+type IndexedEdge<W> = (usize, W, Option<usize>);
+
+/// Implements [Contraction Hierarchies](https://en.wikipedia.org/wiki/Contraction_hierarchies)
+/// for answering many shortest-path queries on a static, weighted, directed
+/// graph.
+///
+/// Constructing a `ContractionHierarchies` preprocesses the given graph,
+/// producing an augmented graph with additional shortcut edges.
+/// The original graph is not used after construction, and therefore isn't
+/// borrowed. Each query then only explores a small part of the augmented
+/// graph, making it much faster than running
+/// [`DijkstraShortestPaths`](struct.DijkstraShortestPaths.html) for every
+/// query.
+///
+/// Parallel edges are treated as one edge with the smallest weight of the
+/// parallel edges, while loops are ignored.
+///
+/// ### Usage
+///
+/// ```
+/// # use graphene::{
+/// # 	algo::ContractionHierarchies,
+/// # 	common::AdjListGraph,
+/// # 	core::property::{AddEdge, NewVertex},
+/// # };
+/// let mut graph = AdjListGraph::<(), u32>::new();
+///
+/// let v0 = graph.new_vertex().unwrap();
+/// let v1 = graph.new_vertex().unwrap();
+/// let v2 = graph.new_vertex().unwrap();
+/// let v3 = graph.new_vertex().unwrap();
+///
+/// graph.add_edge_weighted(&v0, &v1, 1).unwrap();
+/// graph.add_edge_weighted(&v1, &v2, 2).unwrap();
+/// graph.add_edge_weighted(&v2, &v3, 3).unwrap();
+/// graph.add_edge_weighted(&v0, &v3, 10).unwrap();
+///
+/// // Preprocess the graph once
+/// let ch = ContractionHierarchies::new_simple(&graph);
+///
+/// // Then query it as many times as needed
+/// assert_eq!(ch.shortest_path(v0, v3), Some((6, vec![v0, v1, v2, v3])));
+/// assert_eq!(ch.distance(v1, v3), Some(5));
+/// assert_eq!(ch.distance(v3, v0), None);
+/// ```
+///
+/// ### Related
+///
+/// - [`DijkstraShortestPaths`](struct.DijkstraShortestPaths.html): No
+///   preprocessing needed.
+pub struct ContractionHierarchies<V, W>
+where
+	V: Copy + Eq + Hash,
+	W: PrimInt + Unsigned,
+{
+	/// The augmented graph, containing the original edges and all shortcuts.
+	///
+	/// Each vertex's weight is the vertex it represents in the original graph.
+	graph: AdjListGraph<V, ContractionEdge<W>>,
+
+	/// Maps vertices of the original graph to the augmented graph.
+	indices: HashMap<V, usize>,
+
+	/// The original vertex of each vertex in the augmented graph.
+	vertices: Vec<V>,
+
+	/// The rank of each vertex in the augmented graph.
+	rank: Vec<usize>,
+
+	/// For each vertex, the edges sourced in it going up in rank.
+	upward: Vec<Vec<IndexedEdge<W>>>,
+
+	/// For each vertex, the edges sinked in it coming down in rank.
+	///
+	/// The other vertex of the edge is its source.
+	downward: Vec<Vec<IndexedEdge<W>>>,
+}
+
+impl<V, W> ContractionHierarchies<V, W>
+where
+	V: Copy + Eq + Hash,
+	W: PrimInt + Unsigned,
+{
+	/// Preprocesses the given graph, using `get_weight` to get the weight of
+	/// each edge.
+	pub fn new<G>(graph: &G, get_weight: fn(&G::EdgeWeight) -> W) -> Self
+	where
+		G: Graph<Vertex = V, Directedness = Directed>,
+	{
+		let vertices: Vec<_> = graph.all_vertices().collect();
+		let indices: HashMap<_, _> = vertices
+			.iter()
+			.enumerate()
+			.map(|(idx, &v)| (v, idx))
+			.collect();
+
+		let mut contraction = Contraction::new(vertices.len());
+		for (source, sink, weight) in graph.all_edges()
+		{
+			contraction.insert_edge(indices[&source], indices[&sink], get_weight(weight), None);
+		}
+		let rank = contraction.contract_all();
+
+		let mut augmented = AdjListGraph::new();
+		for &v in vertices.iter()
+		{
+			augmented
+				.new_vertex_weighted(v)
+				.expect("Couldn't add vertex to augmented graph");
+		}
+		let mut upward = vec![Vec::new(); vertices.len()];
+		let mut downward = vec![Vec::new(); vertices.len()];
+		for (source, edges) in contraction.outgoing.into_iter().enumerate()
+		{
+			for (sink, weight, middle) in edges
+			{
+				augmented
+					.add_edge_weighted(source, sink, ContractionEdge { weight, middle })
+					.expect("Couldn't add edge to augmented graph");
+				if rank[sink] > rank[source]
+				{
+					upward[source].push((sink, weight, middle));
+				}
+				else
+				{
+					downward[sink].push((source, weight, middle));
+				}
+			}
+		}
+
+		Self {
+			graph: augmented,
+			indices,
+			vertices,
+			rank,
+			upward,
+			downward,
+		}
+	}
+
+	/// Preprocesses the given graph, using the edge weights directly.
+	pub fn new_simple<G>(graph: &G) -> Self
+	where
+		G: Graph<Vertex = V, EdgeWeight = W, Directedness = Directed>,
+	{
+		Self::new(graph, Clone::clone)
+	}
+
+	/// Returns the augmented graph.
+	///
+	/// It contains a vertex for each vertex in the original graph, weighted by
+	/// the original vertex. Its edges are the original edges (without loops
+	/// and with parallel edges merged) and all shortcut edges added during
+	/// preprocessing.
+	pub fn graph(&self) -> &AdjListGraph<V, ContractionEdge<W>>
+	{
+		&self.graph
+	}
+
+	/// Returns the rank assigned to the given vertex of the original graph.
+	///
+	/// The rank is the order in which the vertex was contracted, starting
+	/// from 0.
+	pub fn rank(&self, v: impl Borrow<V>) -> Option<usize>
+	{
+		self.indices.get(v.borrow()).map(|&idx| self.rank[idx])
+	}
+
+	/// Returns the length of the shortest path from `source` to `sink`.
+	///
+	/// Returns `None` if there is no such path, or if either vertex wasn't in
+	/// the original graph.
+	pub fn distance(&self, source: impl Borrow<V>, sink: impl Borrow<V>) -> Option<W>
+	{
+		Some(self.search(source.borrow(), sink.borrow())?.0)
+	}
+
+	/// Returns the length of the shortest path from `source` to `sink`
+	/// and the path itself.
+	///
+	/// The path consists of vertices of the original graph, starting with
+	/// `source` and ending with `sink`. All shortcuts have been unpacked.
+	///
+	/// Returns `None` if there is no such path, or if either vertex wasn't in
+	/// the original graph.
+	pub fn shortest_path(&self, source: impl Borrow<V>, sink: impl Borrow<V>)
+		-> Option<(W, Vec<V>)>
+	{
+		let (weight, meeting, forward, backward) = self.search(source.borrow(), sink.borrow())?;
+
+		// Walk back to the source and then forward to the sink
+		let mut augmented_path = vec![meeting];
+		let mut current = meeting;
+		while let Some(pred) = forward[&current].1
+		{
+			augmented_path.push(pred);
+			current = pred;
+		}
+		augmented_path.reverse();
+		current = meeting;
+		while let Some(succ) = backward[&current].1
+		{
+			augmented_path.push(succ);
+			current = succ;
+		}
+
+		let mut path = vec![augmented_path[0]];
+		for pair in augmented_path.windows(2)
+		{
+			self.unpack(pair[0], pair[1], &mut path);
+		}
+		Some((weight, path.into_iter().map(|v| self.vertices[v]).collect()))
+	}
+
+	/// Runs the bidirectional upward search.
+	///
+	/// Returns the length of the shortest path, the vertex where the two
+	/// searches meet, and the distances and predecessors found by the forward
+	/// and backward searches respectively.
+	fn search(&self, source: &V, sink: &V) -> Option<(W, usize, SearchTree<W>, SearchTree<W>)>
+	{
+		let source = *self.indices.get(source)?;
+		let sink = *self.indices.get(sink)?;
+
+		let mut forward = UpwardSearch::new(source);
+		let mut backward = UpwardSearch::new(sink);
+		let mut best: Option<(W, usize)> = None;
+		if source == sink
+		{
+			best = Some((W::zero(), source));
+		}
+
+		loop
+		{
+			// Only continue a search if it can still improve on the best path
+			let can_improve = |min: &W| best.is_none_or(|(b, _)| *min < b);
+			let forward_min = forward.min().filter(can_improve);
+			let backward_min = backward.min().filter(can_improve);
+
+			let (search, other, edges) = match (forward_min, backward_min)
+			{
+				(Some(f), Some(b)) if b < f => (&mut backward, &forward, &self.downward),
+				(Some(_), _) => (&mut forward, &backward, &self.upward),
+				(None, Some(_)) => (&mut backward, &forward, &self.downward),
+				(None, None) => break,
+			};
+
+			if let Some((v, dist)) = search.settle_next(edges)
+			{
+				if let Some(&(other_dist, _)) = other.tree.get(&v)
+				{
+					let candidate = dist + other_dist;
+					if best.is_none_or(|(b, _)| candidate < b)
+					{
+						best = Some((candidate, v));
+					}
+				}
+			}
+		}
+
+		let (weight, meeting) = best?;
+		Some((weight, meeting, forward.tree, backward.tree))
+	}
+
+	/// Appends to the path the vertices of the original edges represented by
+	/// the given edge of the augmented graph, excluding the source.
+	fn unpack(&self, source: usize, sink: usize, path: &mut Vec<usize>)
+	{
+		if let Some(middle) = self.middle_of(source, sink)
+		{
+			self.unpack(source, middle, path);
+			self.unpack(middle, sink, path);
+		}
+		else
+		{
+			path.push(sink);
+		}
+	}
+
+	/// Returns the middle vertex of the edge between the given vertices,
+	/// if it is a shortcut.
+	fn middle_of(&self, source: usize, sink: usize) -> Option<usize>
+	{
+		let (edges, other) = if self.rank[sink] > self.rank[source]
+		{
+			(&self.upward[source], sink)
+		}
+		else
+		{
+			(&self.downward[sink], source)
+		};
+		edges
+			.iter()
+			.find(|(v, _, _)| *v == other)
+			.expect("Couldn't find edge of shortest path in augmented graph")
+			.2
+	}
+}
+
+/// The state of the graph while vertices are being contracted.
+struct Contraction<W>
+{
+	/// For each vertex, the edges sourced in it.
+	outgoing: Vec<Vec<IndexedEdge<W>>>,
+	/// For each vertex, the edges sinked in it.
+	incoming: Vec<Vec<IndexedEdge<W>>>,
+	/// Whether each vertex has been contracted.
+	contracted: Vec<bool>,
+	/// The number of contracted neighbors of each vertex.
+	contracted_neighbors: Vec<usize>,
+}
+
+impl<W: PrimInt + Unsigned> Contraction<W>
+{
+	fn new(vertex_count: usize) -> Self
+	{
+		Self {
+			outgoing: vec![Vec::new(); vertex_count],
+			incoming: vec![Vec::new(); vertex_count],
+			contracted: vec![false; vertex_count],
+			contracted_neighbors: vec![0; vertex_count],
+		}
+	}
+
+	/// Inserts the given edge, unless there already is an edge between the two
+	/// vertices with a smaller or equal weight. Loops are ignored.
+	fn insert_edge(&mut self, source: usize, sink: usize, weight: W, middle: Option<usize>)
+	{
+		if source == sink
+		{
+			return;
+		}
+		if let Some(existing) = self.outgoing[source].iter_mut().find(|e| e.0 == sink)
+		{
+			if weight < existing.1
+			{
+				*existing = (sink, weight, middle);
+				let reverse = self.incoming[sink]
+					.iter_mut()
+					.find(|e| e.0 == source)
+					.expect("Missing incoming counterpart of outgoing edge");
+				*reverse = (source, weight, middle);
+			}
+		}
+		else
+		{
+			self.outgoing[source].push((sink, weight, middle));
+			self.incoming[sink].push((source, weight, middle));
+		}
+	}
+
+	/// Contracts all vertices, returning the rank of each.
+	fn contract_all(&mut self) -> Vec<usize>
+	{
+		let mut rank = vec![0; self.contracted.len()];
+		let mut queue: BinaryHeap<_> = (0..self.contracted.len())
+			.map(|v| Reverse((self.priority(v, &self.shortcuts(v)), v)))
+			.collect();
+		let mut next_rank = 0;
+
+		while let Some(Reverse((_, v))) = queue.pop()
+		{
+			// Lazily update the priority
+			let shortcuts = self.shortcuts(v);
+			let priority = self.priority(v, &shortcuts);
+			if let Some(Reverse((next_priority, _))) = queue.peek()
+			{
+				if priority > *next_priority
+				{
+					queue.push(Reverse((priority, v)));
+					continue;
+				}
+			}
+
+			self.contracted[v] = true;
+			rank[v] = next_rank;
+			next_rank += 1;
+			for (source, sink, weight) in shortcuts
+			{
+				self.insert_edge(source, sink, weight, Some(v));
+			}
+			for &(n, _, _) in self.outgoing[v].iter().chain(self.incoming[v].iter())
+			{
+				self.contracted_neighbors[n] += 1;
+			}
+		}
+		rank
+	}
+
+	/// The priority of the given vertex, where lower priorities are contracted
+	/// first.
+	fn priority(&self, v: usize, shortcuts: &[(usize, usize, W)]) -> isize
+	{
+		let removed =
+			self.remaining(&self.outgoing[v]).count() + self.remaining(&self.incoming[v]).count();
+		shortcuts.len() as isize - removed as isize + self.contracted_neighbors[v] as isize
+	}
+
+	/// Returns the edges of the given list whose other vertex hasn't been
+	/// contracted.
+	fn remaining<'a>(
+		&'a self,
+		edges: &'a [IndexedEdge<W>],
+	) -> impl 'a + Iterator<Item = &'a IndexedEdge<W>>
+	{
+		edges.iter().filter(move |e| !self.contracted[e.0])
+	}
+
+	/// Returns the shortcuts needed if the given vertex were to be contracted.
+	fn shortcuts(&self, v: usize) -> Vec<(usize, usize, W)>
+	{
+		let mut result = Vec::new();
+		let max_outgoing = match self.remaining(&self.outgoing[v]).map(|e| e.1).max()
+		{
+			Some(max) => max,
+			None => return result,
+		};
+
+		for &(source, in_weight, _) in self.remaining(&self.incoming[v])
+		{
+			let witnesses = self.witness_search(source, v, in_weight + max_outgoing);
+			for &(sink, out_weight, _) in self.remaining(&self.outgoing[v])
+			{
+				let via = in_weight + out_weight;
+				if sink != source && witnesses.get(&sink).is_none_or(|&w| w > via)
+				{
+					result.push((source, sink, via));
+				}
+			}
+		}
+		result
+	}
+
+	/// Runs a limited Dijkstra search from the given vertex, avoiding the
+	/// given vertex and any contracted vertex.
+	///
+	/// Returns the distances found that are no greater than `max_weight`.
+	fn witness_search(&self, source: usize, avoid: usize, max_weight: W) -> HashMap<usize, W>
+	{
+		let mut dist = HashMap::new();
+		let mut queue = BinaryHeap::new();
+		let mut settled = 0;
+		dist.insert(source, W::zero());
+		queue.push(Reverse((W::zero(), source)));
+
+		while let Some(Reverse((d, v))) = queue.pop()
+		{
+			if d > max_weight || settled >= WITNESS_SEARCH_LIMIT
+			{
+				break;
+			}
+			if d > dist[&v]
+			{
+				continue;
+			}
+			settled += 1;
+			for &(sink, weight, _) in self.remaining(&self.outgoing[v])
+			{
+				let new_dist = d + weight;
+				if sink != avoid && dist.get(&sink).is_none_or(|&old| new_dist < old)
+				{
+					dist.insert(sink, new_dist);
+					queue.push(Reverse((new_dist, sink)));
+				}
+			}
+		}
+		dist
+	}
+}
+
+/// The distance to and predecessor of each vertex reached by a search.
+type SearchTree<W> = HashMap<usize, (W, Option<usize>)>;
+
+/// A Dijkstra search that can be advanced one vertex at a time.
+struct UpwardSearch<W>
+{
+	tree: SearchTree<W>,
+	queue: BinaryHeap<Reverse<(W, usize)>>,
+}
+
+impl<W: PrimInt + Unsigned> UpwardSearch<W>
+{
+	fn new(start: usize) -> Self
+	{
+		let mut tree = HashMap::new();
+		tree.insert(start, (W::zero(), None));
+		let mut queue = BinaryHeap::new();
+		queue.push(Reverse((W::zero(), start)));
+		Self { tree, queue }
+	}
+
+	/// The smallest distance of any vertex not yet settled.
+	fn min(&mut self) -> Option<W>
+	{
+		// Throw away outdated entries
+		while let Some(Reverse((d, v))) = self.queue.peek()
+		{
+			if *d > self.tree[v].0
+			{
+				self.queue.pop();
+			}
+			else
+			{
+				return Some(*d);
+			}
+		}
+		None
+	}
+
+	/// Settles the next vertex, relaxing the given edges sourced in it.
+	fn settle_next(&mut self, edges: &[Vec<IndexedEdge<W>>]) -> Option<(usize, W)>
+	{
+		self.min()?;
+		let Reverse((d, v)) = self.queue.pop()?;
+		for &(other, weight, _) in edges[v].iter()
+		{
+			let new_dist = d + weight;
+			if self.tree.get(&other).is_none_or(|&(old, _)| new_dist < old)
+			{
+				self.tree.insert(other, (new_dist, Some(v)));
+				self.queue.push(Reverse((new_dist, other)));
+			}
+		}
+		Some((v, d))
+	}
+}
@@ -0,0 +1,228 @@
+//! An implementation of Gabow's path-based Strongly Connected Components (SCC)
+//! algorithm.
+//!
+//! The algorithm:
+//!
+//! Starting from some vertex, do a Dfs.
+//! Each visited vertex is given a preorder number, in the order they are
+//! visited. Two stacks are maintained: The first holds all visited vertices
+//! that haven't been assigned to an SCC yet. The second holds the vertices on
+//! the current path of the Dfs that may be the root of an SCC (the root
+//! being the first vertex of the SCC to be visited).
+//!
+//! When a vertex is visited, it is pushed on both stacks. Then, for each edge
+//! to another vertex:
+//!
+//! - If the other vertex hasn't been visited, visit it.
+//! - Otherwise, if the other vertex hasn't been assigned to an SCC yet, it is
+//!   part of the same SCC as the current vertex. Therefore, none of the
+//!   vertices on the second stack with a higher preorder number than the other
+//!   vertex can be the root of an SCC, and are popped from it.
+//!
+//! When finished visiting a vertex, if it is on the top of the second stack, it
+//! is the root of an SCC. It is popped from the second stack, and all vertices
+//! on the first stack until it (and including it) are popped and make up the
+//! SCC.
+//!
+//! When the Dfs can no longer reach any vertices, the algorithm starts again on
+//! a new unvisited vertex.
+//! When all vertices have been visited, all SCCs have been found and the
+//! algorithm is done.
+//!
+//! Implementation:
+//!
+//! The preorder numbers are kept in a hash map, and whether each vertex has
+//! been assigned to an SCC is kept by preorder number.
+//! The Dfs is done using an explicit call stack, such that an SCC can be
+//! returned from `next()` as soon as it is found.
+use crate::{
+	algo::strongly_connected_component,
+	core::{property::ConnectedGraph, proxy::SubgraphProxy, Directed, Graph},
+};
+use std::{collections::HashMap, hash::Hash};
+
+/// Implements Gabow's path-based [Strongly Connected Components](https://mathworld.wolfram.com/StronglyConnectedComponent.html) Algorithm.
+///
+/// It implements [`Iterator`](https://doc.rust-lang.org/std/iter/trait.Iterator.html). [`next`]
+/// is therefore the primary way to use this struct.
+/// Each call to [`next`] will traverse the graph enough to identify a strongly
+/// connected component (SCC) and return it.
+/// When all SCCs have been returned [`next`] will return [`None`](https://doc.rust-lang.org/std/option/enum.Option.html#variant.None).
+///
+/// Like [`TarjanScc`](struct.TarjanScc.html), it returns the SCCs in reverse
+/// topological order, such that for any two returned SCCs, the later SCC may
+/// have an edge to the earlier, but not the other way around.
+/// Instead of lowlink values, it keeps a stack of the possible roots of SCCs on
+/// the current path.
+///
+/// ### Usage
+/// ```
+/// # use graphene::{
+/// # 	algo::GabowScc,
+/// # 	common::AdjListGraph,
+/// # 	core::property::{NewVertex, AddEdge, Subgraph},
+/// # };
+/// # use graphene::core::Graph;
+/// let mut graph = AdjListGraph::<(),()>::new();
+///
+/// let v0 = graph.new_vertex().unwrap();
+/// let v1 = graph.new_vertex().unwrap();
+/// let v2 = graph.new_vertex().unwrap();
+///
+/// // First SCC has v0 and v1, second SCC has only v2
+/// graph.add_edge(&v0,&v1).unwrap();
+/// graph.add_edge(&v1,&v0).unwrap();
+/// graph.add_edge(&v1,&v2).unwrap();
+///
+/// let mut gabow = GabowScc::new(&graph);
+///
+/// let scc1 = gabow.next().unwrap();
+/// let scc2 = gabow.next().unwrap();
+/// assert!(gabow.next().is_none());
+/// assert!(scc1.contains_vertex(&v2));
+/// assert!(scc2.contains_vertex(&v0) && scc2.contains_vertex(&v1));
+/// assert!(scc2.reaches(&scc1).is_some());
+/// ```
+///
+/// ### Related
+/// - [`TarjanScc`](struct.TarjanScc.html): Also finds SCCs in a single pass, in
+///   reverse topological order.
+/// - [`KosarajuScc`](struct.KosarajuScc.html): Finds SCCs using two passes,
+///   returning them in topological order instead.
+///
+/// [`next`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#tymethod.next
+pub struct GabowScc<'a, G>
+where
+	G: 'a + Graph<Directedness = Directed>,
+	G::Vertex: Hash,
+{
+	graph: &'a G,
+
+	/// We use this to keep track of which vertices we have check for
+	/// whether they have been visited.
+	unchecked: Box<dyn 'a + Iterator<Item = G::Vertex>>,
+
+	/// The preorder number of each visited vertex.
+	preorder: HashMap<G::Vertex, usize>,
+
+	/// Whether each visited vertex has been assigned to an SCC, by preorder
+	/// number.
+	assigned: Vec<bool>,
+
+	/// The visited vertices that haven't been assigned to an SCC.
+	unassigned: Vec<G::Vertex>,
+
+	/// The preorder numbers of the vertices on the current path that may be
+	/// the root of an SCC.
+	roots: Vec<usize>,
+
+	/// The vertices currently being visited by the Dfs.
+	///
+	/// Each entry holds the preorder number of the vertex, the vertices it has
+	/// edges to, and how many of those have been explored.
+	call_stack: Vec<(usize, Vec<G::Vertex>, usize)>,
+}
+
+impl<'a, G> GabowScc<'a, G>
+where
+	G: 'a + Graph<Directedness = Directed>,
+	G::Vertex: Hash,
+{
+	/// Constructs a new `GabowScc` to find the [strongly connected components](https://mathworld.wolfram.com/StronglyConnectedComponent.html)
+	/// of the specified graph.
+	///
+	/// The Dfs starts from the vertices in the order they are returned by
+	/// [`all_vertices`](../core/trait.Graph.html#method.all_vertices).
+	/// If the graph is empty, no SCCs are found.
+	pub fn new(graph: &'a G) -> Self
+	{
+		Self {
+			graph,
+			unchecked: graph.all_vertices(),
+			preorder: HashMap::new(),
+			assigned: Vec::new(),
+			unassigned: Vec::new(),
+			roots: Vec::new(),
+			call_stack: Vec::new(),
+		}
+	}
+
+	/// Starts visiting the given vertex, giving it the next preorder number.
+	fn visit(&mut self, v: G::Vertex)
+	{
+		let number = self.assigned.len();
+		self.preorder.insert(v, number);
+		self.assigned.push(false);
+		self.unassigned.push(v);
+		self.roots.push(number);
+
+		let sinks = self.graph.edges_sourced_in(v).map(|(v2, _)| v2).collect();
+		self.call_stack.push((number, sinks, 0));
+	}
+}
+
+impl<'a, G> Iterator for GabowScc<'a, G>
+where
+	G: 'a + Graph<Directedness = Directed>,
+	G::Vertex: Hash,
+{
+	type Item = ConnectedGraph<SubgraphProxy<&'a G>>;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		// Repeat until either an SCC is found or all vertices have been visited.
+		loop
+		{
+			if let Some((number, sinks, explored)) = self.call_stack.last_mut()
+			{
+				let number = *number;
+				if let Some(&v) = sinks.get(*explored)
+				{
+					*explored += 1;
+					if let Some(&v_number) = self.preorder.get(&v)
+					{
+						if !self.assigned[v_number]
+						{
+							// Everything after the vertex on the path is in its SCC
+							while *self.roots.last().unwrap() > v_number
+							{
+								self.roots.pop();
+							}
+						}
+					}
+					else
+					{
+						self.visit(v);
+					}
+				}
+				else
+				{
+					// Finished visiting the vertex
+					self.call_stack.pop();
+					if self.roots.last() == Some(&number)
+					{
+						// Vertex is root of SCC, pop all unassigned vertices after it
+						self.roots.pop();
+						let start = self
+							.unassigned
+							.iter()
+							.rposition(|v| self.preorder[v] == number)
+							.unwrap();
+						let scc = self.unassigned.split_off(start);
+						for v in scc.iter()
+						{
+							self.assigned[self.preorder[v]] = true;
+						}
+						return Some(strongly_connected_component(self.graph, scc));
+					}
+				}
+			}
+			else
+			{
+				let preorder = &self.preorder;
+				let v = self.unchecked.find(|v| !preorder.contains_key(v))?;
+				self.visit(v);
+			}
+		}
+	}
+}
@@ -0,0 +1,346 @@
+//! An implementation of Johnson's algorithm for finding all elementary cycles
+//! of a directed graph.
+//!
+//! The algorithm:
+//!
+//! The vertices are given some order. Then, for each vertex `s` in order, all
+//! elementary cycles that start in `s` and only visit vertices later in the
+//! order are found. This ensures each cycle is found exactly once, namely
+//! when starting from its least vertex.
+//!
+//! Cycles starting in `s` are found by a depth-first search that returns to
+//! `s`. To avoid searching the same dead ends repeatedly, a vertex is
+//! "blocked" when it is visited, and only unblocked when it is found to lead
+//! to a cycle. If a vertex leads to no cycle, it stays blocked until one of its
+//! neighbors is unblocked, since only then could it lead to a new cycle.
+//! For this, each vertex keeps a list of the vertices that should be unblocked
+//! with it.
+//!
+//! Since a cycle can't span multiple strongly connected components, the
+//! search is restricted to the strongly connected component `s` is in, in the
+//! subgraph induced by `s` and the vertices after it. Removing the earlier
+//! vertices can split a component, so the components are recomputed for each
+//! `s`. If `s` isn't on any cycle in the subgraph, it is skipped in favor of
+//! the least vertex of a component that has cycles.
+//!
+//! Implementation:
+//!
+//! The vertices are numbered by their position in the order, and the strongly
+//! connected components of each subgraph are found by `TarjanScc` on the
+//! `SubgraphProxy` induced by the vertices from `s` onwards. The search is done
+//! using an explicit stack, such that the search can be paused whenever a cycle
+//! is found and resumed on the next call to `next()`.
+//!
+//! When the length of cycles is limited, the search doesn't continue past the
+//! limit. In that case, we can't know whether the vertex where the search
+//! stopped leads to a cycle, so we treat it as if it did. Otherwise, vertices
+//! could stay blocked even though they lead to a short enough cycle.
+use crate::{
+	algo::TarjanScc,
+	core::{proxy::SubgraphProxy, Directed, Graph},
+};
+use std::{collections::HashMap, hash::Hash};
+
+/// Implements Johnson's algorithm for finding all [elementary cycles](https://mathworld.wolfram.com/SimpleCycle.html)
+/// in a directed graph.
+///
+/// It implements [`Iterator`](https://doc.rust-lang.org/std/iter/trait.Iterator.html). [`next`]
+/// is therefore the primary way to use this struct.
+/// Each call to [`next`] will search the graph just enough to find the next
+/// elementary cycle and return its vertices in the order they are visited by
+/// the cycle. The first vertex of the cycle is not repeated at the end.
+/// A graph loop is returned as a cycle with a single vertex.
+///
+/// When all cycles have been found, or the cycle count limit has been
+/// reached, [`next`] will return [`None`](https://doc.rust-lang.org/std/option/enum.Option.html#variant.None).
+///
+/// Cycles are identified by their vertices, so parallel edges don't result in
+/// multiple identical cycles being returned.
+///
+/// ### Usage
+///
+/// ```
+/// # use graphene::{
+/// # 	algo::JohnsonCycles,
+/// # 	common::AdjListGraph,
+/// # 	core::property::{AddEdge, NewVertex},
+/// # };
+/// let mut graph = AdjListGraph::<(), ()>::new();
+///
+/// let v0 = graph.new_vertex().unwrap();
+/// let v1 = graph.new_vertex().unwrap();
+/// let v2 = graph.new_vertex().unwrap();
+///
+/// graph.add_edge(&v0, &v1).unwrap();
+/// graph.add_edge(&v1, &v0).unwrap();
+/// graph.add_edge(&v1, &v2).unwrap();
+/// graph.add_edge(&v2, &v0).unwrap();
+///
+/// let cycles: Vec<_> = JohnsonCycles::new_simple(&graph).collect();
+/// assert_eq!(cycles.len(), 2);
+/// assert!(cycles.contains(&vec![v0, v1]));
+/// assert!(cycles.contains(&vec![v0, v1, v2]));
+///
+/// // Only find cycles of length 2 or less
+/// let mut short_cycles = JohnsonCycles::new(&graph, Some(2), None);
+/// assert_eq!(short_cycles.next(), Some(vec![v0, v1]));
+/// assert_eq!(short_cycles.next(), None);
+/// ```
+///
+/// ### Related
+///
+/// - [`TarjanScc`](struct.TarjanScc.html): Finds the strongly connected
+///   components cycles are restricted to.
+/// - [`AcyclicGraph`](../core/property/struct.AcyclicGraph.html): Ensures a
+///   graph has no cycles.
+///
+/// [`next`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#tymethod.next
+pub struct JohnsonCycles<'a, G>
+where
+	G: 'a + Graph<Directedness = Directed>,
+	G::Vertex: Hash,
+{
+	graph: &'a G,
+
+	/// The vertices of the graph, in the order used by the algorithm.
+	vertices: Vec<G::Vertex>,
+
+	/// The position of each vertex in the order.
+	indices: HashMap<G::Vertex, usize>,
+
+	/// For each vertex, the (deduplicated) vertices it has edges to.
+	adjacency: Vec<Vec<usize>>,
+
+	/// The least vertex that may start the cycles searched for next.
+	next_start: usize,
+
+	/// The strongly connected component currently being searched.
+	component: Vec<usize>,
+
+	/// Whether each vertex is part of the subgraph currently being searched.
+	allowed: Vec<bool>,
+	blocked: Vec<bool>,
+
+	/// For each vertex, the vertices to unblock when it is unblocked.
+	blocked_by: Vec<Vec<usize>>,
+
+	/// The current path of the search.
+	///
+	/// Each entry holds the vertex, the position in its adjacency of the next
+	/// edge to explore, and whether it was found to lead to a cycle.
+	stack: Vec<(usize, usize, bool)>,
+
+	max_length: Option<usize>,
+	remaining_count: Option<usize>,
+}
+
+impl<'a, G> JohnsonCycles<'a, G>
+where
+	G: 'a + Graph<Directedness = Directed>,
+	G::Vertex: Hash,
+{
+	/// Constructs a new `JohnsonCycles` to find the elementary cycles of the
+	/// given graph.
+	///
+	/// If `max_length` is given, only cycles with at most that many vertices
+	/// are found. If `max_count` is given, at most that many cycles are found.
+	pub fn new(graph: &'a G, max_length: Option<usize>, max_count: Option<usize>) -> Self
+	{
+		let vertices: Vec<_> = graph.all_vertices().collect();
+		let indices: HashMap<_, _> = vertices.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+		let index_of = |v: G::Vertex| indices[&v];
+
+		let adjacency: Vec<_> = vertices
+			.iter()
+			.map(|&v| {
+				let mut sinks: Vec<_> = graph
+					.edges_sourced_in(v)
+					.map(|(v2, _)| index_of(v2))
+					.collect();
+				sinks.sort();
+				sinks.dedup();
+				sinks
+			})
+			.collect();
+
+		Self {
+			graph,
+			adjacency,
+			next_start: 0,
+			component: Vec::new(),
+			allowed: vec![false; vertices.len()],
+			blocked: vec![false; vertices.len()],
+			blocked_by: vec![Vec::new(); vertices.len()],
+			stack: Vec::new(),
+			vertices,
+			indices,
+			max_length,
+			remaining_count: max_count,
+		}
+	}
+
+	/// Constructs a new `JohnsonCycles` to find all the elementary cycles of
+	/// the given graph.
+	pub fn new_simple(graph: &'a G) -> Self
+	{
+		Self::new(graph, None, None)
+	}
+
+	/// Returns the graph whose cycles are being found.
+	pub fn graph(&self) -> &'a G
+	{
+		self.graph
+	}
+
+	/// Starts the search for cycles from the next start vertex.
+	///
+	/// Returns false if there are no more start vertices.
+	fn start_next(&mut self) -> bool
+	{
+		for &v in self.component.iter()
+		{
+			self.allowed[v] = false;
+		}
+		self.component = match self.least_component(self.next_start)
+		{
+			Some(component) => component,
+			None =>
+			{
+				self.next_start = self.vertices.len();
+				self.component.clear();
+				return false;
+			},
+		};
+
+		let start = *self.component.iter().min().unwrap();
+		for &v in self.component.iter()
+		{
+			self.allowed[v] = true;
+			self.blocked[v] = false;
+			self.blocked_by[v].clear();
+		}
+		self.next_start = start + 1;
+
+		self.blocked[start] = true;
+		self.stack.push((start, 0, false));
+		true
+	}
+
+	/// Finds the strongly connected components of the subgraph induced by the
+	/// vertices from `first` onwards, and returns the one with the least
+	/// vertex of those that have cycles.
+	fn least_component(&self, first: usize) -> Option<Vec<usize>>
+	{
+		let subgraph =
+			SubgraphProxy::induced_unchecked(self.graph, self.vertices[first..].to_vec());
+		TarjanScc::new(&subgraph)
+			.map(|scc| {
+				scc.all_vertices()
+					.map(|v| self.indices[&v])
+					.collect::<Vec<_>>()
+			})
+			// A component of a single vertex only has a cycle if it has a loop
+			.filter(|component| component.len() > 1 || self.adjacency[component[0]].contains(&component[0]))
+			.min_by_key(|component| *component.iter().min().unwrap())
+	}
+
+	/// Unblocks the given vertex and all vertices that should be unblocked with
+	/// it.
+	fn unblock(&mut self, v: usize)
+	{
+		let mut to_unblock = vec![v];
+		while let Some(v) = to_unblock.pop()
+		{
+			if self.blocked[v]
+			{
+				self.blocked[v] = false;
+				to_unblock.append(&mut self.blocked_by[v]);
+			}
+		}
+	}
+}
+
+impl<'a, G> Iterator for JohnsonCycles<'a, G>
+where
+	G: 'a + Graph<Directedness = Directed>,
+	G::Vertex: Hash,
+{
+	type Item = Vec<G::Vertex>;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		if self.remaining_count == Some(0)
+		{
+			return None;
+		}
+
+		loop
+		{
+			if self.stack.is_empty() && !self.start_next()
+			{
+				return None;
+			}
+			let start = self.stack[0].0;
+			let depth = self.stack.len();
+			let (v, next_edge, _) = *self.stack.last().unwrap();
+
+			if let Some(&w) = self.adjacency[v].get(next_edge)
+			{
+				self.stack.last_mut().unwrap().1 += 1;
+				if !self.allowed[w]
+				{
+					continue;
+				}
+
+				if w == start
+				{
+					self.stack.last_mut().unwrap().2 = true;
+					self.remaining_count = self.remaining_count.map(|c| c - 1);
+					return Some(
+						self.stack
+							.iter()
+							.map(|&(v, _, _)| self.vertices[v])
+							.collect(),
+					);
+				}
+				else if !self.blocked[w]
+				{
+					if self.max_length.is_some_and(|max| depth >= max)
+					{
+						// Can't know whether it leads to a cycle, so assume it does.
+						self.stack.last_mut().unwrap().2 = true;
+					}
+					else
+					{
+						self.blocked[w] = true;
+						self.stack.push((w, 0, false));
+					}
+				}
+			}
+			else
+			{
+				// Finished exploring all edges from the vertex
+				let (v, _, found) = self.stack.pop().unwrap();
+				if found
+				{
+					self.unblock(v);
+				}
+				else
+				{
+					for i in 0..self.adjacency[v].len()
+					{
+						let w = self.adjacency[v][i];
+						if self.allowed[w] && !self.blocked_by[w].contains(&v)
+						{
+							self.blocked_by[w].push(v);
+						}
+					}
+				}
+				if let Some(parent) = self.stack.last_mut()
+				{
+					parent.2 |= found;
+				}
+			}
+		}
+	}
+}
@@ -0,0 +1,191 @@
+//! An implementation of Kosaraju's Strongly Connected Components (SCC)
+//! algorithm.
+//!
+//! The algorithm:
+//!
+//! First, do a Dfs of the whole graph (restarting from unvisited vertices
+//! until all have been visited), recording the order in which the vertices are
+//! finished being visited.
+//!
+//! Then, go through the vertices in reverse order of when they were finished.
+//! For each vertex not yet assigned to an SCC, do a Dfs on the reverse graph
+//! starting from it, only visiting vertices not yet assigned to an SCC. All the
+//! vertices visited make up the SCC of the starting vertex.
+//!
+//! The vertex finished last in the first pass must be in an SCC that no other
+//! SCC has edges to. In the reverse graph, its SCC therefore has no edges to
+//! other SCCs, so the second Dfs finds exactly its SCC. After removing (i.e.
+//! assigning) it, the same argument applies to the remaining vertices.
+//!
+//! Implementation:
+//!
+//! The first pass is done when the algorithm is constructed, while the second
+//! pass is done lazily, finding one SCC per call to `next()`.
+//! The reverse graph is provided by `ReverseGraph`.
+//! Which vertices have been visited and assigned are kept in hash sets.
+use crate::{
+	algo::strongly_connected_component,
+	core::{
+		property::ConnectedGraph,
+		proxy::{ReverseGraph, SubgraphProxy},
+		Directed, Graph,
+	},
+};
+use std::{collections::HashSet, hash::Hash};
+
+/// Implements Kosaraju's [Strongly Connected Components](https://mathworld.wolfram.com/StronglyConnectedComponent.html) Algorithm.
+///
+/// It implements [`Iterator`](https://doc.rust-lang.org/std/iter/trait.Iterator.html). [`next`]
+/// is therefore the primary way to use this struct.
+/// The whole graph is traversed once when the struct is constructed.
+/// Afterwards, each call to [`next`] will traverse the reverse of the graph
+/// enough to identify a strongly connected component (SCC) and return it.
+/// When all SCCs have been returned [`next`] will return [`None`](https://doc.rust-lang.org/std/option/enum.Option.html#variant.None).
+///
+/// Unlike [`TarjanScc`](struct.TarjanScc.html), this algorithm returns the
+/// SCCs in topological order, such that for any two returned SCCs, the earlier
+/// SCC may have an edge to the later, but not the other way around.
+///
+/// ### Usage
+/// ```
+/// # use graphene::{
+/// # 	algo::KosarajuScc,
+/// # 	common::AdjListGraph,
+/// # 	core::property::{NewVertex, AddEdge, Subgraph},
+/// # };
+/// # use graphene::core::Graph;
+/// let mut graph = AdjListGraph::<(),()>::new();
+///
+/// let v0 = graph.new_vertex().unwrap();
+/// let v1 = graph.new_vertex().unwrap();
+/// let v2 = graph.new_vertex().unwrap();
+///
+/// // First SCC has v0 and v1, second SCC has only v2
+/// graph.add_edge(&v0,&v1).unwrap();
+/// graph.add_edge(&v1,&v0).unwrap();
+/// graph.add_edge(&v1,&v2).unwrap();
+///
+/// let mut kosaraju = KosarajuScc::new(&graph);
+///
+/// let scc1 = kosaraju.next().unwrap();
+/// let scc2 = kosaraju.next().unwrap();
+/// assert!(kosaraju.next().is_none());
+/// assert!(scc1.contains_vertex(&v0) && scc1.contains_vertex(&v1));
+/// assert!(scc2.contains_vertex(&v2));
+/// assert!(scc1.reaches(&scc2).is_some());
+/// ```
+///
+/// ### Related
+/// - [`TarjanScc`](struct.TarjanScc.html): Finds SCCs in a single pass,
+///   returning them in reverse topological order.
+/// - [`GabowScc`](struct.GabowScc.html): Finds SCCs in a single pass, returning
+///   them in reverse topological order.
+/// - [`ReverseGraph`](../core/proxy/struct.ReverseGraph.html): Used for the
+///   second pass.
+///
+/// [`next`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#tymethod.next
+pub struct KosarajuScc<'a, G>
+where
+	G: 'a + Graph<Directedness = Directed>,
+	G::Vertex: Hash,
+{
+	graph: &'a G,
+	reverse: ReverseGraph<&'a G>,
+
+	/// The vertices in the order they were finished being visited in the first
+	/// pass.
+	finished: Vec<G::Vertex>,
+
+	/// The vertices already assigned to an SCC.
+	assigned: HashSet<G::Vertex>,
+}
+
+impl<'a, G> KosarajuScc<'a, G>
+where
+	G: 'a + Graph<Directedness = Directed>,
+	G::Vertex: Hash,
+{
+	/// Constructs a new `KosarajuScc` to find the [strongly connected components](https://mathworld.wolfram.com/StronglyConnectedComponent.html)
+	/// of the specified graph.
+	///
+	/// This does the first pass of the algorithm, traversing the whole graph.
+	/// If the graph is empty, no SCCs are found.
+	pub fn new(graph: &'a G) -> Self
+	{
+		let mut visited = HashSet::new();
+		let mut finished = Vec::new();
+		// Each entry holds a vertex being visited, the vertices it has edges to,
+		// and how many of those have been explored.
+		let mut call_stack: Vec<(G::Vertex, Vec<G::Vertex>, usize)> = Vec::new();
+
+		for v in graph.all_vertices()
+		{
+			if !visited.insert(v)
+			{
+				continue;
+			}
+			call_stack.push((v, graph.edges_sourced_in(v).map(|(v2, _)| v2).collect(), 0));
+
+			while let Some((v, sinks, explored)) = call_stack.last_mut()
+			{
+				if let Some(&v2) = sinks.get(*explored)
+				{
+					*explored += 1;
+					if visited.insert(v2)
+					{
+						let sinks = graph.edges_sourced_in(v2).map(|(v3, _)| v3).collect();
+						call_stack.push((v2, sinks, 0));
+					}
+				}
+				else
+				{
+					finished.push(*v);
+					call_stack.pop();
+				}
+			}
+		}
+
+		Self {
+			graph,
+			reverse: ReverseGraph::new(graph),
+			finished,
+			assigned: HashSet::new(),
+		}
+	}
+}
+
+impl<'a, G> Iterator for KosarajuScc<'a, G>
+where
+	G: 'a + Graph<Directedness = Directed>,
+	G::Vertex: Hash,
+{
+	type Item = ConnectedGraph<SubgraphProxy<&'a G>>;
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		// Find the unassigned vertex that was finished last
+		let mut root = self.finished.pop()?;
+		while self.assigned.contains(&root)
+		{
+			root = self.finished.pop()?;
+		}
+
+		// Find all unassigned vertices reachable from it in the reverse graph
+		self.assigned.insert(root);
+		let mut scc = vec![root];
+		let mut to_visit = vec![root];
+		while let Some(v) = to_visit.pop()
+		{
+			for (v2, _) in self.reverse.edges_sourced_in(v)
+			{
+				if self.assigned.insert(v2)
+				{
+					scc.push(v2);
+					to_visit.push(v2);
+				}
+			}
+		}
+
+		Some(strongly_connected_component(self.graph, scc))
+	}
+}
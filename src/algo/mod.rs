@@ -1,13 +1,28 @@
 //! A collection of graph algorithm implementations.
 
 mod bfs;
+mod condensation;
+mod contraction_hierarchies;
 mod dfs;
 mod dijkstra_shortest_paths;
+mod gabow_scc;
+mod johnson_cycles;
+mod kosaraju_scc;
+mod neighborhood;
 mod tarjan_scc;
+mod yen_shortest_paths;
 
-pub use self::{bfs::*, dfs::*, dijkstra_shortest_paths::*, tarjan_scc::*};
-use crate::core::{property::VertexInGraph, Ensure, Graph};
-use std::borrow::Borrow;
+pub use self::{
+	bfs::*, condensation::*, contraction_hierarchies::*, dfs::*, dijkstra_shortest_paths::*,
+	gabow_scc::*, johnson_cycles::*, kosaraju_scc::*, neighborhood::*, tarjan_scc::*,
+	yen_shortest_paths::*,
+};
+use crate::core::{
+	property::{ConnectedGraph, VertexInGraph},
+	proxy::SubgraphProxy,
+	Ensure, Graph,
+};
+use std::{borrow::Borrow, hash::Hash};
 
 pub fn path_exists<G: Graph>(
 	g: &G,
@@ -24,3 +39,19 @@ pub fn path_exists<G: Graph>(
 	}
 	false
 }
+
+/// Constructs the strongly connected component of the given graph consisting
+/// of the given vertices.
+///
+/// Used by the SCC algorithms, which guarantee the vertices are strongly
+/// connected and in the graph, so this isn't validated.
+fn strongly_connected_component<G: Graph>(
+	graph: &G,
+	vertices: impl IntoIterator<Item = G::Vertex>,
+) -> ConnectedGraph<SubgraphProxy<&G>>
+where
+	G::Vertex: Hash,
+{
+	let scc = SubgraphProxy::induced_unchecked(graph, vertices.into_iter().collect());
+	ConnectedGraph::ensure_unvalidated(scc, ())
+}
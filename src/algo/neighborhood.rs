@@ -0,0 +1,85 @@
+use crate::{
+	algo::Bfs,
+	core::{property::HasVertex, proxy::SubgraphProxy, Graph},
+};
+use std::hash::Hash;
+
+/// Constructs the [ego network](https://en.wikipedia.org/wiki/Egocentric_network)
+/// of the graph's vertex, i.e. the subgraph induced by the vertices at most the
+/// given number of hops away from it.
+///
+/// The vertex is the one returned by the graph's [`get_vertex`], and the
+/// vertices around it are found using [`Bfs`](struct.Bfs.html).
+/// In directed graphs, only the edges sourced in a vertex are followed.
+///
+/// ### Usage
+///
+/// ```
+/// # use graphene::{
+/// # 	algo::ego_network,
+/// # 	common::AdjListGraph,
+/// # 	core::{Ensure, Graph, property::{AddEdge, NewVertex, VertexInGraph}},
+/// # };
+/// let mut graph = AdjListGraph::<(), ()>::new();
+/// let v0 = graph.new_vertex().unwrap();
+/// let v1 = graph.new_vertex().unwrap();
+/// let v2 = graph.new_vertex().unwrap();
+/// graph.add_edge(&v0, &v1).unwrap();
+/// graph.add_edge(&v1, &v2).unwrap();
+///
+/// let graph = VertexInGraph::ensure(graph, v0).unwrap();
+/// let ego = ego_network(&graph, 1);
+///
+/// assert!(ego.contains_vertex(v1));
+/// assert!(!ego.contains_vertex(v2));
+/// ```
+///
+/// [`get_vertex`]: ../core/property/trait.HasVertex.html#method.get_vertex
+pub fn ego_network<G: HasVertex>(graph: &G, hops: usize) -> SubgraphProxy<&G>
+where
+	G::Vertex: Hash,
+{
+	let mut subgraph = SubgraphProxy::new(graph);
+	expand(&mut subgraph, graph.get_vertex());
+
+	let mut bfs = Bfs::new(graph);
+	while let Some(v) = bfs.next()
+	{
+		// Bfs visits vertices in order of depth, so the rest are too far away
+		if bfs.depth(v) > hops
+		{
+			break;
+		}
+		expand(&mut subgraph, v);
+	}
+	subgraph
+}
+
+/// Constructs the subgraph induced by the vertices reachable from the graph's
+/// vertex, i.e. the one returned by its [`get_vertex`].
+///
+/// In directed graphs, only the edges sourced in a vertex are followed.
+///
+/// [`get_vertex`]: ../core/property/trait.HasVertex.html#method.get_vertex
+pub fn reachable_subgraph<G: HasVertex>(graph: &G) -> SubgraphProxy<&G>
+where
+	G::Vertex: Hash,
+{
+	let mut subgraph = SubgraphProxy::new(graph);
+	expand(&mut subgraph, graph.get_vertex());
+	for v in Bfs::new(graph)
+	{
+		expand(&mut subgraph, v);
+	}
+	subgraph
+}
+
+/// Expands the subgraph with a vertex found by traversing the underlying graph.
+fn expand<G: Graph>(subgraph: &mut SubgraphProxy<&G>, v: G::Vertex)
+where
+	G::Vertex: Hash,
+{
+	subgraph
+		.expand(v)
+		.unwrap_or_else(|_| panic!("Traversal produced vertex not in the graph"));
+}
@@ -33,30 +33,21 @@
 //!
 //! Implementation:
 //!
-//! The Stack:
-//! We use a Vec as stack. A vertex's index is simply its position on the stack.
-//! We can do this, since indices aren't used after a vertex is popped from the
-//! stack, so the next time a vertex is "assigned" the index (by being pushed on
-//! the stack), it's still unique. Besides pushing the vertex on the stack, we
-//! also add the lowlink value.
+//! Each visited vertex is given an index in the order it is visited, which we
+//! use to look up its lowlink value and whether it is on the stack, without
+//! having to search the stack. The vertices' indices are kept in a hash map.
 //!
-//! Most of the action happens in the Dfs's on_exit function. However, instead
-//! of allowing the Dfs to call the on_exit on its own, we start every call to
-//! `next()` by prompting Dfs to call any on_exit it can. This is because any
-//! on_exit call can result in an SCC being found. However, we can't return it
-//! from the on_exit to the next(). Therefore, we instead put it in a temporary.
-//! By calling the on_exit from next() manually, we can check if an SCC is
-//! produced, and if so return it. If not, we can continue the algorithm and if
-//! the Dfs is done, check for any unvisited vertices.
+//! The Dfs is done using an explicit call stack, where each entry holds the
+//! index of the vertex being visited, the vertices it has edges to, and how
+//! many of those have been explored. When all have been explored, we are
+//! finished visiting the vertex, and check whether it is the root of an SCC.
+//! If it is, the SCC is returned from `next()` immediately, and the next call
+//! to `next()` continues the Dfs where it left off.
 use crate::{
-	algo::Dfs,
-	core::{
-		property::{ConnectedGraph, HasVertex},
-		proxy::SubgraphProxy,
-		Directed, EnsureUnloaded, Graph,
-	},
+	algo::strongly_connected_component,
+	core::{property::ConnectedGraph, proxy::SubgraphProxy, Directed, Graph},
 };
-use std::cmp::min;
+use std::{cmp::min, collections::HashMap, hash::Hash};
 
 /// Implements Tarjan's [Strongly Connected Components](https://mathworld.wolfram.com/StronglyConnectedComponent.html) Algorithm.
 ///
@@ -81,12 +72,7 @@ use std::cmp::min;
 /// # use graphene::{
 /// # 	algo::TarjanScc,
 /// # 	common::AdjListGraph,
-/// # 	core::{
-/// # 		EnsureUnloaded,
-/// # 		property::{
-/// # 			NewVertex, AddEdge, HasVertexGraph, Subgraph
-/// # 		}
-/// # 	},
+/// # 	core::property::{NewVertex, AddEdge, Subgraph},
 /// # };
 /// # use graphene::core::Graph;
 /// let mut graph = AdjListGraph::<(),()>::new();
@@ -105,9 +91,6 @@ use std::cmp::min;
 /// // Connect first SCC to second
 /// graph.add_edge(&v0,&v2).unwrap();
 ///
-/// // We use `HasVertexGraph` because we don't care where we start
-/// let graph = HasVertexGraph::ensure(graph).unwrap();
-///
 /// // Initialize algorithm
 /// let mut tarj = TarjanScc::new(&graph);
 ///
@@ -134,12 +117,15 @@ use std::cmp::min;
 ///
 /// ### Related
 /// - [`Connected`]: All SCCs are strongly connected by definition.
-/// - [`ConnectedGraph`](../core/property/struct.ConnectedGraph.html):
-/// Returned by [`next`] and implements [`Connected`].
+/// - [`ConnectedGraph`](../core/property/struct.ConnectedGraph.html): Returned
+///   by [`next`] and implements [`Connected`].
 /// - [`Subgraph`]: All SCCs are subgraphs of the original graph.
-/// - [`SubgraphProxy`](../core/proxy/struct.SubgraphProxy.html):
-/// Returned by [`next`] and implements [`Subgraph`].
-/// TODO: other SCC algorithms
+/// - [`SubgraphProxy`](../core/proxy/struct.SubgraphProxy.html): Returned by
+///   [`next`] and implements [`Subgraph`].
+/// - [`KosarajuScc`](struct.KosarajuScc.html): Finds SCCs using two passes,
+///   returning them in topological order instead.
+/// - [`GabowScc`](struct.GabowScc.html): Also finds SCCs in a single pass, in
+///   reverse topological order.
 ///
 /// [`next`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#tymethod.next
 /// [`connected`]: ../core/property/trait.Connected.html
@@ -150,82 +136,72 @@ use std::cmp::min;
 pub struct TarjanScc<'a, G>
 where
 	G: 'a + Graph<Directedness = Directed>,
+	G::Vertex: Hash,
 {
-	dfs: Dfs<'a, G, Vec<(G::Vertex, usize)>>,
+	graph: &'a G,
 
 	/// We use this to keep track of which vertices we have check for
 	/// whether they have been visited.
 	unchecked: Box<dyn 'a + Iterator<Item = G::Vertex>>,
+
+	/// The index of each visited vertex.
+	indices: HashMap<G::Vertex, usize>,
+
+	/// The lowlink value of each visited vertex, and whether it is on the
+	/// stack, by index.
+	lowlinks: Vec<(usize, bool)>,
+
+	/// The stack of vertices whose SCC hasn't been found yet.
+	stack: Vec<G::Vertex>,
+
+	/// The vertices currently being visited by the Dfs.
+	///
+	/// Each entry holds the index of the vertex, the vertices it has edges to,
+	/// and how many of those have been explored.
+	call_stack: Vec<(usize, Vec<G::Vertex>, usize)>,
 }
 
 impl<'a, G> TarjanScc<'a, G>
 where
-	G: 'a + Graph<Directedness = Directed> + HasVertex,
+	G: 'a + Graph<Directedness = Directed>,
+	G::Vertex: Hash,
 {
 	/// Constructs a new `TarjanScc` to find the [strongly connected components](https://mathworld.wolfram.com/StronglyConnectedComponent.html)
 	/// of the specified graph.
 	///
-	/// It calls [`get_vertex`] on the graph, making the algorithm start from
-	/// the returned vertex. TODO: what does this imply?
-	///
-	/// ### Hint
-	///
-	/// [`HasVertexGraph`](../core/property/struct.HasVertexGraph.html) can be
-	/// used when you don't care which vertex the algorithm starts at. When you
-	/// do, [`VertexInGraph`](../core/property/struct.VertexInGraph.html) can be
-	/// used.
-	///
-	/// [`next`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#tymethod.next
-	/// [`get_vertex`]:
-	/// ../core/property/trait.HasVertex.html#tymethod.get_vertex
+	/// The Dfs starts from the vertices in the order they are returned by
+	/// [`all_vertices`](../core/trait.Graph.html#method.all_vertices).
+	/// If the graph is empty, no SCCs are found.
 	pub fn new(graph: &'a G) -> Self
 	{
-		/// Implements part of Tarjan's algorithm, namely what happens when we
-		/// are finished visiting a vertex.
-		fn on_exit<G>(g: &G, v: G::Vertex, stack: &mut Vec<(G::Vertex, usize)>)
-		where
-			G: Graph<Directedness = Directed>,
-		{
-			// Find the index of the vertex
-			let index = stack.iter().position(|(v2, _)| *v2 == v).unwrap();
-
-			// Check which vertices can be reached, and update lowlink if necessary
-			for (e, _) in g.edges_sourced_in(v)
-			{
-				if let Some(&lowlink) = stack.iter().find_map(|(v2, lowlink)| {
-					if e == *v2
-					{
-						Some(lowlink)
-					}
-					else
-					{
-						None
-					}
-				})
-				{
-					stack[index].1 = min(stack[index].1, lowlink);
-				}
-			}
-		}
-
-		// Push the start vertex on the stack with low-link = 0
-		let dfs = Dfs::new(
-			graph,
-			Dfs::do_nothing_on_visit,
-			on_exit,
-			Dfs::do_nothing_on_explore,
-			vec![(graph.get_vertex(), 0)],
-		);
 		Self {
-			dfs,
+			graph,
 			unchecked: graph.all_vertices(),
+			indices: HashMap::new(),
+			lowlinks: Vec::new(),
+			stack: Vec::new(),
+			call_stack: Vec::new(),
 		}
 	}
+
+	/// Starts visiting the given vertex, giving it the next index.
+	fn visit(&mut self, v: G::Vertex)
+	{
+		// Give the vertex an index and a lowlink value equal to it
+		let index = self.lowlinks.len();
+		self.indices.insert(v, index);
+		self.lowlinks.push((index, true));
+		self.stack.push(v);
+
+		let sinks = self.graph.edges_sourced_in(v).map(|(v2, _)| v2).collect();
+		self.call_stack.push((index, sinks, 0));
+	}
 }
 
 impl<'a, G> Iterator for TarjanScc<'a, G>
 where
 	G: 'a + Graph<Directedness = Directed>,
+	G::Vertex: Hash,
 {
 	type Item = ConnectedGraph<SubgraphProxy<&'a G>>;
 
@@ -234,47 +210,59 @@ where
 		// Repeat until either an SCC is found or all vertices have been visited.
 		loop
 		{
-			// For each vertex we are finished visiting, check if its the root of a SCC.
-			while let Some(v) = self.dfs.advance_next_exit()
+			if let Some((index, sinks, explored)) = self.call_stack.last_mut()
 			{
-				let stack = &mut self.dfs.payload;
-
-				// Find the index of the vertex
-				let index = stack.iter().position(|(v2, _)| *v2 == v).unwrap();
-
-				if stack[index].1 == index
+				let index = *index;
+				if let Some(&v) = sinks.get(*explored)
 				{
-					// Vertex is root of SCC, pop stack for all before it
-
-					let mut scc = SubgraphProxy::new(self.dfs.graph);
-					while stack.len() > index
+					*explored += 1;
+					if let Some(&v_index) = self.indices.get(&v)
 					{
-						scc.expand(stack.pop().unwrap().0).unwrap();
+						// Already visited, update lowlink if it's on the stack
+						let (v_lowlink, on_stack) = self.lowlinks[v_index];
+						if on_stack
+						{
+							self.lowlinks[index].0 = min(self.lowlinks[index].0, v_lowlink);
+						}
+					}
+					else
+					{
+						self.visit(v);
 					}
-
-					return Some(
-						ConnectedGraph::ensure(scc)
-							.expect("Tarjans algorithm produced non-strongly-connected subgraph"),
-					);
-					// return Some(ConnectedGraph::new(scc));
 				}
-				// Vertex is part of SCC but not root, keep it on stack.
-			}
+				else
+				{
+					// Finished visiting the vertex
+					self.call_stack.pop();
+					let lowlink = self.lowlinks[index].0;
+					if let Some((parent, _, _)) = self.call_stack.last()
+					{
+						self.lowlinks[*parent].0 = min(self.lowlinks[*parent].0, lowlink);
+					}
 
-			// No SCCs found, let the Dfs run once
-			if let Some(v) = self.dfs.next()
-			{
-				// First push vertex onto stack, with lowlink value equal to its index
-				let stack = &mut self.dfs.payload;
-				stack.push((v.clone(), stack.len()));
+					if lowlink == index
+					{
+						// Vertex is root of SCC, pop stack for all after it
+						let start = self
+							.stack
+							.iter()
+							.rposition(|v| self.indices[v] == index)
+							.unwrap();
+						let scc = self.stack.split_off(start);
+						for v in scc.iter()
+						{
+							self.lowlinks[self.indices[v]].1 = false;
+						}
+						return Some(strongly_connected_component(self.graph, scc));
+					}
+					// Vertex is part of SCC but not root, keep it on stack.
+				}
 			}
 			else
 			{
-				let dfs = &mut self.dfs;
-				if !self.unchecked.any(|v| dfs.continue_from(v))
-				{
-					return None;
-				}
+				let indices = &self.indices;
+				let v = self.unchecked.find(|v| !indices.contains_key(v))?;
+				self.visit(v);
 			}
 		}
 	}
@@ -0,0 +1,325 @@
+//! An implementation of Yen's algorithm for finding the K shortest simple
+//! paths between two vertices.
+//!
+//! The algorithm:
+//!
+//! The first path is simply the shortest path from the source to the sink.
+//! Every subsequent path is found by looking at the previously found path and,
+//! for each vertex on it (called the spur vertex), finding the shortest path
+//! that deviates from it at that vertex. The part of the previous path up to
+//! the spur vertex is called the root path. To ensure the new path deviates
+//! and doesn't revisit the root path, we temporarily remove:
+//!
+//! - The vertices of the root path, except for the spur vertex.
+//! - For each already found path sharing the same root path, the edge leaving
+//!   the spur vertex on that path.
+//!
+//! The shortest path from the spur vertex to the sink in the resulting graph
+//! (called the spur path) is then appended to the root path, giving a
+//! candidate for the next shortest path. After all spur vertices have been
+//! tried, the cheapest candidate found so far is the next shortest path.
+//!
+//! Implementation:
+//!
+//! The temporary removals are simulated by an `EdgeProxyGraph` over a
+//! `VertexProxyGraph`, such that the underlying graph isn't mutated. The
+//! shortest spur paths are found using `DijkstraShortestPaths` on the proxy.
+//!
+//! Paths are identified by their vertices. Therefore, if there are multiple
+//! edges between two vertices, only the one with the lowest weight is used.
+use crate::{
+	algo::DijkstraShortestPaths,
+	core::{
+		property::VertexInGraph,
+		proxy::{EdgeProxyGraph, ProxyVertex, VertexProxyGraph},
+		Ensure, Graph,
+	},
+};
+use num_traits::{PrimInt, Unsigned};
+use std::{borrow::Borrow, collections::HashMap, hash::Hash, iter::FusedIterator};
+
+/// The proxy the temporary removals are made on.
+type RemovalProxy<'a, G> = EdgeProxyGraph<VertexProxyGraph<&'a G>>;
+
+/// Implements [Yen's algorithm](https://en.wikipedia.org/wiki/Yen%27s_algorithm)
+/// for finding the K shortest [simple paths](https://mathworld.wolfram.com/SimplePath.html)
+/// from one vertex to another.
+///
+/// It implements [`Iterator`](https://doc.rust-lang.org/std/iter/trait.Iterator.html). [`next`]
+/// is therefore the primary way to use this struct.
+/// Each call to [`next`] finds the next shortest simple path from the source
+/// to the sink and returns its length together with the vertices of the path
+/// (including the source and sink). Paths are therefore returned in
+/// nondecreasing order of their lengths.
+/// The K shortest paths can be found using
+/// [`take`](https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.take).
+///
+/// When no more simple paths exist, [`next`] will return [`None`](https://doc.rust-lang.org/std/option/enum.Option.html#variant.None).
+///
+/// ### Usage
+///
+/// ```
+/// # use graphene::{
+/// # 	algo::YenShortestPaths,
+/// # 	common::AdjListGraph,
+/// # 	core::property::{AddEdge, NewVertex},
+/// # };
+/// let mut graph = AdjListGraph::<(), u32>::new();
+///
+/// let v0 = graph.new_vertex().unwrap();
+/// let v1 = graph.new_vertex().unwrap();
+/// let v2 = graph.new_vertex().unwrap();
+///
+/// graph.add_edge_weighted(&v0, &v1, 1).unwrap();
+/// graph.add_edge_weighted(&v1, &v2, 1).unwrap();
+/// graph.add_edge_weighted(&v0, &v2, 3).unwrap();
+///
+/// let mut paths = YenShortestPaths::new_simple(&graph, v0, v2);
+///
+/// assert_eq!(paths.next(), Some((2, vec![v0, v1, v2])));
+/// assert_eq!(paths.next(), Some((3, vec![v0, v2])));
+/// assert_eq!(paths.next(), None);
+/// ```
+///
+/// ### Related
+///
+/// - [`DijkstraShortestPaths`](struct.DijkstraShortestPaths.html): Used to find
+///   each spur path.
+///
+/// [`next`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#tymethod.next
+pub struct YenShortestPaths<'a, G, W>
+where
+	G: 'a + Graph,
+	G::Vertex: Hash,
+	W: PrimInt + Unsigned,
+{
+	graph: &'a G,
+	source: G::Vertex,
+	sink: G::Vertex,
+	get_weight: fn(&G::EdgeWeight) -> W,
+
+	/// The paths already returned.
+	found: Vec<Vec<G::Vertex>>,
+
+	/// Candidates for the next path.
+	///
+	/// We keep it sorted with the lowest weight at the end for efficiency.
+	candidates: Vec<(W, Vec<G::Vertex>)>,
+
+	/// Whether the first shortest path has been looked for.
+	started: bool,
+
+	/// Whether all paths have been found.
+	exhausted: bool,
+}
+
+impl<'a, G, W> YenShortestPaths<'a, G, W>
+where
+	G: 'a + Graph,
+	G::Vertex: Hash,
+	W: PrimInt + Unsigned,
+{
+	/// Constructs a new `YenShortestPaths` for finding the shortest simple
+	/// paths from `source` to `sink`, using `get_weight` to get the weight of
+	/// each edge.
+	///
+	/// If either vertex isn't in the graph, no paths are found.
+	pub fn new(
+		graph: &'a G,
+		source: impl Borrow<G::Vertex>,
+		sink: impl Borrow<G::Vertex>,
+		get_weight: fn(&G::EdgeWeight) -> W,
+	) -> Self
+	{
+		Self {
+			graph,
+			source: *source.borrow(),
+			sink: *sink.borrow(),
+			get_weight,
+			found: Vec::new(),
+			candidates: Vec::new(),
+			started: false,
+			exhausted: false,
+		}
+	}
+
+	/// Returns the length of the shortest path from `from` to the sink
+	/// in the given graph, and the vertices of the path.
+	fn shortest_path(&self, graph: &RemovalProxy<G>, from: G::Vertex)
+		-> Option<(W, Vec<G::Vertex>)>
+	{
+		// Each reached vertex with its predecessor and distance
+		let mut reached = HashMap::new();
+		reached.insert(from, (from, W::zero()));
+
+		if from != self.sink
+		{
+			let graph = VertexInGraph::ensure_unvalidated(graph, ProxyVertex::Underlying(from));
+			let get_weight = self.get_weight;
+			let sink = self.sink;
+			let found = DijkstraShortestPaths::new(&graph, get_weight).any(|(so, si, w)| {
+				let (so, si) = (underlying(so), underlying(si));
+				let dist = reached[&so].1 + get_weight(w);
+				reached.insert(si, (so, dist));
+				si == sink
+			});
+			if !found
+			{
+				return None;
+			}
+		}
+
+		let length = reached[&self.sink].1;
+		let mut path = vec![self.sink];
+		let mut current = self.sink;
+		while current != from
+		{
+			current = reached[&current].0;
+			path.push(current);
+		}
+		path.reverse();
+		Some((length, path))
+	}
+
+	/// Returns the weight of the lightest edge from `source` to `sink`.
+	fn edge_weight(&self, source: G::Vertex, sink: G::Vertex) -> W
+	{
+		self.graph
+			.edges_between(source, sink)
+			.map(self.get_weight)
+			.min()
+			.expect("Path contains nonexistent edge")
+	}
+
+	/// Adds a candidate if it hasn't been found before.
+	fn add_candidate(&mut self, length: W, path: Vec<G::Vertex>)
+	{
+		if !self.found.contains(&path) && !self.candidates.iter().any(|(_, p)| *p == path)
+		{
+			self.candidates.push((length, path));
+			self.candidates.sort_by(|(w1, _), (w2, _)| w2.cmp(w1));
+		}
+	}
+
+	/// Finds all the paths deviating from the given path and adds them as
+	/// candidates.
+	fn add_deviations(&mut self, previous: &[G::Vertex])
+	{
+		let mut root_length = W::zero();
+
+		for (i, &spur) in previous.iter().enumerate().take(previous.len() - 1)
+		{
+			let root = &previous[..=i];
+			let mut vertices = VertexProxyGraph::new(self.graph);
+			for &v in root[..i].iter()
+			{
+				vertices
+					.discard_vertex(ProxyVertex::Underlying(v))
+					.unwrap_or_else(|_| panic!("Path contains nonexistent vertex"));
+			}
+			let mut proxy = EdgeProxyGraph::new(vertices);
+			for path in self.found.iter()
+			{
+				if path.len() > i + 1 && path[..=i] == *root
+				{
+					let (source, sink) = (
+						ProxyVertex::Underlying(path[i]),
+						ProxyVertex::Underlying(path[i + 1]),
+					);
+					while proxy
+						.discard_edge_where_weight(source, sink, |_| true)
+						.is_ok()
+					{}
+				}
+			}
+
+			if let Some((spur_length, spur_path)) = self.shortest_path(&proxy, spur)
+			{
+				let mut path = root[..i].to_vec();
+				path.extend(spur_path);
+				self.add_candidate(root_length + spur_length, path);
+			}
+			root_length = root_length + self.edge_weight(spur, previous[i + 1]);
+		}
+	}
+}
+
+impl<'a, G> YenShortestPaths<'a, G, G::EdgeWeight>
+where
+	G: 'a + Graph,
+	G::Vertex: Hash,
+	G::EdgeWeight: PrimInt + Unsigned,
+{
+	/// Constructs a new `YenShortestPaths` for finding the shortest simple
+	/// paths from `source` to `sink`, using the edge weights directly.
+	pub fn new_simple(
+		graph: &'a G,
+		source: impl Borrow<G::Vertex>,
+		sink: impl Borrow<G::Vertex>,
+	) -> Self
+	{
+		Self::new(graph, source, sink, Clone::clone)
+	}
+}
+
+impl<'a, G, W> Iterator for YenShortestPaths<'a, G, W>
+where
+	G: 'a + Graph,
+	G::Vertex: Hash,
+	W: PrimInt + Unsigned,
+{
+	type Item = (W, Vec<G::Vertex>);
+
+	fn next(&mut self) -> Option<Self::Item>
+	{
+		if self.exhausted
+		{
+			return None;
+		}
+		if let Some(previous) = self.found.last().cloned()
+		{
+			self.add_deviations(&previous);
+		}
+		else if !self.started
+		{
+			self.started = true;
+			if self.graph.contains_vertex(self.source) && self.graph.contains_vertex(self.sink)
+			{
+				let proxy = EdgeProxyGraph::new(VertexProxyGraph::new(self.graph));
+				if let Some((length, path)) = self.shortest_path(&proxy, self.source)
+				{
+					self.add_candidate(length, path);
+				}
+			}
+		}
+
+		if let Some((length, path)) = self.candidates.pop()
+		{
+			self.found.push(path.clone());
+			Some((length, path))
+		}
+		else
+		{
+			self.exhausted = true;
+			None
+		}
+	}
+}
+
+impl<'a, G, W> FusedIterator for YenShortestPaths<'a, G, W>
+where
+	G: 'a + Graph,
+	G::Vertex: Hash,
+	W: PrimInt + Unsigned,
+{
+}
+
+/// Returns the vertex of the underlying graph that the proxy vertex represents.
+fn underlying<V: Copy + Eq>(v: ProxyVertex<V>) -> V
+{
+	match v
+	{
+		ProxyVertex::Underlying(v) => v,
+		ProxyVertex::New(_) => unreachable!("No vertices are added to the proxy"),
+	}
+}
@@ -1,8 +1,10 @@
 use crate::{
 	common::AdjListGraph,
 	core::{
-		property::{AddEdge, EdgeCount, NewVertex, RemoveEdge, RemoveVertex, VertexCount},
-		Directedness, Graph, GraphMut,
+		property::{
+			AddEdge, EdgeCount, MergeVertices, NewVertex, RemoveEdge, RemoveVertex, VertexCount,
+		},
+		Directedness, Graph, GraphError, GraphMut,
 	},
 };
 use std::borrow::Borrow;
@@ -67,6 +69,97 @@ where
 				),
 		)
 	}
+
+	fn vertex_weight(&self, v: impl Borrow<Self::Vertex>) -> Option<&Self::VertexWeight>
+	{
+		self.vertices.get(*v.borrow()).map(|(w, _)| w)
+	}
+
+	fn contains_vertex(&self, v: impl Borrow<Self::Vertex>) -> bool
+	{
+		*v.borrow() < self.vertices.len()
+	}
+
+	fn edges_sourced_in<'a: 'b, 'b>(
+		&'a self,
+		v: impl 'b + Borrow<Self::Vertex>,
+	) -> Box<dyn 'b + Iterator<Item = (Self::Vertex, &'a Self::EdgeWeight)>>
+	{
+		let v = *v.borrow();
+		let outgoing = self
+			.vertices
+			.get(v)
+			.into_iter()
+			.flat_map(|(_, edges)| edges.iter().map(|(sink, w)| (*sink, w)));
+
+		if D::directed()
+		{
+			Box::new(outgoing)
+		}
+		else
+		{
+			// Loops are already among the outgoing edges
+			Box::new(
+				outgoing.chain(
+					self.incoming_edges(v)
+						.filter(move |(source, _)| *source != v),
+				),
+			)
+		}
+	}
+
+	fn edges_sinked_in<'a: 'b, 'b>(
+		&'a self,
+		v: impl 'b + Borrow<Self::Vertex>,
+	) -> Box<dyn 'b + Iterator<Item = (Self::Vertex, &'a Self::EdgeWeight)>>
+	{
+		if D::directed()
+		{
+			Box::new(self.incoming_edges(*v.borrow()))
+		}
+		else
+		{
+			self.edges_sourced_in(v)
+		}
+	}
+}
+
+impl<Vw, Ew, D> AdjListGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	/// Returns the source and weight of each edge stored in the list of
+	/// another vertex with the given vertex as its sink.
+	fn incoming_edges(&self, v: usize) -> impl '_ + Iterator<Item = (usize, &Ew)>
+	{
+		self.incoming.get(v).into_iter().flat_map(move |sources| {
+			sources
+				.iter()
+				.map(move |(source, i)| (*source, &self.vertices[*source].1[*i].1))
+		})
+	}
+
+	/// Removes the edge at the given position in the list of the given source.
+	fn remove_edge_at(&mut self, source: usize, i: usize) -> Ew
+	{
+		let (sink, w) = self.vertices[source].1.remove(i);
+		let incoming = &mut self.incoming[sink];
+		let idx = incoming
+			.binary_search(&(source, i))
+			.expect("Edge missing from the incoming edges of its sink");
+		incoming.remove(idx);
+
+		// The edges after the removed one have moved down one position
+		for (j, (sink, _)) in self.vertices[source].1.iter().enumerate().skip(i)
+		{
+			let incoming = &mut self.incoming[*sink];
+			let idx = incoming
+				.binary_search(&(source, j + 1))
+				.expect("Edge missing from the incoming edges of its sink");
+			incoming[idx] = (source, j);
+		}
+		w
+	}
 }
 
 impl<Vw, Ew, D> GraphMut for AdjListGraph<Vw, Ew, D>
@@ -133,16 +226,26 @@ where
 				}),
 		)
 	}
+
+	fn vertex_weight_mut(&mut self, v: impl Borrow<Self::Vertex>)
+		-> Option<&mut Self::VertexWeight>
+	{
+		self.vertices.get_mut(*v.borrow()).map(|(w, _)| w)
+	}
 }
 
 impl<Vw, Ew, D> NewVertex for AdjListGraph<Vw, Ew, D>
 where
 	D: Directedness,
 {
-	fn new_vertex_weighted(&mut self, w: Self::VertexWeight) -> Result<Self::Vertex, ()>
+	fn new_vertex_weighted(
+		&mut self,
+		w: Self::VertexWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
 	{
 		let new_v = self.vertices.len();
 		self.vertices.push((w, Vec::new()));
+		self.incoming.push(Vec::new());
 		Ok(new_v)
 	}
 }
@@ -150,27 +253,33 @@ impl<Vw, Ew, D> RemoveVertex for AdjListGraph<Vw, Ew, D>
 where
 	D: Directedness,
 {
-	fn remove_vertex(&mut self, v: impl Borrow<Self::Vertex>) -> Result<Self::VertexWeight, ()>
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
 	{
-		if *v.borrow() < self.vertices.len()
+		let v = *v.borrow();
+		if v < self.vertices.len()
 		{
-			let neighbors: Vec<_> = self.vertex_neighbors(v.borrow()).collect();
-
-			for n in neighbors
+			let (w, _) = self.vertices.remove(v);
+			for (_, edges) in self.vertices.iter_mut()
 			{
-				while let Ok(_) = self.remove_edge(v.borrow(), &n)
-				{}
-				if D::directed()
+				edges.retain(|(sink, _)| *sink != v);
+				// The vertices after the removed one have moved down one index
+				for (sink, _) in edges.iter_mut()
 				{
-					while let Ok(_) = self.remove_edge(&n, v.borrow())
-					{}
+					if *sink > v
+					{
+						*sink -= 1;
+					}
 				}
 			}
-			Ok(self.vertices.remove(*v.borrow()).0)
+			self.index_incoming();
+			Ok(w)
 		}
 		else
 		{
-			Err(())
+			Err(GraphError::VertexNotFound(v))
 		}
 	}
 }
@@ -184,19 +293,29 @@ where
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		weight: Self::EdgeWeight,
-	) -> Result<(), ()>
+	) -> Result<(), GraphError<Self::Vertex>>
 	{
 		let len = self.vertices.len();
 		if *source.borrow() < len && *sink.borrow() < len
 		{
-			self.vertices[*source.borrow()]
-				.1
-				.push((*sink.borrow(), weight));
+			let (source, sink) = (*source.borrow(), *sink.borrow());
+			let edges = &mut self.vertices[source].1;
+			let edge = (source, edges.len());
+			edges.push((sink, weight));
+			// Keep the incoming edges in order, such that it doesn't depend on
+			// the order the edges were added in
+			let incoming = &mut self.incoming[sink];
+			let idx = incoming.binary_search(&edge).unwrap_err();
+			incoming.insert(idx, edge);
 			Ok(())
 		}
+		else if *source.borrow() >= len
+		{
+			Err(GraphError::VertexNotFound(*source.borrow()))
+		}
 		else
 		{
-			Err(())
+			Err(GraphError::VertexNotFound(*sink.borrow()))
 		}
 	}
 }
@@ -210,45 +329,108 @@ where
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		f: F,
-	) -> Result<Self::EdgeWeight, ()>
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
 	where
 		F: Fn(&Self::EdgeWeight) -> bool,
 	{
 		if self.contains_vertex(source.borrow()) && self.contains_vertex(sink.borrow())
 		{
-			let found = self
-				.vertices
-				.iter()
-				.enumerate()
-				.flat_map(|(so_i, (_, edges))| {
-					edges
-						.iter()
-						.enumerate()
-						.map(move |(si_i, (si, w))| ((so_i, si_i, si, w)))
-				})
-				.find(|(so_i, _, si, w)| {
-					((so_i == source.borrow() && *si == sink.borrow())
-						|| (!Self::Directedness::directed()
-							&& (so_i == sink.borrow() && *si == source.borrow())))
-						&& f(w)
-				});
-			if let Some((so_i, si_i, _, _)) = found
+			let (source, sink) = (*source.borrow(), *sink.borrow());
+			// Undirected edges may be stored by either vertex, so look through
+			// the lists in the order of the vertices.
+			let mut lists = vec![(source, sink)];
+			if !Self::Directedness::directed() && source != sink
 			{
-				let (_, w) = self.vertices[so_i].1.remove(si_i);
-				Ok(w)
+				lists.push((sink, source));
+				lists.sort();
+			}
+			let found = lists.into_iter().find_map(|(so, si)| {
+				self.vertices[so]
+					.1
+					.iter()
+					.position(|(s, w)| *s == si && f(w))
+					.map(|i| (so, i))
+			});
+			if let Some((so, i)) = found
+			{
+				Ok(self.remove_edge_at(so, i))
 			}
 			else
 			{
-				Err(())
+				Err(GraphError::EdgeNotFound(source, sink))
 			}
 		}
+		else if !self.contains_vertex(source.borrow())
+		{
+			Err(GraphError::VertexNotFound(*source.borrow()))
+		}
 		else
 		{
-			Err(())
+			Err(GraphError::VertexNotFound(*sink.borrow()))
 		}
 	}
 }
 
+impl<Vw, Ew, D> MergeVertices for AdjListGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	fn merge_vertices<F>(
+		&mut self,
+		v1: impl Borrow<Self::Vertex>,
+		v2: impl Borrow<Self::Vertex>,
+		combine: F,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
+	where
+		F: FnOnce(Self::VertexWeight, Self::VertexWeight) -> Self::VertexWeight,
+	{
+		let (v1, v2) = (*v1.borrow(), *v2.borrow());
+		if v1 >= self.vertices.len()
+		{
+			return Err(GraphError::VertexNotFound(v1));
+		}
+		if v2 >= self.vertices.len()
+		{
+			return Err(GraphError::VertexNotFound(v2));
+		}
+		if v1 == v2
+		{
+			return Ok(v1);
+		}
+
+		// Removing the second vertex shifts all vertices after it
+		let (w2, edges2) = self.vertices.remove(v2);
+		let merged = if v1 > v2 { v1 - 1 } else { v1 };
+		let redirect = move |v: usize| {
+			if v == v2
+			{
+				merged
+			}
+			else if v > v2
+			{
+				v - 1
+			}
+			else
+			{
+				v
+			}
+		};
+		for (_, edges) in self.vertices.iter_mut()
+		{
+			for (sink, _) in edges.iter_mut()
+			{
+				*sink = redirect(*sink);
+			}
+		}
+
+		let (w1, mut edges1) = self.vertices.remove(merged);
+		edges1.extend(edges2.into_iter().map(|(sink, w)| (redirect(sink), w)));
+		self.vertices.insert(merged, (combine(w1, w2), edges1));
+		self.index_incoming();
+		Ok(merged)
+	}
+}
+
 impl<Vw, Ew, D> VertexCount for AdjListGraph<Vw, Ew, D>
 where
 	D: Directedness,
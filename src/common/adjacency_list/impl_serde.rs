@@ -0,0 +1,49 @@
+use crate::{common::AdjListGraph, core::Directedness};
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+use std::marker::PhantomData;
+
+/// Serializes the graph as its list of vertices, each with its weight and the
+/// list of its outgoing edges.
+impl<Vw, Ew, D> Serialize for AdjListGraph<Vw, Ew, D>
+where
+	Vw: Serialize,
+	Ew: Serialize,
+	D: Directedness,
+{
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	{
+		self.vertices.serialize(serializer)
+	}
+}
+
+/// Deserializes a graph serialized by its `Serialize` implementation.
+///
+/// Fails if an edge is incident on a vertex that isn't in the graph.
+impl<'de, Vw, Ew, D> Deserialize<'de> for AdjListGraph<Vw, Ew, D>
+where
+	Vw: Deserialize<'de>,
+	Ew: Deserialize<'de>,
+	D: Directedness,
+{
+	fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error>
+	{
+		let vertices = Vec::<(Vw, Vec<(usize, Ew)>)>::deserialize(deserializer)?;
+		if let Some(sink) = vertices
+			.iter()
+			.flat_map(|(_, edges)| edges.iter().map(|(sink, _)| *sink))
+			.find(|sink| *sink >= vertices.len())
+		{
+			return Err(De::Error::custom(format!(
+				"edge incident on missing vertex {}",
+				sink
+			)));
+		}
+		let mut g = Self {
+			vertices,
+			incoming: Vec::new(),
+			phantom: PhantomData,
+		};
+		g.index_incoming();
+		Ok(g)
+	}
+}
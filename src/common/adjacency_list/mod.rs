@@ -1,8 +1,10 @@
 mod impl_graph;
+#[cfg(feature = "serde")]
+mod impl_serde;
 
 pub use self::impl_graph::*;
-use crate::core::{Directed, Directedness};
-use std::marker::PhantomData;
+use crate::core::{property::AddEdge, Directed, Directedness, GraphElement};
+use std::{iter::FromIterator, marker::PhantomData};
 
 /// A graph using the adjacency list representation.
 ///
@@ -29,6 +31,9 @@ where
 			Ew,
 		)>,
 	)>,
+	/// The edges sinked in each vertex, as the index of their source and their
+	/// position in its list of outgoing edges, in order.
+	incoming: Vec<Vec<(usize, usize)>>,
 	phantom: PhantomData<D>,
 }
 
@@ -41,7 +46,84 @@ where
 	{
 		Self {
 			vertices: Vec::new(),
+			incoming: Vec::new(),
 			phantom: PhantomData,
 		}
 	}
+
+	/// Rebuilds the list of edges sinked in each vertex from the lists of
+	/// outgoing edges.
+	fn index_incoming(&mut self)
+	{
+		self.incoming = vec![Vec::new(); self.vertices.len()];
+		for (source, (_, edges)) in self.vertices.iter().enumerate()
+		{
+			for (i, (sink, _)) in edges.iter().enumerate()
+			{
+				self.incoming[*sink].push((source, i));
+			}
+		}
+	}
+}
+
+impl<Vw, Ew, D> Default for AdjListGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
+
+/// Adds the given vertices and edges in order.
+///
+/// Each added vertex gets the next index, so edges can refer to vertices added
+/// earlier by the same iterator.
+///
+/// ### Panics
+///
+/// If an edge is incident on a vertex that isn't in the graph when the edge is
+/// added.
+impl<Vw, Ew, D> Extend<GraphElement<usize, Vw, Ew>> for AdjListGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	fn extend<I: IntoIterator<Item = GraphElement<usize, Vw, Ew>>>(&mut self, iter: I)
+	{
+		for element in iter
+		{
+			match element
+			{
+				GraphElement::Vertex(w) =>
+				{
+					self.vertices.push((w, Vec::new()));
+					self.incoming.push(Vec::new());
+				},
+				GraphElement::Edge(source, sink, w) =>
+				{
+					self.add_edge_weighted(source, sink, w)
+						.expect("Edge incident on a missing vertex");
+				},
+			}
+		}
+	}
+}
+
+/// Creates a graph with the given vertices and edges, such that the first
+/// vertex is `0`, the second is `1`, and so on.
+///
+/// ### Panics
+///
+/// If an edge is incident on a vertex that hasn't been added before it.
+impl<Vw, Ew, D> FromIterator<GraphElement<usize, Vw, Ew>> for AdjListGraph<Vw, Ew, D>
+where
+	D: Directedness,
+{
+	fn from_iter<I: IntoIterator<Item = GraphElement<usize, Vw, Ew>>>(iter: I) -> Self
+	{
+		let mut g = Self::new();
+		g.extend(iter);
+		g
+	}
 }
@@ -2,5 +2,9 @@
 
 mod adjacency_list;
 mod ensured;
+#[cfg(feature = "persistent")]
+mod persistent;
 
+#[cfg(feature = "persistent")]
+pub use self::persistent::*;
 pub use self::{adjacency_list::*, ensured::*};
@@ -0,0 +1,294 @@
+use crate::{
+	common::PersistentGraph,
+	core::{
+		property::{AddEdge, EdgeCount, NewVertex, RemoveEdge, RemoveVertex, VertexCount},
+		Directedness, Graph, GraphError, GraphMut,
+	},
+};
+use std::borrow::Borrow;
+
+impl<Vw, Ew, D> Graph for PersistentGraph<Vw, Ew, D>
+where
+	Vw: Clone,
+	Ew: Clone,
+	D: Directedness,
+{
+	type Directedness = D;
+	type EdgeWeight = Ew;
+	type Vertex = usize;
+	type VertexWeight = Vw;
+
+	fn all_vertices_weighted<'a>(
+		&'a self,
+	) -> Box<dyn 'a + Iterator<Item = (Self::Vertex, &'a Self::VertexWeight)>>
+	{
+		Box::new(self.vertices.iter().map(|(v, w)| (*v, w)))
+	}
+
+	fn vertex_weight(&self, v: impl Borrow<Self::Vertex>) -> Option<&Self::VertexWeight>
+	{
+		self.vertices.get(v.borrow())
+	}
+
+	fn contains_vertex(&self, v: impl Borrow<Self::Vertex>) -> bool
+	{
+		self.vertices.contains_key(v.borrow())
+	}
+
+	fn edges_between<'a: 'b, 'b>(
+		&'a self,
+		source: impl 'b + Borrow<Self::Vertex>,
+		sink: impl 'b + Borrow<Self::Vertex>,
+	) -> Box<dyn 'b + Iterator<Item = &'a Self::EdgeWeight>>
+	{
+		Box::new(
+			self.edges
+				.get(&Self::key(*source.borrow(), *sink.borrow()))
+				.into_iter()
+				.flat_map(|weights| weights.iter()),
+		)
+	}
+
+	fn all_edges<'a>(
+		&'a self,
+	) -> Box<dyn 'a + Iterator<Item = (Self::Vertex, Self::Vertex, &'a Self::EdgeWeight)>>
+	{
+		Box::new(
+			self.edges.iter().flat_map(|((source, sink), weights)| {
+				weights.iter().map(move |w| (*source, *sink, w))
+			}),
+		)
+	}
+
+	fn edges_sourced_in<'a: 'b, 'b>(
+		&'a self,
+		v: impl 'b + Borrow<Self::Vertex>,
+	) -> Box<dyn 'b + Iterator<Item = (Self::Vertex, &'a Self::EdgeWeight)>>
+	{
+		let v = *v.borrow();
+		Box::new(
+			self.adjacent
+				.get(&v)
+				.into_iter()
+				.flat_map(|adjacent| adjacent.iter())
+				.flat_map(move |sink| self.edges_between(v, *sink).map(move |w| (*sink, w))),
+		)
+	}
+
+	fn edges_sinked_in<'a: 'b, 'b>(
+		&'a self,
+		v: impl 'b + Borrow<Self::Vertex>,
+	) -> Box<dyn 'b + Iterator<Item = (Self::Vertex, &'a Self::EdgeWeight)>>
+	{
+		let v = *v.borrow();
+		Box::new(
+			self.adjacent
+				.get(&v)
+				.into_iter()
+				.flat_map(|adjacent| adjacent.iter())
+				.flat_map(move |source| self.edges_between(*source, v).map(move |w| (*source, w))),
+		)
+	}
+}
+
+impl<Vw, Ew, D> GraphMut for PersistentGraph<Vw, Ew, D>
+where
+	Vw: Clone,
+	Ew: Clone,
+	D: Directedness,
+{
+	fn all_vertices_weighted_mut<'a>(
+		&'a mut self,
+	) -> Box<dyn 'a + Iterator<Item = (Self::Vertex, &'a mut Self::VertexWeight)>>
+	{
+		Box::new(self.vertices.iter_mut().map(|(v, w)| (*v, w)))
+	}
+
+	fn vertex_weight_mut(&mut self, v: impl Borrow<Self::Vertex>)
+		-> Option<&mut Self::VertexWeight>
+	{
+		self.vertices.get_mut(v.borrow())
+	}
+
+	fn edges_between_mut<'a: 'b, 'b>(
+		&'a mut self,
+		source: impl 'b + Borrow<Self::Vertex>,
+		sink: impl 'b + Borrow<Self::Vertex>,
+	) -> Box<dyn 'b + Iterator<Item = &'a mut Self::EdgeWeight>>
+	{
+		Box::new(
+			self.edges
+				.get_mut(&Self::key(*source.borrow(), *sink.borrow()))
+				.into_iter()
+				.flat_map(|weights| weights.iter_mut()),
+		)
+	}
+}
+
+impl<Vw, Ew, D> NewVertex for PersistentGraph<Vw, Ew, D>
+where
+	Vw: Clone,
+	Ew: Clone,
+	D: Directedness,
+{
+	fn new_vertex_weighted(
+		&mut self,
+		w: Self::VertexWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
+	{
+		let v = self.next_vertex;
+		self.next_vertex += 1;
+		self.vertices.insert(v, w);
+		Ok(v)
+	}
+}
+
+impl<Vw, Ew, D> RemoveVertex for PersistentGraph<Vw, Ew, D>
+where
+	Vw: Clone,
+	Ew: Clone,
+	D: Directedness,
+{
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
+	{
+		let v = *v.borrow();
+		let w = self
+			.vertices
+			.remove(&v)
+			.ok_or(GraphError::VertexNotFound(v))?;
+
+		for other in self.adjacent.remove(&v).unwrap_or_default()
+		{
+			for key in &[Self::key(v, other), Self::key(other, v)]
+			{
+				if let Some(weights) = self.edges.remove(key)
+				{
+					self.edge_count -= weights.len();
+				}
+			}
+			if let Some(adjacent) = self.adjacent.get_mut(&other)
+			{
+				adjacent.remove(&v);
+			}
+		}
+		Ok(w)
+	}
+}
+
+impl<Vw, Ew, D> AddEdge for PersistentGraph<Vw, Ew, D>
+where
+	Vw: Clone,
+	Ew: Clone,
+	D: Directedness,
+{
+	fn add_edge_weighted(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+		weight: Self::EdgeWeight,
+	) -> Result<(), GraphError<Self::Vertex>>
+	{
+		let (source, sink) = (*source.borrow(), *sink.borrow());
+		if !self.contains_vertex(source)
+		{
+			return Err(GraphError::VertexNotFound(source));
+		}
+		if !self.contains_vertex(sink)
+		{
+			return Err(GraphError::VertexNotFound(sink));
+		}
+
+		self.edges
+			.entry(Self::key(source, sink))
+			.or_default()
+			.push_back(weight);
+		self.adjacent.entry(source).or_default().insert(sink);
+		self.adjacent.entry(sink).or_default().insert(source);
+		self.edge_count += 1;
+		Ok(())
+	}
+}
+
+impl<Vw, Ew, D> RemoveEdge for PersistentGraph<Vw, Ew, D>
+where
+	Vw: Clone,
+	Ew: Clone,
+	D: Directedness,
+{
+	fn remove_edge_where_weight<F>(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+		f: F,
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
+	where
+		F: Fn(&Self::EdgeWeight) -> bool,
+	{
+		let (source, sink) = (*source.borrow(), *sink.borrow());
+		let key = Self::key(source, sink);
+		let weights = self
+			.edges
+			.get_mut(&key)
+			.ok_or(GraphError::EdgeNotFound(source, sink))?;
+		let index = weights
+			.iter()
+			.position(f)
+			.ok_or(GraphError::EdgeNotFound(source, sink))?;
+		let w = weights.remove(index);
+		self.edge_count -= 1;
+
+		// The vertices are no longer adjacent if no edges are left between them
+		if weights.is_empty()
+		{
+			self.edges.remove(&key);
+			if !self.edges.contains_key(&Self::key(sink, source))
+			{
+				if let Some(adjacent) = self.adjacent.get_mut(&source)
+				{
+					adjacent.remove(&sink);
+				}
+				if let Some(adjacent) = self.adjacent.get_mut(&sink)
+				{
+					adjacent.remove(&source);
+				}
+			}
+		}
+		Ok(w)
+	}
+}
+
+impl<Vw, Ew, D> VertexCount for PersistentGraph<Vw, Ew, D>
+where
+	Vw: Clone,
+	Ew: Clone,
+	D: Directedness,
+{
+	type Count = usize;
+
+	fn vertex_count(&self) -> Self::Count
+	{
+		self.vertices.len()
+	}
+}
+
+impl<Vw, Ew, D> EdgeCount for PersistentGraph<Vw, Ew, D>
+where
+	Vw: Clone,
+	Ew: Clone,
+	D: Directedness,
+{
+	type Count = usize;
+
+	fn edge_count(&self) -> Self::Count
+	{
+		self.edge_count
+	}
+}
+
+base_graph! {
+	use<Vw, Ew, D> PersistentGraph<Vw, Ew, D>
+	where Vw: Clone, Ew: Clone, D: Directedness,
+}
@@ -0,0 +1,183 @@
+mod impl_graph;
+
+use crate::core::{
+	property::{AddEdge, NewVertex, RemoveEdge, RemoveVertex},
+	Directed, Directedness, GraphError,
+};
+use im::{HashMap, HashSet, Vector};
+use std::{borrow::Borrow, marker::PhantomData};
+
+/// A persistent graph, where versions share their structure.
+///
+/// Cloning the graph takes constant time, and the clone shares all of its
+/// vertices and edges with the original.
+/// Mutating either of them afterwards only copies the parts of the graph
+/// that are changed, in logarithmic time.
+/// This makes it cheap to keep many versions of a graph that differ by only a
+/// few vertices or edges.
+///
+/// Besides the usual mutation traits, e.g.
+/// [`NewVertex`](../core/property/trait.NewVertex.html) and
+/// [`AddEdge`](../core/property/trait.AddEdge.html), the graph has functional
+/// counterparts that return a new version instead of mutating, e.g.
+/// [`with_vertex_weighted`](#method.with_vertex_weighted) and
+/// [`with_edge_weighted`](#method.with_edge_weighted).
+///
+/// Vertices are not reused after being removed.
+///
+/// It accepts the following generic parameters:
+/// - `Vw`: Vertex weights.
+/// - `Ew`: Edge weights.
+/// - `D`: Whether the graph should be directed or undirected. Defaults to
+///   [Directed](../core/struct.Directed.html).
+///
+/// ### Usage
+/// ```
+/// # use graphene::{common::PersistentGraph, core::Graph};
+/// let empty = PersistentGraph::<(), ()>::new();
+/// let (one, v0) = empty.with_vertex();
+/// let (two, v1) = one.with_vertex();
+/// let connected = two.with_edge(&v0, &v1).unwrap();
+///
+/// assert_eq!(empty.all_vertices().count(), 0);
+/// assert_eq!(two.all_edges().count(), 0);
+/// assert_eq!(connected.all_edges().count(), 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct PersistentGraph<Vw, Ew, D = Directed>
+where
+	Vw: Clone,
+	Ew: Clone,
+	D: Directedness,
+{
+	/// The weight of each vertex.
+	vertices: HashMap<usize, Vw>,
+
+	/// The weights of the edges between each pair of vertices.
+	///
+	/// If the graph is undirected, the smaller vertex is first.
+	edges: HashMap<(usize, usize), Vector<Ew>>,
+
+	/// The vertices each vertex has edges to or from.
+	adjacent: HashMap<usize, HashSet<usize>>,
+
+	/// The total number of edges.
+	edge_count: usize,
+
+	/// The vertex to give the next new vertex.
+	next_vertex: usize,
+
+	phantom: PhantomData<D>,
+}
+
+impl<Vw, Ew, D> PersistentGraph<Vw, Ew, D>
+where
+	Vw: Clone,
+	Ew: Clone,
+	D: Directedness,
+{
+	/// Constructs a new, empty `PersistentGraph`.
+	pub fn new() -> Self
+	{
+		Self {
+			vertices: HashMap::new(),
+			edges: HashMap::new(),
+			adjacent: HashMap::new(),
+			edge_count: 0,
+			next_vertex: 0,
+			phantom: PhantomData,
+		}
+	}
+
+	/// Returns the key of the edges between the given vertices.
+	fn key(source: usize, sink: usize) -> (usize, usize)
+	{
+		if D::directed() || source <= sink
+		{
+			(source, sink)
+		}
+		else
+		{
+			(sink, source)
+		}
+	}
+
+	/// Returns a new version of the graph with a new vertex with the given
+	/// weight, and the new vertex.
+	pub fn with_vertex_weighted(&self, w: Vw) -> (Self, usize)
+	{
+		let mut g = self.clone();
+		let v = g.new_vertex_weighted(w).unwrap_or_else(|_| unreachable!());
+		(g, v)
+	}
+
+	/// Returns a new version of the graph with a new vertex with the default
+	/// weight, and the new vertex.
+	pub fn with_vertex(&self) -> (Self, usize)
+	where
+		Vw: Default,
+	{
+		self.with_vertex_weighted(Vw::default())
+	}
+
+	/// Returns a new version of the graph without the given vertex and the
+	/// edges incident on it, and the weight of the vertex.
+	pub fn without_vertex(&self, v: impl Borrow<usize>) -> Result<(Self, Vw), GraphError<usize>>
+	{
+		let mut g = self.clone();
+		let w = g.remove_vertex(v)?;
+		Ok((g, w))
+	}
+
+	/// Returns a new version of the graph with an edge between the given
+	/// vertices with the given weight.
+	pub fn with_edge_weighted(
+		&self,
+		source: impl Borrow<usize>,
+		sink: impl Borrow<usize>,
+		weight: Ew,
+	) -> Result<Self, GraphError<usize>>
+	{
+		let mut g = self.clone();
+		g.add_edge_weighted(source, sink, weight)?;
+		Ok(g)
+	}
+
+	/// Returns a new version of the graph with an edge between the given
+	/// vertices with the default weight.
+	pub fn with_edge(
+		&self,
+		source: impl Borrow<usize>,
+		sink: impl Borrow<usize>,
+	) -> Result<Self, GraphError<usize>>
+	where
+		Ew: Default,
+	{
+		self.with_edge_weighted(source, sink, Ew::default())
+	}
+
+	/// Returns a new version of the graph without an edge between the given
+	/// vertices, and the weight of the edge.
+	pub fn without_edge(
+		&self,
+		source: impl Borrow<usize>,
+		sink: impl Borrow<usize>,
+	) -> Result<(Self, Ew), GraphError<usize>>
+	{
+		let mut g = self.clone();
+		let w = g.remove_edge(source, sink)?;
+		Ok((g, w))
+	}
+}
+
+impl<Vw, Ew, D> Default for PersistentGraph<Vw, Ew, D>
+where
+	Vw: Clone,
+	Ew: Clone,
+	D: Directedness,
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
@@ -0,0 +1,137 @@
+use crate::core::{
+	property::{AddEdge, NewVertex},
+	Graph, GraphError,
+};
+use std::{collections::HashMap, hash::Hash};
+
+/// Which vertex of the target graph each vertex of a copied graph was added
+/// as.
+pub type Copies<G, T> = HashMap<<G as Graph>::Vertex, <T as Graph>::Vertex>;
+
+/// The new graph created from a copied graph, along with which of its vertices
+/// each vertex of the copied graph was added as.
+type FromGraphResult<G, T> = Result<(T, Copies<G, T>), GraphError<<T as Graph>::Vertex>>;
+
+/// A vertex or an edge to be added to a graph, e.g. when collecting a graph
+/// from an iterator.
+///
+/// Edges refer to vertices as they are given by the graph being added to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GraphElement<V, Vw, Ew>
+{
+	/// A vertex with the given weight.
+	Vertex(Vw),
+
+	/// An edge from the first vertex to the second with the given weight.
+	Edge(V, V, Ew),
+}
+
+/// Adds the vertices and edges of the given graph to the target graph, with
+/// cloned weights.
+///
+/// Returns which vertex of the target graph each vertex of the given graph was
+/// added as.
+/// If the target graph rejects a vertex or an edge, the error is returned and
+/// whatever was added before it remains.
+///
+/// ### Usage
+/// ```
+/// # use graphene::{
+/// # 	common::AdjListGraph,
+/// # 	core::{copy_into, property::{AddEdge, NewVertex}, proxy::ReverseGraph, Graph},
+/// # };
+/// let mut graph = AdjListGraph::<u32, ()>::new();
+/// let v0 = graph.new_vertex_weighted(0).unwrap();
+/// let v1 = graph.new_vertex_weighted(1).unwrap();
+/// graph.add_edge(&v0, &v1).unwrap();
+///
+/// let mut reversed = AdjListGraph::new();
+/// let copies = copy_into(&ReverseGraph::new(&graph), &mut reversed).unwrap();
+///
+/// assert!(reversed.edges_between(copies[&v1], copies[&v0]).next().is_some());
+/// assert_eq!(reversed.vertex_weight(copies[&v1]), Some(&1));
+/// ```
+pub fn copy_into<G, T>(g: &G, target: &mut T) -> Result<Copies<G, T>, GraphError<T::Vertex>>
+where
+	G: Graph,
+	G::Vertex: Hash,
+	G::VertexWeight: Clone,
+	G::EdgeWeight: Clone,
+	T: NewVertex
+		+ AddEdge<
+			VertexWeight = G::VertexWeight,
+			EdgeWeight = G::EdgeWeight,
+			Directedness = G::Directedness,
+		>,
+{
+	copy_into_mapped(g, target, Clone::clone, Clone::clone)
+}
+
+/// Adds the vertices and edges of the given graph to the target graph, with
+/// their weights given by the given functions.
+///
+/// See [`copy_into`](fn.copy_into.html).
+pub fn copy_into_mapped<G, T>(
+	g: &G,
+	target: &mut T,
+	vertex_weight: impl Fn(&G::VertexWeight) -> T::VertexWeight,
+	edge_weight: impl Fn(&G::EdgeWeight) -> T::EdgeWeight,
+) -> Result<Copies<G, T>, GraphError<T::Vertex>>
+where
+	G: Graph,
+	G::Vertex: Hash,
+	T: NewVertex + AddEdge<Directedness = G::Directedness>,
+{
+	let mut copies = HashMap::new();
+	for (v, w) in g.all_vertices_weighted()
+	{
+		copies.insert(v, target.new_vertex_weighted(vertex_weight(w))?);
+	}
+	for (source, sink, w) in g.all_edges()
+	{
+		target.add_edge_weighted(copies[&source], copies[&sink], edge_weight(w))?;
+	}
+	Ok(copies)
+}
+
+/// Creates a new graph with the vertices and edges of the given graph, with
+/// cloned weights.
+///
+/// Returns the new graph and which of its vertices each vertex of the given
+/// graph was added as.
+/// See [`copy_into`](fn.copy_into.html).
+pub fn from_graph<G, T>(g: &G) -> FromGraphResult<G, T>
+where
+	G: Graph,
+	G::Vertex: Hash,
+	G::VertexWeight: Clone,
+	G::EdgeWeight: Clone,
+	T: Default
+		+ NewVertex
+		+ AddEdge<
+			VertexWeight = G::VertexWeight,
+			EdgeWeight = G::EdgeWeight,
+			Directedness = G::Directedness,
+		>,
+{
+	from_graph_mapped(g, Clone::clone, Clone::clone)
+}
+
+/// Creates a new graph with the vertices and edges of the given graph, with
+/// their weights given by the given functions.
+///
+/// See [`from_graph`](fn.from_graph.html).
+pub fn from_graph_mapped<G, T>(
+	g: &G,
+	vertex_weight: impl Fn(&G::VertexWeight) -> T::VertexWeight,
+	edge_weight: impl Fn(&G::EdgeWeight) -> T::EdgeWeight,
+) -> FromGraphResult<G, T>
+where
+	G: Graph,
+	G::Vertex: Hash,
+	T: Default + NewVertex + AddEdge<Directedness = G::Directedness>,
+{
+	let mut target = T::default();
+	let copies = copy_into_mapped(g, &mut target, vertex_weight, edge_weight)?;
+	Ok((target, copies))
+}
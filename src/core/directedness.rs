@@ -10,6 +10,7 @@ pub trait Directedness: Copy + Clone + Send + Sync + 'static + Eq + Ord
 
 /// Marker type for something that is directed.
 #[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Directed();
 impl Directedness for Directed
 {
@@ -20,6 +21,7 @@ impl Directedness for Directed
 }
 /// Marker type for something that is not directed.
 #[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Undirected();
 impl Directedness for Undirected
 {
@@ -1,4 +1,4 @@
-use crate::core::{Graph, GraphDeref};
+use crate::core::{Graph, GraphDeref, GraphError, Rebase, Transaction, TransactionProxy};
 use std::ops::Deref;
 
 pub trait Payload<B>
@@ -37,6 +37,15 @@ impl<A, B> Payload<B> for (A, B)
 	}
 }
 
+/// The type of the vertices of the base graph of an ensurer.
+///
+/// Errors from ensuring properties use this vertex type, such that errors from
+/// all levels of ensurers have the same type.
+pub type BaseVertex<E> = <<<E as Release>::Base as GraphDeref>::Graph as Graph>::Vertex;
+
+/// The type of the vertices of the graph an ensurer ensures.
+pub type EnsuredVertex<E> = <<<E as Release>::Ensured as GraphDeref>::Graph as Graph>::Vertex;
+
 /// A marker trait that specifies that the type is a base implementation of a
 /// graph with fixed properties that cannot be removed.
 ///
@@ -51,7 +60,7 @@ impl<A, B> Payload<B> for (A, B)
 /// it possible.)
 pub trait BaseGraph: Sized + GraphDeref
 {
-	fn ensure_all<G>(self, p: G::Payload) -> Result<G, ()>
+	fn ensure_all<G>(self, p: G::Payload) -> Result<G, GraphError<BaseVertex<G>>>
 	where
 		G: Ensure<Base = Self>,
 	{
@@ -60,7 +69,7 @@ pub trait BaseGraph: Sized + GraphDeref
 }
 pub trait BaseGraphUnloaded: BaseGraph
 {
-	fn ensure_all<G>(self) -> Result<G, ()>
+	fn ensure_all<G>(self) -> Result<G, GraphError<BaseVertex<G>>>
 	where
 		G: Ensure<Base = Self>,
 		G::Payload: Payload<(), Item = ()>,
@@ -84,26 +93,66 @@ pub trait Ensure: Release
 		p: &<Self::Payload as Payload<<Self::Ensured as Release>::Payload>>::Item,
 	) -> bool;
 
-	fn ensure(
-		c: Self::Ensured,
-		p: <Self::Payload as Payload<<Self::Ensured as Release>::Payload>>::Item,
-	) -> Result<Self, ()>
+	/// Validates the given graph like [`validate`](#tymethod.validate), but
+	/// describes how the property is violated, if it is.
+	///
+	/// On failure, returns
+	/// [`PropertyViolated`](enum.GraphError.html#variant.PropertyViolated)
+	/// with a witness of the violation.
+	/// By default, the witness is empty. Ensurers that can point out the
+	/// offending vertices should override this method.
+	fn diagnose(
+		c: &Self::Ensured,
+		p: &<Self::Payload as Payload<<Self::Ensured as Release>::Payload>>::Item,
+	) -> Result<(), GraphError<EnsuredVertex<Self>>>
 	{
-		if Self::validate(&c, &p)
+		if Self::validate(c, p)
 		{
-			Ok(Self::ensure_unvalidated(c, p))
+			Ok(())
 		}
 		else
 		{
-			Err(())
+			Err(GraphError::violated(Self::property_name()))
 		}
 	}
 
-	fn ensure_all(g: Self::Base, p: Self::Payload) -> Result<Self, ()>
+	/// The name of the property ensured, used when reporting that it was
+	/// violated.
+	fn property_name() -> &'static str
+	{
+		std::any::type_name::<Self>()
+	}
+
+	/// Ensures the given graph, if it has the property.
+	///
+	/// Otherwise, returns the error from [`diagnose`](#method.diagnose).
+	fn ensure(
+		c: Self::Ensured,
+		p: <Self::Payload as Payload<<Self::Ensured as Release>::Payload>>::Item,
+	) -> Result<Self, GraphError<BaseVertex<Self>>>
+	{
+		Self::diagnose(&c, &p)?;
+		Ok(Self::ensure_unvalidated(c, p))
+	}
+
+	/// Ensures the given base graph on all levels, starting from the innermost.
+	///
+	/// Fails with the error of the first level whose property doesn't hold.
+	fn ensure_all(g: Self::Base, p: Self::Payload) -> Result<Self, GraphError<BaseVertex<Self>>>
 	{
 		let (p, rest) = p.split();
 		Self::ensure(Self::Ensured::ensure_all(g, rest)?, p)
 	}
+
+	/// Starts a [`Transaction`](struct.Transaction.html) on this graph, such
+	/// that multiple mutations can be made before the properties are
+	/// validated.
+	fn transaction<'a>(&'a mut self) -> Transaction<'a, Self>
+	where
+		Self: Graph + Rebase<TransactionProxy<'a, Self>>,
+	{
+		Transaction::new(self)
+	}
 }
 pub trait EnsureUnloaded: Ensure
 where
@@ -118,11 +167,15 @@ where
 	{
 		<Self as Ensure>::validate(c, &())
 	}
-	fn ensure(c: Self::Ensured) -> Result<Self, ()>
+	fn diagnose(c: &Self::Ensured) -> Result<(), GraphError<EnsuredVertex<Self>>>
+	{
+		<Self as Ensure>::diagnose(c, &())
+	}
+	fn ensure(c: Self::Ensured) -> Result<Self, GraphError<BaseVertex<Self>>>
 	{
 		<Self as Ensure>::ensure(c, ())
 	}
-	fn ensure_all(g: Self::Base) -> Result<Self, ()>
+	fn ensure_all(g: Self::Base) -> Result<Self, GraphError<BaseVertex<Self>>>
 	where
 		<Self as Release>::Payload: Payload<(), Item = ()>,
 	{
@@ -132,11 +185,14 @@ where
 
 pub trait Release: Sized + GraphDeref
 {
-	/// The base graph implementation being ensured
-	type Base: BaseGraph;
+	/// The base graph implementation being ensured.
+	///
+	/// It has the same vertices as the ensurer, such that errors from all
+	/// levels of ensurers can use the base graph's vertices.
+	type Base: BaseGraph + GraphDeref<Graph: Graph<Vertex = <Self::Graph as Graph>::Vertex>>;
 
 	/// The next level of properties.
-	type Ensured: Ensure<Base = Self::Base>;
+	type Ensured: Ensure<Base = Self::Base, Graph: Graph<Vertex = <Self::Graph as Graph>::Vertex>>;
 	type Payload: Payload<<Self::Ensured as Release>::Payload>;
 
 	/// Release only this level's properties, maintaining
@@ -193,7 +249,12 @@ impl<B: BaseGraph> Ensure for B
 		true
 	}
 
-	fn ensure_all(g: Self::Base, _: Self::Payload) -> Result<Self, ()>
+	fn property_name() -> &'static str
+	{
+		"Base"
+	}
+
+	fn ensure_all(g: Self::Base, _: Self::Payload) -> Result<Self, GraphError<BaseVertex<Self>>>
 	{
 		Ensure::ensure(g, ())
 	}
@@ -0,0 +1,100 @@
+use std::{
+	error::Error,
+	fmt::{Debug, Display, Formatter},
+};
+
+/// The error returned by fallible graph operations.
+///
+/// It is generic over the type of the graph's vertices, such that it can
+/// point out which vertices caused the error.
+///
+/// ### Related
+///
+/// - [`Ensure::ensure`](trait.Ensure.html#method.ensure): Returns
+///   [`PropertyViolated`](#variant.PropertyViolated) when the graph doesn't
+///   have the ensured property.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GraphError<V>
+{
+	/// The given vertex isn't in the graph.
+	VertexNotFound(V),
+
+	/// No edge matching the one given, from the first vertex to the second,
+	/// is in the graph.
+	EdgeNotFound(V, V),
+
+	/// The graph doesn't have (or the operation would have resulted in it not
+	/// having) the named property.
+	///
+	/// The witness holds the vertices that show how the property was violated.
+	/// What they are depends on the property, and it may be empty if no such
+	/// vertices are known.
+	PropertyViolated
+	{
+		property: &'static str,
+		witness: Vec<V>,
+	},
+
+	/// The graph doesn't support the operation.
+	Unsupported,
+}
+
+impl<V> GraphError<V>
+{
+	/// Constructs a [`PropertyViolated`](#variant.PropertyViolated) error
+	/// without a witness.
+	pub fn violated(property: &'static str) -> Self
+	{
+		Self::PropertyViolated {
+			property,
+			witness: Vec::new(),
+		}
+	}
+
+	/// Converts the vertices of the error using the given function.
+	///
+	/// Useful for converting errors from a proxy graph to errors of the
+	/// underlying graph.
+	pub fn map_vertices<V2>(self, f: impl Fn(V) -> V2) -> GraphError<V2>
+	{
+		match self
+		{
+			Self::VertexNotFound(v) => GraphError::VertexNotFound(f(v)),
+			Self::EdgeNotFound(source, sink) => GraphError::EdgeNotFound(f(source), f(sink)),
+			Self::PropertyViolated { property, witness } =>
+			{
+				GraphError::PropertyViolated {
+					property,
+					witness: witness.into_iter().map(f).collect(),
+				}
+			},
+			Self::Unsupported => GraphError::Unsupported,
+		}
+	}
+}
+
+impl<V: Debug> Display for GraphError<V>
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result
+	{
+		match self
+		{
+			Self::VertexNotFound(v) => write!(f, "Vertex {:?} not found in graph", v),
+			Self::EdgeNotFound(source, sink) =>
+			{
+				write!(f, "Edge from {:?} to {:?} not found in graph", source, sink)
+			},
+			Self::PropertyViolated { property, witness } if witness.is_empty() =>
+			{
+				write!(f, "Property '{}' violated", property)
+			},
+			Self::PropertyViolated { property, witness } =>
+			{
+				write!(f, "Property '{}' violated by {:?}", property, witness)
+			},
+			Self::Unsupported => write!(f, "Operation not supported by graph"),
+		}
+	}
+}
+
+impl<V: Debug> Error for GraphError<V> {}
@@ -1,12 +1,20 @@
 //! Contains the basic traits and structs needed to define graphs and work on
 //! them.
+mod convert;
 mod deref_graph;
 mod directedness;
 mod edge;
 mod ensure;
+mod error;
 mod graph;
+mod patch;
 #[macro_use]
 pub mod property;
+pub mod operators;
 pub mod proxy;
+mod transaction;
 
-pub use self::{deref_graph::*, directedness::*, edge::*, ensure::*, graph::*};
+pub use self::{
+	convert::*, deref_graph::*, directedness::*, edge::*, ensure::*, error::*, graph::*, patch::*,
+	transaction::*,
+};
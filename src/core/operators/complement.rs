@@ -0,0 +1,111 @@
+use crate::core::{
+	copy_into_mapped,
+	property::{AddEdge, NewVertex, NoLoops, Unique},
+	Copies, Ensure, Graph, GraphError,
+};
+use delegate::delegate;
+use std::{
+	borrow::Borrow,
+	hash::Hash,
+	iter::{empty, once},
+};
+
+/// The [complement](https://mathworld.wolfram.com/GraphComplement.html) of a
+/// graph, which has the same vertices and an edge between two distinct
+/// vertices exactly when the graph doesn't.
+///
+/// If the graph is directed, the complement is too, so an edge from one vertex
+/// to another is in the complement when the graph has no edge in that
+/// direction.
+/// The complement never has loops, and its edges have no weights.
+///
+/// ### Usage
+/// ```
+/// # use graphene::{
+/// # 	common::AdjListGraph,
+/// # 	core::{operators::ComplementProxy, property::{AddEdge, NewVertex}, Graph, Undirected},
+/// # };
+/// let mut graph = AdjListGraph::<(), (), Undirected>::new();
+/// let v0 = graph.new_vertex().unwrap();
+/// let v1 = graph.new_vertex().unwrap();
+/// let v2 = graph.new_vertex().unwrap();
+/// graph.add_edge(&v0, &v1).unwrap();
+///
+/// let complement = ComplementProxy::new(&graph);
+///
+/// assert!(!complement.neighbors(v0, v1));
+/// assert!(complement.neighbors(v0, v2));
+/// assert!(complement.neighbors(v1, v2));
+/// ```
+pub struct ComplementProxy<C: Ensure>(C);
+
+impl<C: Ensure> ComplementProxy<C>
+{
+	/// Creates the complement of the given graph.
+	pub fn new(underlying: C) -> Self
+	{
+		Self(underlying)
+	}
+}
+
+impl<C: Ensure> Graph for ComplementProxy<C>
+{
+	type Directedness = <C::Graph as Graph>::Directedness;
+	type EdgeWeight = ();
+	type Vertex = <C::Graph as Graph>::Vertex;
+	type VertexWeight = <C::Graph as Graph>::VertexWeight;
+
+	delegate! {
+		to self.0.graph() {
+			fn all_vertices_weighted<'a>(
+				&'a self,
+			) -> Box<dyn 'a + Iterator<Item = (Self::Vertex, &'a Self::VertexWeight)>>;
+		}
+	}
+
+	fn edges_between<'a: 'b, 'b>(
+		&'a self,
+		source: impl 'b + Borrow<Self::Vertex>,
+		sink: impl 'b + Borrow<Self::Vertex>,
+	) -> Box<dyn 'b + Iterator<Item = &'a Self::EdgeWeight>>
+	{
+		let graph = self.0.graph();
+		let (source, sink) = (*source.borrow(), *sink.borrow());
+		if source != sink
+			&& graph.contains_vertex(source)
+			&& graph.contains_vertex(sink)
+			&& graph.edges_between(source, sink).next().is_none()
+		{
+			Box::new(once(&()))
+		}
+		else
+		{
+			Box::new(empty())
+		}
+	}
+}
+
+impl<C: Ensure> NoLoops for ComplementProxy<C> {}
+
+impl<C: Ensure> Unique for ComplementProxy<C> {}
+
+base_graph! {
+	use<C> ComplementProxy<C>: HasVertex
+	as (self.0): C
+	where C: Ensure
+}
+
+/// Adds the [complement](struct.ComplementProxy.html) of the given graph to the
+/// target graph, with the default weight on each edge.
+pub fn complement<G, T>(g: &G, target: &mut T) -> Result<Copies<G, T>, GraphError<T::Vertex>>
+where
+	G: Graph,
+	G::Vertex: Hash,
+	G::VertexWeight: Clone,
+	T: NewVertex + AddEdge<VertexWeight = G::VertexWeight, Directedness = G::Directedness>,
+	T::EdgeWeight: Default,
+{
+	copy_into_mapped(&ComplementProxy::new(g), target, Clone::clone, |_| {
+		T::EdgeWeight::default()
+	})
+}
@@ -0,0 +1,16 @@
+//! Graph operators, constructing new graphs from existing ones.
+//!
+//! Each operator is available both as a proxy, which computes the resulting
+//! graph lazily from its operands, and as a function that materializes the
+//! resulting graph into any graph that implements
+//! [`NewVertex`](../property/trait.NewVertex.html) and
+//! [`AddEdge`](../property/trait.AddEdge.html).
+//!
+//! The materializing functions return which vertex of the target graph each
+//! vertex of the resulting graph was added as.
+
+mod complement;
+mod product;
+mod union;
+
+pub use self::{complement::*, product::*, union::*};
@@ -0,0 +1,271 @@
+use crate::core::{
+	copy_into_mapped,
+	property::{AddEdge, NewVertex},
+	Ensure, Graph, GraphError,
+};
+use std::{
+	borrow::Borrow,
+	collections::HashMap,
+	hash::Hash,
+	iter::{empty, repeat_n},
+};
+
+/// Which vertex of the target graph each vertex of a product was added as.
+type ProductCopies<G1, G2, T> =
+	HashMap<(<G1 as Graph>::Vertex, <G2 as Graph>::Vertex), <T as Graph>::Vertex>;
+
+/// The kinds of graph products.
+///
+/// In each product, the vertices are the pairs of a vertex from the first
+/// graph and a vertex from the second graph.
+/// They differ in which pairs have edges between them.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Product
+{
+	/// The [cartesian product](https://mathworld.wolfram.com/GraphCartesianProduct.html),
+	/// where `(u1, u2)` and `(v1, v2)` have an edge for each edge between `u1`
+	/// and `v1` if `u2 == v2`, and for each edge between `u2` and `v2` if
+	/// `u1 == v1`.
+	Cartesian,
+
+	/// The [tensor product](https://mathworld.wolfram.com/GraphTensorProduct.html),
+	/// where `(u1, u2)` and `(v1, v2)` have an edge for each pair of an edge
+	/// between `u1` and `v1` and an edge between `u2` and `v2`.
+	Tensor,
+
+	/// The [strong product](https://mathworld.wolfram.com/GraphStrongProduct.html),
+	/// which has the edges of both the cartesian and the tensor products.
+	Strong,
+}
+
+/// The product of two graphs.
+///
+/// Each vertex is a pair of a vertex from the first graph and a vertex from
+/// the second graph, and which pairs have edges between them is given by the
+/// [`Product`](enum.Product.html).
+/// Neither the vertices nor the edges have weights.
+///
+/// ### Usage
+/// ```
+/// # use graphene::{
+/// # 	common::AdjListGraph,
+/// # 	core::{operators::ProductProxy, property::{AddEdge, NewVertex}, Graph, Undirected},
+/// # };
+/// let mut path = AdjListGraph::<(), (), Undirected>::new();
+/// let v0 = path.new_vertex().unwrap();
+/// let v1 = path.new_vertex().unwrap();
+/// path.add_edge(&v0, &v1).unwrap();
+///
+/// // The cartesian product of two edges is a square
+/// let square = ProductProxy::cartesian(&path, &path);
+///
+/// assert_eq!(square.all_vertices().count(), 4);
+/// assert_eq!(square.all_edges().count(), 4);
+/// assert!(square.neighbors((v0, v0), (v0, v1)));
+/// assert!(!square.neighbors((v0, v0), (v1, v1)));
+/// ```
+pub struct ProductProxy<C1: Ensure, C2: Ensure>
+{
+	/// The first graph
+	first: C1,
+	/// The second graph
+	second: C2,
+	/// Which product this is
+	product: Product,
+}
+
+impl<C1: Ensure, C2: Ensure> ProductProxy<C1, C2>
+{
+	/// Creates the given product of the two given graphs.
+	pub fn new(first: C1, second: C2, product: Product) -> Self
+	{
+		Self {
+			first,
+			second,
+			product,
+		}
+	}
+
+	/// Creates the cartesian product of the two given graphs.
+	pub fn cartesian(first: C1, second: C2) -> Self
+	{
+		Self::new(first, second, Product::Cartesian)
+	}
+
+	/// Creates the tensor product of the two given graphs.
+	pub fn tensor(first: C1, second: C2) -> Self
+	{
+		Self::new(first, second, Product::Tensor)
+	}
+
+	/// Creates the strong product of the two given graphs.
+	pub fn strong(first: C1, second: C2) -> Self
+	{
+		Self::new(first, second, Product::Strong)
+	}
+}
+
+impl<C1: Ensure, C2: Ensure> Graph for ProductProxy<C1, C2>
+where
+	C2::Graph: Graph<Directedness = <C1::Graph as Graph>::Directedness>,
+{
+	type Directedness = <C1::Graph as Graph>::Directedness;
+	type EdgeWeight = ();
+	type Vertex = (<C1::Graph as Graph>::Vertex, <C2::Graph as Graph>::Vertex);
+	type VertexWeight = ();
+
+	fn all_vertices_weighted<'a>(
+		&'a self,
+	) -> Box<dyn 'a + Iterator<Item = (Self::Vertex, &'a Self::VertexWeight)>>
+	{
+		Box::new(self.first.graph().all_vertices().flat_map(move |v1| {
+			self.second
+				.graph()
+				.all_vertices()
+				.map(move |v2| ((v1, v2), &()))
+		}))
+	}
+
+	fn edges_between<'a: 'b, 'b>(
+		&'a self,
+		source: impl 'b + Borrow<Self::Vertex>,
+		sink: impl 'b + Borrow<Self::Vertex>,
+	) -> Box<dyn 'b + Iterator<Item = &'a Self::EdgeWeight>>
+	{
+		let ((u1, u2), (v1, v2)) = (*source.borrow(), *sink.borrow());
+		let (first, second) = (self.first.graph(), self.second.graph());
+		if !(self.contains_vertex((u1, u2)) && self.contains_vertex((v1, v2)))
+		{
+			return Box::new(empty());
+		}
+
+		let first_count = first.edges_between(u1, v1).count();
+		let second_count = second.edges_between(u2, v2).count();
+		let cartesian =
+			if u1 == v1 { second_count } else { 0 } + if u2 == v2 { first_count } else { 0 };
+		let tensor = first_count * second_count;
+		let count = match self.product
+		{
+			Product::Cartesian => cartesian,
+			Product::Tensor => tensor,
+			Product::Strong => cartesian + tensor,
+		};
+		Box::new(repeat_n(&(), count))
+	}
+
+	fn contains_vertex(&self, v: impl Borrow<Self::Vertex>) -> bool
+	{
+		let (v1, v2) = *v.borrow();
+		self.first.graph().contains_vertex(v1) && self.second.graph().contains_vertex(v2)
+	}
+
+	fn edges_sourced_in<'a: 'b, 'b>(
+		&'a self,
+		v: impl 'b + Borrow<Self::Vertex>,
+	) -> Box<dyn 'b + Iterator<Item = (Self::Vertex, &'a Self::EdgeWeight)>>
+	{
+		let (v1, v2) = *v.borrow();
+		let sinks = Self::candidates(
+			self.first.graph().edges_sourced_in(v1),
+			v1,
+			self.second.graph().edges_sourced_in(v2),
+			v2,
+		);
+		Box::new(
+			sinks
+				.into_iter()
+				.flat_map(move |sink| self.edges_between((v1, v2), sink).map(move |w| (sink, w))),
+		)
+	}
+
+	fn edges_sinked_in<'a: 'b, 'b>(
+		&'a self,
+		v: impl 'b + Borrow<Self::Vertex>,
+	) -> Box<dyn 'b + Iterator<Item = (Self::Vertex, &'a Self::EdgeWeight)>>
+	{
+		let (v1, v2) = *v.borrow();
+		let sources = Self::candidates(
+			self.first.graph().edges_sinked_in(v1),
+			v1,
+			self.second.graph().edges_sinked_in(v2),
+			v2,
+		);
+		Box::new(sources.into_iter().flat_map(move |source| {
+			self.edges_between(source, (v1, v2))
+				.map(move |w| (source, w))
+		}))
+	}
+}
+
+impl<C1: Ensure, C2: Ensure> ProductProxy<C1, C2>
+where
+	C2::Graph: Graph<Directedness = <C1::Graph as Graph>::Directedness>,
+{
+	/// Returns the pairs of vertices that may have edges to or from the pair of
+	/// the given vertices, given the vertices adjacent to each of them in their
+	/// graphs.
+	///
+	/// Any vertex is either adjacent to the given one or is the given one, in
+	/// each of the graphs.
+	fn candidates<'a, W1: 'a, W2: 'a>(
+		adjacent_first: impl Iterator<Item = (<C1::Graph as Graph>::Vertex, &'a W1)>,
+		v1: <C1::Graph as Graph>::Vertex,
+		adjacent_second: impl Iterator<Item = (<C2::Graph as Graph>::Vertex, &'a W2)>,
+		v2: <C2::Graph as Graph>::Vertex,
+	) -> Vec<<Self as Graph>::Vertex>
+	{
+		let mut firsts = vec![v1];
+		for (v, _) in adjacent_first
+		{
+			if !firsts.contains(&v)
+			{
+				firsts.push(v);
+			}
+		}
+		let mut seconds = vec![v2];
+		for (v, _) in adjacent_second
+		{
+			if !seconds.contains(&v)
+			{
+				seconds.push(v);
+			}
+		}
+		firsts
+			.into_iter()
+			.flat_map(|first| seconds.iter().map(move |second| (first, *second)))
+			.collect()
+	}
+}
+
+base_graph! {
+	use<C1, C2> ProductProxy<C1, C2>
+	where
+		C1: Ensure,
+		C2: Ensure,
+		C2::Graph: Graph<Directedness = <C1::Graph as Graph>::Directedness>
+}
+
+/// Adds the given [product](struct.ProductProxy.html) of the two given graphs
+/// to the target graph, with the default weight on each vertex and edge.
+pub fn product<G1, G2, T>(
+	first: &G1,
+	second: &G2,
+	product: Product,
+	target: &mut T,
+) -> Result<ProductCopies<G1, G2, T>, GraphError<T::Vertex>>
+where
+	G1: Graph,
+	G2: Graph<Directedness = G1::Directedness>,
+	G1::Vertex: Hash,
+	G2::Vertex: Hash,
+	T: NewVertex + AddEdge<Directedness = G1::Directedness>,
+	T::VertexWeight: Default,
+	T::EdgeWeight: Default,
+{
+	copy_into_mapped(
+		&ProductProxy::new(first, second, product),
+		target,
+		|_| T::VertexWeight::default(),
+		|_| T::EdgeWeight::default(),
+	)
+}
@@ -0,0 +1,372 @@
+use crate::core::{
+	copy_into_mapped,
+	property::{AddEdge, NewVertex},
+	Copies, Ensure, Graph, GraphError,
+};
+use std::{borrow::Borrow, collections::HashMap, hash::Hash, iter::empty};
+
+/// Which vertex of the target graph each vertex of a disjoint union was added
+/// as.
+type DisjointCopies<G1, G2, T> =
+	HashMap<DisjointVertex<<G1 as Graph>::Vertex, <G2 as Graph>::Vertex>, <T as Graph>::Vertex>;
+
+/// A vertex of a [`DisjointUnionProxy`](struct.DisjointUnionProxy.html),
+/// identifying which of the operands it is from.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DisjointVertex<V1, V2>
+{
+	First(V1),
+	Second(V2),
+}
+
+/// The disjoint union of two graphs, which has all the vertices and edges of
+/// both graphs, without any edges between them.
+///
+/// Since the two graphs may use the same vertex values, the vertices are
+/// remapped to [`DisjointVertex`](enum.DisjointVertex.html)s.
+///
+/// ### Usage
+/// ```
+/// # use graphene::{
+/// # 	common::AdjListGraph,
+/// # 	core::{
+/// # 		operators::{DisjointUnionProxy, DisjointVertex},
+/// # 		property::{AddEdge, NewVertex},
+/// # 		Graph,
+/// # 	},
+/// # };
+/// let mut first = AdjListGraph::<(), ()>::new();
+/// let v0 = first.new_vertex().unwrap();
+/// let v1 = first.new_vertex().unwrap();
+/// first.add_edge(&v0, &v1).unwrap();
+/// let second = first.clone();
+///
+/// let union = DisjointUnionProxy::new(&first, &second);
+///
+/// assert_eq!(union.all_vertices().count(), 4);
+/// assert!(union.contains_vertex(DisjointVertex::Second(v0)));
+/// assert!(!union.neighbors(DisjointVertex::First(v0), DisjointVertex::Second(v1)));
+/// ```
+pub struct DisjointUnionProxy<C1: Ensure, C2: Ensure>(C1, C2);
+
+impl<C1: Ensure, C2: Ensure> DisjointUnionProxy<C1, C2>
+{
+	/// Creates the disjoint union of the two given graphs.
+	pub fn new(first: C1, second: C2) -> Self
+	{
+		Self(first, second)
+	}
+}
+
+impl<C1: Ensure, C2: Ensure> Graph for DisjointUnionProxy<C1, C2>
+where
+	C2::Graph: Graph<
+		VertexWeight = <C1::Graph as Graph>::VertexWeight,
+		EdgeWeight = <C1::Graph as Graph>::EdgeWeight,
+		Directedness = <C1::Graph as Graph>::Directedness,
+	>,
+{
+	type Directedness = <C1::Graph as Graph>::Directedness;
+	type EdgeWeight = <C1::Graph as Graph>::EdgeWeight;
+	type Vertex = DisjointVertex<<C1::Graph as Graph>::Vertex, <C2::Graph as Graph>::Vertex>;
+	type VertexWeight = <C1::Graph as Graph>::VertexWeight;
+
+	fn all_vertices_weighted<'a>(
+		&'a self,
+	) -> Box<dyn 'a + Iterator<Item = (Self::Vertex, &'a Self::VertexWeight)>>
+	{
+		Box::new(
+			self.0
+				.graph()
+				.all_vertices_weighted()
+				.map(|(v, w)| (DisjointVertex::First(v), w))
+				.chain(
+					self.1
+						.graph()
+						.all_vertices_weighted()
+						.map(|(v, w)| (DisjointVertex::Second(v), w)),
+				),
+		)
+	}
+
+	fn edges_between<'a: 'b, 'b>(
+		&'a self,
+		source: impl 'b + Borrow<Self::Vertex>,
+		sink: impl 'b + Borrow<Self::Vertex>,
+	) -> Box<dyn 'b + Iterator<Item = &'a Self::EdgeWeight>>
+	{
+		match (*source.borrow(), *sink.borrow())
+		{
+			(DisjointVertex::First(source), DisjointVertex::First(sink)) =>
+			{
+				self.0.graph().edges_between(source, sink)
+			},
+			(DisjointVertex::Second(source), DisjointVertex::Second(sink)) =>
+			{
+				self.1.graph().edges_between(source, sink)
+			},
+			_ => Box::new(empty()),
+		}
+	}
+}
+
+base_graph! {
+	use<C1, C2> DisjointUnionProxy<C1, C2>
+	where
+		C1: Ensure,
+		C2: Ensure,
+		C2::Graph: Graph<
+			VertexWeight = <C1::Graph as Graph>::VertexWeight,
+			EdgeWeight = <C1::Graph as Graph>::EdgeWeight,
+			Directedness = <C1::Graph as Graph>::Directedness,
+		>
+}
+
+/// The union of two graphs over the same vertices, which has the vertices of
+/// either graph.
+///
+/// The edges between two vertices are those of the first graph, or if it has
+/// none, those of the second graph.
+/// Likewise, vertices in both graphs have the weights they have in the first
+/// graph.
+pub struct UnionProxy<C1: Ensure, C2: Ensure>(C1, C2);
+
+impl<C1: Ensure, C2: Ensure> UnionProxy<C1, C2>
+{
+	/// Creates the union of the two given graphs.
+	pub fn new(first: C1, second: C2) -> Self
+	{
+		Self(first, second)
+	}
+}
+
+impl<C1: Ensure, C2: Ensure> Graph for UnionProxy<C1, C2>
+where
+	C2::Graph: Graph<
+		Vertex = <C1::Graph as Graph>::Vertex,
+		VertexWeight = <C1::Graph as Graph>::VertexWeight,
+		EdgeWeight = <C1::Graph as Graph>::EdgeWeight,
+		Directedness = <C1::Graph as Graph>::Directedness,
+	>,
+{
+	type Directedness = <C1::Graph as Graph>::Directedness;
+	type EdgeWeight = <C1::Graph as Graph>::EdgeWeight;
+	type Vertex = <C1::Graph as Graph>::Vertex;
+	type VertexWeight = <C1::Graph as Graph>::VertexWeight;
+
+	fn all_vertices_weighted<'a>(
+		&'a self,
+	) -> Box<dyn 'a + Iterator<Item = (Self::Vertex, &'a Self::VertexWeight)>>
+	{
+		Box::new(
+			self.0.graph().all_vertices_weighted().chain(
+				self.1
+					.graph()
+					.all_vertices_weighted()
+					.filter(move |(v, _)| !self.0.graph().contains_vertex(v)),
+			),
+		)
+	}
+
+	fn edges_between<'a: 'b, 'b>(
+		&'a self,
+		source: impl 'b + Borrow<Self::Vertex>,
+		sink: impl 'b + Borrow<Self::Vertex>,
+	) -> Box<dyn 'b + Iterator<Item = &'a Self::EdgeWeight>>
+	{
+		let (source, sink) = (*source.borrow(), *sink.borrow());
+		if self.0.graph().edges_between(source, sink).next().is_some()
+		{
+			self.0.graph().edges_between(source, sink)
+		}
+		else
+		{
+			self.1.graph().edges_between(source, sink)
+		}
+	}
+}
+
+base_graph! {
+	use<C1, C2> UnionProxy<C1, C2>
+	where
+		C1: Ensure,
+		C2: Ensure,
+		C2::Graph: Graph<
+			Vertex = <C1::Graph as Graph>::Vertex,
+			VertexWeight = <C1::Graph as Graph>::VertexWeight,
+			EdgeWeight = <C1::Graph as Graph>::EdgeWeight,
+			Directedness = <C1::Graph as Graph>::Directedness,
+		>
+}
+
+/// The intersection of two graphs over the same vertices, which has the
+/// vertices that are in both graphs.
+///
+/// Two vertices have edges between them if they do in both graphs, in which
+/// case the edges and vertex weights are those of the first graph.
+pub struct IntersectionProxy<C1: Ensure, C2: Ensure>(C1, C2);
+
+impl<C1: Ensure, C2: Ensure> IntersectionProxy<C1, C2>
+{
+	/// Creates the intersection of the two given graphs.
+	pub fn new(first: C1, second: C2) -> Self
+	{
+		Self(first, second)
+	}
+}
+
+impl<C1: Ensure, C2: Ensure> Graph for IntersectionProxy<C1, C2>
+where
+	C2::Graph: Graph<
+		Vertex = <C1::Graph as Graph>::Vertex,
+		VertexWeight = <C1::Graph as Graph>::VertexWeight,
+		EdgeWeight = <C1::Graph as Graph>::EdgeWeight,
+		Directedness = <C1::Graph as Graph>::Directedness,
+	>,
+{
+	type Directedness = <C1::Graph as Graph>::Directedness;
+	type EdgeWeight = <C1::Graph as Graph>::EdgeWeight;
+	type Vertex = <C1::Graph as Graph>::Vertex;
+	type VertexWeight = <C1::Graph as Graph>::VertexWeight;
+
+	fn all_vertices_weighted<'a>(
+		&'a self,
+	) -> Box<dyn 'a + Iterator<Item = (Self::Vertex, &'a Self::VertexWeight)>>
+	{
+		Box::new(
+			self.0
+				.graph()
+				.all_vertices_weighted()
+				.filter(move |(v, _)| self.1.graph().contains_vertex(v)),
+		)
+	}
+
+	fn edges_between<'a: 'b, 'b>(
+		&'a self,
+		source: impl 'b + Borrow<Self::Vertex>,
+		sink: impl 'b + Borrow<Self::Vertex>,
+	) -> Box<dyn 'b + Iterator<Item = &'a Self::EdgeWeight>>
+	{
+		let (source, sink) = (*source.borrow(), *sink.borrow());
+		if self.1.graph().edges_between(source, sink).next().is_some()
+		{
+			self.0.graph().edges_between(source, sink)
+		}
+		else
+		{
+			Box::new(empty())
+		}
+	}
+}
+
+base_graph! {
+	use<C1, C2> IntersectionProxy<C1, C2>
+	where
+		C1: Ensure,
+		C2: Ensure,
+		C2::Graph: Graph<
+			Vertex = <C1::Graph as Graph>::Vertex,
+			VertexWeight = <C1::Graph as Graph>::VertexWeight,
+			EdgeWeight = <C1::Graph as Graph>::EdgeWeight,
+			Directedness = <C1::Graph as Graph>::Directedness,
+		>
+}
+
+/// Adds the [disjoint union](struct.DisjointUnionProxy.html) of the two given
+/// graphs to the target graph.
+pub fn disjoint_union<G1, G2, T>(
+	first: &G1,
+	second: &G2,
+	target: &mut T,
+) -> Result<DisjointCopies<G1, G2, T>, GraphError<T::Vertex>>
+where
+	G1: Graph,
+	G2: Graph<
+		VertexWeight = G1::VertexWeight,
+		EdgeWeight = G1::EdgeWeight,
+		Directedness = G1::Directedness,
+	>,
+	G1::Vertex: Hash,
+	G2::Vertex: Hash,
+	G1::VertexWeight: Clone,
+	G1::EdgeWeight: Clone,
+	T: NewVertex
+		+ AddEdge<
+			VertexWeight = G1::VertexWeight,
+			EdgeWeight = G1::EdgeWeight,
+			Directedness = G1::Directedness,
+		>,
+{
+	copy_into_mapped(
+		&DisjointUnionProxy::new(first, second),
+		target,
+		Clone::clone,
+		Clone::clone,
+	)
+}
+
+/// Adds the [union](struct.UnionProxy.html) of the two given graphs to the
+/// target graph.
+pub fn union<G1, G2, T>(
+	first: &G1,
+	second: &G2,
+	target: &mut T,
+) -> Result<Copies<G1, T>, GraphError<T::Vertex>>
+where
+	G1: Graph,
+	G2: Graph<
+		Vertex = G1::Vertex,
+		VertexWeight = G1::VertexWeight,
+		EdgeWeight = G1::EdgeWeight,
+		Directedness = G1::Directedness,
+	>,
+	G1::Vertex: Hash,
+	G1::VertexWeight: Clone,
+	G1::EdgeWeight: Clone,
+	T: NewVertex
+		+ AddEdge<
+			VertexWeight = G1::VertexWeight,
+			EdgeWeight = G1::EdgeWeight,
+			Directedness = G1::Directedness,
+		>,
+{
+	copy_into_mapped(
+		&UnionProxy::new(first, second),
+		target,
+		Clone::clone,
+		Clone::clone,
+	)
+}
+
+/// Adds the [intersection](struct.IntersectionProxy.html) of the two given
+/// graphs to the target graph.
+pub fn intersection<G1, G2, T>(
+	first: &G1,
+	second: &G2,
+	target: &mut T,
+) -> Result<Copies<G1, T>, GraphError<T::Vertex>>
+where
+	G1: Graph,
+	G2: Graph<
+		Vertex = G1::Vertex,
+		VertexWeight = G1::VertexWeight,
+		EdgeWeight = G1::EdgeWeight,
+		Directedness = G1::Directedness,
+	>,
+	G1::Vertex: Hash,
+	G1::VertexWeight: Clone,
+	G1::EdgeWeight: Clone,
+	T: NewVertex
+		+ AddEdge<
+			VertexWeight = G1::VertexWeight,
+			EdgeWeight = G1::EdgeWeight,
+			Directedness = G1::Directedness,
+		>,
+{
+	copy_into_mapped(
+		&IntersectionProxy::new(first, second),
+		target,
+		Clone::clone,
+		Clone::clone,
+	)
+}
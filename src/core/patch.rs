@@ -0,0 +1,216 @@
+use crate::core::{
+	property::{remove_vertex_tracked, AddEdge, NewVertex, RemoveEdge, RemoveVertex},
+	Directedness, Graph, GraphError, GraphMut,
+};
+use std::{
+	collections::{HashMap, HashSet},
+	hash::Hash,
+};
+
+/// The changes that turn one version of a graph into another.
+///
+/// Since edges have no identity other than their vertices and weight, a
+/// changed edge weight is represented as the edge being removed and an edge
+/// with the new weight being added.
+///
+/// Created by [`diff`](fn.diff.html) and applied by
+/// [`apply_patch`](fn.apply_patch.html).
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GraphPatch<V, Vw, Ew>
+{
+	/// The vertices that were added, with their weights.
+	pub added_vertices: Vec<(V, Vw)>,
+
+	/// The vertices that were removed.
+	pub removed_vertices: Vec<V>,
+
+	/// The vertices whose weights were changed, with their new weights.
+	pub changed_vertices: Vec<(V, Vw)>,
+
+	/// The edges that were added.
+	pub added_edges: Vec<(V, V, Ew)>,
+
+	/// The edges that were removed, including those incident on removed
+	/// vertices.
+	pub removed_edges: Vec<(V, V, Ew)>,
+}
+
+impl<V, Vw, Ew> GraphPatch<V, Vw, Ew>
+{
+	/// Returns whether the patch makes no changes.
+	pub fn is_empty(&self) -> bool
+	{
+		self.added_vertices.is_empty()
+			&& self.removed_vertices.is_empty()
+			&& self.changed_vertices.is_empty()
+			&& self.added_edges.is_empty()
+			&& self.removed_edges.is_empty()
+	}
+}
+
+/// Computes the patch that turns the first graph into the second.
+///
+/// Vertices are compared by identity, so the graphs should be versions of the
+/// same graph, e.g. a graph and a mutated clone of it.
+/// Parallel edges are compared by weight, such that only the edges whose
+/// weights don't match any remaining edge are part of the patch.
+///
+/// ### Usage
+/// ```
+/// # use graphene::{
+/// # 	common::AdjListGraph,
+/// # 	core::{apply_patch, diff, property::{AddEdge, NewVertex}},
+/// # };
+/// let mut old = AdjListGraph::<u32, u32>::new();
+/// let v0 = old.new_vertex_weighted(0).unwrap();
+/// let v1 = old.new_vertex_weighted(1).unwrap();
+///
+/// let mut new = old.clone();
+/// new.add_edge_weighted(&v0, &v1, 5).unwrap();
+///
+/// let patch = diff(&old, &new);
+/// assert_eq!(patch.added_edges, vec![(v0, v1, 5)]);
+///
+/// apply_patch(&mut old, patch).unwrap();
+/// assert_eq!(diff(&old, &new).is_empty(), true);
+/// ```
+pub fn diff<G1, G2>(old: &G1, new: &G2) -> GraphPatch<G1::Vertex, G1::VertexWeight, G1::EdgeWeight>
+where
+	G1: Graph,
+	G2: Graph<
+		Vertex = G1::Vertex,
+		VertexWeight = G1::VertexWeight,
+		EdgeWeight = G1::EdgeWeight,
+		Directedness = G1::Directedness,
+	>,
+	G1::Vertex: Hash,
+	G1::VertexWeight: Clone + PartialEq,
+	G1::EdgeWeight: Clone + PartialEq,
+{
+	let mut patch = GraphPatch {
+		added_vertices: Vec::new(),
+		removed_vertices: Vec::new(),
+		changed_vertices: Vec::new(),
+		added_edges: Vec::new(),
+		removed_edges: Vec::new(),
+	};
+
+	for (v, w) in old.all_vertices_weighted()
+	{
+		match new.vertex_weight(v)
+		{
+			None => patch.removed_vertices.push(v),
+			Some(new_w) if new_w != w => patch.changed_vertices.push((v, new_w.clone())),
+			_ => (),
+		}
+	}
+	for (v, w) in new.all_vertices_weighted()
+	{
+		if !old.contains_vertex(v)
+		{
+			patch.added_vertices.push((v, w.clone()));
+		}
+	}
+
+	// Compare the edges between each pair of vertices that has any
+	let mut pairs = HashSet::new();
+	for (source, sink, _) in old.all_edges().chain(new.all_edges())
+	{
+		if G1::Directedness::directed() || !pairs.contains(&(sink, source))
+		{
+			pairs.insert((source, sink));
+		}
+	}
+	for (source, sink) in pairs
+	{
+		let mut added: Vec<_> = new.edges_between(source, sink).collect();
+		for w in old.edges_between(source, sink)
+		{
+			match added.iter().position(|new_w| *new_w == w)
+			{
+				Some(i) =>
+				{
+					added.swap_remove(i);
+				},
+				None => patch.removed_edges.push((source, sink, w.clone())),
+			}
+		}
+		patch
+			.added_edges
+			.extend(added.into_iter().map(|w| (source, sink, w.clone())));
+	}
+	patch
+}
+
+/// Applies the given patch to the graph.
+///
+/// The graph gives its own vertices to the added vertices, so the vertex at
+/// index `i` of the returned list is the one given to the added vertex at index
+/// `i` in the patch.
+/// Added edges incident on added vertices are applied to their vertices in the
+/// graph.
+///
+/// Edges are removed before vertices, and vertices are added before edges.
+/// If removing a vertex gives the remaining vertices new identities, like in
+/// [`AdjListGraph`](../common/struct.AdjListGraph.html), the rest of the patch
+/// is applied to their new identities. The graph is assumed to list its
+/// remaining vertices in the same order after a removal.
+///
+/// The patch isn't applied atomically: If the graph rejects a change, the
+/// error is returned and the changes applied before it remain.
+/// To apply a patch to an ensured graph atomically, apply it to a
+/// [`Transaction`](struct.Transaction.html) on the graph.
+pub fn apply_patch<G>(
+	g: &mut G,
+	patch: GraphPatch<G::Vertex, G::VertexWeight, G::EdgeWeight>,
+) -> Result<Vec<G::Vertex>, GraphError<G::Vertex>>
+where
+	G: GraphMut + NewVertex + RemoveVertex + AddEdge + RemoveEdge,
+	G::Vertex: Hash,
+	G::EdgeWeight: PartialEq,
+{
+	for (source, sink, w) in patch.removed_edges
+	{
+		g.remove_edge_where_weight(source, sink, |weight| *weight == w)?;
+	}
+
+	// The current identity of each vertex of the patch that was given a new one
+	let mut moved: HashMap<G::Vertex, G::Vertex> = HashMap::new();
+	for v in patch.removed_vertices
+	{
+		let current = moved.remove(&v).unwrap_or(v);
+		let (_, renamed) = remove_vertex_tracked(g, current)?;
+		let mut renamed: HashMap<_, _> = renamed.into_iter().collect();
+		for current in moved.values_mut()
+		{
+			if let Some(new) = renamed.remove(current)
+			{
+				*current = new;
+			}
+		}
+		// The rest had kept their identity until now
+		moved.extend(renamed);
+	}
+	let current = |v: G::Vertex| moved.get(&v).copied().unwrap_or(v);
+	for (v, w) in patch.changed_vertices
+	{
+		*g.vertex_weight_mut(current(v))
+			.ok_or(GraphError::VertexNotFound(v))? = w;
+	}
+
+	let mut added = HashMap::new();
+	let mut new_vertices = Vec::new();
+	for (v, w) in patch.added_vertices
+	{
+		let new_v = g.new_vertex_weighted(w)?;
+		added.insert(v, new_v);
+		new_vertices.push(new_v);
+	}
+	let resolve = |v: G::Vertex| added.get(&v).copied().unwrap_or_else(|| current(v));
+	for (source, sink, w) in patch.added_edges
+	{
+		g.add_edge_weighted(resolve(source), resolve(sink), w)?;
+	}
+	Ok(new_vertices)
+}
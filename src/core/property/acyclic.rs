@@ -1,11 +1,16 @@
 use crate::{
-	algo::{path_exists, Dfs},
+	algo::path_exists,
 	core::{
-		property::{AddEdge, NoLoops, VertexInGraph},
-		Directedness, Ensure, Graph, GraphDerefMut,
+		property::{AddEdge, NoLoops},
+		BaseGraph, Directedness, Ensure, Graph, GraphDeref, GraphDerefMut, GraphError, Rebase,
+		Release,
 	},
 };
-use std::borrow::Borrow;
+use std::{
+	borrow::Borrow,
+	collections::{HashMap, HashSet},
+	hash::Hash,
+};
 
 /// An acyclic graph
 pub trait Acyclic: NoLoops
@@ -16,6 +21,8 @@ pub trait Acyclic: NoLoops
 pub struct AcyclicGraph<C: Ensure>(C);
 
 impl<C: Ensure> Ensure for AcyclicGraph<C>
+where
+	<C::Graph as Graph>::Vertex: Hash,
 {
 	fn ensure_unvalidated(c: Self::Ensured, _: ()) -> Self
 	{
@@ -24,82 +31,113 @@ impl<C: Ensure> Ensure for AcyclicGraph<C>
 
 	fn validate(c: &Self::Ensured, _: &()) -> bool
 	{
-		fn on_visit<G: Graph>(dfs: &mut Dfs<G, (Vec<G::Vertex>, &mut bool)>, v: G::Vertex)
+		Self::diagnose(c, &()).is_ok()
+	}
+
+	/// If the graph has a cycle, returns its vertices as the witness, in the
+	/// order they are visited by the cycle.
+	fn diagnose(c: &Self::Ensured, _: &()) -> Result<(), GraphError<<C::Graph as Graph>::Vertex>>
+	{
+		match find_cycle(c.graph())
 		{
-			dfs.payload.0.push(v);
+			Some(cycle) =>
+			{
+				Err(GraphError::PropertyViolated {
+					property: Self::property_name(),
+					witness: cycle,
+				})
+			},
+			None => Ok(()),
 		}
-		fn on_exit<G: Graph>(_: &G, _: G::Vertex, (stack, _): &mut (Vec<G::Vertex>, &mut bool))
+	}
+
+	fn property_name() -> &'static str
+	{
+		"Acyclic"
+	}
+}
+
+/// Finds a cycle in the given graph, if it has any.
+///
+/// Does a Dfs from each unvisited vertex, keeping the current path.
+/// An edge to a vertex on the path closes a cycle, made up of the path from
+/// that vertex.
+/// In undirected graphs, the edge back to the previous vertex on the path
+/// doesn't count, unless there are multiple edges between the two.
+fn find_cycle<G: Graph>(g: &G) -> Option<Vec<G::Vertex>>
+where
+	G::Vertex: Hash,
+{
+	let mut visited = HashSet::new();
+	// The position of each vertex on the current path
+	let mut on_path = HashMap::new();
+	// Each entry holds a vertex on the path, the vertices it has edges to,
+	// how many of those have been explored, and whether the edge back to the
+	// previous vertex has been skipped.
+	let mut path = Vec::new();
+
+	for v in g.all_vertices()
+	{
+		if !visited.insert(v)
 		{
-			stack.pop();
+			continue;
 		}
-		fn on_explore<G: Graph>(
-			dfs: &mut Dfs<G, (Vec<G::Vertex>, &mut bool)>,
-			source: G::Vertex,
-			sink: G::Vertex,
-			_: &G::EdgeWeight,
-		)
+		on_path.insert(v, 0);
+		path.push((
+			v,
+			g.edges_sourced_in(v).map(|(v2, _)| v2).collect::<Vec<_>>(),
+			0,
+			false,
+		));
+
+		while let Some((_, sinks, explored, _)) = path.last_mut()
 		{
-			if G::Directedness::directed()
+			if let Some(&v2) = sinks.get(*explored)
 			{
-				*dfs.payload.1 |= dfs.payload.0.contains(&sink);
-			}
-			else
-			{
-				// Check whether the second to last element is the same as the sink
-				// (the last element is the same as source, since on_visit is called
-				// before exploration)
-				if dfs.payload.0.len() >= 2
-					&& dfs
-						.payload
-						.0
-						.get(dfs.payload.0.len() - 2)
-						.map_or(false, |&v| v == sink)
+				*explored += 1;
+				let len = path.len();
+				if !G::Directedness::directed()
+					&& len >= 2 && path[len - 2].0 == v2
+					&& !path[len - 1].3
+				{
+					path[len - 1].3 = true;
+				}
+				else if let Some(&position) = on_path.get(&v2)
 				{
-					// This is an edge to the direct predecessor
-					// Therefore, only counts as a cycle if there
-					// are multiple edge between these two
-					*dfs.payload.1 |= dfs.graph.edges_between(source, sink).nth(1).is_some();
+					return Some(path[position..].iter().map(|(v, _, _, _)| *v).collect());
 				}
-				else
+				else if visited.insert(v2)
 				{
-					*dfs.payload.1 |= dfs.visited(sink);
+					on_path.insert(v2, len);
+					path.push((
+						v2,
+						g.edges_sourced_in(v2).map(|(v3, _)| v3).collect(),
+						0,
+						false,
+					));
 				}
 			}
-		}
-		let mut result = false;
-		let mut done = Vec::new();
-
-		// Ensure we have explored all the graph
-		for v in c.graph().all_vertices()
-		{
-			if !done.contains(&v)
+			else
 			{
-				done.push(v); // not returned by the dfs
-				let g = VertexInGraph::ensure_unvalidated(c.graph(), v);
-				let dfs = Dfs::new(&g, on_visit, on_exit, on_explore, (Vec::new(), &mut result));
-
-				dfs.for_each(|v| {
-					if !done.contains(&v)
-					{
-						done.push(v)
-					}
-				});
+				let (v, _, _, _) = path.pop().unwrap();
+				on_path.remove(&v);
 			}
 		}
-		!result
 	}
+	None
 }
 
 impl<C: Ensure + GraphDerefMut> AddEdge for AcyclicGraph<C>
 where
 	C::Graph: AddEdge,
+	<C::Graph as Graph>::Vertex: Hash,
 {
 	fn add_edge_weighted(
 		&mut self,
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		weight: Self::EdgeWeight,
-	) -> Result<(), ()>
+	) -> Result<(), GraphError<Self::Vertex>>
 	{
 		if !path_exists(self, sink.borrow(), source.borrow())
 		{
@@ -107,7 +145,10 @@ where
 		}
 		else
 		{
-			Err(())
+			Err(GraphError::PropertyViolated {
+				property: Self::property_name(),
+				witness: vec![*source.borrow(), *sink.borrow()],
+			})
 		}
 	}
 }
@@ -115,8 +156,29 @@ where
 impl<C: Ensure> NoLoops for AcyclicGraph<C> {}
 impl<C: Ensure> Acyclic for AcyclicGraph<C> {}
 
+impl<C, B> Rebase<B> for AcyclicGraph<C>
+where
+	C: Rebase<B>,
+	B: BaseGraph,
+	<C::Graph as Graph>::Vertex: Hash,
+	<<C::Rebased as GraphDeref>::Graph as Graph>::Vertex: Hash,
+{
+	type Rebased = AcyclicGraph<C::Rebased>;
+
+	fn rebased_payload(&self) -> <Self::Rebased as Release>::Payload
+	{
+		self.0.rebased_payload()
+	}
+}
+
 impl_ensurer! {
 	use<C> AcyclicGraph<C>: Ensure, Acyclic, NoLoops, AddEdge
 	as (self.0) : C
 	where C: Ensure
 }
+
+impl_serde! {
+	use<C> AcyclicGraph<C>
+	as (self.0) : C
+	where <C::Graph as Graph>::Vertex: Hash
+}
@@ -1,4 +1,4 @@
-use crate::core::{Directedness, Graph};
+use crate::core::{Directedness, Graph, GraphError};
 use num_traits::{One, PrimInt, Unsigned, Zero};
 use std::borrow::Borrow;
 
@@ -7,13 +7,16 @@ pub trait NewVertex: Graph
 {
 	/// Adds a new vertex with the given weight to the graph.
 	/// Returns the id of the new vertex.
-	fn new_vertex_weighted(&mut self, w: Self::VertexWeight) -> Result<Self::Vertex, ()>;
+	fn new_vertex_weighted(
+		&mut self,
+		w: Self::VertexWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>;
 
 	// Optional methods
 	/// Adds a new vertex to the graph.
 	/// Returns the id of the new vertex.
 	/// The weight of the vertex is the default.
-	fn new_vertex(&mut self) -> Result<Self::Vertex, ()>
+	fn new_vertex(&mut self) -> Result<Self::Vertex, GraphError<Self::Vertex>>
 	where
 		Self::VertexWeight: Default,
 	{
@@ -29,7 +32,48 @@ pub trait RemoveVertex: Graph
 	/// Removes the given vertex from the graph, returning its weight.
 	/// If the vertex still has edges incident on it, they are also removed,
 	/// dropping their weights.
-	fn remove_vertex(&mut self, v: impl Borrow<Self::Vertex>) -> Result<Self::VertexWeight, ()>;
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>;
+}
+
+/// The weight of a removed vertex and the new identities of the vertices that
+/// were given one.
+type TrackedRemoval<G> = Result<
+	(
+		<G as Graph>::VertexWeight,
+		Vec<(<G as Graph>::Vertex, <G as Graph>::Vertex)>,
+	),
+	GraphError<<G as Graph>::Vertex>,
+>;
+
+/// Removes the given vertex from the graph, returning its weight and the new
+/// identity of each remaining vertex that was given one.
+///
+/// Since removing a vertex may invalidate existing vertices, the graph is
+/// assumed to list its remaining vertices in the same order after the removal.
+pub(crate) fn remove_vertex_tracked<G: RemoveVertex>(g: &mut G, v: G::Vertex) -> TrackedRemoval<G>
+{
+	track_removal(g, v, |g, v| g.remove_vertex(v))
+}
+
+/// Removes the given vertex from the graph using the given function, like
+/// [`remove_vertex_tracked`](fn.remove_vertex_tracked.html).
+pub(crate) fn track_removal<G: Graph>(
+	g: &mut G,
+	v: G::Vertex,
+	remove: impl FnOnce(&mut G, G::Vertex) -> Result<G::VertexWeight, GraphError<G::Vertex>>,
+) -> TrackedRemoval<G>
+{
+	let before: Vec<_> = g.all_vertices().filter(|u| *u != v).collect();
+	let w = remove(g, v)?;
+	let moved = before
+		.into_iter()
+		.zip(g.all_vertices())
+		.filter(|(old, new)| old != new)
+		.collect();
+	Ok((w, moved))
 }
 
 pub trait AddEdge: Graph
@@ -40,7 +84,7 @@ pub trait AddEdge: Graph
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		weight: Self::EdgeWeight,
-	) -> Result<(), ()>;
+	) -> Result<(), GraphError<Self::Vertex>>;
 
 	// Optional methods
 
@@ -67,7 +111,7 @@ pub trait AddEdge: Graph
 		&mut self,
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
-	) -> Result<(), ()>
+	) -> Result<(), GraphError<Self::Vertex>>
 	where
 		Self::EdgeWeight: Default,
 	{
@@ -82,7 +126,7 @@ pub trait RemoveEdge: Graph
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		f: F,
-	) -> Result<Self::EdgeWeight, ()>
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
 	where
 		F: Fn(&Self::EdgeWeight) -> bool;
 
@@ -108,7 +152,7 @@ pub trait RemoveEdge: Graph
 		&mut self,
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
-	) -> Result<Self::EdgeWeight, ()>
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
 	{
 		self.remove_edge_where_weight(source, sink, |_| true)
 	}
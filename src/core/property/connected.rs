@@ -2,14 +2,14 @@ use crate::{
 	algo::Dfs,
 	core::{
 		property::{
-			proxy_remove_edge_where_weight, proxy_remove_vertex, DirectedGraph, HasVertexGraph,
-			RemoveEdge, RemoveVertex, Unilateral, Weak,
+			proxy_remove_edge_where_weight, proxy_remove_vertex, DirectedGraph, HasVertex,
+			HasVertexGraph, RemoveEdge, RemoveVertex, Unilateral, Weak,
 		},
 		proxy::ReverseGraph,
-		Ensure, Graph, GraphDerefMut,
+		BaseGraph, Ensure, Graph, GraphDeref, GraphDerefMut, GraphError, Rebase, Release,
 	},
 };
-use std::borrow::Borrow;
+use std::{borrow::Borrow, collections::HashSet, hash::Hash};
 
 /// A marker trait for graphs that are connected.
 ///
@@ -33,6 +33,8 @@ impl<C: Ensure> ConnectedGraph<C>
 }
 
 impl<C: Ensure> Ensure for ConnectedGraph<C>
+where
+	<C::Graph as Graph>::Vertex: Hash,
 {
 	fn ensure_unvalidated(c: Self::Ensured, _: ()) -> Self
 	{
@@ -40,37 +42,65 @@ impl<C: Ensure> Ensure for ConnectedGraph<C>
 	}
 
 	fn validate(c: &Self::Ensured, _: &()) -> bool
+	{
+		Self::diagnose(c, &()).is_ok()
+	}
+
+	/// If the graph isn't connected, returns two vertices as the witness, such
+	/// that there is no path from the first to the second.
+	fn diagnose(c: &Self::Ensured, _: &()) -> Result<(), GraphError<<C::Graph as Graph>::Vertex>>
 	{
 		let g = c.graph();
-		let v_count = g.all_vertices().count();
+		let violated = |witness| {
+			Err(GraphError::PropertyViolated {
+				property: Self::property_name(),
+				witness,
+			})
+		};
 
 		if let Ok(g) = HasVertexGraph::ensure(g, ())
 		{
-			let dfs_count = Dfs::new_simple(&g).count();
-			if (dfs_count + 1) == v_count
+			let v = g.get_vertex();
+			let reached: HashSet<_> = Dfs::new_simple(&g).collect();
+			if let Some(v2) = g
+				.all_vertices()
+				.find(|v2| *v2 != v && !reached.contains(v2))
 			{
-				// If its undirected, no more needs to be done
-				if let Ok(g) = DirectedGraph::ensure(g, ())
+				return violated(vec![v, v2]);
+			}
+
+			// If its undirected, no more needs to be done
+			if let Ok(g) = DirectedGraph::ensure(g, ())
+			{
+				let reverse = ReverseGraph::new(g);
+				let reached: HashSet<_> = Dfs::new_simple(&reverse).collect();
+				if let Some(v2) = c
+					.graph()
+					.all_vertices()
+					.find(|v2| *v2 != v && !reached.contains(v2))
 				{
-					let reverse = ReverseGraph::new(g);
-					if (Dfs::new_simple(&reverse).count() + 1) != v_count
-					{
-						return false;
-					}
+					return violated(vec![v2, v]);
 				}
-				return true;
 			}
-			return false;
 		}
-		true
+		Ok(())
+	}
+
+	fn property_name() -> &'static str
+	{
+		"Connected"
 	}
 }
 
 impl<C: Ensure + GraphDerefMut> RemoveVertex for ConnectedGraph<C>
 where
 	C::Graph: RemoveVertex,
+	<C::Graph as Graph>::Vertex: Hash,
 {
-	fn remove_vertex(&mut self, v: impl Borrow<Self::Vertex>) -> Result<Self::VertexWeight, ()>
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
 	{
 		proxy_remove_vertex::<ConnectedGraph<_>, _>(self.0.graph_mut(), v.borrow())
 	}
@@ -79,13 +109,14 @@ where
 impl<C: Ensure + GraphDerefMut> RemoveEdge for ConnectedGraph<C>
 where
 	C::Graph: RemoveEdge,
+	<C::Graph as Graph>::Vertex: Hash,
 {
 	fn remove_edge_where_weight<F>(
 		&mut self,
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		f: F,
-	) -> Result<Self::EdgeWeight, ()>
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
 	where
 		F: Fn(&Self::EdgeWeight) -> bool,
 	{
@@ -102,9 +133,30 @@ impl<C: Ensure> Weak for ConnectedGraph<C> {}
 impl<C: Ensure> Unilateral for ConnectedGraph<C> {}
 impl<C: Ensure> Connected for ConnectedGraph<C> {}
 
+impl<C, B> Rebase<B> for ConnectedGraph<C>
+where
+	C: Rebase<B>,
+	B: BaseGraph,
+	<C::Graph as Graph>::Vertex: Hash,
+	<<C::Rebased as GraphDeref>::Graph as Graph>::Vertex: Hash,
+{
+	type Rebased = ConnectedGraph<C::Rebased>;
+
+	fn rebased_payload(&self) -> <Self::Rebased as Release>::Payload
+	{
+		self.0.rebased_payload()
+	}
+}
+
 impl_ensurer! {
 	use<C> ConnectedGraph<C>: Ensure, Connected, Unilateral, Weak, RemoveVertex, RemoveEdge,
 	// A new vertex wouldn't be connected to the rest of the graph
 	NewVertex
 	as (self.0) : C
 }
+
+impl_serde! {
+	use<C> ConnectedGraph<C>
+	as (self.0) : C
+	where <C::Graph as Graph>::Vertex: Hash
+}
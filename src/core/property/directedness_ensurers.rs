@@ -1,4 +1,4 @@
-use crate::core::{Directed, Directedness, Ensure, Graph, Undirected};
+use crate::core::{BaseGraph, Directed, Directedness, Ensure, Graph, Rebase, Release, Undirected};
 use delegate::delegate;
 use std::borrow::Borrow;
 
@@ -16,6 +16,11 @@ impl<C: Ensure> Ensure for DirectedGraph<C>
 	{
 		<<C::Graph as Graph>::Directedness as Directedness>::directed()
 	}
+
+	fn property_name() -> &'static str
+	{
+		"Directed"
+	}
 }
 
 impl<C: Ensure> Graph for DirectedGraph<C>
@@ -40,11 +45,29 @@ impl<C: Ensure> Graph for DirectedGraph<C>
 	}
 }
 
+impl<C, B> Rebase<B> for DirectedGraph<C>
+where
+	C: Rebase<B>,
+	B: BaseGraph,
+{
+	type Rebased = DirectedGraph<C::Rebased>;
+
+	fn rebased_payload(&self) -> <Self::Rebased as Release>::Payload
+	{
+		self.0.rebased_payload()
+	}
+}
+
 impl_ensurer! {
 	use<C> DirectedGraph<C>: Ensure, Graph, DirectedConstraint
 	as (self.0) : C
 }
 
+impl_serde! {
+	use<C> DirectedGraph<C>
+	as (self.0) : C
+}
+
 #[derive(Clone, Debug)]
 pub struct UndirectedGraph<C: Ensure>(C);
 
@@ -59,6 +82,11 @@ impl<C: Ensure> Ensure for UndirectedGraph<C>
 	{
 		!<<C::Graph as Graph>::Directedness as Directedness>::directed()
 	}
+
+	fn property_name() -> &'static str
+	{
+		"Undirected"
+	}
 }
 
 impl<C: Ensure> Graph for UndirectedGraph<C>
@@ -83,7 +111,25 @@ impl<C: Ensure> Graph for UndirectedGraph<C>
 	}
 }
 
+impl<C, B> Rebase<B> for UndirectedGraph<C>
+where
+	C: Rebase<B>,
+	B: BaseGraph,
+{
+	type Rebased = UndirectedGraph<C::Rebased>;
+
+	fn rebased_payload(&self) -> <Self::Rebased as Release>::Payload
+	{
+		self.0.rebased_payload()
+	}
+}
+
 impl_ensurer! {
 	use<C> UndirectedGraph<C>: Ensure, Graph, UndirectedConstraint
 	as (self.0) : C
 }
+
+impl_serde! {
+	use<C> UndirectedGraph<C>
+	as (self.0) : C
+}
@@ -1,4 +1,7 @@
-use crate::core::{property::RemoveVertex, Ensure, Graph, GraphDerefMut};
+use crate::core::{
+	property::RemoveVertex, proxy::ProxyVertex, BaseGraph, Ensure, Graph, GraphDeref,
+	GraphDerefMut, GraphError, Rebase, Release,
+};
 use std::{
 	borrow::Borrow,
 	fmt::{Debug, Error, Formatter},
@@ -37,13 +40,21 @@ impl<C: Ensure> Ensure for HasVertexGraph<C>
 	{
 		c.graph().all_vertices().next().is_some()
 	}
+
+	fn property_name() -> &'static str
+	{
+		"HasVertex"
+	}
 }
 
 impl<C: Ensure + GraphDerefMut> RemoveVertex for HasVertexGraph<C>
 where
 	C::Graph: RemoveVertex,
 {
-	fn remove_vertex(&mut self, v: impl Borrow<Self::Vertex>) -> Result<Self::VertexWeight, ()>
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
 	{
 		if self.all_vertices().nth(1).is_some()
 		{
@@ -51,7 +62,10 @@ where
 		}
 		else
 		{
-			Err(())
+			Err(GraphError::PropertyViolated {
+				property: Self::property_name(),
+				witness: vec![*v.borrow()],
+			})
 		}
 	}
 }
@@ -66,11 +80,29 @@ impl<C: Ensure> HasVertex for HasVertexGraph<C>
 	}
 }
 
+impl<C, B> Rebase<B> for HasVertexGraph<C>
+where
+	C: Rebase<B>,
+	B: BaseGraph,
+{
+	type Rebased = HasVertexGraph<C::Rebased>;
+
+	fn rebased_payload(&self) -> <Self::Rebased as Release>::Payload
+	{
+		self.0.rebased_payload()
+	}
+}
+
 impl_ensurer! {
 	use<C> HasVertexGraph<C>: Ensure, HasVertex, RemoveVertex
 	as (self.0) : C
 }
 
+impl_serde! {
+	use<C> HasVertexGraph<C>
+	as (self.0) : C
+}
+
 /// Ensures a specific vertex is in the underlying graph.
 ///
 /// That vertex is guaranteed to be returned by any call to `get_vertex` and
@@ -80,7 +112,10 @@ pub struct VertexInGraph<C: Ensure>(C, <C::Graph as Graph>::Vertex);
 
 impl<C: Ensure> VertexInGraph<C>
 {
-	pub fn set_vertex(&mut self, v: impl Borrow<<C::Graph as Graph>::Vertex>) -> Result<(), ()>
+	pub fn set_vertex(
+		&mut self,
+		v: impl Borrow<<C::Graph as Graph>::Vertex>,
+	) -> Result<(), GraphError<<C::Graph as Graph>::Vertex>>
 	{
 		if self.0.graph().contains_vertex(v.borrow())
 		{
@@ -89,7 +124,7 @@ impl<C: Ensure> VertexInGraph<C>
 		}
 		else
 		{
-			Err(())
+			Err(GraphError::VertexNotFound(*v.borrow()))
 		}
 	}
 }
@@ -119,13 +154,40 @@ impl<C: Ensure> Ensure for VertexInGraph<C>
 	{
 		c.graph().contains_vertex(*p)
 	}
+
+	/// If the vertex isn't in the graph, returns it as the witness.
+	fn diagnose(
+		c: &Self::Ensured,
+		p: &<C::Graph as Graph>::Vertex,
+	) -> Result<(), GraphError<<C::Graph as Graph>::Vertex>>
+	{
+		if Self::validate(c, p)
+		{
+			Ok(())
+		}
+		else
+		{
+			Err(GraphError::PropertyViolated {
+				property: Self::property_name(),
+				witness: vec![*p],
+			})
+		}
+	}
+
+	fn property_name() -> &'static str
+	{
+		"VertexInGraph"
+	}
 }
 
 impl<C: Ensure + GraphDerefMut> RemoveVertex for VertexInGraph<C>
 where
 	C::Graph: RemoveVertex,
 {
-	fn remove_vertex(&mut self, v: impl Borrow<Self::Vertex>) -> Result<Self::VertexWeight, ()>
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
 	{
 		if self.1.borrow() != v.borrow()
 		{
@@ -133,7 +195,10 @@ where
 		}
 		else
 		{
-			Err(())
+			Err(GraphError::PropertyViolated {
+				property: Self::property_name(),
+				witness: vec![*v.borrow()],
+			})
 		}
 	}
 }
@@ -146,8 +211,28 @@ impl<C: Ensure> HasVertex for VertexInGraph<C>
 	}
 }
 
+/// The vertex is ensured to be in the proxy as an underlying vertex.
+impl<C, B> Rebase<B> for VertexInGraph<C>
+where
+	C: Rebase<B>,
+	B: BaseGraph,
+	C::Rebased: GraphDeref<Graph: Graph<Vertex = ProxyVertex<<C::Graph as Graph>::Vertex>>>,
+{
+	type Rebased = VertexInGraph<C::Rebased>;
+
+	fn rebased_payload(&self) -> <Self::Rebased as Release>::Payload
+	{
+		(ProxyVertex::Underlying(self.1), self.0.rebased_payload())
+	}
+}
+
 impl_ensurer! {
 	use<C> VertexInGraph<C>: Ensure, HasVertex, RemoveVertex
 	as (self.0) : C
 	as (self.1) : <C::Graph as Graph>::Vertex
 }
+
+impl_serde! {
+	use<C> VertexInGraph<C>
+	as (self.0, self.1) : C, <C::Graph as Graph>::Vertex
+}
@@ -0,0 +1,677 @@
+use crate::core::{
+	property::{
+		remove_vertex_tracked, track_removal, AddEdge, NewVertex, RemoveEdge, RemoveVertex,
+	},
+	BaseGraph, Directedness, Ensure, Graph, GraphDerefMut, GraphError, MutateUnvalidated, Rebase,
+	Release,
+};
+use std::{borrow::Borrow, collections::HashMap, hash::Hash};
+
+/// A graph whose mutations can be undone and redone.
+///
+/// Ensurers wrapping a graph with this property validate their own property
+/// after each undo or redo. If it no longer holds, the undo or redo is
+/// reverted and refused with the error describing the violation.
+pub trait History: Graph
+{
+	/// Returns whether there is a mutation to undo.
+	fn can_undo(&self) -> bool;
+
+	/// Returns whether there is an undone mutation to redo.
+	fn can_redo(&self) -> bool;
+
+	/// Marks the current point in the history with the given name, such that
+	/// the graph can later be reverted to it.
+	///
+	/// Replaces any existing checkpoint with the same name.
+	fn checkpoint(&mut self, name: impl Into<String>);
+
+	/// Returns how many mutations must be undone to get to the given
+	/// checkpoint.
+	///
+	/// Is negative if undone mutations must be redone instead, and `None` if
+	/// the checkpoint doesn't exist.
+	fn checkpoint_distance(&self, name: &str) -> Option<isize>;
+
+	/// Reverts the latest mutation that hasn't been undone.
+	///
+	/// Returns whether there was a mutation to undo.
+	fn undo(&mut self) -> Result<bool, GraphError<Self::Vertex>>;
+
+	/// Redoes the latest undone mutation.
+	///
+	/// Returns whether there was a mutation to redo.
+	fn redo(&mut self) -> Result<bool, GraphError<Self::Vertex>>;
+
+	/// Undoes or redoes mutations until the graph is at the given checkpoint.
+	///
+	/// If the checkpoint doesn't exist, returns
+	/// [`Unsupported`](../enum.GraphError.html#variant.Unsupported).
+	/// If an undo or redo is refused, the graph is left at the point in the
+	/// history where it was refused.
+	fn revert_to(&mut self, name: &str) -> Result<(), GraphError<Self::Vertex>>
+	{
+		let distance = self
+			.checkpoint_distance(name)
+			.ok_or(GraphError::Unsupported)?;
+		for _ in 0..distance
+		{
+			self.undo()?;
+		}
+		for _ in distance..0
+		{
+			self.redo()?;
+		}
+		Ok(())
+	}
+}
+
+/// A recorded change to a graph.
+///
+/// Vertices are referred to by their keys in the history, since the graph may
+/// give them new identities.
+///
+/// Performing a change returns the change that reverts it.
+enum Change<G: Graph>
+{
+	/// Adds the vertex with the given key and weight, together with the given
+	/// edges.
+	InsertVertex(usize, G::VertexWeight, Vec<(usize, usize, G::EdgeWeight)>),
+
+	/// Removes the vertex and the edges incident on it.
+	RemoveVertex(usize),
+
+	/// Adds an edge with the given weight.
+	AddEdge(usize, usize, G::EdgeWeight),
+
+	/// Removes an edge from the first vertex to the second with the given
+	/// weight.
+	RemoveEdge(usize, usize, G::EdgeWeight),
+}
+
+impl<G> Change<G>
+where
+	G: NewVertex + RemoveVertex + AddEdge + RemoveEdge,
+	G::Vertex: Hash,
+	G::VertexWeight: Clone,
+	G::EdgeWeight: Clone + PartialEq,
+{
+	/// Performs the change on the given graph, returning the change that
+	/// reverts it.
+	///
+	/// If the graph rejects the change, the graph is left as it was.
+	fn perform(&self, g: &mut G, vertices: &mut Vertices<G>)
+		-> Result<Self, GraphError<G::Vertex>>
+	{
+		match self
+		{
+			Change::InsertVertex(key, w, edges) =>
+			{
+				let v = g.new_vertex_weighted(w.clone())?;
+				vertices.insert(*key, v);
+				for (source, sink, w) in edges.iter()
+				{
+					let (source, sink) = (vertices.vertex(*source), vertices.vertex(*sink));
+					if let Err(err) = g.add_edge_weighted(source, sink, w.clone())
+					{
+						// Removing the vertex also removes the edges added so far
+						let _ = vertices.remove(g, v);
+						return Err(err);
+					}
+				}
+				Ok(Change::RemoveVertex(*key))
+			},
+			Change::RemoveVertex(key) =>
+			{
+				let v = vertices.vertex(*key);
+				let edges = incident_edges(g, v, vertices);
+				let w = vertices.remove(g, v)?;
+				Ok(Change::InsertVertex(*key, w, edges))
+			},
+			Change::AddEdge(source, sink, w) =>
+			{
+				g.add_edge_weighted(vertices.vertex(*source), vertices.vertex(*sink), w.clone())?;
+				Ok(Change::RemoveEdge(*source, *sink, w.clone()))
+			},
+			Change::RemoveEdge(source, sink, w) =>
+			{
+				let w = g.remove_edge_where_weight(
+					vertices.vertex(*source),
+					vertices.vertex(*sink),
+					|weight| weight == w,
+				)?;
+				Ok(Change::AddEdge(*source, *sink, w))
+			},
+		}
+	}
+}
+
+/// Returns a copy of every edge incident on the given vertex, with its
+/// endpoints given by their keys.
+fn incident_edges<G: Graph>(
+	g: &G,
+	v: G::Vertex,
+	vertices: &mut Vertices<G>,
+) -> Vec<(usize, usize, G::EdgeWeight)>
+where
+	G::Vertex: Hash,
+	G::EdgeWeight: Clone,
+{
+	let mut edges: Vec<_> = g
+		.edges_sourced_in(v)
+		.map(|(sink, w)| (v, sink, w.clone()))
+		.collect();
+	if G::Directedness::directed()
+	{
+		edges.extend(
+			g.edges_sinked_in(v)
+				.filter(|(source, _)| *source != v)
+				.map(|(source, w)| (source, v, w.clone())),
+		);
+	}
+	edges
+		.into_iter()
+		.map(|(source, sink, w)| (vertices.key(source), vertices.key(sink), w))
+		.collect()
+}
+
+/// The vertices mentioned by a history, each identified by a key that stays
+/// the same when the graph gives the vertex a new identity.
+struct Vertices<G: Graph>
+{
+	/// The vertex with each key, or `None` while it is removed
+	vertices: Vec<Option<G::Vertex>>,
+
+	/// The key of each vertex in the graph that has one
+	keys: HashMap<G::Vertex, usize>,
+}
+
+impl<G: Graph> Vertices<G>
+where
+	G::Vertex: Hash,
+{
+	/// Returns the key of the given vertex, giving it one if it has none.
+	fn key(&mut self, v: G::Vertex) -> usize
+	{
+		let vertices = &mut self.vertices;
+		*self.keys.entry(v).or_insert_with(|| {
+			vertices.push(Some(v));
+			vertices.len() - 1
+		})
+	}
+
+	/// Returns the vertex with the given key.
+	///
+	/// ### Panics
+	///
+	/// If the vertex is removed. Changes are only performed at the point in
+	/// the history where they were recorded, where all the vertices they
+	/// mention are in the graph.
+	fn vertex(&self, key: usize) -> G::Vertex
+	{
+		self.vertices[key].expect("a change mentions a removed vertex")
+	}
+
+	/// Gives the vertex the given key.
+	fn insert(&mut self, key: usize, v: G::Vertex)
+	{
+		self.vertices[key] = Some(v);
+		self.keys.insert(v, key);
+	}
+
+	/// Removes the vertex from the graph, keeping the keys of the remaining
+	/// vertices.
+	fn remove(&mut self, g: &mut G, v: G::Vertex) -> Result<G::VertexWeight, GraphError<G::Vertex>>
+	where
+		G: RemoveVertex,
+	{
+		let (w, moved) = remove_vertex_tracked(g, v)?;
+		self.removed(v, moved);
+		Ok(w)
+	}
+
+	/// Forgets the given vertex that was removed from the graph, and follows
+	/// the remaining vertices the graph gave new identities.
+	fn removed(&mut self, v: G::Vertex, moved: Vec<(G::Vertex, G::Vertex)>)
+	{
+		if let Some(key) = self.keys.remove(&v)
+		{
+			self.vertices[key] = None;
+		}
+		let moved: Vec<_> = moved
+			.into_iter()
+			.filter_map(|(old, new)| self.keys.remove(&old).map(|key| (key, new)))
+			.collect();
+		for (key, new) in moved
+		{
+			self.insert(key, new);
+		}
+	}
+}
+
+/// The undoable and redoable changes of a history graph.
+struct Record<G: Graph>
+{
+	/// The changes that revert each mutation, with the latest mutation last
+	undo: Vec<Change<G>>,
+
+	/// The changes that redo each undone mutation, with the latest undo last
+	redo: Vec<Change<G>>,
+
+	/// The number of undoable mutations at each checkpoint
+	checkpoints: HashMap<String, usize>,
+
+	/// The vertices the changes mention
+	vertices: Vertices<G>,
+}
+
+/// Ensures the underlying graph records its mutations, such that they can be
+/// undone and redone.
+///
+/// Every mutation made through [`NewVertex`](trait.NewVertex.html),
+/// [`RemoveVertex`](trait.RemoveVertex.html), [`AddEdge`](trait.AddEdge.html),
+/// or [`RemoveEdge`](trait.RemoveEdge.html) is recorded as the change that
+/// reverts it. Changes to weights made through
+/// [`GraphMut`](../trait.GraphMut.html) are not recorded.
+/// Making a new mutation after undoing discards the undone mutations, along
+/// with any checkpoints made after them.
+///
+/// Undoing and redoing are performed through the underlying graph, so any
+/// ensurer it wraps, e.g. [`UniqueGraph`](struct.UniqueGraph.html) or
+/// [`AcyclicGraph`](struct.AcyclicGraph.html), may refuse them.
+/// Ensurers wrapping the history graph validate their properties after each
+/// undo or redo made through [`History`](trait.History.html), and refuse it if
+/// they no longer hold.
+/// A refused undo or redo leaves the graph and its history as they were.
+///
+/// Undoing the removal of a vertex adds a new vertex, which the graph might
+/// give a new identity. Likewise, removing a vertex might give the remaining
+/// vertices new identities, like in
+/// [`AdjListGraph`](../../common/struct.AdjListGraph.html). The history keeps
+/// track of the vertices' identities, assuming the graph lists its remaining
+/// vertices in the same order after a removal. Any copies of the old vertices
+/// held elsewhere are not updated. Undoing and redoing require the weights to
+/// be cloneable, and edge weights to be comparable, such that the right one of
+/// any parallel edges is removed.
+///
+/// ### Usage
+/// ```
+/// # use graphene::{
+/// # 	common::AdjListGraph,
+/// # 	core::{
+/// # 		property::{AcyclicGraph, AddEdge, History, HistoryGraph, NewVertex, RemoveEdge},
+/// # 		EnsureUnloaded, Graph,
+/// # 	},
+/// # };
+/// let mut graph = AdjListGraph::<(), ()>::new();
+/// let v0 = graph.new_vertex().unwrap();
+/// let v1 = graph.new_vertex().unwrap();
+/// let mut graph = AcyclicGraph::ensure(HistoryGraph::ensure(graph).unwrap()).unwrap();
+///
+/// graph.add_edge(&v0, &v1).unwrap();
+/// graph.checkpoint("one edge");
+/// graph.remove_edge(&v0, &v1).unwrap();
+/// graph.add_edge(&v1, &v0).unwrap();
+///
+/// graph.revert_to("one edge").unwrap();
+/// assert!(graph.edges_between(&v0, &v1).next().is_some());
+/// assert!(graph.edges_between(&v1, &v0).next().is_none());
+///
+/// assert!(graph.redo().unwrap());
+/// assert!(graph.edges_between(&v0, &v1).next().is_none());
+/// ```
+pub struct HistoryGraph<C: Ensure>(C, Record<C::Graph>);
+
+impl<C: Ensure> HistoryGraph<C>
+{
+	/// Records a mutation, given the change that reverts it.
+	fn record(&mut self, revert: Change<C::Graph>)
+	{
+		let done = self.1.undo.len();
+		self.1.checkpoints.retain(|_, &mut point| point <= done);
+		self.1.redo.clear();
+		self.1.undo.push(revert);
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> HistoryGraph<C>
+where
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	/// Records the addition of the given vertex.
+	fn record_new_vertex(&mut self, v: <C::Graph as Graph>::Vertex)
+	{
+		let key = self.1.vertices.key(v);
+		self.record(Change::RemoveVertex(key));
+	}
+
+	/// Removes the given vertex with the given function, which also returns
+	/// the new identities it gave the remaining vertices, and records the
+	/// removal.
+	fn record_remove_vertex(
+		&mut self,
+		v: <C::Graph as Graph>::Vertex,
+		remove: impl FnOnce(
+			&mut C::Graph,
+			<C::Graph as Graph>::Vertex,
+		) -> Result<
+			(
+				<C::Graph as Graph>::VertexWeight,
+				Vec<(<C::Graph as Graph>::Vertex, <C::Graph as Graph>::Vertex)>,
+			),
+			GraphError<<C::Graph as Graph>::Vertex>,
+		>,
+	) -> Result<<C::Graph as Graph>::VertexWeight, GraphError<<C::Graph as Graph>::Vertex>>
+	where
+		<C::Graph as Graph>::VertexWeight: Clone,
+		<C::Graph as Graph>::EdgeWeight: Clone,
+	{
+		let vertices = &mut self.1.vertices;
+		let edges = incident_edges(self.0.graph(), v, vertices);
+		let key = vertices.key(v);
+		let (w, moved) = remove(self.0.graph_mut(), v)?;
+		vertices.removed(v, moved);
+		self.record(Change::InsertVertex(key, w.clone(), edges));
+		Ok(w)
+	}
+
+	/// Records the addition of an edge with the given weight.
+	fn record_add_edge(
+		&mut self,
+		source: <C::Graph as Graph>::Vertex,
+		sink: <C::Graph as Graph>::Vertex,
+		w: <C::Graph as Graph>::EdgeWeight,
+	)
+	{
+		let vertices = &mut self.1.vertices;
+		let (source, sink) = (vertices.key(source), vertices.key(sink));
+		self.record(Change::RemoveEdge(source, sink, w));
+	}
+
+	/// Records the removal of an edge with the given weight.
+	fn record_remove_edge(
+		&mut self,
+		source: <C::Graph as Graph>::Vertex,
+		sink: <C::Graph as Graph>::Vertex,
+		w: <C::Graph as Graph>::EdgeWeight,
+	)
+	{
+		let vertices = &mut self.1.vertices;
+		let (source, sink) = (vertices.key(source), vertices.key(sink));
+		self.record(Change::AddEdge(source, sink, w));
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> HistoryGraph<C>
+where
+	C::Graph: NewVertex + RemoveVertex + AddEdge + RemoveEdge,
+	<C::Graph as Graph>::Vertex: Hash,
+	<C::Graph as Graph>::VertexWeight: Clone,
+	<C::Graph as Graph>::EdgeWeight: Clone + PartialEq,
+{
+	/// Performs the latest change in `from`, moving the change that reverts it
+	/// to `to`.
+	fn step(
+		g: &mut C::Graph,
+		vertices: &mut Vertices<C::Graph>,
+		from: &mut Vec<Change<C::Graph>>,
+		to: &mut Vec<Change<C::Graph>>,
+	) -> Result<bool, GraphError<<C::Graph as Graph>::Vertex>>
+	{
+		let revert = match from.last()
+		{
+			Some(change) => change.perform(g, vertices)?,
+			None => return Ok(false),
+		};
+		from.pop();
+		to.push(revert);
+		Ok(true)
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> History for HistoryGraph<C>
+where
+	C::Graph: NewVertex + RemoveVertex + AddEdge + RemoveEdge,
+	<C::Graph as Graph>::Vertex: Hash,
+	<C::Graph as Graph>::VertexWeight: Clone,
+	<C::Graph as Graph>::EdgeWeight: Clone + PartialEq,
+{
+	fn can_undo(&self) -> bool
+	{
+		!self.1.undo.is_empty()
+	}
+
+	fn can_redo(&self) -> bool
+	{
+		!self.1.redo.is_empty()
+	}
+
+	fn checkpoint(&mut self, name: impl Into<String>)
+	{
+		self.1.checkpoints.insert(name.into(), self.1.undo.len());
+	}
+
+	fn checkpoint_distance(&self, name: &str) -> Option<isize>
+	{
+		self.1
+			.checkpoints
+			.get(name)
+			.map(|point| self.1.undo.len() as isize - *point as isize)
+	}
+
+	fn undo(&mut self) -> Result<bool, GraphError<Self::Vertex>>
+	{
+		let Record {
+			undo,
+			redo,
+			vertices,
+			..
+		} = &mut self.1;
+		Self::step(self.0.graph_mut(), vertices, undo, redo)
+	}
+
+	fn redo(&mut self) -> Result<bool, GraphError<Self::Vertex>>
+	{
+		let Record {
+			undo,
+			redo,
+			vertices,
+			..
+		} = &mut self.1;
+		Self::step(self.0.graph_mut(), vertices, redo, undo)
+	}
+}
+
+impl<C: Ensure> Ensure for HistoryGraph<C>
+{
+	fn ensure_unvalidated(c: Self::Ensured, _: ()) -> Self
+	{
+		Self(
+			c,
+			Record {
+				undo: Vec::new(),
+				redo: Vec::new(),
+				checkpoints: HashMap::new(),
+				vertices: Vertices {
+					vertices: Vec::new(),
+					keys: HashMap::new(),
+				},
+			},
+		)
+	}
+
+	fn validate(_: &Self::Ensured, _: &()) -> bool
+	{
+		true
+	}
+
+	fn property_name() -> &'static str
+	{
+		"History"
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> NewVertex for HistoryGraph<C>
+where
+	C::Graph: NewVertex,
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	fn new_vertex_weighted(
+		&mut self,
+		w: Self::VertexWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
+	{
+		let v = self.0.graph_mut().new_vertex_weighted(w)?;
+		self.record_new_vertex(v);
+		Ok(v)
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> RemoveVertex for HistoryGraph<C>
+where
+	C::Graph: RemoveVertex,
+	<C::Graph as Graph>::Vertex: Hash,
+	<C::Graph as Graph>::VertexWeight: Clone,
+	<C::Graph as Graph>::EdgeWeight: Clone,
+{
+	/// Since the weights are needed to undo the removal, the vertex weight and
+	/// the weights of the incident edges are cloned.
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
+	{
+		self.record_remove_vertex(*v.borrow(), remove_vertex_tracked)
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> AddEdge for HistoryGraph<C>
+where
+	C::Graph: AddEdge,
+	<C::Graph as Graph>::Vertex: Hash,
+	<C::Graph as Graph>::EdgeWeight: Clone,
+{
+	/// Since the weight is needed to undo the addition, it is cloned.
+	fn add_edge_weighted(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+		weight: Self::EdgeWeight,
+	) -> Result<(), GraphError<Self::Vertex>>
+	{
+		let (source, sink) = (*source.borrow(), *sink.borrow());
+		let w = weight.clone();
+		self.0.graph_mut().add_edge_weighted(source, sink, weight)?;
+		self.record_add_edge(source, sink, w);
+		Ok(())
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> RemoveEdge for HistoryGraph<C>
+where
+	C::Graph: RemoveEdge,
+	<C::Graph as Graph>::Vertex: Hash,
+	<C::Graph as Graph>::EdgeWeight: Clone,
+{
+	/// Since the weight is needed to undo the removal, it is cloned.
+	fn remove_edge_where_weight<F>(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+		f: F,
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
+	where
+		F: Fn(&Self::EdgeWeight) -> bool,
+	{
+		let (source, sink) = (*source.borrow(), *sink.borrow());
+		let w = self
+			.0
+			.graph_mut()
+			.remove_edge_where_weight(source, sink, f)?;
+		self.record_remove_edge(source, sink, w.clone());
+		Ok(w)
+	}
+}
+
+/// Records the mutations like the mutations made through the mutation
+/// traits.
+impl<C: Ensure + GraphDerefMut> MutateUnvalidated for HistoryGraph<C>
+where
+	C::Graph: MutateUnvalidated,
+	<C::Graph as Graph>::Vertex: Hash,
+	<C::Graph as Graph>::VertexWeight: Clone,
+	<C::Graph as Graph>::EdgeWeight: Clone,
+{
+	fn new_vertex_unvalidated(
+		&mut self,
+		w: Self::VertexWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
+	{
+		let v = self.0.graph_mut().new_vertex_unvalidated(w)?;
+		self.record_new_vertex(v);
+		Ok(v)
+	}
+
+	fn remove_vertex_unvalidated(
+		&mut self,
+		v: Self::Vertex,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
+	{
+		self.record_remove_vertex(v, |g, v| {
+			track_removal(g, v, |g, v| g.remove_vertex_unvalidated(v))
+		})
+	}
+
+	fn add_edge_unvalidated(
+		&mut self,
+		source: Self::Vertex,
+		sink: Self::Vertex,
+		weight: Self::EdgeWeight,
+	) -> Result<(), GraphError<Self::Vertex>>
+	{
+		let w = weight.clone();
+		self.0
+			.graph_mut()
+			.add_edge_unvalidated(source, sink, weight)?;
+		self.record_add_edge(source, sink, w);
+		Ok(())
+	}
+
+	fn remove_edge_unvalidated<F>(
+		&mut self,
+		source: Self::Vertex,
+		sink: Self::Vertex,
+		f: F,
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
+	where
+		F: Fn(&Self::EdgeWeight) -> bool,
+	{
+		let w = self
+			.0
+			.graph_mut()
+			.remove_edge_unvalidated(source, sink, f)?;
+		self.record_remove_edge(source, sink, w.clone());
+		Ok(w)
+	}
+}
+
+/// Since it always holds, the rebased ensurer leaves this ensurer out.
+impl<C, B> Rebase<B> for HistoryGraph<C>
+where
+	C: Rebase<B>,
+	B: BaseGraph,
+{
+	type Rebased = C::Rebased;
+
+	fn rebased_payload(&self) -> <Self::Rebased as Release>::Payload
+	{
+		self.0.rebased_payload()
+	}
+}
+
+impl_ensurer! {
+	use<C> HistoryGraph<C>: Ensure, NewVertex, RemoveVertex, AddEdge, RemoveEdge, History,
+	MutateUnvalidated
+	as (self.0) : C
+	where C: Ensure
+}
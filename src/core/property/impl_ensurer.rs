@@ -106,6 +106,49 @@ macro_rules! base_graph_inner {
 		impl$(<$($generics),+>)? $crate::core::BaseGraph for $struct
 		where $($bounds)*
 		{}
+
+		impl$(<$($generics),+>)? $crate::core::MutateUnvalidated for $struct
+		where
+			Self: $crate::core::property::NewVertex
+				+ $crate::core::property::RemoveVertex
+				+ $crate::core::property::AddEdge
+				+ $crate::core::property::RemoveEdge,
+			$($bounds)*
+		{
+			fn new_vertex_unvalidated(&mut self, w: Self::VertexWeight)
+				-> Result<Self::Vertex, $crate::core::GraphError<Self::Vertex>>
+			{
+				$crate::core::property::NewVertex::new_vertex_weighted(self, w)
+			}
+
+			fn remove_vertex_unvalidated(&mut self, v: Self::Vertex)
+				-> Result<Self::VertexWeight, $crate::core::GraphError<Self::Vertex>>
+			{
+				$crate::core::property::RemoveVertex::remove_vertex(self, v)
+			}
+
+			fn add_edge_unvalidated(
+				&mut self,
+				source: Self::Vertex,
+				sink: Self::Vertex,
+				weight: Self::EdgeWeight,
+			) -> Result<(), $crate::core::GraphError<Self::Vertex>>
+			{
+				$crate::core::property::AddEdge::add_edge_weighted(self, source, sink, weight)
+			}
+
+			fn remove_edge_unvalidated<P>(
+				&mut self,
+				source: Self::Vertex,
+				sink: Self::Vertex,
+				f: P,
+			) -> Result<Self::EdgeWeight, $crate::core::GraphError<Self::Vertex>>
+			where
+				P: Fn(&Self::EdgeWeight) -> bool,
+			{
+				$crate::core::property::RemoveEdge::remove_edge_where_weight(self, source, sink, f)
+			}
+		}
 	}
 }
 
@@ -309,7 +352,7 @@ macro_rules! impl_properties {
 				delegate::delegate! {
 					to $crate::core::GraphDerefMut::graph_mut(&mut self$($delegate)+){
 						fn new_vertex_weighted(&mut self, w: Self::VertexWeight)
-							-> Result<Self::Vertex, ()>;
+							-> Result<Self::Vertex, $crate::core::GraphError<Self::Vertex>>;
 					}
 				}
 			}
@@ -333,7 +376,7 @@ macro_rules! impl_properties {
 				delegate::delegate! {
 					to $crate::core::GraphDerefMut::graph_mut(&mut self$($delegate)+) {
 						fn remove_vertex(&mut self, v: impl std::borrow::Borrow<Self::Vertex>)
-							-> Result<Self::VertexWeight, ()>;
+							-> Result<Self::VertexWeight, $crate::core::GraphError<Self::Vertex>>;
 					}
 				}
 			}
@@ -361,7 +404,7 @@ macro_rules! impl_properties {
 							source: impl std::borrow::Borrow<Self::Vertex>,
 							sink: impl std::borrow::Borrow<Self::Vertex>,
 							weight: Self::EdgeWeight,
-						) -> Result<(), ()>;
+						) -> Result<(), $crate::core::GraphError<Self::Vertex>>;
 					}
 				}
 			}
@@ -389,7 +432,47 @@ macro_rules! impl_properties {
 							source: impl std::borrow::Borrow<Self::Vertex>,
 							sink: impl std::borrow::Borrow<Self::Vertex>,
 							f: F,
-						) -> Result<Self::EdgeWeight, ()>
+						) -> Result<Self::EdgeWeight, $crate::core::GraphError<Self::Vertex>>
+							where
+								F: Fn(&Self::EdgeWeight) -> bool;
+					}
+				}
+			}
+		}
+
+		// MutateUnvalidated
+		$crate::impl_properties!{
+			@struct [ $struct ]
+			@generic [ $($generics)* ]
+			@delegate [ $delegate_type ]
+			$(@exclude [ $($exclude_props)* ])?
+			$(@include [ $($include_props)* ])?
+			@bounds [
+				$delegate_type: $crate::core::GraphDerefMut,
+				<$delegate_type as $crate::core::GraphDeref>::Graph:
+					$crate::core::MutateUnvalidated,
+				$($bounds)*
+			]
+			@trait_id MutateUnvalidated [$crate::core]
+			@implement {
+				delegate::delegate! {
+					to $crate::core::GraphDerefMut::graph_mut(&mut self$($delegate)+) {
+						fn new_vertex_unvalidated(&mut self, w: Self::VertexWeight)
+							-> Result<Self::Vertex, $crate::core::GraphError<Self::Vertex>>;
+						fn remove_vertex_unvalidated(&mut self, v: Self::Vertex)
+							-> Result<Self::VertexWeight, $crate::core::GraphError<Self::Vertex>>;
+						fn add_edge_unvalidated(
+							&mut self,
+							source: Self::Vertex,
+							sink: Self::Vertex,
+							weight: Self::EdgeWeight,
+						) -> Result<(), $crate::core::GraphError<Self::Vertex>>;
+						fn remove_edge_unvalidated<F>(
+							&mut self,
+							source: Self::Vertex,
+							sink: Self::Vertex,
+							f: F,
+						) -> Result<Self::EdgeWeight, $crate::core::GraphError<Self::Vertex>>
 							where
 								F: Fn(&Self::EdgeWeight) -> bool;
 					}
@@ -561,11 +644,85 @@ macro_rules! impl_properties {
 					}
 					to $crate::core::GraphDerefMut::graph_mut(&mut self$($delegate)+) {
 						fn set_root(&mut self, v: impl std::borrow::Borrow<Self::Vertex>)
-							-> std::result::Result<(), ()>;
+							-> std::result::Result<(), $crate::core::GraphError<Self::Vertex>>;
 					}
 				}
 			}
 		}
+
+		// History
+		$crate::impl_properties!{
+			@struct [ $struct ]
+			@generic [ $($generics)* ]
+			@delegate [ $delegate_type ]
+			$(@exclude [ $($exclude_props)* ])?
+			$(@include [ $($include_props)* ])?
+			@bounds [
+				$struct: $crate::core::Ensure<
+					Ensured = $delegate_type,
+					Payload = $crate::ensurer_payload!(
+						$($payload_type)? ; <$delegate_type as $crate::core::Release>::Payload
+					)
+				>,
+				$delegate_type: $crate::core::GraphDerefMut,
+				<$delegate_type as $crate::core::GraphDeref>::Graph:
+					$crate::core::property::History,
+				$($bounds)*
+			]
+			@trait_id History [$crate::core::property]
+			@implement {
+				delegate::delegate! {
+					to $crate::core::GraphDeref::graph(&self$($delegate)+) {
+						fn can_undo(&self) -> bool;
+						fn can_redo(&self) -> bool;
+						fn checkpoint_distance(&self, name: &str) -> Option<isize>;
+					}
+					to $crate::core::GraphDerefMut::graph_mut(&mut self$($delegate)+) {
+						fn checkpoint(&mut self, name: impl Into<String>);
+					}
+				}
+
+				// Undoes on the wrapped graph, and redoes if this level's
+				// property no longer holds
+				fn undo(&mut self)
+					-> std::result::Result<bool, $crate::core::GraphError<Self::Vertex>>
+				{
+					use $crate::core::{property::History, GraphDerefMut};
+					if !History::undo(GraphDerefMut::graph_mut(&mut self$($delegate)+))?
+					{
+						return Ok(false);
+					}
+					if let Err(err) = <Self as $crate::core::Ensure>::diagnose(
+						&self$($delegate)+, &($(self$($payload_to)+)?)
+					)
+					{
+						History::redo(GraphDerefMut::graph_mut(&mut self$($delegate)+))?;
+						return Err(err);
+					}
+					Ok(true)
+				}
+
+				// Redoes on the wrapped graph, and undoes if this level's
+				// property no longer holds
+				fn redo(&mut self)
+					-> std::result::Result<bool, $crate::core::GraphError<Self::Vertex>>
+				{
+					use $crate::core::{property::History, GraphDerefMut};
+					if !History::redo(GraphDerefMut::graph_mut(&mut self$($delegate)+))?
+					{
+						return Ok(false);
+					}
+					if let Err(err) = <Self as $crate::core::Ensure>::diagnose(
+						&self$($delegate)+, &($(self$($payload_to)+)?)
+					)
+					{
+						History::undo(GraphDerefMut::graph_mut(&mut self$($delegate)+))?;
+						return Err(err);
+					}
+					Ok(true)
+				}
+			}
+		}
 	};
 
 	{
@@ -659,6 +816,15 @@ macro_rules! impl_properties {
 	} => {}
 }
 
+/// The payload of an ensurer with the given payload, ensuring a graph with the
+/// given payload.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! ensurer_payload {
+	{ $payload:ty ; $rest:ty } => { ($payload, $rest) };
+	{ ; $rest:ty } => { $rest };
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! make_ensurer {
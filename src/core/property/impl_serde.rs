@@ -0,0 +1,103 @@
+#[cfg(feature = "serde")]
+use crate::core::{Ensure, EnsuredVertex, Payload, Release};
+#[cfg(feature = "serde")]
+use std::fmt::Debug;
+
+/// Implements `Serialize` and `Deserialize` for the given ensurer, when the
+/// `serde` feature is enabled.
+///
+/// An ensurer without a payload is serialized as the graph it ensures, while
+/// an ensurer with a payload is serialized as a pair of the graph and the
+/// payload.
+/// Deserializing validates the property again, failing if it doesn't hold.
+///
+/// Must be used in the module defining the ensurer, such that its members are
+/// visible.
+macro_rules! impl_serde {
+	{
+		use<C> $struct:ty
+		as (self.0) : C
+		$(where $($bounds:tt)*)?
+	} => {
+		#[cfg(feature = "serde")]
+		impl<C: crate::core::Ensure> serde::Serialize for $struct
+		where
+			C: serde::Serialize,
+			$($($bounds)*)?
+		{
+			fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+			{
+				self.0.serialize(serializer)
+			}
+		}
+
+		#[cfg(feature = "serde")]
+		impl<'de, C: crate::core::Ensure> serde::Deserialize<'de> for $struct
+		where
+			C: serde::Deserialize<'de>,
+			crate::core::EnsuredVertex<Self>: std::fmt::Debug,
+			$($($bounds)*)?
+		{
+			fn deserialize<De: serde::Deserializer<'de>>(deserializer: De)
+				-> Result<Self, De::Error>
+			{
+				crate::core::property::impl_serde::ensure_deserialized(
+					C::deserialize(deserializer)?,
+					(),
+				)
+			}
+		}
+	};
+	{
+		use<C> $struct:ty
+		as (self.0, self.1) : C, $payload_type:ty
+		$(where $($bounds:tt)*)?
+	} => {
+		#[cfg(feature = "serde")]
+		impl<C: crate::core::Ensure> serde::Serialize for $struct
+		where
+			C: serde::Serialize,
+			$payload_type: serde::Serialize,
+			$($($bounds)*)?
+		{
+			fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+			{
+				(&self.0, &self.1).serialize(serializer)
+			}
+		}
+
+		#[cfg(feature = "serde")]
+		impl<'de, C: crate::core::Ensure> serde::Deserialize<'de> for $struct
+		where
+			C: serde::Deserialize<'de>,
+			crate::core::EnsuredVertex<Self>: std::fmt::Debug,
+			$payload_type: serde::Deserialize<'de>,
+			$($($bounds)*)?
+		{
+			fn deserialize<De: serde::Deserializer<'de>>(deserializer: De)
+				-> Result<Self, De::Error>
+			{
+				let (c, p) = <(C, $payload_type)>::deserialize(deserializer)?;
+				crate::core::property::impl_serde::ensure_deserialized(c, p)
+			}
+		}
+	};
+}
+
+/// Ensures a deserialized graph, failing with a deserialization error if the
+/// property doesn't hold.
+///
+/// The error names the property and the witness of its violation.
+#[cfg(feature = "serde")]
+pub(crate) fn ensure_deserialized<E, Er>(
+	c: E::Ensured,
+	p: <E::Payload as Payload<<E::Ensured as Release>::Payload>>::Item,
+) -> Result<E, Er>
+where
+	E: Ensure,
+	EnsuredVertex<E>: Debug,
+	Er: serde::de::Error,
+{
+	E::diagnose(&c, &p).map_err(Er::custom)?;
+	Ok(E::ensure_unvalidated(c, p))
+}
@@ -0,0 +1,142 @@
+use crate::core::{
+	property::{AddEdge, RemoveEdge},
+	Directedness, Graph, GraphError,
+};
+use std::borrow::Borrow;
+
+/// A graph where two vertices can be merged into one.
+///
+/// This is also what's needed to contract edges, see
+/// [`contract_edge`](#method.contract_edge).
+///
+/// Merging vertices may invalidate existing vertices.
+pub trait MergeVertices: Graph
+{
+	/// Merges the second vertex into the first, returning the merged vertex.
+	///
+	/// The weight of the merged vertex is given by `combine`, which gets the
+	/// weights of the first and the second vertex.
+	/// All edges incident on the second vertex are redirected to the merged
+	/// vertex, so any edges between the two vertices become loops and edges to
+	/// a common neighbor become parallel edges.
+	/// See [`remove_loops`](#method.remove_loops) and
+	/// [`merge_parallel_edges`](#method.merge_parallel_edges) for getting rid
+	/// of them.
+	///
+	/// Merging a vertex with itself does nothing.
+	fn merge_vertices<F>(
+		&mut self,
+		v1: impl Borrow<Self::Vertex>,
+		v2: impl Borrow<Self::Vertex>,
+		combine: F,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
+	where
+		F: FnOnce(Self::VertexWeight, Self::VertexWeight) -> Self::VertexWeight;
+
+	// Optional methods
+
+	/// Contracts an edge between the given vertices, removing it and merging
+	/// the sink into the source, returning the merged vertex.
+	///
+	/// Any other edges between the vertices become loops.
+	/// See [`merge_vertices`](#tymethod.merge_vertices).
+	///
+	/// ### Usage
+	/// ```
+	/// # use graphene::{
+	/// # 	common::AdjListGraph,
+	/// # 	core::{property::{AddEdge, MergeVertices, NewVertex}, Graph},
+	/// # };
+	/// let mut graph = AdjListGraph::<u32, ()>::new();
+	/// let v0 = graph.new_vertex_weighted(1).unwrap();
+	/// let v1 = graph.new_vertex_weighted(2).unwrap();
+	/// let v2 = graph.new_vertex_weighted(3).unwrap();
+	/// graph.add_edge(&v0, &v1).unwrap();
+	/// graph.add_edge(&v1, &v2).unwrap();
+	///
+	/// let merged = graph.contract_edge(&v0, &v1, |w1, w2| w1 + w2).unwrap();
+	///
+	/// assert_eq!(graph.all_vertices().count(), 2);
+	/// assert_eq!(graph.vertex_weight(merged), Some(&3));
+	/// assert_eq!(graph.all_edges().count(), 1);
+	/// ```
+	fn contract_edge<F>(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+		combine: F,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
+	where
+		Self: RemoveEdge,
+		F: FnOnce(Self::VertexWeight, Self::VertexWeight) -> Self::VertexWeight,
+	{
+		self.remove_edge(source.borrow(), sink.borrow())?;
+		self.merge_vertices(source, sink, combine)
+	}
+
+	/// Removes all loops on the given vertex, returning their weights.
+	fn remove_loops(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Vec<Self::EdgeWeight>, GraphError<Self::Vertex>>
+	where
+		Self: RemoveEdge,
+	{
+		let v = *v.borrow();
+		if !self.contains_vertex(v)
+		{
+			return Err(GraphError::VertexNotFound(v));
+		}
+
+		let mut removed = Vec::new();
+		while let Ok(w) = self.remove_edge(v, v)
+		{
+			removed.push(w);
+		}
+		Ok(removed)
+	}
+
+	/// Replaces any parallel edges incident on the given vertex with a single
+	/// edge, whose weight is given by folding the weights of the parallel edges
+	/// with `combine`.
+	///
+	/// In directed graphs, only edges in the same direction are parallel.
+	fn merge_parallel_edges<F>(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+		combine: F,
+	) -> Result<(), GraphError<Self::Vertex>>
+	where
+		Self: RemoveEdge + AddEdge,
+		F: Fn(Self::EdgeWeight, Self::EdgeWeight) -> Self::EdgeWeight,
+	{
+		let v = *v.borrow();
+		if !self.contains_vertex(v)
+		{
+			return Err(GraphError::VertexNotFound(v));
+		}
+
+		let mut pairs = Vec::new();
+		for neighbor in self.vertex_neighbors(v).collect::<Vec<_>>()
+		{
+			pairs.push((v, neighbor));
+			if Self::Directedness::directed() && neighbor != v
+			{
+				pairs.push((neighbor, v));
+			}
+		}
+		for (source, sink) in pairs
+		{
+			if self.edges_between(source, sink).nth(1).is_some()
+			{
+				let mut merged = self.remove_edge(source, sink)?;
+				while let Ok(w) = self.remove_edge(source, sink)
+				{
+					merged = combine(merged, w);
+				}
+				self.add_edge_weighted(source, sink, merged)?;
+			}
+		}
+		Ok(())
+	}
+}
@@ -1,11 +1,16 @@
 #[macro_use]
 mod impl_ensurer;
+#[macro_use]
+mod impl_serde;
 mod acyclic;
 mod base_props;
 mod connected;
 mod directedness_ensurers;
 mod has_vertex;
+mod history;
+mod merge_vertices;
 mod no_loops;
+mod observed;
 mod reflexive;
 mod rooted;
 mod subgraph;
@@ -14,13 +19,13 @@ mod unique;
 mod weak;
 
 pub use self::{
-	acyclic::*, base_props::*, connected::*, directedness_ensurers::*, has_vertex::*,
-	impl_ensurer::*, no_loops::*, reflexive::*, rooted::*, subgraph::*, unilateral::*, unique::*,
-	weak::*,
+	acyclic::*, base_props::*, connected::*, directedness_ensurers::*, has_vertex::*, history::*,
+	impl_ensurer::*, merge_vertices::*, no_loops::*, observed::*, reflexive::*, rooted::*,
+	subgraph::*, unilateral::*, unique::*, weak::*,
 };
 use crate::core::{
 	proxy::{EdgeProxyGraph, ProxyVertex, VertexProxyGraph},
-	Ensure,
+	Ensure, GraphError,
 };
 use std::borrow::Borrow;
 
@@ -35,7 +40,7 @@ pub fn proxy_remove_edge_where_weight<'a, C, G, F>(
 	source: &G::Vertex,
 	sink: &G::Vertex,
 	f: F,
-) -> Result<G::EdgeWeight, ()>
+) -> Result<G::EdgeWeight, GraphError<G::Vertex>>
 where
 	G: RemoveEdge,
 	F: Fn(&G::EdgeWeight) -> bool,
@@ -49,7 +54,7 @@ where
 	let g2: &G = unsafe { (g as *mut G).as_ref().unwrap() };
 
 	let mut proxy = EdgeProxyGraph::new(g2);
-	proxy.remove_edge(source, sink)?;
+	proxy.discard_edge_where_weight(source, sink, &f)?;
 
 	if C::validate(&proxy, &())
 	{
@@ -60,7 +65,10 @@ where
 	}
 	else
 	{
-		Err(())
+		Err(GraphError::PropertyViolated {
+			property: C::property_name(),
+			witness: vec![*source, *sink],
+		})
 	}
 }
 
@@ -70,7 +78,10 @@ where
 /// the vertex isn't removed in the first place.
 ///
 /// Will always need a type annotation for the Ensure 'C'.
-pub fn proxy_remove_vertex<'a, C, G>(g: &'a mut G, v: &G::Vertex) -> Result<G::VertexWeight, ()>
+pub fn proxy_remove_vertex<'a, C, G>(
+	g: &'a mut G,
+	v: &G::Vertex,
+) -> Result<G::VertexWeight, GraphError<G::Vertex>>
 where
 	G: RemoveVertex,
 	C: Ensure<Ensured = VertexProxyGraph<&'a G>, Base = VertexProxyGraph<&'a G>, Payload = ()>,
@@ -84,8 +95,8 @@ where
 	let mut proxy = VertexProxyGraph::new(g2);
 
 	proxy
-		.remove_vertex(&ProxyVertex::Underlying(v.borrow().clone()))
-		.expect("Couldn't remove a vertex from the proxy");
+		.discard_vertex(ProxyVertex::Underlying(*v.borrow()))
+		.unwrap_or_else(|_| panic!("Couldn't remove a vertex from the proxy"));
 
 	if C::validate(&proxy, &())
 	{
@@ -93,6 +104,9 @@ where
 	}
 	else
 	{
-		Err(())
+		Err(GraphError::PropertyViolated {
+			property: C::property_name(),
+			witness: vec![*v],
+		})
 	}
 }
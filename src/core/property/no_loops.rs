@@ -1,4 +1,6 @@
-use crate::core::{property::AddEdge, Ensure, Graph, GraphDerefMut};
+use crate::core::{
+	property::AddEdge, BaseGraph, Ensure, Graph, GraphDerefMut, GraphError, Rebase, Release,
+};
 use std::borrow::Borrow;
 
 /// A marker trait for graphs containing no graph loops.
@@ -10,6 +12,7 @@ pub trait NoLoops: Graph
 {
 }
 
+#[derive(Clone, Debug)]
 pub struct NoLoopsGraph<C: Ensure>(C);
 
 impl<C: Ensure> Ensure for NoLoopsGraph<C>
@@ -21,9 +24,31 @@ impl<C: Ensure> Ensure for NoLoopsGraph<C>
 
 	fn validate(c: &Self::Ensured, _: &()) -> bool
 	{
-		c.graph()
+		Self::diagnose(c, &()).is_ok()
+	}
+
+	/// If the graph has a loop, returns the vertex it is on as the witness.
+	fn diagnose(c: &Self::Ensured, _: &()) -> Result<(), GraphError<<C::Graph as Graph>::Vertex>>
+	{
+		match c
+			.graph()
 			.all_vertices()
-			.all(|v| c.graph().edges_between(v, v).next().is_none())
+			.find(|v| c.graph().edges_between(v, v).next().is_some())
+		{
+			Some(v) =>
+			{
+				Err(GraphError::PropertyViolated {
+					property: Self::property_name(),
+					witness: vec![v],
+				})
+			},
+			None => Ok(()),
+		}
+	}
+
+	fn property_name() -> &'static str
+	{
+		"NoLoops"
 	}
 }
 
@@ -36,11 +61,14 @@ where
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		weight: Self::EdgeWeight,
-	) -> Result<(), ()>
+	) -> Result<(), GraphError<Self::Vertex>>
 	{
 		if source.borrow() == sink.borrow()
 		{
-			Err(())
+			Err(GraphError::PropertyViolated {
+				property: Self::property_name(),
+				witness: vec![*source.borrow()],
+			})
 		}
 		else
 		{
@@ -51,7 +79,25 @@ where
 
 impl<C: Ensure> NoLoops for NoLoopsGraph<C> {}
 
+impl<C, B> Rebase<B> for NoLoopsGraph<C>
+where
+	C: Rebase<B>,
+	B: BaseGraph,
+{
+	type Rebased = NoLoopsGraph<C::Rebased>;
+
+	fn rebased_payload(&self) -> <Self::Rebased as Release>::Payload
+	{
+		self.0.rebased_payload()
+	}
+}
+
 impl_ensurer! {
 	use<C> NoLoopsGraph<C>: Ensure, NoLoops, AddEdge
 	as (self.0) : C
 }
+
+impl_serde! {
+	use<C> NoLoopsGraph<C>
+	as (self.0) : C
+}
@@ -0,0 +1,373 @@
+use crate::core::{
+	property::{AddEdge, NewVertex, RemoveEdge, RemoveVertex},
+	BaseGraph, Directedness, Ensure, Graph, GraphDerefMut, GraphError, GraphMut, MutateUnvalidated,
+	Rebase, Release,
+};
+use std::borrow::Borrow;
+
+/// A change made to an observed graph.
+///
+/// Each event holds the affected vertices and weights.
+pub enum GraphEvent<'a, G: Graph>
+{
+	/// A vertex with the given weight was added.
+	VertexAdded(G::Vertex, &'a G::VertexWeight),
+
+	/// A vertex with the given weight was removed.
+	///
+	/// It is preceded by an `EdgeRemoved` event for each edge incident on the
+	/// vertex.
+	VertexRemoved(G::Vertex, &'a G::VertexWeight),
+
+	/// An edge from the first vertex to the second with the given weight was
+	/// added.
+	EdgeAdded(G::Vertex, G::Vertex, &'a G::EdgeWeight),
+
+	/// An edge from the first vertex to the second with the given weight was
+	/// removed.
+	EdgeRemoved(G::Vertex, G::Vertex, &'a G::EdgeWeight),
+
+	/// The weight of the vertex was handed out mutably, and may be changed.
+	///
+	/// Holds the weight before any change.
+	VertexWeightMutable(G::Vertex, &'a G::VertexWeight),
+
+	/// The weight of an edge from the first vertex to the second was handed
+	/// out mutably, and may be changed.
+	///
+	/// Holds the weight before any change.
+	EdgeWeightMutable(G::Vertex, G::Vertex, &'a G::EdgeWeight),
+}
+
+// Implemented manually, since deriving would require the graph to be `Copy`.
+impl<'a, G: Graph> Clone for GraphEvent<'a, G>
+{
+	fn clone(&self) -> Self
+	{
+		*self
+	}
+}
+impl<'a, G: Graph> Copy for GraphEvent<'a, G> {}
+
+/// A listener to the changes made to an observed graph.
+pub type GraphListener<G> = Box<dyn FnMut(GraphEvent<'_, G>)>;
+
+/// Ensures the underlying graph notifies listeners of all changes made to it.
+///
+/// Listeners are notified after each vertex and edge is added or removed.
+/// Since mutable weights are handed out by reference, listeners are notified
+/// when a weight is handed out by [`GraphMut`](../trait.GraphMut.html), and
+/// not when it is actually changed.
+///
+/// When a vertex is removed, the edges incident on it are dropped with it.
+/// Their weights are therefore cloned before the vertex is removed, and once
+/// it has been, the removal of each edge is notified before that of the
+/// vertex.
+/// Since the weight of an added edge is moved into the graph, it is cloned
+/// before the edge is added, and the clone is notified.
+/// Listeners are only notified of mutations the underlying graph accepts.
+///
+/// Changes made to the underlying graph before it was ensured, or after it
+/// was released, aren't observed.
+pub struct ObservedGraph<C: Ensure>(C, Vec<GraphListener<C::Graph>>);
+
+impl<C: Ensure> ObservedGraph<C>
+{
+	/// Registers the given listener, which will be notified of every
+	/// following change to the graph.
+	pub fn add_listener(&mut self, listener: impl 'static + FnMut(GraphEvent<'_, C::Graph>))
+	{
+		self.1.push(Box::new(listener));
+	}
+
+	/// Notifies all listeners of the given event.
+	fn notify(listeners: &mut [GraphListener<C::Graph>], event: GraphEvent<'_, C::Graph>)
+	{
+		for listener in listeners.iter_mut()
+		{
+			listener(event);
+		}
+	}
+}
+
+impl<C: Ensure> Ensure for ObservedGraph<C>
+{
+	fn ensure_unvalidated(c: Self::Ensured, _: ()) -> Self
+	{
+		Self(c, Vec::new())
+	}
+
+	fn validate(_: &Self::Ensured, _: &()) -> bool
+	{
+		true
+	}
+
+	fn property_name() -> &'static str
+	{
+		"Observed"
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> GraphMut for ObservedGraph<C>
+where
+	C::Graph: GraphMut,
+{
+	fn all_vertices_weighted_mut<'a>(
+		&'a mut self,
+	) -> Box<dyn 'a + Iterator<Item = (Self::Vertex, &'a mut Self::VertexWeight)>>
+	{
+		let listeners = &mut self.1;
+		Box::new(
+			self.0
+				.graph_mut()
+				.all_vertices_weighted_mut()
+				.inspect(move |(v, w)| {
+					Self::notify(listeners, GraphEvent::VertexWeightMutable(*v, &**w))
+				}),
+		)
+	}
+
+	fn edges_between_mut<'a: 'b, 'b>(
+		&'a mut self,
+		source: impl 'b + Borrow<Self::Vertex>,
+		sink: impl 'b + Borrow<Self::Vertex>,
+	) -> Box<dyn 'b + Iterator<Item = &'a mut Self::EdgeWeight>>
+	{
+		let listeners = &mut self.1;
+		let (so, si) = (*source.borrow(), *sink.borrow());
+		Box::new(
+			self.0
+				.graph_mut()
+				.edges_between_mut(source, sink)
+				.inspect(move |w| {
+					Self::notify(listeners, GraphEvent::EdgeWeightMutable(so, si, &**w))
+				}),
+		)
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> ObservedGraph<C>
+{
+	/// Notifies the listeners of the addition of the given vertex.
+	fn added_vertex(&mut self, v: <C::Graph as Graph>::Vertex)
+	{
+		if let Some(w) = self.0.graph().vertex_weight(v)
+		{
+			Self::notify(&mut self.1, GraphEvent::VertexAdded(v, w));
+		}
+	}
+
+	/// Removes the given vertex with the given function, notifying the
+	/// listeners once it has been removed.
+	fn observe_remove_vertex(
+		&mut self,
+		v: <C::Graph as Graph>::Vertex,
+		remove: impl FnOnce(
+			&mut C::Graph,
+			<C::Graph as Graph>::Vertex,
+		) -> Result<
+			<C::Graph as Graph>::VertexWeight,
+			GraphError<<C::Graph as Graph>::Vertex>,
+		>,
+	) -> Result<<C::Graph as Graph>::VertexWeight, GraphError<<C::Graph as Graph>::Vertex>>
+	where
+		<C::Graph as Graph>::EdgeWeight: Clone,
+	{
+		let graph = self.0.graph();
+		if !graph.contains_vertex(v)
+		{
+			return Err(GraphError::VertexNotFound(v));
+		}
+		let mut edges: Vec<_> = graph
+			.edges_sourced_in(v)
+			.map(|(sink, w)| (v, sink, w.clone()))
+			.collect();
+		if <<C::Graph as Graph>::Directedness as Directedness>::directed()
+		{
+			edges.extend(
+				graph
+					.edges_sinked_in(v)
+					.filter(|(source, _)| *source != v)
+					.map(|(source, w)| (source, v, w.clone())),
+			);
+		}
+
+		let w = remove(self.0.graph_mut(), v)?;
+		for (source, sink, w) in edges.iter()
+		{
+			Self::notify(&mut self.1, GraphEvent::EdgeRemoved(*source, *sink, w));
+		}
+		Self::notify(&mut self.1, GraphEvent::VertexRemoved(v, &w));
+		Ok(w)
+	}
+
+	/// Adds an edge with the given weight using the given function, notifying
+	/// the listeners with a clone of the weight once it has been added.
+	fn observe_add_edge(
+		&mut self,
+		source: <C::Graph as Graph>::Vertex,
+		sink: <C::Graph as Graph>::Vertex,
+		weight: <C::Graph as Graph>::EdgeWeight,
+		add: impl FnOnce(
+			&mut C::Graph,
+			<C::Graph as Graph>::EdgeWeight,
+		) -> Result<(), GraphError<<C::Graph as Graph>::Vertex>>,
+	) -> Result<(), GraphError<<C::Graph as Graph>::Vertex>>
+	where
+		<C::Graph as Graph>::EdgeWeight: Clone,
+	{
+		let w = weight.clone();
+		add(self.0.graph_mut(), weight)?;
+		Self::notify(&mut self.1, GraphEvent::EdgeAdded(source, sink, &w));
+		Ok(())
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> NewVertex for ObservedGraph<C>
+where
+	C::Graph: NewVertex,
+{
+	fn new_vertex_weighted(
+		&mut self,
+		w: Self::VertexWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
+	{
+		let v = self.0.graph_mut().new_vertex_weighted(w)?;
+		self.added_vertex(v);
+		Ok(v)
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> RemoveVertex for ObservedGraph<C>
+where
+	C::Graph: RemoveVertex,
+	<C::Graph as Graph>::EdgeWeight: Clone,
+{
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
+	{
+		self.observe_remove_vertex(*v.borrow(), |g, v| g.remove_vertex(v))
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> AddEdge for ObservedGraph<C>
+where
+	C::Graph: AddEdge,
+	<C::Graph as Graph>::EdgeWeight: Clone,
+{
+	fn add_edge_weighted(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+		weight: Self::EdgeWeight,
+	) -> Result<(), GraphError<Self::Vertex>>
+	{
+		let (source, sink) = (*source.borrow(), *sink.borrow());
+		self.observe_add_edge(source, sink, weight, |g, w| {
+			g.add_edge_weighted(source, sink, w)
+		})
+	}
+}
+
+impl<C: Ensure + GraphDerefMut> RemoveEdge for ObservedGraph<C>
+where
+	C::Graph: RemoveEdge,
+{
+	fn remove_edge_where_weight<F>(
+		&mut self,
+		source: impl Borrow<Self::Vertex>,
+		sink: impl Borrow<Self::Vertex>,
+		f: F,
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
+	where
+		F: Fn(&Self::EdgeWeight) -> bool,
+	{
+		let w = self
+			.0
+			.graph_mut()
+			.remove_edge_where_weight(source.borrow(), sink.borrow(), f)?;
+		Self::notify(
+			&mut self.1,
+			GraphEvent::EdgeRemoved(*source.borrow(), *sink.borrow(), &w),
+		);
+		Ok(w)
+	}
+}
+
+/// Notifies the listeners like the mutations made through the mutation
+/// traits.
+impl<C: Ensure + GraphDerefMut> MutateUnvalidated for ObservedGraph<C>
+where
+	C::Graph: MutateUnvalidated,
+	<C::Graph as Graph>::EdgeWeight: Clone,
+{
+	fn new_vertex_unvalidated(
+		&mut self,
+		w: Self::VertexWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
+	{
+		let v = self.0.graph_mut().new_vertex_unvalidated(w)?;
+		self.added_vertex(v);
+		Ok(v)
+	}
+
+	fn remove_vertex_unvalidated(
+		&mut self,
+		v: Self::Vertex,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
+	{
+		self.observe_remove_vertex(v, |g, v| g.remove_vertex_unvalidated(v))
+	}
+
+	fn add_edge_unvalidated(
+		&mut self,
+		source: Self::Vertex,
+		sink: Self::Vertex,
+		weight: Self::EdgeWeight,
+	) -> Result<(), GraphError<Self::Vertex>>
+	{
+		self.observe_add_edge(source, sink, weight, |g, w| {
+			g.add_edge_unvalidated(source, sink, w)
+		})
+	}
+
+	fn remove_edge_unvalidated<F>(
+		&mut self,
+		source: Self::Vertex,
+		sink: Self::Vertex,
+		f: F,
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
+	where
+		F: Fn(&Self::EdgeWeight) -> bool,
+	{
+		let w = self
+			.0
+			.graph_mut()
+			.remove_edge_unvalidated(source, sink, f)?;
+		Self::notify(&mut self.1, GraphEvent::EdgeRemoved(source, sink, &w));
+		Ok(w)
+	}
+}
+
+/// Since it always holds, the rebased ensurer leaves this ensurer out.
+impl<C, B> Rebase<B> for ObservedGraph<C>
+where
+	C: Rebase<B>,
+	B: BaseGraph,
+{
+	type Rebased = C::Rebased;
+
+	fn rebased_payload(&self) -> <Self::Rebased as Release>::Payload
+	{
+		self.0.rebased_payload()
+	}
+}
+
+impl_ensurer! {
+	use<C> ObservedGraph<C>: Ensure, GraphMut, NewVertex, RemoveVertex, AddEdge, RemoveEdge,
+	MutateUnvalidated
+	as (self.0) : C
+	where C: Ensure
+}
@@ -1,6 +1,6 @@
 use crate::core::{
 	property::{AddEdge, NewVertex, RemoveEdge, RemoveVertex},
-	Ensure, Graph, GraphDerefMut,
+	BaseGraph, Ensure, Graph, GraphDeref, GraphDerefMut, GraphError, Rebase, Release,
 };
 use std::borrow::Borrow;
 
@@ -16,6 +16,7 @@ where
 {
 }
 
+#[derive(Clone, Debug)]
 pub struct ReflexiveGraph<C: Ensure>(C)
 where
 	<C::Graph as Graph>::EdgeWeight: Default;
@@ -30,19 +31,33 @@ where
 	}
 
 	fn validate(c: &Self::Ensured, _: &()) -> bool
+	{
+		Self::diagnose(c, &()).is_ok()
+	}
+
+	/// If the graph isn't reflexive, returns a vertex that doesn't have exactly
+	/// one loop as the witness.
+	fn diagnose(c: &Self::Ensured, _: &()) -> Result<(), GraphError<<C::Graph as Graph>::Vertex>>
 	{
 		let g = c.graph();
-		g.all_vertices().all(|v| {
-			let mut between = g.edges_between(v, v);
-			if let Some(_) = between.next()
-			{
-				between.next().is_none()
-			}
-			else
-			{
-				false
-			}
-		})
+		if let Some(v) = g
+			.all_vertices()
+			.find(|v| g.edges_between(v, v).take(2).count() != 1)
+		{
+			Err(GraphError::PropertyViolated {
+				property: Self::property_name(),
+				witness: vec![v],
+			})
+		}
+		else
+		{
+			Ok(())
+		}
+	}
+
+	fn property_name() -> &'static str
+	{
+		"Reflexive"
 	}
 }
 
@@ -51,7 +66,10 @@ where
 	C::Graph: NewVertex + AddEdge,
 	<C::Graph as Graph>::EdgeWeight: Default,
 {
-	fn new_vertex_weighted(&mut self, w: Self::VertexWeight) -> Result<Self::Vertex, ()>
+	fn new_vertex_weighted(
+		&mut self,
+		w: Self::VertexWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
 	{
 		let v = self.0.graph_mut().new_vertex_weighted(w)?;
 		self.0.graph_mut().add_edge(v.clone(), v.clone())?;
@@ -64,7 +82,10 @@ where
 	C::Graph: RemoveVertex + RemoveEdge,
 	<C::Graph as Graph>::EdgeWeight: Default,
 {
-	fn remove_vertex(&mut self, v: impl Borrow<Self::Vertex>) -> Result<Self::VertexWeight, ()>
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
 	{
 		self.0.graph_mut().remove_edge(v.borrow(), v.borrow())?;
 		self.0.graph_mut().remove_vertex(v)
@@ -73,8 +94,29 @@ where
 
 impl<C: Ensure> Reflexive for ReflexiveGraph<C> where <C::Graph as Graph>::EdgeWeight: Default {}
 
+impl<C, B> Rebase<B> for ReflexiveGraph<C>
+where
+	C: Rebase<B>,
+	B: BaseGraph,
+	<C::Graph as Graph>::EdgeWeight: Default,
+	<<C::Rebased as GraphDeref>::Graph as Graph>::EdgeWeight: Default,
+{
+	type Rebased = ReflexiveGraph<C::Rebased>;
+
+	fn rebased_payload(&self) -> <Self::Rebased as Release>::Payload
+	{
+		self.0.rebased_payload()
+	}
+}
+
 impl_ensurer! {
 	use<C> ReflexiveGraph<C>: Ensure, NewVertex, RemoveVertex, Reflexive
 	as (self.0) : C
 	where <C::Graph as Graph>::EdgeWeight: Default,
 }
+
+impl_serde! {
+	use<C> ReflexiveGraph<C>
+	as (self.0) : C
+	where <C::Graph as Graph>::EdgeWeight: Default
+}
@@ -1,6 +1,7 @@
 use crate::core::{
-	property::{HasVertex, VertexInGraph},
-	Ensure, Graph, Release,
+	property::{HasVertex, History, VertexInGraph},
+	proxy::ProxyVertex,
+	BaseGraph, Ensure, Graph, GraphDeref, GraphError, Rebase, Release,
 };
 use std::borrow::Borrow;
 
@@ -27,7 +28,7 @@ pub trait Rooted: HasVertex
 	///
 	/// Returns error if it was unable to change the root of the graph.
 	/// E.g. if the given vertex is not in the graph.
-	fn set_root(&mut self, v: impl Borrow<Self::Vertex>) -> Result<(), ()>;
+	fn set_root(&mut self, v: impl Borrow<Self::Vertex>) -> Result<(), GraphError<Self::Vertex>>;
 
 	/// Return true of the given vertex is the root of the graph.
 	/// Otherwise returns false.
@@ -73,6 +74,30 @@ impl<C: Ensure> Ensure for RootedGraph<C>
 	{
 		VertexInGraph::<C>::validate(c, p)
 	}
+
+	/// If the root isn't in the graph, returns it as the witness.
+	fn diagnose(
+		c: &Self::Ensured,
+		p: &<C::Graph as Graph>::Vertex,
+	) -> Result<(), GraphError<<C::Graph as Graph>::Vertex>>
+	{
+		if Self::validate(c, p)
+		{
+			Ok(())
+		}
+		else
+		{
+			Err(GraphError::PropertyViolated {
+				property: Self::property_name(),
+				witness: vec![*p],
+			})
+		}
+	}
+
+	fn property_name() -> &'static str
+	{
+		"Rooted"
+	}
 }
 
 impl<C: Ensure> Rooted for RootedGraph<C>
@@ -82,14 +107,92 @@ impl<C: Ensure> Rooted for RootedGraph<C>
 		self.0.get_vertex()
 	}
 
-	fn set_root(&mut self, v: impl Borrow<Self::Vertex>) -> Result<(), ()>
+	fn set_root(&mut self, v: impl Borrow<Self::Vertex>) -> Result<(), GraphError<Self::Vertex>>
 	{
 		self.0.set_vertex(v)
 	}
 }
 
+/// Undoing and redoing are validated by the wrapped
+/// [`VertexInGraph`](struct.VertexInGraph.html) holding the root.
+impl<C: Ensure> History for RootedGraph<C>
+where
+	VertexInGraph<C>: History,
+{
+	fn can_undo(&self) -> bool
+	{
+		self.0.can_undo()
+	}
+
+	fn can_redo(&self) -> bool
+	{
+		self.0.can_redo()
+	}
+
+	fn checkpoint(&mut self, name: impl Into<String>)
+	{
+		self.0.checkpoint(name)
+	}
+
+	fn checkpoint_distance(&self, name: &str) -> Option<isize>
+	{
+		self.0.checkpoint_distance(name)
+	}
+
+	fn undo(&mut self) -> Result<bool, GraphError<Self::Vertex>>
+	{
+		self.0.undo()
+	}
+
+	fn redo(&mut self) -> Result<bool, GraphError<Self::Vertex>>
+	{
+		self.0.redo()
+	}
+}
+
+/// The root is ensured to be in the proxy as an underlying vertex.
+impl<C, B> Rebase<B> for RootedGraph<C>
+where
+	C: Rebase<B>,
+	B: BaseGraph,
+	C::Rebased: GraphDeref<Graph: Graph<Vertex = ProxyVertex<<C::Graph as Graph>::Vertex>>>,
+{
+	type Rebased = RootedGraph<C::Rebased>;
+
+	fn rebased_payload(&self) -> <Self::Rebased as Release>::Payload
+	{
+		self.0.rebased_payload()
+	}
+}
+
 impl_ensurer! {
-	use<C> RootedGraph<C>: Release, Ensure, Rooted
+	use<C> RootedGraph<C>: Release, Ensure, Rooted, History
 	as (self.0) : VertexInGraph<C>
 	where C: Ensure
 }
+
+/// Serialized like the [`VertexInGraph`](struct.VertexInGraph.html) holding
+/// the root.
+#[cfg(feature = "serde")]
+impl<C: Ensure> serde::Serialize for RootedGraph<C>
+where
+	VertexInGraph<C>: serde::Serialize,
+{
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	{
+		self.0.serialize(serializer)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de, C: Ensure> serde::Deserialize<'de> for RootedGraph<C>
+where
+	VertexInGraph<C>: serde::Deserialize<'de>,
+	<C::Graph as Graph>::Vertex: std::fmt::Debug,
+{
+	fn deserialize<De: serde::Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error>
+	{
+		let (c, root) = VertexInGraph::<C>::deserialize(deserializer)?.release();
+		crate::core::property::impl_serde::ensure_deserialized(c, root)
+	}
+}
@@ -2,13 +2,13 @@ use crate::{
 	algo::TarjanScc,
 	core::{
 		property::{
-			proxy_remove_edge_where_weight, proxy_remove_vertex, HasVertexGraph, RemoveEdge,
-			RemoveVertex, Subgraph, Weak,
+			proxy_remove_edge_where_weight, proxy_remove_vertex, RemoveEdge, RemoveVertex,
+			Subgraph, Weak,
 		},
-		Directed, Ensure, Graph, GraphDerefMut,
+		BaseGraph, Directed, Ensure, Graph, GraphDeref, GraphDerefMut, GraphError, Rebase, Release,
 	},
 };
-use std::borrow::Borrow;
+use std::{borrow::Borrow, hash::Hash};
 
 /// A marker trait for graphs that are unilaterally connected.
 ///
@@ -35,6 +35,7 @@ where
 impl<C: Ensure> Ensure for UnilateralGraph<C>
 where
 	C::Graph: Graph<Directedness = Directed>,
+	<C::Graph as Graph>::Vertex: Hash,
 {
 	fn ensure_unvalidated(c: Self::Ensured, _: ()) -> Self
 	{
@@ -43,40 +44,60 @@ where
 
 	fn validate(c: &Self::Ensured, _: &()) -> bool
 	{
-		if let Ok(graph) = HasVertexGraph::ensure(c.graph(), ())
-		{
-			// Algorithm: First use Tarjan's Strongly Connected Component (SCC) algorithm to
-			// find SCCs and then check whether every component has an edge to the next one
-			// in the list. Note: Tarjan's  algorithm produces SCCs in reverse topological
-			// order, so we don't need to sort, just check the first has an edge to it from
-			// the next.
+		Self::diagnose(c, &()).is_ok()
+	}
 
-			let mut tarjan = TarjanScc::new(&graph);
+	/// If the graph isn't unilaterally connected, returns two vertices as the
+	/// witness, such that there is no path from either to the other.
+	fn diagnose(c: &Self::Ensured, _: &()) -> Result<(), GraphError<<C::Graph as Graph>::Vertex>>
+	{
+		// Algorithm: First use Tarjan's Strongly Connected Component (SCC) algorithm to
+		// find SCCs and then check whether every component has an edge to the next one
+		// in the list. Note: Tarjan's  algorithm produces SCCs in reverse topological
+		// order, so we don't need to sort, just check the first has an edge to it from
+		// the next. If not, neither can reach the other, since any path between them
+		// would have to pass through a component between them in the order.
 
-			let mut scc_current = tarjan.next();
+		let mut tarjan = TarjanScc::new(c.graph());
 
-			while let Some(scc1) = &scc_current
+		let mut scc_current = tarjan.next();
+
+		while let Some(scc1) = &scc_current
+		{
+			let scc_next = tarjan.next();
+			if let Some(scc2) = &scc_next
 			{
-				let scc_next = tarjan.next();
-				if let Some(scc2) = &scc_next
+				if scc2.reaches(scc1).is_none()
 				{
-					if scc2.reaches(scc1).is_none()
-					{
-						return false;
-					}
+					return Err(GraphError::PropertyViolated {
+						property: Self::property_name(),
+						witness: vec![
+							scc2.all_vertices().next().unwrap(),
+							scc1.all_vertices().next().unwrap(),
+						],
+					});
 				}
-				scc_current = scc_next;
 			}
+			scc_current = scc_next;
 		}
-		true
+		Ok(())
+	}
+
+	fn property_name() -> &'static str
+	{
+		"Unilateral"
 	}
 }
 
 impl<C: Ensure + GraphDerefMut> RemoveVertex for UnilateralGraph<C>
 where
 	C::Graph: RemoveVertex<Directedness = Directed>,
+	<C::Graph as Graph>::Vertex: Hash,
 {
-	fn remove_vertex(&mut self, v: impl Borrow<Self::Vertex>) -> Result<Self::VertexWeight, ()>
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
 	{
 		proxy_remove_vertex::<UnilateralGraph<_>, _>(self.0.graph_mut(), v.borrow())
 	}
@@ -85,13 +106,14 @@ where
 impl<C: Ensure + GraphDerefMut> RemoveEdge for UnilateralGraph<C>
 where
 	C::Graph: RemoveEdge<Directedness = Directed>,
+	<C::Graph as Graph>::Vertex: Hash,
 {
 	fn remove_edge_where_weight<F>(
 		&mut self,
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		f: F,
-	) -> Result<Self::EdgeWeight, ()>
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
 	where
 		F: Fn(&Self::EdgeWeight) -> bool,
 	{
@@ -107,6 +129,23 @@ where
 impl<C: Ensure> Weak for UnilateralGraph<C> where C::Graph: Graph<Directedness = Directed> {}
 impl<C: Ensure> Unilateral for UnilateralGraph<C> where C::Graph: Graph<Directedness = Directed> {}
 
+impl<C, B> Rebase<B> for UnilateralGraph<C>
+where
+	C: Rebase<B>,
+	B: BaseGraph,
+	C::Graph: Graph<Directedness = Directed>,
+	<C::Graph as Graph>::Vertex: Hash,
+	<C::Rebased as GraphDeref>::Graph: Graph<Directedness = Directed>,
+	<<C::Rebased as GraphDeref>::Graph as Graph>::Vertex: Hash,
+{
+	type Rebased = UnilateralGraph<C::Rebased>;
+
+	fn rebased_payload(&self) -> <Self::Rebased as Release>::Payload
+	{
+		self.0.rebased_payload()
+	}
+}
+
 impl_ensurer! {
 	use<C> UnilateralGraph<C>: Ensure, Unilateral, Weak, RemoveVertex, RemoveEdge,
 	// A new vertex would be unconnected to the rest of the graph
@@ -114,3 +153,9 @@ impl_ensurer! {
 	as (self.0) : C
 	where C::Graph: Graph<Directedness=Directed>
 }
+
+impl_serde! {
+	use<C> UnilateralGraph<C>
+	as (self.0) : C
+	where C::Graph: Graph<Directedness = Directed>, <C::Graph as Graph>::Vertex: Hash
+}
@@ -1,4 +1,7 @@
-use crate::core::{property::AddEdge, Directedness, Edge, Ensure, Graph, GraphDerefMut};
+use crate::core::{
+	property::AddEdge, BaseGraph, Directedness, Edge, Ensure, Graph, GraphDerefMut, GraphError,
+	Rebase, Release,
+};
 use std::borrow::Borrow;
 
 /// A marker trait for graphs containing only unique edges.
@@ -45,6 +48,13 @@ impl<C: Ensure> Ensure for UniqueGraph<C>
 	}
 
 	fn validate(c: &Self::Ensured, _: &()) -> bool
+	{
+		Self::diagnose(c, &()).is_ok()
+	}
+
+	/// If the graph has duplicate edges, returns the source and sink of one of
+	/// them as the witness.
+	fn diagnose(c: &Self::Ensured, _: &()) -> Result<(), GraphError<<C::Graph as Graph>::Vertex>>
 	{
 		let edges: Vec<_> = c.graph().all_edges().collect();
 		let mut iter = edges.iter();
@@ -57,11 +67,19 @@ impl<C: Ensure> Ensure for UniqueGraph<C>
 						&& e.sink() == e2.source()
 						&& !<C::Graph as Graph>::Directedness::directed())
 				{
-					return false;
+					return Err(GraphError::PropertyViolated {
+						property: Self::property_name(),
+						witness: vec![e.source(), e.sink()],
+					});
 				}
 			}
 		}
-		true
+		Ok(())
+	}
+
+	fn property_name() -> &'static str
+	{
+		"Unique"
 	}
 }
 
@@ -74,14 +92,17 @@ where
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		weight: Self::EdgeWeight,
-	) -> Result<(), ()>
+	) -> Result<(), GraphError<Self::Vertex>>
 	{
 		if self
 			.edges_between(source.borrow(), sink.borrow())
 			.next()
 			.is_some()
 		{
-			return Err(());
+			return Err(GraphError::PropertyViolated {
+				property: Self::property_name(),
+				witness: vec![*source.borrow(), *sink.borrow()],
+			});
 		}
 		self.0.graph_mut().add_edge_weighted(source, sink, weight)
 	}
@@ -89,8 +110,26 @@ where
 
 impl<C: Ensure> Unique for UniqueGraph<C> {}
 
+impl<C, B> Rebase<B> for UniqueGraph<C>
+where
+	C: Rebase<B>,
+	B: BaseGraph,
+{
+	type Rebased = UniqueGraph<C::Rebased>;
+
+	fn rebased_payload(&self) -> <Self::Rebased as Release>::Payload
+	{
+		self.0.rebased_payload()
+	}
+}
+
 impl_ensurer! {
 	use<C> UniqueGraph<C>: Ensure, Unique, AddEdge
 	as (self.0) : C
 	where C: Ensure
 }
+
+impl_serde! {
+	use<C> UniqueGraph<C>
+	as (self.0) : C
+}
@@ -4,9 +4,9 @@ use crate::core::{
 		RemoveVertex,
 	},
 	proxy::UndirectedProxy,
-	Directed, Ensure, Graph, GraphDerefMut,
+	BaseGraph, Directed, Ensure, Graph, GraphDeref, GraphDerefMut, GraphError, Rebase, Release,
 };
-use std::borrow::Borrow;
+use std::{borrow::Borrow, hash::Hash};
 
 /// A marker trait for graphs that are weakly connected.
 ///
@@ -44,6 +44,7 @@ where
 impl<C: Ensure> Ensure for WeakGraph<C>
 where
 	C::Graph: Graph<Directedness = Directed>,
+	<C::Graph as Graph>::Vertex: Hash,
 {
 	fn ensure_unvalidated(c: Self::Ensured, _: ()) -> Self
 	{
@@ -51,18 +52,47 @@ where
 	}
 
 	fn validate(c: &Self::Ensured, _: &()) -> bool
+	{
+		Self::diagnose(c, &()).is_ok()
+	}
+
+	/// If the graph isn't weakly connected, returns two vertices as the
+	/// witness, such that there is no path between them even when ignoring the
+	/// direction of edges.
+	fn diagnose(c: &Self::Ensured, _: &()) -> Result<(), GraphError<<C::Graph as Graph>::Vertex>>
 	{
 		let undirected = UndirectedProxy::new(c.graph());
 
-		ConnectedGraph::validate(&undirected, &())
+		ConnectedGraph::diagnose(&undirected, &()).map_err(|err| {
+			match err
+			{
+				GraphError::PropertyViolated { witness, .. } =>
+				{
+					GraphError::PropertyViolated {
+						property: Self::property_name(),
+						witness,
+					}
+				},
+				err => err,
+			}
+		})
+	}
+
+	fn property_name() -> &'static str
+	{
+		"Weak"
 	}
 }
 
 impl<C: Ensure + GraphDerefMut> RemoveVertex for WeakGraph<C>
 where
 	C::Graph: RemoveVertex<Directedness = Directed>,
+	<C::Graph as Graph>::Vertex: Hash,
 {
-	fn remove_vertex(&mut self, v: impl Borrow<Self::Vertex>) -> Result<Self::VertexWeight, ()>
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
 	{
 		proxy_remove_vertex::<WeakGraph<_>, _>(self.0.graph_mut(), v.borrow())
 	}
@@ -71,13 +101,14 @@ where
 impl<C: Ensure + GraphDerefMut> RemoveEdge for WeakGraph<C>
 where
 	C::Graph: RemoveEdge<Directedness = Directed>,
+	<C::Graph as Graph>::Vertex: Hash,
 {
 	fn remove_edge_where_weight<F>(
 		&mut self,
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		f: F,
-	) -> Result<Self::EdgeWeight, ()>
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
 	where
 		F: Fn(&Self::EdgeWeight) -> bool,
 	{
@@ -92,6 +123,23 @@ where
 
 impl<C: Ensure> Weak for WeakGraph<C> where C::Graph: Graph<Directedness = Directed> {}
 
+impl<C, B> Rebase<B> for WeakGraph<C>
+where
+	C: Rebase<B>,
+	B: BaseGraph,
+	C::Graph: Graph<Directedness = Directed>,
+	<C::Graph as Graph>::Vertex: Hash,
+	<C::Rebased as GraphDeref>::Graph: Graph<Directedness = Directed>,
+	<<C::Rebased as GraphDeref>::Graph as Graph>::Vertex: Hash,
+{
+	type Rebased = WeakGraph<C::Rebased>;
+
+	fn rebased_payload(&self) -> <Self::Rebased as Release>::Payload
+	{
+		self.0.rebased_payload()
+	}
+}
+
 impl_ensurer! {
 	use<C> WeakGraph<C>: Ensure, Weak, RemoveVertex, RemoveEdge,
 	// A new vertex wouldn't be connected to the rest of the graph
@@ -99,3 +147,9 @@ impl_ensurer! {
 	as (self.0) : C
 	where C::Graph: Graph<Directedness=Directed>
 }
+
+impl_serde! {
+	use<C> WeakGraph<C>
+	as (self.0) : C
+	where C::Graph: Graph<Directedness = Directed>, <C::Graph as Graph>::Vertex: Hash
+}
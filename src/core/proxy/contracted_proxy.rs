@@ -0,0 +1,180 @@
+use super::quotient_proxy::edges_between_classes;
+use crate::core::{Ensure, Graph, GraphError};
+use std::{borrow::Borrow, collections::HashMap, hash::Hash, iter::empty};
+
+/// A proxy that presents the underlying graph with some of its vertices
+/// merged, without mutating it.
+///
+/// Each merged vertex represents a group of vertices of the underlying graph,
+/// and is identified by one of them, the group's representative.
+/// The merged vertex has the weight of the representative and all the edges
+/// incident on any vertex in its group.
+/// Edges between vertices in the same group are therefore loops on the merged
+/// vertex.
+///
+/// ### Usage
+/// ```
+/// # use graphene::{
+/// # 	common::AdjListGraph,
+/// # 	core::{property::{AddEdge, NewVertex}, proxy::ContractedProxy, Graph},
+/// # };
+/// let mut graph = AdjListGraph::<(), ()>::new();
+/// let v0 = graph.new_vertex().unwrap();
+/// let v1 = graph.new_vertex().unwrap();
+/// let v2 = graph.new_vertex().unwrap();
+/// graph.add_edge(&v0, &v1).unwrap();
+/// graph.add_edge(&v1, &v2).unwrap();
+///
+/// let mut contracted = ContractedProxy::new(&graph);
+/// let merged = contracted.contract_edge(v0, v1).unwrap();
+///
+/// assert_eq!(contracted.all_vertices().count(), 2);
+/// assert_eq!(contracted.edges_between(merged, v2).count(), 1);
+/// assert_eq!(graph.all_vertices().count(), 3);
+/// ```
+pub struct ContractedProxy<C: Ensure>
+{
+	/// The underlying graph
+	graph: C,
+	/// The representative of each vertex that has been merged into another.
+	representatives: HashMap<<C::Graph as Graph>::Vertex, <C::Graph as Graph>::Vertex>,
+	/// The vertices of each group with more than one vertex, keyed by the
+	/// group's representative.
+	groups: HashMap<<C::Graph as Graph>::Vertex, Vec<<C::Graph as Graph>::Vertex>>,
+}
+
+impl<C: Ensure> ContractedProxy<C>
+where
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	/// Creates a proxy of the given graph with no vertices merged.
+	pub fn new(underlying: C) -> Self
+	{
+		Self {
+			graph: underlying,
+			representatives: HashMap::new(),
+			groups: HashMap::new(),
+		}
+	}
+
+	/// Returns the merged vertex that the given vertex of the underlying graph
+	/// is part of.
+	pub fn representative(&self, v: <C::Graph as Graph>::Vertex) -> <C::Graph as Graph>::Vertex
+	{
+		self.representatives.get(&v).copied().unwrap_or(v)
+	}
+
+	/// Merges the two given vertices, returning the merged vertex.
+	///
+	/// The merged vertex is the first one, such that the vertex weight is that
+	/// of the first vertex.
+	pub fn merge_vertices(
+		&mut self,
+		v1: <C::Graph as Graph>::Vertex,
+		v2: <C::Graph as Graph>::Vertex,
+	) -> Result<<C::Graph as Graph>::Vertex, GraphError<<C::Graph as Graph>::Vertex>>
+	{
+		for v in &[v1, v2]
+		{
+			if !self.contains_vertex(v)
+			{
+				return Err(GraphError::VertexNotFound(*v));
+			}
+		}
+		if v1 != v2
+		{
+			let absorbed = self.groups.remove(&v2).unwrap_or_else(|| vec![v2]);
+			for v in absorbed.iter()
+			{
+				self.representatives.insert(*v, v1);
+			}
+			self.groups
+				.entry(v1)
+				.or_insert_with(|| vec![v1])
+				.extend(absorbed);
+		}
+		Ok(v1)
+	}
+
+	/// Contracts an edge between the given vertices, merging the sink into the
+	/// source and returning the merged vertex.
+	///
+	/// Since the underlying graph isn't mutated, the contracted edge isn't
+	/// removed, but becomes a loop on the merged vertex like any other edge
+	/// between them.
+	pub fn contract_edge(
+		&mut self,
+		source: <C::Graph as Graph>::Vertex,
+		sink: <C::Graph as Graph>::Vertex,
+	) -> Result<<C::Graph as Graph>::Vertex, GraphError<<C::Graph as Graph>::Vertex>>
+	{
+		if self.edges_between(source, sink).next().is_none()
+		{
+			return Err(GraphError::EdgeNotFound(source, sink));
+		}
+		self.merge_vertices(source, sink)
+	}
+
+	/// Returns the vertices of the underlying graph that the given merged
+	/// vertex consists of.
+	fn group(&self, v: <C::Graph as Graph>::Vertex) -> Vec<<C::Graph as Graph>::Vertex>
+	{
+		self.groups.get(&v).cloned().unwrap_or_else(|| vec![v])
+	}
+}
+
+impl<C: Ensure> Graph for ContractedProxy<C>
+where
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	type Directedness = <C::Graph as Graph>::Directedness;
+	type EdgeWeight = <C::Graph as Graph>::EdgeWeight;
+	type Vertex = <C::Graph as Graph>::Vertex;
+	type VertexWeight = <C::Graph as Graph>::VertexWeight;
+
+	fn all_vertices_weighted<'a>(
+		&'a self,
+	) -> Box<dyn 'a + Iterator<Item = (Self::Vertex, &'a Self::VertexWeight)>>
+	{
+		Box::new(
+			self.graph
+				.graph()
+				.all_vertices_weighted()
+				.filter(move |(v, _)| !self.representatives.contains_key(v)),
+		)
+	}
+
+	fn edges_between<'a: 'b, 'b>(
+		&'a self,
+		source: impl 'b + Borrow<Self::Vertex>,
+		sink: impl 'b + Borrow<Self::Vertex>,
+	) -> Box<dyn 'b + Iterator<Item = &'a Self::EdgeWeight>>
+	{
+		let (source, sink) = (*source.borrow(), *sink.borrow());
+		if !(self.contains_vertex(source) && self.contains_vertex(sink))
+		{
+			return Box::new(empty());
+		}
+
+		Box::new(
+			edges_between_classes(
+				self.graph.graph(),
+				&self.group(source),
+				&self.group(sink),
+				source == sink,
+			)
+			.into_iter(),
+		)
+	}
+
+	fn contains_vertex(&self, v: impl Borrow<Self::Vertex>) -> bool
+	{
+		let v = *v.borrow();
+		self.graph.graph().contains_vertex(v) && !self.representatives.contains_key(&v)
+	}
+}
+
+base_graph! {
+	use<C> ContractedProxy<C>
+	where C: Ensure, <C::Graph as Graph>::Vertex: Hash
+}
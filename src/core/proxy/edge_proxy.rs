@@ -1,10 +1,20 @@
 use crate::core::{
-	property::{AddEdge, RemoveEdge, RemoveVertex},
-	Directedness, Edge, Ensure, Graph, GraphDerefMut, GraphMut,
+	property::{remove_vertex_tracked, AddEdge, RemoveEdge, RemoveVertex},
+	Directedness, Ensure, Graph, GraphDeref, GraphDerefMut, GraphError, GraphMut,
 };
 use delegate::delegate;
 use std::borrow::Borrow;
 
+/// Edges between vertices of the given graph, each with the given data.
+pub(crate) type EdgeList<G, T> = Vec<(<G as Graph>::Vertex, <G as Graph>::Vertex, T)>;
+
+/// The underlying graph of an edge proxy, with its added and removed edges.
+type EdgeProxyParts<C> = (
+	C,
+	EdgeList<<C as GraphDeref>::Graph, <<C as GraphDeref>::Graph as Graph>::EdgeWeight>,
+	EdgeList<<C as GraphDeref>::Graph, usize>,
+);
+
 /// A wrapper around a graph, that allows for addition and removal
 /// of edges, without mutating the underlying graph.
 ///
@@ -18,9 +28,15 @@ use std::borrow::Borrow;
 /// addition and removal trait. The proxy can then simulate how the graph would
 /// look regardless.
 ///
+/// The edges keep the weights they have in the underlying graph, and added
+/// edges keep the weights they were added with.
+/// Removed edges are identified by their position among the edges between
+/// their vertices, so the underlying graph must list those in the same order
+/// every time, and must only be mutated through the proxy.
+///
 /// If the underlying graph is mutable from the ensurer, then the edge proxy
-/// can also be used to mutate vertices, however, this is done directly on the
-/// underlying graph and not simulated as edge mutations are.
+/// can also be used to mutate vertices and edge weights, however, this is done
+/// directly on the underlying graph and not simulated as edge mutations are.
 /// To also simulate vertex mutations, first wrap the underlying graph in
 /// VertexProxy.
 pub struct EdgeProxyGraph<C: Ensure>
@@ -29,9 +45,22 @@ pub struct EdgeProxyGraph<C: Ensure>
 	graph: C,
 	/// Edges that have been added to the proxy and are not in the underlying
 	/// graph.
-	new: Vec<(<C::Graph as Graph>::Vertex, <C::Graph as Graph>::Vertex)>,
+	new: Vec<(
+		<C::Graph as Graph>::Vertex,
+		<C::Graph as Graph>::Vertex,
+		<C::Graph as Graph>::EdgeWeight,
+	)>,
 	/// Edges that have been removed from the underlying graph.
-	removed: Vec<(<C::Graph as Graph>::Vertex, <C::Graph as Graph>::Vertex)>,
+	///
+	/// Each edge is identified by its position among the edges the underlying
+	/// graph lists between its source and sink. In undirected graphs, the
+	/// removed edges between two vertices are all listed in the direction the
+	/// first of them was removed in.
+	removed: Vec<(
+		<C::Graph as Graph>::Vertex,
+		<C::Graph as Graph>::Vertex,
+		usize,
+	)>,
 }
 
 impl<C: Ensure> EdgeProxyGraph<C>
@@ -44,12 +73,119 @@ impl<C: Ensure> EdgeProxyGraph<C>
 			removed: Vec::new(),
 		}
 	}
+
+	/// Removes an edge whose weight satisfies the given function, like
+	/// [`remove_edge_where_weight`](../property/trait.RemoveEdge.html#tymethod.
+	/// remove_edge_where_weight), but discards the weight instead of returning
+	/// it.
+	///
+	/// Therefore, unlike `remove_edge_where_weight`, this doesn't require the
+	/// edge weights to be clonable.
+	pub fn discard_edge_where_weight<F>(
+		&mut self,
+		source: impl Borrow<<C::Graph as Graph>::Vertex>,
+		sink: impl Borrow<<C::Graph as Graph>::Vertex>,
+		f: F,
+	) -> Result<(), GraphError<<C::Graph as Graph>::Vertex>>
+	where
+		F: Fn(&<C::Graph as Graph>::EdgeWeight) -> bool,
+	{
+		let (source, sink) = (*source.borrow(), *sink.borrow());
+		let (so, si, removed) = self.removed_between(source, sink);
+		let underlying = self
+			.graph
+			.graph()
+			.edges_between(so, si)
+			.enumerate()
+			.find(|(i, w)| !removed.contains(i) && f(w))
+			.map(|(i, _)| i);
+
+		if let Some(i) = underlying
+		{
+			self.removed.push((so, si, i));
+			Ok(())
+		}
+		else if let Some(idx) = self
+			.new
+			.iter()
+			.position(|(so, si, w)| Self::connects(so, si, &source, &sink) && f(w))
+		{
+			self.new.remove(idx);
+			Ok(())
+		}
+		else
+		{
+			Err(GraphError::EdgeNotFound(source, sink))
+		}
+	}
+
+	/// Returns the underlying graph, the edges added to the proxy, and the
+	/// edges removed from the underlying graph, in the order they were added
+	/// and removed.
+	pub(crate) fn into_parts(self) -> EdgeProxyParts<C>
+	{
+		(self.graph, self.new, self.removed)
+	}
+
+	/// Whether an edge from `so` to `si` is an edge from `source` to `sink`.
+	fn connects(
+		so: &<C::Graph as Graph>::Vertex,
+		si: &<C::Graph as Graph>::Vertex,
+		source: &<C::Graph as Graph>::Vertex,
+		sink: &<C::Graph as Graph>::Vertex,
+	) -> bool
+	{
+		(so == source && si == sink)
+			|| (!<<C::Graph as Graph>::Directedness as Directedness>::directed()
+				&& so == sink
+				&& si == source)
+	}
+
+	/// Returns the direction to list the underlying edges from `source` to
+	/// `sink` in, and the positions of the removed ones among them.
+	fn removed_between(
+		&self,
+		source: <C::Graph as Graph>::Vertex,
+		sink: <C::Graph as Graph>::Vertex,
+	) -> (
+		<C::Graph as Graph>::Vertex,
+		<C::Graph as Graph>::Vertex,
+		Vec<usize>,
+	)
+	{
+		let mut removed = self
+			.removed
+			.iter()
+			.filter(|(so, si, _)| Self::connects(so, si, &source, &sink))
+			.peekable();
+		let (so, si) = removed
+			.peek()
+			.map_or((source, sink), |(so, si, _)| (*so, *si));
+		(so, si, removed.map(|(_, _, i)| *i).collect())
+	}
+
+	/// Returns the weights of the edges from `source` to `sink` in the
+	/// underlying graph that haven't been removed.
+	fn kept_between<'a>(
+		&'a self,
+		source: <C::Graph as Graph>::Vertex,
+		sink: <C::Graph as Graph>::Vertex,
+	) -> impl 'a + Iterator<Item = &'a <C::Graph as Graph>::EdgeWeight>
+	{
+		let (so, si, removed) = self.removed_between(source, sink);
+		self.graph
+			.graph()
+			.edges_between(so, si)
+			.enumerate()
+			.filter(move |(i, _)| !removed.contains(i))
+			.map(|(_, w)| w)
+	}
 }
 
 impl<C: Ensure> Graph for EdgeProxyGraph<C>
 {
 	type Directedness = <C::Graph as Graph>::Directedness;
-	type EdgeWeight = ();
+	type EdgeWeight = <C::Graph as Graph>::EdgeWeight;
 	type Vertex = <C::Graph as Graph>::Vertex;
 	type VertexWeight = <C::Graph as Graph>::VertexWeight;
 
@@ -67,24 +203,66 @@ impl<C: Ensure> Graph for EdgeProxyGraph<C>
 		sink: impl 'b + Borrow<Self::Vertex>,
 	) -> Box<dyn 'b + Iterator<Item = &'a Self::EdgeWeight>>
 	{
-		let applicable = |so, si| {
-			(source.borrow() == so && sink.borrow() == si)
-				|| (!Self::Directedness::directed()
-					&& (source.borrow() == si && sink.borrow() == so))
-		};
-		let removed_count = self
-			.removed
-			.iter()
-			.filter(|(so, si)| applicable(so, si))
-			.count();
-		let added_count = self
-			.new
-			.iter()
-			.filter(|(so, si)| applicable(so, si))
-			.count();
-		let underlying_count = self.graph.graph().edges_between(source, sink).count();
+		let (source, sink) = (*source.borrow(), *sink.borrow());
+		Box::new(
+			self.kept_between(source, sink).chain(
+				self.new
+					.iter()
+					.filter(move |(so, si, _)| Self::connects(so, si, &source, &sink))
+					.map(|(_, _, w)| w),
+			),
+		)
+	}
 
-		Box::new((0..(underlying_count - removed_count + added_count)).map(|_| &()))
+	fn edges_sourced_in<'a: 'b, 'b>(
+		&'a self,
+		v: impl 'b + Borrow<Self::Vertex>,
+	) -> Box<dyn 'b + Iterator<Item = (Self::Vertex, &'a Self::EdgeWeight)>>
+	{
+		let v = *v.borrow();
+		let other_end = move |so: &Self::Vertex, si: &Self::Vertex| {
+			if *so == v
+			{
+				Some(*si)
+			}
+			else if !Self::Directedness::directed() && *si == v
+			{
+				Some(*so)
+			}
+			else
+			{
+				None
+			}
+		};
+		// The edges to vertices with removed edges are listed separately
+		let mut touched = Vec::new();
+		for (so, si, _) in self.removed.iter()
+		{
+			if let Some(sink) = other_end(so, si)
+			{
+				if !touched.contains(&sink)
+				{
+					touched.push(sink);
+				}
+			}
+		}
+		let listed = touched.clone();
+		Box::new(
+			self.graph
+				.graph()
+				.edges_sourced_in(v)
+				.filter(move |(sink, _)| !listed.contains(sink))
+				.chain(
+					touched
+						.into_iter()
+						.flat_map(move |sink| self.kept_between(v, sink).map(move |w| (sink, w))),
+				)
+				.chain(
+					self.new
+						.iter()
+						.filter_map(move |(so, si, w)| other_end(so, si).map(|sink| (sink, w))),
+				),
+		)
 	}
 }
 
@@ -106,10 +284,21 @@ where
 		sink: impl 'b + Borrow<Self::Vertex>,
 	) -> Box<dyn 'b + Iterator<Item = &'a mut Self::EdgeWeight>>
 	{
-		// Safe because &mut () can't mutate anything
+		let (source, sink) = (*source.borrow(), *sink.borrow());
+		let (so, si, removed) = self.removed_between(source, sink);
 		Box::new(
-			self.edges_between(source, sink)
-				.map(|w| unsafe { &mut *((w as *const ()) as *mut ()) }),
+			self.graph
+				.graph_mut()
+				.edges_between_mut(so, si)
+				.enumerate()
+				.filter(move |(i, _)| !removed.contains(i))
+				.map(|(_, w)| w)
+				.chain(
+					self.new
+						.iter_mut()
+						.filter(move |(so, si, _)| Self::connects(so, si, &source, &sink))
+						.map(|(_, _, w)| w),
+				),
 		)
 	}
 }
@@ -120,75 +309,82 @@ impl<C: Ensure> AddEdge for EdgeProxyGraph<C>
 		&mut self,
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
-		_: Self::EdgeWeight,
-	) -> Result<(), ()>
+		weight: Self::EdgeWeight,
+	) -> Result<(), GraphError<Self::Vertex>>
 	{
 		if self.contains_vertex(source.borrow()) && self.contains_vertex(sink.borrow())
 		{
-			self.new
-				.push((source.borrow().clone(), sink.borrow().clone()));
+			self.new.push((*source.borrow(), *sink.borrow(), weight));
 			Ok(())
 		}
+		else if !self.contains_vertex(source.borrow())
+		{
+			Err(GraphError::VertexNotFound(*source.borrow()))
+		}
 		else
 		{
-			Err(())
+			Err(GraphError::VertexNotFound(*sink.borrow()))
 		}
 	}
 }
 
 impl<C: Ensure> RemoveEdge for EdgeProxyGraph<C>
+where
+	<C::Graph as Graph>::EdgeWeight: Clone,
 {
 	fn remove_edge_where_weight<F>(
 		&mut self,
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
-		_: F,
-	) -> Result<Self::EdgeWeight, ()>
+		f: F,
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
 	where
 		F: Fn(&Self::EdgeWeight) -> bool,
 	{
-		// First try to find a valid new vertex
-		let to_remove = self.new.iter().cloned().enumerate().find(|(_, e)| {
-			(e.source() == *source.borrow() && e.sink() == *sink.borrow())
-				|| !Self::Directedness::directed()
-					&& (e.source() == *sink.borrow() && e.sink() == *source.borrow())
-		});
-
-		if let Some((idx, _)) = to_remove
-		{
-			self.new.remove(idx);
-			Ok(())
-		}
-		else
-		{
-			// If no new vertex is valid, look through the existing ones.
-			if let Some(_) = self
-				.graph
-				.graph()
-				.edges_between(source.borrow(), sink.borrow())
-				.next()
-			{
-				self.removed
-					.push((source.borrow().clone(), sink.borrow().clone()));
-				Ok(())
-			}
-			else
-			{
-				Err(())
-			}
-		}
+		let weight = self
+			.edges_between(source.borrow(), sink.borrow())
+			.find(|w| f(w))
+			.cloned()
+			.ok_or(GraphError::EdgeNotFound(*source.borrow(), *sink.borrow()))?;
+		self.discard_edge_where_weight(source, sink, f)?;
+		Ok(weight)
 	}
 }
 
+/// Removes the vertex from the underlying graph, following any vertices it
+/// gives new identities.
 impl<C: Ensure + GraphDerefMut> RemoveVertex for EdgeProxyGraph<C>
 where
 	C::Graph: RemoveVertex,
 {
-	fn remove_vertex(&mut self, v: impl Borrow<Self::Vertex>) -> Result<Self::VertexWeight, ()>
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
 	{
-		self.new
-			.retain(|e| e.source() != *v.borrow() && e.sink() != *v.borrow());
-		self.graph.graph_mut().remove_vertex(v)
+		let v = *v.borrow();
+		let (w, moved) = remove_vertex_tracked(self.graph.graph_mut(), v)?;
+		self.new.retain(|(so, si, _)| *so != v && *si != v);
+		self.removed.retain(|(so, si, _)| *so != v && *si != v);
+
+		// Follow the vertices the underlying graph gave new identities
+		let current = |u: &mut Self::Vertex| {
+			if let Some((_, new)) = moved.iter().find(|(old, _)| old == u)
+			{
+				*u = *new;
+			}
+		};
+		for (so, si, _) in self.new.iter_mut()
+		{
+			current(so);
+			current(si);
+		}
+		for (so, si, _) in self.removed.iter_mut()
+		{
+			current(so);
+			current(si);
+		}
+		Ok(w)
 	}
 }
 
@@ -0,0 +1,170 @@
+use crate::core::{Ensure, Graph};
+use delegate::delegate;
+use std::borrow::Borrow;
+
+/// A proxy that hides the vertices of the underlying graph whose weights don't
+/// satisfy a predicate, together with the edges incident on them.
+///
+/// The predicate is evaluated lazily, whenever the graph is looked at, so the
+/// underlying graph isn't copied.
+/// Since changing the weight of a vertex could hide or reveal it without any
+/// ensurer wrapping the proxy noticing, the weights can't be mutated through
+/// the proxy.
+pub struct VertexFilterProxy<C: Ensure, F>(C, F)
+where
+	F: Fn(&<C::Graph as Graph>::VertexWeight) -> bool;
+
+impl<C: Ensure, F> VertexFilterProxy<C, F>
+where
+	F: Fn(&<C::Graph as Graph>::VertexWeight) -> bool,
+{
+	/// Creates a proxy showing only the vertices whose weights satisfy the
+	/// given predicate.
+	pub fn new(underlying: C, predicate: F) -> Self
+	{
+		Self(underlying, predicate)
+	}
+
+	/// Returns whether the given vertex is in the underlying graph and isn't
+	/// hidden.
+	fn visible(&self, v: &<C::Graph as Graph>::Vertex) -> bool
+	{
+		self.0.graph().vertex_weight(v).is_some_and(&self.1)
+	}
+}
+
+impl<C: Ensure, F> Graph for VertexFilterProxy<C, F>
+where
+	F: Fn(&<C::Graph as Graph>::VertexWeight) -> bool,
+{
+	type Directedness = <C::Graph as Graph>::Directedness;
+	type EdgeWeight = <C::Graph as Graph>::EdgeWeight;
+	type Vertex = <C::Graph as Graph>::Vertex;
+	type VertexWeight = <C::Graph as Graph>::VertexWeight;
+
+	fn all_vertices_weighted<'a>(
+		&'a self,
+	) -> Box<dyn 'a + Iterator<Item = (Self::Vertex, &'a Self::VertexWeight)>>
+	{
+		Box::new(
+			self.0
+				.graph()
+				.all_vertices_weighted()
+				.filter(move |(_, w)| (self.1)(w)),
+		)
+	}
+
+	fn edges_between<'a: 'b, 'b>(
+		&'a self,
+		source: impl 'b + Borrow<Self::Vertex>,
+		sink: impl 'b + Borrow<Self::Vertex>,
+	) -> Box<dyn 'b + Iterator<Item = &'a Self::EdgeWeight>>
+	{
+		let visible = self.visible(source.borrow()) && self.visible(sink.borrow());
+		Box::new(
+			self.0
+				.graph()
+				.edges_between(source, sink)
+				.filter(move |_| visible),
+		)
+	}
+}
+
+base_graph! {
+	use<C, F> VertexFilterProxy<C, F>: NoLoops, Unique
+	as (self.0): C
+	where
+		C: Ensure,
+		F: Fn(&<C::Graph as Graph>::VertexWeight) -> bool
+}
+
+/// A proxy that hides the edges of the underlying graph whose weights don't
+/// satisfy a predicate.
+///
+/// All vertices of the underlying graph are kept.
+/// The predicate is evaluated lazily, whenever the graph is looked at, so the
+/// underlying graph isn't copied.
+/// Like with [`VertexFilterProxy`](struct.VertexFilterProxy.html), the weights
+/// can't be mutated through the proxy.
+///
+/// ### Usage
+/// ```
+/// # use graphene::{
+/// # 	algo::DijkstraShortestPaths,
+/// # 	common::AdjListGraph,
+/// # 	core::{
+/// # 		property::{AddEdge, NewVertex, VertexInGraph},
+/// # 		proxy::EdgeFilterProxy,
+/// # 		Ensure,
+/// # 	},
+/// # };
+/// let mut graph = AdjListGraph::<(), u32>::new();
+/// let v0 = graph.new_vertex().unwrap();
+/// let v1 = graph.new_vertex().unwrap();
+/// let v2 = graph.new_vertex().unwrap();
+/// graph.add_edge_weighted(&v0, &v1, 0).unwrap();
+/// graph.add_edge_weighted(&v0, &v2, 3).unwrap();
+///
+/// // Only search through edges with capacity left
+/// let with_capacity = EdgeFilterProxy::new(&graph, |capacity: &u32| *capacity > 0);
+/// let with_capacity = VertexInGraph::ensure(with_capacity, v0).unwrap();
+/// let mut dijkstra = DijkstraShortestPaths::new_simple(&with_capacity);
+///
+/// assert_eq!(dijkstra.next(), Some((v0, v2, &3)));
+/// assert_eq!(dijkstra.next(), None);
+/// ```
+pub struct EdgeFilterProxy<C: Ensure, F>(C, F)
+where
+	F: Fn(&<C::Graph as Graph>::EdgeWeight) -> bool;
+
+impl<C: Ensure, F> EdgeFilterProxy<C, F>
+where
+	F: Fn(&<C::Graph as Graph>::EdgeWeight) -> bool,
+{
+	/// Creates a proxy showing only the edges whose weights satisfy the given
+	/// predicate.
+	pub fn new(underlying: C, predicate: F) -> Self
+	{
+		Self(underlying, predicate)
+	}
+}
+
+impl<C: Ensure, F> Graph for EdgeFilterProxy<C, F>
+where
+	F: Fn(&<C::Graph as Graph>::EdgeWeight) -> bool,
+{
+	type Directedness = <C::Graph as Graph>::Directedness;
+	type EdgeWeight = <C::Graph as Graph>::EdgeWeight;
+	type Vertex = <C::Graph as Graph>::Vertex;
+	type VertexWeight = <C::Graph as Graph>::VertexWeight;
+
+	delegate! {
+		to self.0.graph() {
+			fn all_vertices_weighted<'a>(
+				&'a self,
+			) -> Box<dyn 'a + Iterator<Item = (Self::Vertex, &'a Self::VertexWeight)>>;
+		}
+	}
+
+	fn edges_between<'a: 'b, 'b>(
+		&'a self,
+		source: impl 'b + Borrow<Self::Vertex>,
+		sink: impl 'b + Borrow<Self::Vertex>,
+	) -> Box<dyn 'b + Iterator<Item = &'a Self::EdgeWeight>>
+	{
+		Box::new(
+			self.0
+				.graph()
+				.edges_between(source, sink)
+				.filter(move |w| (self.1)(w)),
+		)
+	}
+}
+
+base_graph! {
+	use<C, F> EdgeFilterProxy<C, F>: NoLoops, Unique, HasVertex
+	as (self.0): C
+	where
+		C: Ensure,
+		F: Fn(&<C::Graph as Graph>::EdgeWeight) -> bool
+}
@@ -0,0 +1,169 @@
+use crate::core::{property::Unique, Directedness, Ensure, Graph};
+use std::{
+	borrow::Borrow,
+	collections::HashMap,
+	hash::Hash,
+	iter::{empty, once},
+};
+
+/// The number of edges between each source and sink of a graph.
+type EdgeCounts<G> = HashMap<(<G as Graph>::Vertex, <G as Graph>::Vertex), usize>;
+
+/// The [line graph](https://mathworld.wolfram.com/LineGraph.html) of a graph,
+/// whose vertices are the edges of the graph.
+///
+/// Each edge of the underlying graph is identified by its source, its sink,
+/// and its index among the edges between the two, i.e. the vertex
+/// `(source, sink, i)` is the edge whose weight is
+/// `edges_between(source, sink).nth(i)`.
+/// If the graph is undirected, only one of the two orientations identifies
+/// the edges between two vertices.
+/// The weight of each vertex is the weight of its edge, while the edges have no
+/// weights.
+///
+/// If the graph is undirected, two distinct edges are adjacent if they share
+/// an endpoint.
+/// If the graph is directed, there is an edge from one edge to another if the
+/// sink of the first is the source of the second, such that loops give loops.
+/// Either way, there is at most one edge between any two edges.
+///
+/// ### Usage
+/// ```
+/// # use graphene::{
+/// # 	common::AdjListGraph,
+/// # 	core::{property::{AddEdge, NewVertex}, proxy::LineGraphProxy, Graph, Undirected},
+/// # };
+/// let mut graph = AdjListGraph::<(), u32, Undirected>::new();
+/// let v0 = graph.new_vertex().unwrap();
+/// let v1 = graph.new_vertex().unwrap();
+/// let v2 = graph.new_vertex().unwrap();
+/// graph.add_edge_weighted(&v0, &v1, 1).unwrap();
+/// graph.add_edge_weighted(&v1, &v2, 2).unwrap();
+///
+/// let line = LineGraphProxy::new(&graph);
+///
+/// assert_eq!(line.all_vertices().count(), 2);
+/// assert_eq!(line.all_edges().count(), 1);
+/// let mut weights: Vec<_> = line.all_vertex_weights().copied().collect();
+/// weights.sort();
+/// assert_eq!(weights, vec![1, 2]);
+/// ```
+pub struct LineGraphProxy<C: Ensure>
+{
+	/// The underlying graph
+	graph: C,
+	/// The number of edges between each source and sink, in the orientation
+	/// the edges are identified by.
+	edge_counts: EdgeCounts<C::Graph>,
+}
+
+impl<C: Ensure> LineGraphProxy<C>
+where
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	/// Creates the line graph of the given graph.
+	pub fn new(underlying: C) -> Self
+	{
+		let mut edge_counts = HashMap::new();
+		for (source, sink, _) in underlying.graph().all_edges()
+		{
+			// Undirected edges between the same vertices must all be identified in
+			// the same orientation, regardless of how they were listed.
+			let key = if !<C::Graph as Graph>::Directedness::directed()
+				&& edge_counts.contains_key(&(sink, source))
+			{
+				(sink, source)
+			}
+			else
+			{
+				(source, sink)
+			};
+			*edge_counts.entry(key).or_insert(0) += 1;
+		}
+		Self {
+			graph: underlying,
+			edge_counts,
+		}
+	}
+}
+
+impl<C: Ensure> Graph for LineGraphProxy<C>
+where
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	type Directedness = <C::Graph as Graph>::Directedness;
+	type EdgeWeight = ();
+	type Vertex = (
+		<C::Graph as Graph>::Vertex,
+		<C::Graph as Graph>::Vertex,
+		usize,
+	);
+	type VertexWeight = <C::Graph as Graph>::EdgeWeight;
+
+	fn all_vertices_weighted<'a>(
+		&'a self,
+	) -> Box<dyn 'a + Iterator<Item = (Self::Vertex, &'a Self::VertexWeight)>>
+	{
+		Box::new(
+			self.edge_counts
+				.iter()
+				.flat_map(move |(&(source, sink), &count)| {
+					self.graph
+						.graph()
+						.edges_between(source, sink)
+						.take(count)
+						.enumerate()
+						.map(move |(i, w)| ((source, sink, i), w))
+				}),
+		)
+	}
+
+	fn edges_between<'a: 'b, 'b>(
+		&'a self,
+		source: impl 'b + Borrow<Self::Vertex>,
+		sink: impl 'b + Borrow<Self::Vertex>,
+	) -> Box<dyn 'b + Iterator<Item = &'a Self::EdgeWeight>>
+	{
+		let (source, sink) = (*source.borrow(), *sink.borrow());
+		if !(self.contains_vertex(source) && self.contains_vertex(sink))
+		{
+			return Box::new(empty());
+		}
+
+		let adjacent = if Self::Directedness::directed()
+		{
+			source.1 == sink.0
+		}
+		else
+		{
+			source != sink
+				&& (source.0 == sink.0
+					|| source.0 == sink.1
+					|| source.1 == sink.0
+					|| source.1 == sink.1)
+		};
+		if adjacent
+		{
+			Box::new(once(&()))
+		}
+		else
+		{
+			Box::new(empty())
+		}
+	}
+
+	fn contains_vertex(&self, v: impl Borrow<Self::Vertex>) -> bool
+	{
+		let (source, sink, i) = *v.borrow();
+		self.edge_counts
+			.get(&(source, sink))
+			.is_some_and(|count| i < *count)
+	}
+}
+
+impl<C: Ensure> Unique for LineGraphProxy<C> where <C::Graph as Graph>::Vertex: Hash {}
+
+base_graph! {
+	use<C> LineGraphProxy<C>
+	where C: Ensure, <C::Graph as Graph>::Vertex: Hash
+}
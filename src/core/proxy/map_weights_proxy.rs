@@ -0,0 +1,168 @@
+use crate::core::{Directedness, Ensure, Graph};
+use std::{borrow::Borrow, collections::HashMap, hash::Hash};
+
+/// The weights of the edges from the first vertex to the second.
+type EdgeWeights<G, Ew> = HashMap<(<G as Graph>::Vertex, <G as Graph>::Vertex), Vec<Ew>>;
+
+/// A proxy that presents the underlying graph with its vertex and edge weights
+/// transformed by the given functions.
+///
+/// Since graphs hand out their weights by reference, the transformed weights
+/// are computed and cached when the proxy is created. The underlying graph
+/// can't be mutated through the proxy, so the cache stays valid.
+///
+/// This allows algorithms that use the weights directly to run on a graph
+/// whose weights aren't of the type they require.
+///
+/// ### Usage
+/// ```
+/// # use graphene::{
+/// # 	algo::DijkstraShortestPaths,
+/// # 	common::AdjListGraph,
+/// # 	core::{
+/// # 		property::{AddEdge, NewVertex, VertexInGraph},
+/// # 		proxy::MapWeightsProxy,
+/// # 		Ensure,
+/// # 	},
+/// # };
+/// struct Road
+/// {
+/// 	name: &'static str,
+/// 	length: u32,
+/// }
+///
+/// let mut graph = AdjListGraph::<(), Road>::new();
+/// let v0 = graph.new_vertex().unwrap();
+/// let v1 = graph.new_vertex().unwrap();
+/// graph
+/// 	.add_edge_weighted(&v0, &v1, Road { name: "Main Street", length: 7 })
+/// 	.unwrap();
+///
+/// let lengths = MapWeightsProxy::new(&graph, |_| (), |road: &Road| road.length);
+/// let lengths = VertexInGraph::ensure(lengths, v0).unwrap();
+/// let mut dijkstra = DijkstraShortestPaths::new_simple(&lengths);
+///
+/// assert_eq!(dijkstra.next(), Some((v0, v1, &7)));
+/// ```
+pub struct MapWeightsProxy<C: Ensure, Vw, Ew>
+{
+	/// The underlying graph
+	graph: C,
+	/// The transformed weight of each vertex
+	vertex_weights: HashMap<<C::Graph as Graph>::Vertex, Vw>,
+	/// The transformed weights of the edges from the first vertex to the
+	/// second.
+	///
+	/// If the graph is undirected, each edge is only present in one direction.
+	edge_weights: EdgeWeights<C::Graph, Ew>,
+}
+
+impl<C: Ensure, Vw, Ew> MapWeightsProxy<C, Vw, Ew>
+where
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	/// Creates a proxy of the given graph, with each vertex weight transformed
+	/// by `map_vertex` and each edge weight transformed by `map_edge`.
+	pub fn new(
+		underlying: C,
+		map_vertex: impl Fn(&<C::Graph as Graph>::VertexWeight) -> Vw,
+		map_edge: impl Fn(&<C::Graph as Graph>::EdgeWeight) -> Ew,
+	) -> Self
+	{
+		let graph = underlying.graph();
+		let vertex_weights = graph
+			.all_vertices_weighted()
+			.map(|(v, w)| (v, map_vertex(w)))
+			.collect();
+		let mut edge_weights = HashMap::new();
+		for (source, sink, w) in graph.all_edges()
+		{
+			edge_weights
+				.entry((source, sink))
+				.or_insert_with(Vec::new)
+				.push(map_edge(w));
+		}
+
+		Self {
+			graph: underlying,
+			vertex_weights,
+			edge_weights,
+		}
+	}
+
+	/// Returns the cached weights of the edges from the source to the sink.
+	fn weights_between(
+		&self,
+		source: <C::Graph as Graph>::Vertex,
+		sink: <C::Graph as Graph>::Vertex,
+	) -> impl Iterator<Item = &Ew>
+	{
+		self.edge_weights.get(&(source, sink)).into_iter().flatten()
+	}
+}
+
+impl<C: Ensure, Vw, Ew> Graph for MapWeightsProxy<C, Vw, Ew>
+where
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	type Directedness = <C::Graph as Graph>::Directedness;
+	type EdgeWeight = Ew;
+	type Vertex = <C::Graph as Graph>::Vertex;
+	type VertexWeight = Vw;
+
+	fn all_vertices_weighted<'a>(
+		&'a self,
+	) -> Box<dyn 'a + Iterator<Item = (Self::Vertex, &'a Self::VertexWeight)>>
+	{
+		Box::new(
+			self.graph
+				.graph()
+				.all_vertices()
+				.map(move |v| (v, &self.vertex_weights[&v])),
+		)
+	}
+
+	fn edges_between<'a: 'b, 'b>(
+		&'a self,
+		source: impl 'b + Borrow<Self::Vertex>,
+		sink: impl 'b + Borrow<Self::Vertex>,
+	) -> Box<dyn 'b + Iterator<Item = &'a Self::EdgeWeight>>
+	{
+		let (source, sink) = (*source.borrow(), *sink.borrow());
+		let reverse = !Self::Directedness::directed() && source != sink;
+		Box::new(
+			self.weights_between(source, sink)
+				.chain(self.weights_between(sink, source).filter(move |_| reverse)),
+		)
+	}
+
+	/// Lists the edges in the order of the underlying graph.
+	///
+	/// The cached weights between two vertices are in the order the underlying
+	/// graph lists the edges between them, so the weight of an edge is the one
+	/// at the number of edges between the same vertices listed before it.
+	fn all_edges<'a>(
+		&'a self,
+	) -> Box<dyn 'a + Iterator<Item = (Self::Vertex, Self::Vertex, &'a Self::EdgeWeight)>>
+	{
+		let mut listed = HashMap::new();
+		Box::new(
+			self.graph
+				.graph()
+				.all_edges()
+				.map(move |(source, sink, _)| {
+					let count = listed.entry((source, sink)).or_insert(0);
+					let w = &self.edge_weights[&(source, sink)][*count];
+					*count += 1;
+					(source, sink, w)
+				}),
+		)
+	}
+}
+
+base_graph! {
+	use<C, Vw, Ew> MapWeightsProxy<C, Vw, Ew>:
+		HasVertex, NoLoops, Unique, Weak, Unilateral, Connected
+	as (self.graph): C
+	where C: Ensure, <C::Graph as Graph>::Vertex: Hash
+}
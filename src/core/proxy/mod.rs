@@ -1,9 +1,15 @@
+mod contracted_proxy;
 mod edge_proxy;
+mod filter_proxy;
+mod line_graph_proxy;
+mod map_weights_proxy;
+mod quotient_proxy;
 mod reverse_graph;
 mod subgraph_proxy;
 mod undirected_proxy;
 mod vertex_proxy;
 
 pub use self::{
-	edge_proxy::*, reverse_graph::*, subgraph_proxy::*, undirected_proxy::*, vertex_proxy::*,
+	contracted_proxy::*, edge_proxy::*, filter_proxy::*, line_graph_proxy::*, map_weights_proxy::*,
+	quotient_proxy::*, reverse_graph::*, subgraph_proxy::*, undirected_proxy::*, vertex_proxy::*,
 };
@@ -0,0 +1,172 @@
+use crate::core::{Directedness, Ensure, Graph};
+use std::{borrow::Borrow, collections::HashMap, hash::Hash, iter::empty};
+
+/// The [quotient](https://en.wikipedia.org/wiki/Quotient_graph) of a graph,
+/// whose vertices are the classes the vertices of the graph are partitioned
+/// into.
+///
+/// Each edge of the underlying graph is an edge between the classes of its
+/// source and sink, with the same weight.
+/// Edges between vertices in the same class are therefore loops on the class.
+/// The classes have no weights.
+///
+/// Which class each vertex belongs to is decided once, when the proxy is
+/// created.
+///
+/// ### Usage
+/// ```
+/// # use graphene::{
+/// # 	common::AdjListGraph,
+/// # 	core::{property::{AddEdge, NewVertex}, proxy::QuotientProxy, Graph},
+/// # };
+/// // A call graph where each function knows its module
+/// let mut calls = AdjListGraph::<&str, ()>::new();
+/// let parse = calls.new_vertex_weighted("parser").unwrap();
+/// let lex = calls.new_vertex_weighted("parser").unwrap();
+/// let eval = calls.new_vertex_weighted("eval").unwrap();
+/// calls.add_edge(&parse, &lex).unwrap();
+/// calls.add_edge(&eval, &parse).unwrap();
+///
+/// let modules = QuotientProxy::new(&calls, |f| *calls.vertex_weight(f).unwrap());
+///
+/// assert_eq!(modules.all_vertices().count(), 2);
+/// assert_eq!(modules.edges_between("eval", "parser").count(), 1);
+/// assert_eq!(modules.edges_between("parser", "parser").count(), 1);
+/// assert_eq!(modules.members("parser").len(), 2);
+/// ```
+pub struct QuotientProxy<C: Ensure, K>
+{
+	/// The underlying graph
+	graph: C,
+	/// The vertices of each class.
+	classes: HashMap<K, Vec<<C::Graph as Graph>::Vertex>>,
+}
+
+impl<C: Ensure, K: Copy + Eq + Hash> QuotientProxy<C, K>
+where
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	/// Creates the quotient of the given graph, where each vertex belongs to
+	/// the class given by `class`.
+	pub fn new(underlying: C, class: impl Fn(<C::Graph as Graph>::Vertex) -> K) -> Self
+	{
+		let mut classes = HashMap::new();
+		for v in underlying.graph().all_vertices()
+		{
+			classes.entry(class(v)).or_insert_with(Vec::new).push(v);
+		}
+		Self {
+			graph: underlying,
+			classes,
+		}
+	}
+
+	/// Returns the vertices of the underlying graph in the given class.
+	pub fn members(&self, class: impl Borrow<K>) -> &[<C::Graph as Graph>::Vertex]
+	{
+		self.classes
+			.get(class.borrow())
+			.map_or(&[], |members| members.as_slice())
+	}
+}
+
+impl<C: Ensure, K: Copy + Eq + Hash> Graph for QuotientProxy<C, K>
+where
+	<C::Graph as Graph>::Vertex: Hash,
+{
+	type Directedness = <C::Graph as Graph>::Directedness;
+	type EdgeWeight = <C::Graph as Graph>::EdgeWeight;
+	type Vertex = K;
+	type VertexWeight = ();
+
+	fn all_vertices_weighted<'a>(
+		&'a self,
+	) -> Box<dyn 'a + Iterator<Item = (Self::Vertex, &'a Self::VertexWeight)>>
+	{
+		Box::new(self.classes.keys().map(|class| (*class, &())))
+	}
+
+	fn edges_between<'a: 'b, 'b>(
+		&'a self,
+		source: impl 'b + Borrow<Self::Vertex>,
+		sink: impl 'b + Borrow<Self::Vertex>,
+	) -> Box<dyn 'b + Iterator<Item = &'a Self::EdgeWeight>>
+	{
+		let (source, sink) = (*source.borrow(), *sink.borrow());
+		if !(self.contains_vertex(source) && self.contains_vertex(sink))
+		{
+			return Box::new(empty());
+		}
+
+		Box::new(
+			edges_between_classes(
+				self.graph.graph(),
+				self.members(source),
+				self.members(sink),
+				source == sink,
+			)
+			.into_iter(),
+		)
+	}
+
+	fn contains_vertex(&self, v: impl Borrow<Self::Vertex>) -> bool
+	{
+		self.classes.contains_key(v.borrow())
+	}
+}
+
+base_graph! {
+	use<C, K> QuotientProxy<C, K>
+	where C: Ensure, K: Copy + Eq + Hash, <C::Graph as Graph>::Vertex: Hash
+}
+
+/// Returns the weights of the edges of the given graph from a vertex in
+/// `sources` to a vertex in `sinks`.
+///
+/// Only the edges of the smaller of the two classes are visited.
+/// If `same` is true, the two classes are the same, so in undirected graphs an
+/// edge between two of its vertices is only returned once.
+pub(super) fn edges_between_classes<'a, G: Graph>(
+	graph: &'a G,
+	sources: &[G::Vertex],
+	sinks: &[G::Vertex],
+	same: bool,
+) -> Vec<&'a G::EdgeWeight>
+where
+	G::Vertex: Hash,
+{
+	let visit_sources = sources.len() <= sinks.len();
+	let (visited, others) = if visit_sources
+	{
+		(sources, sinks)
+	}
+	else
+	{
+		(sinks, sources)
+	};
+	let positions: HashMap<_, _> = others.iter().enumerate().map(|(j, v)| (*v, j)).collect();
+
+	let mut edges = Vec::new();
+	for (i, u) in visited.iter().enumerate()
+	{
+		let incident = if visit_sources
+		{
+			graph.edges_sourced_in(*u)
+		}
+		else
+		{
+			graph.edges_sinked_in(*u)
+		};
+		for (v, w) in incident
+		{
+			// In undirected graphs, an edge inside a class would otherwise be
+			// counted from both of its ends.
+			match positions.get(&v)
+			{
+				Some(j) if !same || G::Directedness::directed() || i <= *j => edges.push(w),
+				_ => (),
+			}
+		}
+	}
+	edges
+}
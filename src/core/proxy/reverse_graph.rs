@@ -1,6 +1,6 @@
 use crate::core::{
 	property::{AddEdge, RemoveEdge},
-	Directed, Ensure, Graph, GraphDerefMut, GraphMut,
+	Directed, Ensure, Graph, GraphDerefMut, GraphError, GraphMut,
 };
 use delegate::delegate;
 use std::borrow::Borrow;
@@ -45,6 +45,22 @@ where
 	{
 		self.0.graph().edges_between(sink, source)
 	}
+
+	fn edges_sourced_in<'a: 'b, 'b>(
+		&'a self,
+		v: impl 'b + Borrow<Self::Vertex>,
+	) -> Box<dyn 'b + Iterator<Item = (Self::Vertex, &'a Self::EdgeWeight)>>
+	{
+		self.0.graph().edges_sinked_in(v)
+	}
+
+	fn edges_sinked_in<'a: 'b, 'b>(
+		&'a self,
+		v: impl 'b + Borrow<Self::Vertex>,
+	) -> Box<dyn 'b + Iterator<Item = (Self::Vertex, &'a Self::EdgeWeight)>>
+	{
+		self.0.graph().edges_sourced_in(v)
+	}
 }
 
 impl<C: Ensure + GraphDerefMut> GraphMut for ReverseGraph<C>
@@ -77,7 +93,7 @@ where
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		weight: Self::EdgeWeight,
-	) -> Result<(), ()>
+	) -> Result<(), GraphError<Self::Vertex>>
 	{
 		self.0.graph_mut().add_edge_weighted(sink, source, weight)
 	}
@@ -92,7 +108,7 @@ where
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		f: F,
-	) -> Result<Self::EdgeWeight, ()>
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
 	where
 		F: Fn(&Self::EdgeWeight) -> bool,
 	{
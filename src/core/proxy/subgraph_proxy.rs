@@ -1,8 +1,9 @@
 use crate::core::{
+	copy_into,
 	property::{AddEdge, NewVertex, RemoveEdge, RemoveVertex, Subgraph},
-	Edge, Ensure, Graph, GraphDerefMut, GraphMut,
+	Copies, Edge, Ensure, Graph, GraphDerefMut, GraphError, GraphMut,
 };
-use std::borrow::Borrow;
+use std::{borrow::Borrow, collections::HashSet, hash::Hash};
 
 /// A subgraph of another graph.
 ///
@@ -13,28 +14,103 @@ pub struct SubgraphProxy<C: Ensure>
 {
 	/// The underlying graph
 	graph: C,
-	/// Which vertices are part of this subgraph
+	/// Which vertices are part of this subgraph, in the order they were added
 	verts: Vec<<C::Graph as Graph>::Vertex>,
+	/// The same vertices, for checking membership
+	members: HashSet<<C::Graph as Graph>::Vertex>,
 	/// Edges who's sources are in this subgraph but who's sinks aren't.
 	exit_edges: Vec<(<C::Graph as Graph>::Vertex, <C::Graph as Graph>::Vertex)>,
 }
 
 impl<C: Ensure> SubgraphProxy<C>
+where
+	<C::Graph as Graph>::Vertex: Hash,
 {
 	pub fn new(underlying: C) -> Self
 	{
 		Self {
 			graph: underlying,
 			verts: Vec::new(),
+			members: HashSet::new(),
 			exit_edges: Vec::new(),
 		}
 	}
 
-	pub fn expand(&mut self, v: <C::Graph as Graph>::Vertex) -> Result<(), ()>
+	/// Creates the subgraph induced by the given vertices, i.e. the subgraph
+	/// with those vertices and all the edges between them.
+	///
+	/// Returns an error if any of the vertices isn't in the underlying graph.
+	///
+	/// ### Usage
+	/// ```
+	/// # use graphene::{
+	/// # 	common::AdjListGraph,
+	/// # 	core::{property::{AddEdge, NewVertex, Subgraph}, proxy::SubgraphProxy, Graph},
+	/// # };
+	/// let mut graph = AdjListGraph::<(), ()>::new();
+	/// let v0 = graph.new_vertex().unwrap();
+	/// let v1 = graph.new_vertex().unwrap();
+	/// let v2 = graph.new_vertex().unwrap();
+	/// graph.add_edge(&v0, &v1).unwrap();
+	/// graph.add_edge(&v1, &v2).unwrap();
+	///
+	/// let induced = SubgraphProxy::induced(&graph, vec![v0, v1]).unwrap();
+	/// assert_eq!(induced.all_edges().count(), 1);
+	/// assert_eq!(induced.exit_edges().collect::<Vec<_>>(), vec![(v1, v2)]);
+	///
+	/// let mut owned = AdjListGraph::<(), ()>::new();
+	/// induced.copy_into(&mut owned).unwrap();
+	/// assert_eq!(owned.all_vertices().count(), 2);
+	/// ```
+	pub fn induced(
+		underlying: C,
+		vertices: impl IntoIterator<Item = <C::Graph as Graph>::Vertex>,
+	) -> Result<Self, GraphError<<C::Graph as Graph>::Vertex>>
+	{
+		let mut subgraph = Self::new(underlying);
+		for v in vertices
+		{
+			subgraph.expand(v)?;
+		}
+		Ok(subgraph)
+	}
+
+	/// Creates the subgraph induced by the given vertices without checking
+	/// that they are in the underlying graph.
+	///
+	/// This takes time linear in the vertices' outgoing edges, unlike calling
+	/// `expand` for each vertex, which looks through the exit edges.
+	pub(crate) fn induced_unchecked(underlying: C, verts: Vec<<C::Graph as Graph>::Vertex>)
+		-> Self
+	{
+		let members: HashSet<_> = verts.iter().cloned().collect();
+		let exit_edges = verts
+			.iter()
+			.flat_map(|&v| {
+				underlying
+					.graph()
+					.edges_sourced_in(v)
+					.map(move |e| (v, e.0))
+					.collect::<Vec<_>>()
+			})
+			.filter(|e| !members.contains(&e.1))
+			.collect();
+		Self {
+			graph: underlying,
+			verts,
+			members,
+			exit_edges,
+		}
+	}
+
+	pub fn expand(
+		&mut self,
+		v: <C::Graph as Graph>::Vertex,
+	) -> Result<(), GraphError<<C::Graph as Graph>::Vertex>>
 	{
 		if self.graph.graph().contains_vertex(v)
 		{
-			if !self.verts.contains(&v)
+			if self.members.insert(v)
 			{
 				self.verts.push(v);
 
@@ -47,7 +123,7 @@ impl<C: Ensure> SubgraphProxy<C>
 				// Add any exit edge that is sourced in the vertex
 				for e in self.graph.graph().edges_sourced_in(v)
 				{
-					if !self.verts.contains(&e.0)
+					if !self.members.contains(&e.0)
 					{
 						self.exit_edges.push((v, e.0));
 					}
@@ -57,12 +133,34 @@ impl<C: Ensure> SubgraphProxy<C>
 		}
 		else
 		{
-			Err(())
+			Err(GraphError::VertexNotFound(v))
 		}
 	}
+
+	/// Copies the vertices and edges of this subgraph into the given graph,
+	/// such that the copy doesn't depend on the underlying graph.
+	///
+	/// Returns which vertex of the given graph each vertex of the subgraph was
+	/// copied to.
+	pub fn copy_into<G>(&self, g: &mut G) -> Result<Copies<Self, G>, GraphError<G::Vertex>>
+	where
+		G: NewVertex
+			+ AddEdge<
+				VertexWeight = <Self as Graph>::VertexWeight,
+				EdgeWeight = <Self as Graph>::EdgeWeight,
+				Directedness = <Self as Graph>::Directedness,
+			>,
+		<Self as Graph>::Vertex: Hash,
+		<Self as Graph>::VertexWeight: Clone,
+		<Self as Graph>::EdgeWeight: Clone,
+	{
+		copy_into(self, g)
+	}
 }
 
 impl<C: Ensure> Graph for SubgraphProxy<C>
+where
+	<C::Graph as Graph>::Vertex: Hash,
 {
 	type Directedness = <C::Graph as Graph>::Directedness;
 	type EdgeWeight = <C::Graph as Graph>::EdgeWeight;
@@ -73,11 +171,11 @@ impl<C: Ensure> Graph for SubgraphProxy<C>
 		&'a self,
 	) -> Box<dyn 'a + Iterator<Item = (Self::Vertex, &'a Self::VertexWeight)>>
 	{
+		let graph = self.graph.graph();
 		Box::new(
-			self.graph
-				.graph()
-				.all_vertices_weighted()
-				.filter(move |(v, _)| self.verts.contains(v)),
+			self.verts
+				.iter()
+				.filter_map(move |v| graph.vertex_weight(v).map(|w| (*v, w))),
 		)
 	}
 
@@ -96,23 +194,29 @@ impl<C: Ensure> Graph for SubgraphProxy<C>
 				}),
 		)
 	}
+
+	fn contains_vertex(&self, v: impl Borrow<Self::Vertex>) -> bool
+	{
+		self.members.contains(v.borrow())
+	}
 }
 
 impl<C: Ensure + GraphDerefMut> GraphMut for SubgraphProxy<C>
 where
 	C::Graph: GraphMut,
+	<C::Graph as Graph>::Vertex: Hash,
 {
 	fn all_vertices_weighted_mut<'a>(
 		&'a mut self,
 	) -> Box<dyn 'a + Iterator<Item = (Self::Vertex, &'a mut Self::VertexWeight)>>
 	{
-		let verts = &self.verts;
+		let members = &self.members;
 		let graph = self.graph.graph_mut();
 
 		Box::new(
 			graph
 				.all_vertices_weighted_mut()
-				.filter(move |(v, _)| verts.contains(v)),
+				.filter(move |(v, _)| members.contains(v)),
 		)
 	}
 
@@ -136,13 +240,14 @@ where
 impl<C: Ensure + GraphDerefMut> AddEdge for SubgraphProxy<C>
 where
 	C::Graph: AddEdge,
+	<C::Graph as Graph>::Vertex: Hash,
 {
 	fn add_edge_weighted(
 		&mut self,
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		weight: Self::EdgeWeight,
-	) -> Result<(), ()>
+	) -> Result<(), GraphError<Self::Vertex>>
 	{
 		if self.contains_vertex(source.borrow()) && self.contains_vertex(sink.borrow())
 		{
@@ -150,9 +255,13 @@ where
 				.graph_mut()
 				.add_edge_weighted(source, sink, weight)
 		}
+		else if !self.contains_vertex(source.borrow())
+		{
+			Err(GraphError::VertexNotFound(*source.borrow()))
+		}
 		else
 		{
-			Err(())
+			Err(GraphError::VertexNotFound(*sink.borrow()))
 		}
 	}
 }
@@ -160,13 +269,14 @@ where
 impl<C: Ensure + GraphDerefMut> RemoveEdge for SubgraphProxy<C>
 where
 	C::Graph: RemoveEdge,
+	<C::Graph as Graph>::Vertex: Hash,
 {
 	fn remove_edge_where_weight<F>(
 		&mut self,
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		f: F,
-	) -> Result<Self::EdgeWeight, ()>
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
 	where
 		F: Fn(&Self::EdgeWeight) -> bool,
 	{
@@ -176,9 +286,13 @@ where
 				.graph_mut()
 				.remove_edge_where_weight(source, sink, f)
 		}
+		else if !self.contains_vertex(source.borrow())
+		{
+			Err(GraphError::VertexNotFound(*source.borrow()))
+		}
 		else
 		{
-			Err(())
+			Err(GraphError::VertexNotFound(*sink.borrow()))
 		}
 	}
 }
@@ -186,11 +300,16 @@ where
 impl<C: Ensure + GraphDerefMut> NewVertex for SubgraphProxy<C>
 where
 	C::Graph: NewVertex,
+	<C::Graph as Graph>::Vertex: Hash,
 {
-	fn new_vertex_weighted(&mut self, w: Self::VertexWeight) -> Result<Self::Vertex, ()>
+	fn new_vertex_weighted(
+		&mut self,
+		w: Self::VertexWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
 	{
 		let v = self.graph.graph_mut().new_vertex_weighted(w)?;
 		self.verts.push(v);
+		self.members.insert(v);
 		Ok(v)
 	}
 }
@@ -198,8 +317,12 @@ where
 impl<C: Ensure + GraphDerefMut> RemoveVertex for SubgraphProxy<C>
 where
 	C::Graph: RemoveVertex,
+	<C::Graph as Graph>::Vertex: Hash,
 {
-	fn remove_vertex(&mut self, v: impl Borrow<Self::Vertex>) -> Result<Self::VertexWeight, ()>
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
 	{
 		if self.contains_vertex(v.borrow())
 		{
@@ -210,16 +333,19 @@ where
 				.position(|t| t.borrow() == v.borrow())
 				.expect("Couldn't find removed vertex in subgraph");
 			self.verts.remove(index);
+			self.members.remove(v.borrow());
 			Ok(w)
 		}
 		else
 		{
-			Err(())
+			Err(GraphError::VertexNotFound(*v.borrow()))
 		}
 	}
 }
 
 impl<C: Ensure> Subgraph for SubgraphProxy<C>
+where
+	<C::Graph as Graph>::Vertex: Hash,
 {
 	fn exit_edges<'a>(&'a self) -> Box<dyn 'a + Iterator<Item = (Self::Vertex, Self::Vertex)>>
 	{
@@ -229,5 +355,5 @@ impl<C: Ensure> Subgraph for SubgraphProxy<C>
 
 base_graph! {
 	use<C> SubgraphProxy<C>
-	where C: Ensure
+	where C: Ensure, <C::Graph as Graph>::Vertex: Hash
 }
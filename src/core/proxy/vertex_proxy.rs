@@ -1,31 +1,36 @@
 use crate::core::{
 	property::{NewVertex, RemoveVertex},
-	Ensure, Graph,
+	Ensure, Graph, GraphDeref, GraphError,
 };
-use std::borrow::Borrow;
+use std::{borrow::Borrow, hash::Hash};
 
-#[derive(Copy, Clone, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum ProxyVertex<V: Copy + Eq>
 {
 	Underlying(V),
 	New(usize),
 }
 
+/// A vertex of the graph underlying the given ensurer.
+type RemovedVertex<C> = <<C as GraphDeref>::Graph as Graph>::Vertex;
+
 /// A helper proxy graph, that manages addition and removal of
 /// vertices.
 ///
 /// It does not handle addition or removal of edges in any way.
+///
+/// Vertices added to the proxy keep the weights they were added with.
 pub struct VertexProxyGraph<C: Ensure>
 {
 	/// The underlying graph
 	graph: C,
 
-	/// The number of vertices that aren't in the underlying graph,
+	/// The weights of the vertices that aren't in the underlying graph,
 	/// that have been added to the proxy.
 	/// Since this struct does not guarantee that vertices keep their identifier
-	/// upon removal, we just increment and decrement this number whenever a new
-	/// number is added.
-	new_count: usize,
+	/// upon removal, the vertex `New(i)` is the `i`th of them, and removing it
+	/// moves the ones after it down.
+	new: Vec<<C::Graph as Graph>::VertexWeight>,
 
 	/// Vertices that have been removed from the underlying graph.
 	removed: Vec<<C::Graph as Graph>::Vertex>,
@@ -37,10 +42,46 @@ impl<C: Ensure> VertexProxyGraph<C>
 	{
 		Self {
 			graph: underlying,
-			new_count: 0,
+			new: Vec::new(),
 			removed: Vec::new(),
 		}
 	}
+
+	/// Removes the given vertex, like
+	/// [`remove_vertex`](../property/trait.RemoveVertex.html#tymethod.
+	/// remove_vertex), but discards its weight instead of returning it.
+	///
+	/// Therefore, unlike `remove_vertex`, this doesn't require the vertex
+	/// weights to be clonable.
+	pub fn discard_vertex(
+		&mut self,
+		v: impl Borrow<ProxyVertex<<C::Graph as Graph>::Vertex>>,
+	) -> Result<(), GraphError<ProxyVertex<<C::Graph as Graph>::Vertex>>>
+	{
+		let v = *v.borrow();
+		if !self.contains_vertex(v)
+		{
+			return Err(GraphError::VertexNotFound(v));
+		}
+		match v
+		{
+			ProxyVertex::New(i) =>
+			{
+				self.new.remove(i);
+			},
+			ProxyVertex::Underlying(v) => self.removed.push(v),
+		}
+		Ok(())
+	}
+
+	/// Returns the underlying graph, the weights of the vertices added to the
+	/// proxy, and the underlying vertices removed from it, in the order they
+	/// were removed.
+	pub(crate) fn into_parts(self)
+		-> (C, Vec<<Self as Graph>::VertexWeight>, Vec<RemovedVertex<C>>)
+	{
+		(self.graph, self.new, self.removed)
+	}
 }
 
 impl<C: Ensure> Graph for VertexProxyGraph<C>
@@ -48,7 +89,7 @@ impl<C: Ensure> Graph for VertexProxyGraph<C>
 	type Directedness = <C::Graph as Graph>::Directedness;
 	type EdgeWeight = <C::Graph as Graph>::EdgeWeight;
 	type Vertex = ProxyVertex<<C::Graph as Graph>::Vertex>;
-	type VertexWeight = ();
+	type VertexWeight = <C::Graph as Graph>::VertexWeight;
 
 	fn all_vertices_weighted<'a>(
 		&'a self,
@@ -57,13 +98,31 @@ impl<C: Ensure> Graph for VertexProxyGraph<C>
 		Box::new(
 			self.graph
 				.graph()
-				.all_vertices()
-				.filter(move |v| !self.removed.contains(v))
-				.map(|v| (ProxyVertex::Underlying(v), &()))
-				.chain((0..self.new_count).map(|v| (ProxyVertex::New(v), &()))),
+				.all_vertices_weighted()
+				.filter(move |(v, _)| !self.removed.contains(v))
+				.map(|(v, w)| (ProxyVertex::Underlying(v), w))
+				.chain(
+					self.new
+						.iter()
+						.enumerate()
+						.map(|(v, w)| (ProxyVertex::New(v), w)),
+				),
 		)
 	}
 
+	fn vertex_weight(&self, v: impl Borrow<Self::Vertex>) -> Option<&Self::VertexWeight>
+	{
+		match *v.borrow()
+		{
+			ProxyVertex::Underlying(v) if !self.removed.contains(&v) =>
+			{
+				self.graph.graph().vertex_weight(v)
+			},
+			ProxyVertex::New(i) => self.new.get(i),
+			_ => None,
+		}
+	}
+
 	fn edges_between<'a: 'b, 'b>(
 		&'a self,
 		source: impl 'b + Borrow<Self::Vertex>,
@@ -103,42 +162,59 @@ impl<C: Ensure> Graph for VertexProxyGraph<C>
 			edges_between: result,
 		})
 	}
+
+	fn edges_sourced_in<'a: 'b, 'b>(
+		&'a self,
+		v: impl 'b + Borrow<Self::Vertex>,
+	) -> Box<dyn 'b + Iterator<Item = (Self::Vertex, &'a Self::EdgeWeight)>>
+	{
+		match *v.borrow()
+		{
+			ProxyVertex::Underlying(v) if !self.removed.contains(&v) =>
+			{
+				Box::new(
+					self.graph
+						.graph()
+						.edges_sourced_in(v)
+						.filter(move |(sink, _)| !self.removed.contains(sink))
+						.map(|(sink, w)| (ProxyVertex::Underlying(sink), w)),
+				)
+			},
+			_ => Box::new(std::iter::empty()),
+		}
+	}
 }
 
 impl<C: Ensure> NewVertex for VertexProxyGraph<C>
 {
-	fn new_vertex_weighted(&mut self, _: Self::VertexWeight) -> Result<Self::Vertex, ()>
+	fn new_vertex_weighted(
+		&mut self,
+		w: Self::VertexWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
 	{
-		let new_id = self.new_count;
-		self.new_count += 1;
-		Ok(ProxyVertex::New(new_id))
+		self.new.push(w);
+		Ok(ProxyVertex::New(self.new.len() - 1))
 	}
 }
 
 impl<C: Ensure> RemoveVertex for VertexProxyGraph<C>
+where
+	<C::Graph as Graph>::VertexWeight: Clone,
 {
-	fn remove_vertex(&mut self, v: impl Borrow<Self::Vertex>) -> Result<Self::VertexWeight, ()>
+	/// Since the underlying graph isn't mutated, the weight of a vertex
+	/// removed from it is cloned.
+	fn remove_vertex(
+		&mut self,
+		v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
 	{
-		if self.contains_vertex(v.borrow())
-		{
-			match v.borrow()
-			{
-				ProxyVertex::New(_) =>
-				{
-					self.new_count -= 1;
-					Ok(())
-				},
-				ProxyVertex::Underlying(v) =>
-				{
-					self.removed.push(*v);
-					Ok(())
-				},
-			}
-		}
-		else
-		{
-			Err(())
-		}
+		let v = *v.borrow();
+		let w = self
+			.vertex_weight(v)
+			.cloned()
+			.ok_or(GraphError::VertexNotFound(v))?;
+		self.discard_vertex(v)?;
+		Ok(w)
 	}
 }
 
@@ -0,0 +1,312 @@
+use crate::core::{
+	property::track_removal,
+	proxy::{EdgeProxyGraph, ProxyVertex, VertexProxyGraph},
+	BaseGraph, Ensure, Graph, GraphError, Release,
+};
+use std::ops::{Deref, DerefMut};
+
+/// The proxy a [`Transaction`](struct.Transaction.html) on the given ensured
+/// graph makes its mutations on.
+pub type TransactionProxy<'a, E> = EdgeProxyGraph<VertexProxyGraph<&'a mut E>>;
+
+/// A graph whose vertices and edges can be added and removed without
+/// validating the properties it ensures.
+///
+/// Ensurers pass the mutations on to the graph they wrap. Ensurers that track
+/// mutations, like [`HistoryGraph`](property/struct.HistoryGraph.html) and
+/// [`ObservedGraph`](property/struct.ObservedGraph.html), track them like
+/// any other mutation.
+///
+/// [`Transaction`](struct.Transaction.html) uses this to apply its mutations
+/// once their result has been validated. It must not be used to violate the
+/// ensured properties.
+///
+/// Implemented by `impl_ensurer` for ensurers, and by `base_graph` for base
+/// graphs that implement the mutation traits.
+pub trait MutateUnvalidated: Graph
+{
+	/// Adds a new vertex with the given weight, like
+	/// [`new_vertex_weighted`](property/trait.NewVertex.html#tymethod.
+	/// new_vertex_weighted).
+	fn new_vertex_unvalidated(
+		&mut self,
+		w: Self::VertexWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>;
+
+	/// Removes the given vertex, like
+	/// [`remove_vertex`](property/trait.RemoveVertex.html#tymethod.
+	/// remove_vertex).
+	fn remove_vertex_unvalidated(
+		&mut self,
+		v: Self::Vertex,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>;
+
+	/// Adds an edge with the given weight, like
+	/// [`add_edge_weighted`](property/trait.AddEdge.html#tymethod.
+	/// add_edge_weighted).
+	fn add_edge_unvalidated(
+		&mut self,
+		source: Self::Vertex,
+		sink: Self::Vertex,
+		weight: Self::EdgeWeight,
+	) -> Result<(), GraphError<Self::Vertex>>;
+
+	/// Removes an edge whose weight satisfies the given function, like
+	/// [`remove_edge_where_weight`](property/trait.RemoveEdge.html#tymethod.
+	/// remove_edge_where_weight).
+	fn remove_edge_unvalidated<F>(
+		&mut self,
+		source: Self::Vertex,
+		sink: Self::Vertex,
+		f: F,
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
+	where
+		F: Fn(&Self::EdgeWeight) -> bool;
+}
+
+/// An ensurer whose properties can be ensured on another base graph, whose
+/// vertices are [`ProxyVertex`](proxy/enum.ProxyVertex.html)es of the
+/// ensurer's vertices.
+///
+/// This allows a [`Transaction`](struct.Transaction.html) to validate the
+/// properties on its proxy before mutating the ensured graph.
+/// Ensurers whose properties always hold, like those tracking mutations, may
+/// leave themselves out of the rebased ensurer.
+pub trait Rebase<B: BaseGraph>: Ensure
+{
+	/// This ensurer, ensuring `B` instead of its base graph.
+	type Rebased: Ensure<Base = B>;
+
+	/// Returns the payload to ensure `B` with, holding the vertices of this
+	/// ensurer's payload as underlying vertices.
+	fn rebased_payload(&self) -> <Self::Rebased as Release>::Payload;
+}
+
+impl<G: BaseGraph, B: BaseGraph> Rebase<B> for G
+{
+	type Rebased = B;
+
+	fn rebased_payload(&self) {}
+}
+
+/// Collects mutations of an ensured graph, such that its properties are only
+/// validated once all of them have been made.
+///
+/// The transaction makes its mutations on a
+/// [`TransactionProxy`](type.TransactionProxy.html) of the ensured graph,
+/// which it dereferences to. The ensured graph isn't copied, and isn't
+/// mutated until the transaction is committed. Mutations refer to vertices as
+/// the proxy does: vertices of the ensured graph are
+/// [`Underlying`](proxy/enum.ProxyVertex.html#variant.Underlying), and
+/// vertices added by the transaction are
+/// [`New`](proxy/enum.ProxyVertex.html#variant.New).
+///
+/// When the transaction is committed, the properties of all levels of the
+/// ensured graph are validated on the proxy. Only if they hold, the
+/// mutations are made on the ensured graph through
+/// [`MutateUnvalidated`](trait.MutateUnvalidated.html), such that ensurers
+/// tracking mutations see each of them. The removed edges are removed first,
+/// then the removed vertices, after which the new vertices and edges are
+/// added. Otherwise, the ensured graph is left as it was. Dropping the
+/// transaction without committing also leaves the ensured graph as it was.
+///
+/// This allows mutations that are only valid as a whole, like removing an edge
+/// from a connected graph and adding a replacement.
+///
+/// Removed edges are found in the ensured graph by their weights, which must
+/// therefore be clonable and comparable.
+///
+/// ### Usage
+/// ```
+/// # use graphene::{
+/// # 	common::AdjListGraph,
+/// # 	core::{
+/// # 		property::{AddEdge, ConnectedGraph, NewVertex, RemoveEdge},
+/// # 		proxy::ProxyVertex::Underlying,
+/// # 		Ensure, Graph,
+/// # 	},
+/// # };
+/// let mut graph = AdjListGraph::<(), ()>::new();
+/// let v0 = graph.new_vertex().unwrap();
+/// let v1 = graph.new_vertex().unwrap();
+/// graph.add_edge(&v0, &v1).unwrap();
+/// graph.add_edge(&v1, &v0).unwrap();
+/// let mut connected = ConnectedGraph::ensure(graph, ()).unwrap();
+///
+/// // Replace the edge from v0 to v1 with a path through a new vertex
+/// let mut transaction = connected.transaction();
+/// transaction.remove_edge(Underlying(v0), Underlying(v1)).unwrap();
+/// let v2 = transaction.new_vertex().unwrap();
+/// transaction.add_edge(Underlying(v0), v2).unwrap();
+/// transaction.add_edge(v2, Underlying(v1)).unwrap();
+/// let v2 = transaction.commit().unwrap()[0];
+/// assert_eq!(connected.all_vertices().count(), 3);
+///
+/// // Removing the edge into v1 would disconnect the graph, so nothing changes
+/// let mut transaction = connected.transaction();
+/// transaction.remove_edge(Underlying(v2), Underlying(v1)).unwrap();
+/// assert!(transaction.commit().is_err());
+/// assert_eq!(connected.edges_between(v2, v1).count(), 1);
+/// ```
+pub struct Transaction<'a, E>
+where
+	E: Ensure + Graph + Rebase<TransactionProxy<'a, E>>,
+{
+	/// The proxy of the ensured graph the mutations are made on
+	proxy: TransactionProxy<'a, E>,
+
+	/// The payload of the ensured graph, used to ensure the proxy
+	payload: <E::Rebased as Release>::Payload,
+}
+
+impl<'a, E> Transaction<'a, E>
+where
+	E: Ensure + Graph + Rebase<TransactionProxy<'a, E>>,
+{
+	/// Starts a transaction on the given ensured graph.
+	///
+	/// See also [`Ensure::transaction`](trait.Ensure.html#method.transaction).
+	pub fn new(ensured: &'a mut E) -> Self
+	{
+		let payload = <E as Rebase<TransactionProxy<'a, E>>>::rebased_payload(ensured);
+		Self {
+			proxy: EdgeProxyGraph::new(VertexProxyGraph::new(ensured)),
+			payload,
+		}
+	}
+}
+
+impl<'a, E> Transaction<'a, E>
+where
+	E: Ensure + Graph + Rebase<TransactionProxy<'a, E>> + MutateUnvalidated,
+	E::EdgeWeight: Clone + PartialEq,
+{
+	/// Validates the properties of the ensured graph on the proxy, and if they
+	/// hold, makes the mutations on the ensured graph.
+	///
+	/// Returns the vertices the ensured graph gave the vertices added by the
+	/// transaction, such that `New(i)` is at index `i`.
+	/// Otherwise, returns the error of the first level whose property doesn't
+	/// hold, and leaves the ensured graph unchanged.
+	pub fn commit(self) -> Result<Vec<E::Vertex>, GraphError<ProxyVertex<E::Vertex>>>
+	{
+		let (proxy, _) = E::Rebased::ensure_all(self.proxy, self.payload)?.release_all();
+		let (vertices, new_edges, removed_edges) = proxy.into_parts();
+		let (graph, new_vertices, removed_vertices) = vertices.into_parts();
+		apply(
+			graph,
+			new_vertices,
+			removed_vertices,
+			new_edges,
+			removed_edges,
+		)
+		.map_err(|err| err.map_vertices(ProxyVertex::Underlying))
+	}
+}
+
+impl<'a, E> Deref for Transaction<'a, E>
+where
+	E: Ensure + Graph + Rebase<TransactionProxy<'a, E>>,
+{
+	type Target = TransactionProxy<'a, E>;
+
+	fn deref(&self) -> &Self::Target
+	{
+		&self.proxy
+	}
+}
+
+impl<'a, E> DerefMut for Transaction<'a, E>
+where
+	E: Ensure + Graph + Rebase<TransactionProxy<'a, E>>,
+{
+	fn deref_mut(&mut self) -> &mut Self::Target
+	{
+		&mut self.proxy
+	}
+}
+
+/// Edges between proxy vertices, each with the given data.
+type ProxyEdges<V, T> = Vec<(ProxyVertex<V>, ProxyVertex<V>, T)>;
+
+/// Makes the mutations recorded by a transaction's proxy on the graph.
+///
+/// Returns the vertices the graph gave the new vertices, in the order they
+/// were added.
+fn apply<G>(
+	g: &mut G,
+	new_vertices: Vec<G::VertexWeight>,
+	mut removed_vertices: Vec<G::Vertex>,
+	mut new_edges: ProxyEdges<G::Vertex, G::EdgeWeight>,
+	removed_edges: ProxyEdges<G::Vertex, usize>,
+) -> Result<Vec<G::Vertex>, GraphError<G::Vertex>>
+where
+	G: MutateUnvalidated,
+	G::EdgeWeight: Clone + PartialEq,
+{
+	// Removed edges are identified by their position, so their weights are
+	// looked up before any of them are removed. Only edges of the graph can
+	// have been removed, so their vertices are underlying.
+	let removed_edges: Vec<_> = removed_edges
+		.into_iter()
+		.filter_map(|(source, sink, i)| {
+			match (source, sink)
+			{
+				(ProxyVertex::Underlying(source), ProxyVertex::Underlying(sink)) =>
+				{
+					g.edges_between(source, sink)
+						.nth(i)
+						.map(|w| (source, sink, w.clone()))
+				},
+				_ => None,
+			}
+		})
+		.collect();
+	for (source, sink, w) in removed_edges
+	{
+		g.remove_edge_unvalidated(source, sink, |e| *e == w)?;
+	}
+
+	for i in 0..removed_vertices.len()
+	{
+		let (_, moved) = track_removal(g, removed_vertices[i], |g, v| {
+			g.remove_vertex_unvalidated(v)
+		})?;
+
+		// Follow the vertices the graph gave new identities
+		let current = |u: &mut G::Vertex| {
+			if let Some((_, new)) = moved.iter().find(|(old, _)| old == u)
+			{
+				*u = *new;
+			}
+		};
+		removed_vertices[i + 1..].iter_mut().for_each(current);
+		for (source, sink, _) in new_edges.iter_mut()
+		{
+			for v in [source, sink]
+			{
+				if let ProxyVertex::Underlying(v) = v
+				{
+					current(v);
+				}
+			}
+		}
+	}
+
+	let added = new_vertices
+		.into_iter()
+		.map(|w| g.new_vertex_unvalidated(w))
+		.collect::<Result<Vec<_>, _>>()?;
+	let vertex = |v| {
+		match v
+		{
+			ProxyVertex::Underlying(v) => v,
+			ProxyVertex::New(i) => added[i],
+		}
+	};
+	for (source, sink, w) in new_edges
+	{
+		g.add_edge_unvalidated(vertex(source), vertex(sink), w)?;
+	}
+	Ok(added)
+}
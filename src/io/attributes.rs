@@ -0,0 +1,143 @@
+use std::collections::HashMap;
+
+/// Named attributes of a vertex or an edge, as read from a file.
+pub type Attributes = HashMap<String, String>;
+
+/// A weight that can be created from the attributes of a vertex or an edge
+/// when reading a graph.
+///
+/// ### Usage
+/// ```
+/// # use graphene::io::{Attributes, FromAttributes};
+/// struct City
+/// {
+/// 	population: u32,
+/// }
+///
+/// impl FromAttributes for City
+/// {
+/// 	fn from_attributes(attributes: &Attributes) -> Result<Self, String>
+/// 	{
+/// 		let population = attributes
+/// 			.get("population")
+/// 			.ok_or("missing population")?
+/// 			.parse()
+/// 			.map_err(|_| "invalid population")?;
+/// 		Ok(City { population })
+/// 	}
+/// }
+/// ```
+pub trait FromAttributes: Sized
+{
+	/// Creates a weight from the given attributes, or returns a message saying
+	/// why it can't.
+	fn from_attributes(attributes: &Attributes) -> Result<Self, String>;
+}
+
+/// Ignores the attributes.
+impl FromAttributes for ()
+{
+	fn from_attributes(_: &Attributes) -> Result<Self, String>
+	{
+		Ok(())
+	}
+}
+
+/// Keeps all the attributes.
+impl FromAttributes for Attributes
+{
+	fn from_attributes(attributes: &Attributes) -> Result<Self, String>
+	{
+		Ok(attributes.clone())
+	}
+}
+
+/// The type of an attribute, for formats that declare the types of
+/// attributes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AttributeType
+{
+	Boolean,
+	Int,
+	Long,
+	Float,
+	Double,
+	String,
+}
+
+/// A weight that can be turned into the attributes of a vertex or an edge
+/// when writing a graph.
+///
+/// For weights that also implement
+/// [`FromAttributes`](trait.FromAttributes.html), reading the attributes must
+/// give an equal weight, such that graphs are written and read without loss.
+pub trait ToAttributes
+{
+	/// Returns the attributes describing this weight.
+	fn to_attributes(&self) -> Attributes;
+
+	/// Returns the types of the attributes that aren't strings.
+	fn attribute_types() -> Vec<(String, AttributeType)>
+	{
+		Vec::new()
+	}
+}
+
+/// Has no attributes.
+impl ToAttributes for ()
+{
+	fn to_attributes(&self) -> Attributes
+	{
+		Attributes::new()
+	}
+}
+
+/// Has the attributes themselves.
+impl ToAttributes for Attributes
+{
+	fn to_attributes(&self) -> Attributes
+	{
+		self.clone()
+	}
+}
+
+/// Implements reading and writing numbers as the `weight` attribute, which
+/// most formats use for the weights of edges.
+macro_rules! impl_number_attributes {
+	($($number:ty => $attribute_type:ident),*) => {$(
+		/// Parses the `weight` attribute.
+		impl FromAttributes for $number
+		{
+			fn from_attributes(attributes: &Attributes) -> Result<Self, String>
+			{
+				let weight = attributes.get("weight").ok_or("missing weight")?;
+				weight
+					.trim()
+					.parse()
+					.map_err(|_| format!("invalid weight '{}'", weight))
+			}
+		}
+
+		/// Has the number as the `weight` attribute.
+		impl ToAttributes for $number
+		{
+			fn to_attributes(&self) -> Attributes
+			{
+				let mut attributes = Attributes::new();
+				attributes.insert("weight".into(), self.to_string());
+				attributes
+			}
+
+			fn attribute_types() -> Vec<(String, AttributeType)>
+			{
+				vec![("weight".into(), AttributeType::$attribute_type)]
+			}
+		}
+	)*};
+}
+
+impl_number_attributes!(
+	i8 => Int, i16 => Int, i32 => Int, i64 => Long, isize => Long,
+	u8 => Int, u16 => Int, u32 => Long,
+	f32 => Float, f64 => Double
+);
@@ -0,0 +1,7 @@
+//! The formats of the [DIMACS](http://dimacs.rutgers.edu/programs/challenge/)
+//! implementation challenges.
+
+mod reader;
+mod writer;
+
+pub use self::{reader::*, writer::*};
@@ -0,0 +1,541 @@
+use crate::{
+	core::{
+		property::{AddEdge, NewVertex, RootedGraph, VertexInGraph},
+		Directedness, Ensure, Graph,
+	},
+	io::{whitespace_fields, Attributes, FromAttributes, NodeIds, ParseError},
+};
+use std::{
+	collections::{HashMap, HashSet},
+	io::BufRead,
+};
+
+/// The problem described by a DIMACS file, along with its designated
+/// vertices.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DimacsProblem<V>
+{
+	/// A shortest path problem (`p sp`), whose arcs have lengths.
+	ShortestPath,
+
+	/// A maximum flow problem (`p max`), whose arcs have capacities, from the
+	/// given source to the given sink.
+	MaxFlow
+	{
+		source: V, sink: V
+	},
+
+	/// A minimum-cost flow problem (`p min`), whose nodes have supplies and
+	/// whose arcs have lower bounds, capacities and costs.
+	MinCostFlow,
+
+	/// An undirected graph (`p edge` or `p col`), as used for coloring and
+	/// clique problems.
+	Edge,
+
+	/// The primal graph of a formula in conjunctive normal form (`p cnf`),
+	/// with a vertex for each variable and an edge between any variables that
+	/// appear in the same clause.
+	Cnf,
+}
+
+/// A graph with a designated source, as its root, and sink, as its vertex.
+///
+/// The source is given by
+/// [`root`](../core/property/trait.Rooted.html#tymethod.root) and the sink by
+/// [`get_vertex`](../core/property/trait.HasVertex.html#tymethod.get_vertex).
+pub type FlowNetwork<G> = VertexInGraph<RootedGraph<G>>;
+
+/// Which vertex each node was added as, along with the problem read.
+type DimacsResult<G> =
+	Result<(NodeIds<G>, DimacsProblem<<G as Graph>::Vertex>), ParseError<<G as Graph>::Vertex>>;
+
+/// The new graph read, along with which vertex each node was added as and the
+/// problem read.
+type NewDimacsResult<G> =
+	Result<(G, NodeIds<G>, DimacsProblem<<G as Graph>::Vertex>), ParseError<<G as Graph>::Vertex>>;
+
+/// The flow network read, along with which vertex each node was added as.
+type FlowNetworkResult<G> = Result<(FlowNetwork<G>, NodeIds<G>), ParseError<<G as Graph>::Vertex>>;
+
+/// Reads a graph in one of the [DIMACS](http://dimacs.rutgers.edu/programs/challenge/)
+/// formats into the given graph.
+///
+/// A vertex is added for each node, or variable, and an edge for each arc, or
+/// edge. The weights are created using
+/// [`FromAttributes`](trait.FromAttributes.html) from attributes depending on
+/// the problem:
+///
+/// * `sp`: the length of each arc is its `weight` attribute.
+/// * `max`: the capacity of each arc is its `weight` attribute.
+/// * `min`: each node has its `supply` attribute, 0 unless given, and each arc
+///   has its lower bound as `low`, its `capacity` and its `cost`.
+/// * `edge` and `cnf`: nodes and edges have no attributes.
+///
+/// The weights of shortest path and maximum flow problems can therefore be
+/// read as numbers, which read the `weight` attribute.
+///
+/// Flow and shortest path problems must be read into directed graphs, while
+/// `edge` and `cnf` problems must be read into undirected graphs.
+///
+/// The input is read one line at a time, so that large files can be read
+/// without holding them in memory.
+/// Returns which vertex each node was added as, by its number, along with the
+/// problem read.
+///
+/// ### Usage
+/// ```
+/// # use graphene::{
+/// # 	common::AdjListGraph,
+/// # 	core::Graph,
+/// # 	io::{read_dimacs_into, DimacsProblem},
+/// # };
+/// let input = "c a small network
+/// p max 3 2
+/// n 1 s
+/// n 3 t
+/// a 1 2 5
+/// a 2 3 4
+/// ";
+/// let mut graph = AdjListGraph::<(), u32>::new();
+/// let (ids, problem) = read_dimacs_into(input.as_bytes(), &mut graph).unwrap();
+///
+/// assert_eq!(problem, DimacsProblem::MaxFlow { source: ids["1"], sink: ids["3"] });
+/// assert_eq!(graph.edges_between(ids["1"], ids["2"]).next(), Some(&5));
+/// ```
+pub fn read_dimacs_into<G>(input: impl BufRead, g: &mut G) -> DimacsResult<G>
+where
+	G: NewVertex + AddEdge,
+	G::VertexWeight: FromAttributes,
+	G::EdgeWeight: FromAttributes,
+{
+	read(input, g, false)
+}
+
+/// Reads a graph in one of the DIMACS formats into a new graph.
+///
+/// See [`read_dimacs_into`](fn.read_dimacs_into.html).
+pub fn read_dimacs<G>(input: impl BufRead) -> NewDimacsResult<G>
+where
+	G: Default + NewVertex + AddEdge,
+	G::VertexWeight: FromAttributes,
+	G::EdgeWeight: FromAttributes,
+{
+	let mut g = G::default();
+	let (ids, problem) = read_dimacs_into(input, &mut g)?;
+	Ok((g, ids, problem))
+}
+
+/// Reads a DIMACS maximum flow problem into a new flow network, with its
+/// source as the root and its sink as the vertex.
+///
+/// See [`read_dimacs_into`](fn.read_dimacs_into.html).
+///
+/// ### Usage
+/// ```
+/// # use graphene::{
+/// # 	common::AdjListGraph,
+/// # 	core::property::{HasVertex, Rooted},
+/// # 	io::read_dimacs_max_flow,
+/// # };
+/// let input = "p max 2 1\nn 2 t\nn 1 s\na 1 2 7\n";
+/// let (network, ids) =
+/// 	read_dimacs_max_flow::<AdjListGraph<(), u32>>(input.as_bytes()).unwrap();
+///
+/// assert_eq!(network.root(), ids["1"]);
+/// assert_eq!(network.get_vertex(), ids["2"]);
+/// ```
+pub fn read_dimacs_max_flow<G>(input: impl BufRead) -> FlowNetworkResult<G>
+where
+	G: Default + Ensure<Graph = G> + NewVertex + AddEdge,
+	G::VertexWeight: FromAttributes,
+	G::EdgeWeight: FromAttributes,
+{
+	let mut g = G::default();
+	match read(input, &mut g, true)?
+	{
+		(ids, DimacsProblem::MaxFlow { source, sink }) =>
+		{
+			let network =
+				VertexInGraph::ensure_unvalidated(RootedGraph::ensure_unvalidated(g, source), sink);
+			Ok((network, ids))
+		},
+		_ => unreachable!("Only maximum flow problems are read."),
+	}
+}
+
+/// The kinds of problem lines.
+const PROBLEMS: &[&str] = &["sp", "max", "min", "edge", "col", "cnf"];
+
+/// Reads the DIMACS input into the given graph, only allowing maximum flow
+/// problems if so requested.
+fn read<G>(mut input: impl BufRead, g: &mut G, max_flow_only: bool) -> DimacsResult<G>
+where
+	G: NewVertex + AddEdge,
+	G::VertexWeight: FromAttributes,
+	G::EdgeWeight: FromAttributes,
+{
+	let directed = G::Directedness::directed();
+	let mut ids = NodeIds::<G>::new();
+	let mut vertices = Vec::new();
+	// The kind of problem, the number of nodes and of arcs or clauses.
+	let mut problem: Option<(&str, usize, usize)> = None;
+	let mut nodes: HashMap<usize, Attributes> = HashMap::new();
+	let (mut source, mut sink) = (None, None);
+	let mut read_edges = 0;
+	let mut clause = Vec::new();
+	let mut cnf_edges = HashSet::new();
+
+	let mut buffer = String::new();
+	let mut line = 0;
+	loop
+	{
+		buffer.clear();
+		if input.read_line(&mut buffer)? == 0
+		{
+			break;
+		}
+		line += 1;
+		let fields = whitespace_fields(&buffer);
+		let (column, first) = match fields.first()
+		{
+			None => continue,
+			Some((_, "c")) => continue,
+			Some(field) => *field,
+		};
+		let error = |column, message: String| ParseError::syntax(line, column, message);
+		let number = |i: usize, what: &str| {
+			let (column, field) = fields.get(i).ok_or_else(|| {
+				error(
+					buffer.trim_end().chars().count() + 1,
+					format!("expected {}", what),
+				)
+			})?;
+			field
+				.parse::<usize>()
+				.map_err(|_| error(*column, format!("expected {}, found '{}'", what, field)))
+		};
+		let value = |i: usize, what: &str| {
+			fields
+				.get(i)
+				.map(|(_, field)| field.to_string())
+				.ok_or_else(|| {
+					error(
+						buffer.trim_end().chars().count() + 1,
+						format!("expected {}", what),
+					)
+				})
+		};
+
+		let (kind, node_count) = match problem
+		{
+			None if first == "p" =>
+			{
+				let (kind_column, kind) = fields
+					.get(1)
+					.copied()
+					.unwrap_or((buffer.trim_end().chars().count() + 1, ""));
+				let found = kind;
+				let kind = match PROBLEMS.iter().copied().find(|p| *p == found)
+				{
+					Some(kind) if !max_flow_only || kind == "max" => kind,
+					_ =>
+					{
+						let expected = if max_flow_only
+						{
+							"max".to_string()
+						}
+						else
+						{
+							PROBLEMS.join(" or ")
+						};
+						return Err(error(
+							kind_column,
+							format!("expected {}, found '{}'", expected, found),
+						));
+					},
+				};
+				let undirected_kind = kind == "edge" || kind == "col" || kind == "cnf";
+				if directed == undirected_kind
+				{
+					return Err(error(
+						kind_column,
+						format!(
+							"'{}' problems must be read into {} graphs",
+							kind,
+							if directed { "undirected" } else { "directed" }
+						),
+					));
+				}
+				problem = Some((
+					kind,
+					number(2, "the number of nodes")?,
+					number(3, "the number of arcs or clauses")?,
+				));
+				continue;
+			},
+			None => return Err(error(column, "expected the problem line".into())),
+			Some(_) if first == "p" => return Err(error(column, "duplicate problem line".into())),
+			Some((kind, node_count, _)) => (kind, node_count),
+		};
+		let node = |i: usize| {
+			let index = number(i, "a node")?;
+			if index == 0 || index > node_count
+			{
+				Err(error(
+					fields[i].0,
+					format!("node {} is out of bounds", index),
+				))
+			}
+			else
+			{
+				Ok(index)
+			}
+		};
+
+		// Some CNF files end with a line with '%' followed by garbage.
+		if kind == "cnf" && first == "%"
+		{
+			break;
+		}
+		if first == "n" && (kind == "max" || kind == "min")
+		{
+			if !vertices.is_empty()
+			{
+				return Err(error(column, "nodes must be described before arcs".into()));
+			}
+			let index = node(1)?;
+			if kind == "min"
+			{
+				let mut attributes = Attributes::new();
+				attributes.insert("supply".into(), value(2, "a supply")?);
+				nodes.insert(index, attributes);
+			}
+			else
+			{
+				let designation = match value(2, "'s' or 't'")?.as_str()
+				{
+					"s" => &mut source,
+					"t" => &mut sink,
+					other =>
+					{
+						return Err(error(
+							fields[2].0,
+							format!("expected 's' or 't', found '{}'", other),
+						))
+					},
+				};
+				if designation.replace(index).is_some()
+				{
+					return Err(error(column, "duplicate designation".into()));
+				}
+			}
+			continue;
+		}
+
+		create_vertices(
+			g,
+			&mut ids,
+			&mut vertices,
+			&mut nodes,
+			node_count,
+			kind,
+			line,
+		)?;
+		let mut attributes = Attributes::new();
+		let (from, to) = match (kind, first)
+		{
+			("sp", "a") | ("max", "a") | ("min", "a") =>
+			{
+				let names: &[_] = if kind == "min"
+				{
+					&["low", "capacity", "cost"]
+				}
+				else
+				{
+					&["weight"]
+				};
+				for (i, name) in names.iter().enumerate()
+				{
+					attributes.insert(name.to_string(), value(3 + i, name)?);
+				}
+				(node(1)?, node(2)?)
+			},
+			("edge", "e") | ("col", "e") => (node(1)?, node(2)?),
+			("cnf", _) =>
+			{
+				for (column, literal) in fields.iter().copied()
+				{
+					let variable = literal
+						.parse::<i64>()
+						.map_err(|_| {
+							error(column, format!("expected a literal, found '{}'", literal))
+						})?
+						.unsigned_abs() as usize;
+					if variable == 0
+					{
+						add_clause(g, &vertices, &mut clause, &mut cnf_edges, line)?;
+						read_edges += 1;
+					}
+					else if variable > node_count
+					{
+						return Err(error(
+							column,
+							format!("variable {} is out of bounds", variable),
+						));
+					}
+					else
+					{
+						clause.push(variable);
+					}
+				}
+				continue;
+			},
+			_ => return Err(error(column, format!("unexpected '{}' line", first))),
+		};
+		if fields.len() > 3 + attributes.len()
+		{
+			return Err(error(
+				fields[3 + attributes.len()].0,
+				"unexpected field".into(),
+			));
+		}
+		let weight = G::EdgeWeight::from_attributes(&attributes).map_err(|message| {
+			ParseError::Weight {
+				line,
+				column: 1,
+				message,
+			}
+		})?;
+		g.add_edge_weighted(vertices[from - 1], vertices[to - 1], weight)?;
+		read_edges += 1;
+	}
+
+	let (kind, node_count, count) =
+		problem.ok_or_else(|| ParseError::syntax(line + 1, 1, "expected the problem line"))?;
+	create_vertices(
+		g,
+		&mut ids,
+		&mut vertices,
+		&mut nodes,
+		node_count,
+		kind,
+		line + 1,
+	)?;
+	if !clause.is_empty()
+	{
+		add_clause(g, &vertices, &mut clause, &mut cnf_edges, line)?;
+		read_edges += 1;
+	}
+	if read_edges != count
+	{
+		let what = if kind == "cnf" { "clauses" } else { "edges" };
+		return Err(ParseError::syntax(
+			line + 1,
+			1,
+			format!("expected {} {}, found {}", count, what, read_edges),
+		));
+	}
+	let problem = match kind
+	{
+		"sp" => DimacsProblem::ShortestPath,
+		"max" =>
+		{
+			let designated = |index: Option<usize>, what| {
+				index
+					.map(|index| vertices[index - 1])
+					.ok_or_else(|| ParseError::syntax(line + 1, 1, format!("missing {}", what)))
+			};
+			DimacsProblem::MaxFlow {
+				source: designated(source, "source")?,
+				sink: designated(sink, "sink")?,
+			}
+		},
+		"min" => DimacsProblem::MinCostFlow,
+		"cnf" => DimacsProblem::Cnf,
+		_ => DimacsProblem::Edge,
+	};
+	Ok((ids, problem))
+}
+
+/// Adds the vertices of all nodes, if they haven't been added yet, with the
+/// attributes given by the node lines.
+///
+/// The given line is used to report weight errors.
+fn create_vertices<G>(
+	g: &mut G,
+	ids: &mut NodeIds<G>,
+	vertices: &mut Vec<G::Vertex>,
+	nodes: &mut HashMap<usize, Attributes>,
+	node_count: usize,
+	kind: &str,
+	line: usize,
+) -> Result<(), ParseError<G::Vertex>>
+where
+	G: NewVertex,
+	G::VertexWeight: FromAttributes,
+{
+	if !vertices.is_empty() || node_count == 0
+	{
+		return Ok(());
+	}
+	for index in 1..=node_count
+	{
+		let mut attributes = nodes.remove(&index).unwrap_or_default();
+		if kind == "min"
+		{
+			attributes
+				.entry("supply".into())
+				.or_insert_with(|| "0".into());
+		}
+		let weight = G::VertexWeight::from_attributes(&attributes).map_err(|message| {
+			ParseError::Weight {
+				line,
+				column: 1,
+				message,
+			}
+		})?;
+		let v = g.new_vertex_weighted(weight)?;
+		ids.insert(index.to_string(), v);
+		vertices.push(v);
+	}
+	Ok(())
+}
+
+/// Adds an edge between each pair of variables in the given clause that
+/// don't already have one, and clears the clause.
+///
+/// The given line is used to report weight errors.
+fn add_clause<G>(
+	g: &mut G,
+	vertices: &[G::Vertex],
+	clause: &mut Vec<usize>,
+	edges: &mut HashSet<(usize, usize)>,
+	line: usize,
+) -> Result<(), ParseError<G::Vertex>>
+where
+	G: AddEdge,
+	G::EdgeWeight: FromAttributes,
+{
+	for (i, v1) in clause.iter().enumerate()
+	{
+		for v2 in clause[i + 1..].iter()
+		{
+			let pair = (*v1.min(v2), *v1.max(v2));
+			if pair.0 != pair.1 && edges.insert(pair)
+			{
+				let weight =
+					G::EdgeWeight::from_attributes(&Attributes::new()).map_err(|message| {
+						ParseError::Weight {
+							line,
+							column: 1,
+							message,
+						}
+					})?;
+				g.add_edge_weighted(vertices[pair.0 - 1], vertices[pair.1 - 1], weight)?;
+			}
+		}
+	}
+	clause.clear();
+	Ok(())
+}
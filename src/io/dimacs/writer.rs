@@ -0,0 +1,190 @@
+use crate::{
+	core::{Directed, Directedness, Graph},
+	io::ToAttributes,
+};
+use std::{
+	borrow::Borrow,
+	collections::HashMap,
+	fmt::{Display, Formatter, Result},
+	hash::Hash,
+};
+
+/// The problem a graph is written as.
+enum Problem<V>
+{
+	/// A shortest path problem for directed graphs, or an edge problem for
+	/// undirected ones.
+	Graph,
+	MaxFlow(V, V),
+	MinCostFlow,
+}
+
+/// Writes a graph in one of the [DIMACS](http://dimacs.rutgers.edu/programs/challenge/)
+/// formats.
+///
+/// Each vertex is given the node number of its position in
+/// [`all_vertices`](../core/trait.Graph.html#method.all_vertices), counting
+/// from 1. By default, directed graphs are written as shortest path problems
+/// (`p sp`), with the `weight` attribute of each edge as its length, and
+/// undirected graphs are written as edge problems (`p edge`).
+/// Directed graphs can also be written as flow problems, using the same
+/// attributes that [`read_dimacs`](fn.read_dimacs.html) reads.
+/// The lengths and capacities of edges without a `weight` attribute are 1,
+/// while missing supplies, lower bounds and costs are 0.
+///
+/// The graph is written using the [`Display`](https://doc.rust-lang.org/std/fmt/trait.Display.html)
+/// implementation, e.g. with `write!` to a file, in which case it isn't held
+/// in memory.
+///
+/// ### Usage
+/// ```
+/// # use graphene::{
+/// # 	common::AdjListGraph,
+/// # 	core::property::{AddEdge, NewVertex},
+/// # 	io::DimacsWriter,
+/// # };
+/// let mut graph = AdjListGraph::<(), u32>::new();
+/// let v0 = graph.new_vertex().unwrap();
+/// let v1 = graph.new_vertex().unwrap();
+/// graph.add_edge_weighted(&v0, &v1, 5).unwrap();
+///
+/// assert_eq!(
+/// 	DimacsWriter::new(&graph).to_string(),
+/// 	"p sp 2 1\na 1 2 5\n"
+/// );
+/// assert_eq!(
+/// 	DimacsWriter::new(&graph).max_flow(v0, v1).to_string(),
+/// 	"p max 2 1\nn 1 s\nn 2 t\na 1 2 5\n"
+/// );
+/// ```
+pub struct DimacsWriter<'a, G: Graph>
+{
+	/// The graph to write
+	graph: &'a G,
+	problem: Problem<G::Vertex>,
+}
+
+impl<'a, G: Graph> DimacsWriter<'a, G>
+{
+	/// Creates a writer of the given graph.
+	pub fn new(graph: &'a G) -> Self
+	{
+		Self {
+			graph,
+			problem: Problem::Graph,
+		}
+	}
+}
+
+impl<'a, G: Graph<Directedness = Directed>> DimacsWriter<'a, G>
+{
+	/// Writes the graph as a maximum flow problem (`p max`) from the given
+	/// source to the given sink, with the `weight` attribute of each edge as
+	/// its capacity.
+	pub fn max_flow(mut self, source: impl Borrow<G::Vertex>, sink: impl Borrow<G::Vertex>)
+		-> Self
+	{
+		self.problem = Problem::MaxFlow(*source.borrow(), *sink.borrow());
+		self
+	}
+
+	/// Writes the graph as a minimum-cost flow problem (`p min`), with the
+	/// `supply` attribute of each vertex, and the `low`, `capacity` and `cost`
+	/// attributes of each edge.
+	pub fn min_cost_flow(mut self) -> Self
+	{
+		self.problem = Problem::MinCostFlow;
+		self
+	}
+}
+
+impl<'a, G> Display for DimacsWriter<'a, G>
+where
+	G: Graph,
+	G::Vertex: Hash,
+	G::VertexWeight: ToAttributes,
+	G::EdgeWeight: ToAttributes,
+{
+	fn fmt(&self, f: &mut Formatter) -> Result
+	{
+		let directed = G::Directedness::directed();
+		let kind = match self.problem
+		{
+			Problem::Graph if directed => "sp",
+			Problem::Graph => "edge",
+			Problem::MaxFlow(..) => "max",
+			Problem::MinCostFlow => "min",
+		};
+		let indices: HashMap<_, _> = self
+			.graph
+			.all_vertices()
+			.enumerate()
+			.map(|(i, v)| (v, i + 1))
+			.collect();
+		writeln!(
+			f,
+			"p {} {} {}",
+			kind,
+			indices.len(),
+			self.graph.all_edges().count()
+		)?;
+
+		match self.problem
+		{
+			Problem::MaxFlow(source, sink) =>
+			{
+				writeln!(f, "n {} s", indices[&source])?;
+				writeln!(f, "n {} t", indices[&sink])?;
+			},
+			Problem::MinCostFlow =>
+			{
+				for (v, w) in self.graph.all_vertices_weighted()
+				{
+					match w.to_attributes().get("supply")
+					{
+						Some(supply) if supply != "0" =>
+						{
+							writeln!(f, "n {} {}", indices[&v], supply)?
+						},
+						_ => (),
+					}
+				}
+			},
+			Problem::Graph => (),
+		}
+
+		for (source, sink, w) in self.graph.all_edges()
+		{
+			let attributes = w.to_attributes();
+			let attribute = |name, default| {
+				attributes
+					.get(name)
+					.map(String::as_str)
+					.unwrap_or(default)
+					.to_string()
+			};
+			let (source, sink) = (indices[&source], indices[&sink]);
+			if !directed
+			{
+				writeln!(f, "e {} {}", source, sink)?;
+			}
+			else if let Problem::MinCostFlow = self.problem
+			{
+				writeln!(
+					f,
+					"a {} {} {} {} {}",
+					source,
+					sink,
+					attribute("low", "0"),
+					attribute("capacity", "0"),
+					attribute("cost", "0")
+				)?;
+			}
+			else
+			{
+				writeln!(f, "a {} {} {}", source, sink, attribute("weight", "1"))?;
+			}
+		}
+		Ok(())
+	}
+}
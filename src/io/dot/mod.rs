@@ -0,0 +1,6 @@
+//! The [DOT](https://graphviz.org/doc/info/lang.html) language of Graphviz.
+
+mod reader;
+mod writer;
+
+pub use self::{reader::*, writer::*};
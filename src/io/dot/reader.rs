@@ -0,0 +1,890 @@
+use crate::{
+	core::{
+		property::{AddEdge, NewVertex},
+		Directedness,
+	},
+	io::{Attributes, FromAttributes, NodeIds, ParseError, ReadResult},
+};
+use std::{collections::HashMap, iter::Peekable, str::Chars};
+
+/// Reads a graph in the [DOT](https://graphviz.org/doc/info/lang.html) language
+/// into the given graph.
+///
+/// Each node ID is added as a vertex, in the order the IDs first appear, and
+/// the weight of each vertex and edge is created from its attributes
+/// using [`FromAttributes`](trait.FromAttributes.html).
+/// Default attributes given by `node [...]` and `edge [...]` statements apply
+/// to the nodes and edges after them in the same graph or subgraph, while graph
+/// attributes and ports are ignored.
+/// An edge statement between subgraphs gives an edge between each pair of
+/// their nodes, and in a `strict` graph, repeated edges only update the
+/// attributes of the first.
+///
+/// In quoted IDs, `\"`, `\\` and `\n` are read as a quote, a backslash and a
+/// line break, respectively.
+///
+/// The graph must be a `digraph` if the given graph is directed and a `graph`
+/// otherwise.
+/// Returns which vertex each node ID was added as.
+///
+/// ### Usage
+/// ```
+/// # use graphene::{
+/// # 	common::AdjListGraph,
+/// # 	core::Graph,
+/// # 	io::{read_dot, Attributes},
+/// # };
+/// let (graph, ids) = read_dot::<AdjListGraph<Attributes, ()>>(
+/// 	"digraph {
+/// 		node [shape=box];
+/// 		a [label=\"Start\"];
+/// 		a -> b -> c;
+/// 		a -> { c d };
+/// 	}",
+/// )
+/// .unwrap();
+///
+/// assert_eq!(graph.all_vertices().count(), 4);
+/// assert_eq!(graph.all_edges().count(), 4);
+/// assert_eq!(graph.vertex_weight(ids["a"]).unwrap()["label"], "Start");
+/// assert_eq!(graph.vertex_weight(ids["d"]).unwrap()["shape"], "box");
+/// ```
+pub fn read_dot_into<G>(input: &str, g: &mut G) -> Result<NodeIds<G>, ParseError<G::Vertex>>
+where
+	G: NewVertex + AddEdge,
+	G::VertexWeight: FromAttributes,
+	G::EdgeWeight: FromAttributes,
+{
+	let mut parser = Parser::new(input)?;
+	parser.graph(G::Directedness::directed())?;
+
+	let mut ids = HashMap::new();
+	let mut vertices = Vec::new();
+	for node in parser.nodes
+	{
+		let weight = G::VertexWeight::from_attributes(&node.attributes)
+			.map_err(|message| node.position.weight_error(message))?;
+		let v = g.new_vertex_weighted(weight)?;
+		vertices.push(v);
+		ids.insert(node.id, v);
+	}
+	for edge in parser.edges
+	{
+		let weight = G::EdgeWeight::from_attributes(&edge.attributes)
+			.map_err(|message| edge.position.weight_error(message))?;
+		g.add_edge_weighted(vertices[edge.source], vertices[edge.sink], weight)?;
+	}
+	Ok(ids)
+}
+
+/// Reads a graph in the [DOT](https://graphviz.org/doc/info/lang.html) language
+/// into a new graph.
+///
+/// See [`read_dot_into`](fn.read_dot_into.html).
+pub fn read_dot<G>(input: &str) -> ReadResult<G>
+where
+	G: Default + NewVertex + AddEdge,
+	G::VertexWeight: FromAttributes,
+	G::EdgeWeight: FromAttributes,
+{
+	let mut g = G::default();
+	let ids = read_dot_into(input, &mut g)?;
+	Ok((g, ids))
+}
+
+/// A line and column in the input.
+#[derive(Copy, Clone, Debug)]
+struct Position
+{
+	line: usize,
+	column: usize,
+}
+
+impl Position
+{
+	fn syntax_error<V>(self, message: impl Into<String>) -> ParseError<V>
+	{
+		ParseError::syntax(self.line, self.column, message)
+	}
+
+	fn weight_error<V>(self, message: String) -> ParseError<V>
+	{
+		ParseError::Weight {
+			line: self.line,
+			column: self.column,
+			message,
+		}
+	}
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum TokenKind
+{
+	/// An ID, which may be a keyword if it wasn't quoted.
+	Id
+	{
+		value: String,
+		quoted: bool,
+	},
+	OpenBrace,
+	CloseBrace,
+	OpenBracket,
+	CloseBracket,
+	Equals,
+	Semicolon,
+	Comma,
+	Colon,
+	Plus,
+	DirectedEdge,
+	UndirectedEdge,
+	End,
+}
+
+impl TokenKind
+{
+	/// Returns whether this is the given keyword, which is case-insensitive.
+	fn is_keyword(&self, keyword: &str) -> bool
+	{
+		match self
+		{
+			TokenKind::Id {
+				value,
+				quoted: false,
+			} => value.eq_ignore_ascii_case(keyword),
+			_ => false,
+		}
+	}
+
+	/// Describes the token in error messages.
+	fn describe(&self) -> String
+	{
+		match self
+		{
+			TokenKind::Id { value, .. } => format!("'{}'", value),
+			TokenKind::OpenBrace => "'{'".into(),
+			TokenKind::CloseBrace => "'}'".into(),
+			TokenKind::OpenBracket => "'['".into(),
+			TokenKind::CloseBracket => "']'".into(),
+			TokenKind::Equals => "'='".into(),
+			TokenKind::Semicolon => "';'".into(),
+			TokenKind::Comma => "','".into(),
+			TokenKind::Colon => "':'".into(),
+			TokenKind::Plus => "'+'".into(),
+			TokenKind::DirectedEdge => "'->'".into(),
+			TokenKind::UndirectedEdge => "'--'".into(),
+			TokenKind::End => "end of input".into(),
+		}
+	}
+}
+
+struct Token
+{
+	kind: TokenKind,
+	position: Position,
+}
+
+/// Splits the input into tokens, skipping whitespace and comments.
+struct Lexer<'a>
+{
+	chars: Peekable<Chars<'a>>,
+	position: Position,
+	/// Whether the next character is the first on its line
+	line_start: bool,
+}
+
+impl<'a> Lexer<'a>
+{
+	fn new(input: &'a str) -> Self
+	{
+		Self {
+			chars: input.chars().peekable(),
+			position: Position { line: 1, column: 1 },
+			line_start: true,
+		}
+	}
+
+	fn next_char(&mut self) -> Option<char>
+	{
+		let c = self.chars.next()?;
+		if c == '\n'
+		{
+			self.position.line += 1;
+			self.position.column = 1;
+			self.line_start = true;
+		}
+		else
+		{
+			self.position.column += 1;
+			if !c.is_whitespace()
+			{
+				self.line_start = false;
+			}
+		}
+		Some(c)
+	}
+
+	/// Skips whitespace and comments, including lines starting with '#'.
+	fn skip_ignored<V>(&mut self) -> Result<(), ParseError<V>>
+	{
+		loop
+		{
+			match self.chars.peek()
+			{
+				Some(c) if c.is_whitespace() =>
+				{
+					self.next_char();
+				},
+				Some('#') if self.line_start =>
+				{
+					while self.chars.peek().is_some_and(|c| *c != '\n')
+					{
+						self.next_char();
+					}
+				},
+				Some('/') =>
+				{
+					let start = self.position;
+					let mut lookahead = self.chars.clone();
+					lookahead.next();
+					match lookahead.peek()
+					{
+						Some('/') =>
+						{
+							while self.chars.peek().is_some_and(|c| *c != '\n')
+							{
+								self.next_char();
+							}
+						},
+						Some('*') =>
+						{
+							self.next_char();
+							self.next_char();
+							let mut previous = ' ';
+							loop
+							{
+								match self.next_char()
+								{
+									Some('/') if previous == '*' => break,
+									Some(c) => previous = c,
+									None => return Err(start.syntax_error("unterminated comment")),
+								}
+							}
+						},
+						_ => return Ok(()),
+					}
+				},
+				_ => return Ok(()),
+			}
+		}
+	}
+
+	fn token<V>(&mut self) -> Result<Token, ParseError<V>>
+	{
+		self.skip_ignored()?;
+		let position = self.position;
+		let kind = match self.chars.peek().copied()
+		{
+			None => TokenKind::End,
+			Some('"') => self.quoted(position)?,
+			Some('<') => self.html(position)?,
+			Some(c) if c.is_alphabetic() || c == '_' =>
+			{
+				let mut value = String::new();
+				while let Some(c) = self
+					.chars
+					.peek()
+					.copied()
+					.filter(|c| c.is_alphanumeric() || *c == '_')
+				{
+					value.push(c);
+					self.next_char();
+				}
+				TokenKind::Id {
+					value,
+					quoted: false,
+				}
+			},
+			Some(c) if c.is_ascii_digit() || c == '.' => self.numeral(String::new(), position)?,
+			Some('-') =>
+			{
+				self.next_char();
+				match self.chars.peek().copied()
+				{
+					Some('>') =>
+					{
+						self.next_char();
+						TokenKind::DirectedEdge
+					},
+					Some('-') =>
+					{
+						self.next_char();
+						TokenKind::UndirectedEdge
+					},
+					Some(c) if c.is_ascii_digit() || c == '.' =>
+					{
+						self.numeral("-".into(), position)?
+					},
+					_ => return Err(position.syntax_error("unexpected character '-'")),
+				}
+			},
+			Some(c) =>
+			{
+				self.next_char();
+				match c
+				{
+					'{' => TokenKind::OpenBrace,
+					'}' => TokenKind::CloseBrace,
+					'[' => TokenKind::OpenBracket,
+					']' => TokenKind::CloseBracket,
+					'=' => TokenKind::Equals,
+					';' => TokenKind::Semicolon,
+					',' => TokenKind::Comma,
+					':' => TokenKind::Colon,
+					'+' => TokenKind::Plus,
+					c => return Err(position.syntax_error(format!("unexpected character '{}'", c))),
+				}
+			},
+		};
+		Ok(Token { kind, position })
+	}
+
+	/// Reads a numeral, given any sign that has already been read.
+	fn numeral<V>(&mut self, mut value: String, start: Position)
+		-> Result<TokenKind, ParseError<V>>
+	{
+		let mut dot = false;
+		let mut digits = false;
+		while let Some(c) = self.chars.peek().copied()
+		{
+			if c.is_ascii_digit()
+			{
+				digits = true;
+			}
+			else if c == '.' && !dot
+			{
+				dot = true;
+			}
+			else
+			{
+				break;
+			}
+			value.push(c);
+			self.next_char();
+		}
+		if !digits
+		{
+			return Err(start.syntax_error(format!("invalid numeral '{}'", value)));
+		}
+		Ok(TokenKind::Id {
+			value,
+			quoted: false,
+		})
+	}
+
+	fn quoted<V>(&mut self, start: Position) -> Result<TokenKind, ParseError<V>>
+	{
+		self.next_char();
+		let mut value = String::new();
+		loop
+		{
+			match self.next_char()
+			{
+				Some('"') => break,
+				Some('\\') =>
+				{
+					match self.next_char()
+					{
+						Some('"') => value.push('"'),
+						Some('\\') => value.push('\\'),
+						Some('n') => value.push('\n'),
+						// An escaped line break continues the line
+						Some('\n') => (),
+						Some(c) =>
+						{
+							value.push('\\');
+							value.push(c);
+						},
+						None => return Err(start.syntax_error("unterminated string")),
+					}
+				},
+				Some(c) => value.push(c),
+				None => return Err(start.syntax_error("unterminated string")),
+			}
+		}
+		Ok(TokenKind::Id {
+			value,
+			quoted: true,
+		})
+	}
+
+	/// Reads an HTML string, which is delimited by matching angle brackets.
+	fn html<V>(&mut self, start: Position) -> Result<TokenKind, ParseError<V>>
+	{
+		self.next_char();
+		let mut value = String::new();
+		let mut depth = 1;
+		loop
+		{
+			let c = self
+				.next_char()
+				.ok_or_else(|| start.syntax_error("unterminated HTML string"))?;
+			if c == '<'
+			{
+				depth += 1;
+			}
+			else if c == '>'
+			{
+				depth -= 1;
+				if depth == 0
+				{
+					break;
+				}
+			}
+			value.push(c);
+		}
+		Ok(TokenKind::Id {
+			value,
+			quoted: true,
+		})
+	}
+}
+
+/// A node read from the input.
+struct Node
+{
+	id: String,
+	attributes: Attributes,
+	/// Where the node first appeared
+	position: Position,
+}
+
+/// An edge read from the input, between the nodes at the given indices.
+struct Edge
+{
+	source: usize,
+	sink: usize,
+	attributes: Attributes,
+	/// Where the edge operator of the edge appeared
+	position: Position,
+}
+
+/// Reads the nodes and edges of a graph.
+struct Parser
+{
+	tokens: Vec<Token>,
+	next: usize,
+	strict: bool,
+	nodes: Vec<Node>,
+	node_indices: HashMap<String, usize>,
+	edges: Vec<Edge>,
+	/// The index of the edge between each pair of nodes, in strict graphs.
+	edge_indices: HashMap<(usize, usize), usize>,
+}
+
+impl Parser
+{
+	fn new<V>(input: &str) -> Result<Self, ParseError<V>>
+	{
+		let mut lexer = Lexer::new(input);
+		let mut tokens = Vec::new();
+		loop
+		{
+			let token = lexer.token()?;
+			let end = token.kind == TokenKind::End;
+			tokens.push(token);
+			if end
+			{
+				break;
+			}
+		}
+		Ok(Self {
+			tokens,
+			next: 0,
+			strict: false,
+			nodes: Vec::new(),
+			node_indices: HashMap::new(),
+			edges: Vec::new(),
+			edge_indices: HashMap::new(),
+		})
+	}
+
+	fn peek(&self) -> &Token
+	{
+		&self.tokens[self.next]
+	}
+
+	fn peek_second(&self) -> &TokenKind
+	{
+		&self.tokens[(self.next + 1).min(self.tokens.len() - 1)].kind
+	}
+
+	fn advance(&mut self) -> &Token
+	{
+		let token = &self.tokens[self.next];
+		if token.kind != TokenKind::End
+		{
+			self.next += 1;
+		}
+		token
+	}
+
+	fn unexpected<V, T>(&self, expected: &str) -> Result<T, ParseError<V>>
+	{
+		let token = self.peek();
+		Err(token.position.syntax_error(format!(
+			"expected {}, found {}",
+			expected,
+			token.kind.describe()
+		)))
+	}
+
+	fn expect<V>(&mut self, kind: TokenKind) -> Result<(), ParseError<V>>
+	{
+		if self.peek().kind == kind
+		{
+			self.advance();
+			Ok(())
+		}
+		else
+		{
+			self.unexpected(&kind.describe())
+		}
+	}
+
+	/// Skips the given token if it's next, returning whether it was.
+	fn skip(&mut self, kind: TokenKind) -> bool
+	{
+		let found = self.peek().kind == kind;
+		if found
+		{
+			self.advance();
+		}
+		found
+	}
+
+	/// Reads an ID, concatenating quoted strings joined by '+'.
+	fn id<V>(&mut self) -> Result<String, ParseError<V>>
+	{
+		match self.peek().kind.clone()
+		{
+			TokenKind::Id { mut value, quoted } =>
+			{
+				self.advance();
+				if quoted
+				{
+					while self.peek().kind == TokenKind::Plus
+					{
+						self.advance();
+						match self.peek().kind.clone()
+						{
+							TokenKind::Id {
+								value: next,
+								quoted: true,
+							} =>
+							{
+								self.advance();
+								value.push_str(&next);
+							},
+							_ => return self.unexpected("a quoted string"),
+						}
+					}
+				}
+				Ok(value)
+			},
+			_ => self.unexpected("an ID"),
+		}
+	}
+
+	/// Reads the whole graph.
+	fn graph<V>(&mut self, directed: bool) -> Result<(), ParseError<V>>
+	{
+		if self.peek().kind.is_keyword("strict")
+		{
+			self.advance();
+			self.strict = true;
+		}
+		let header = self.peek();
+		let (kind, position) = (header.kind.clone(), header.position);
+		if kind.is_keyword("digraph") || kind.is_keyword("graph")
+		{
+			if kind.is_keyword("digraph") != directed
+			{
+				return Err(position.syntax_error(
+					if directed
+					{
+						"expected a directed graph ('digraph')"
+					}
+					else
+					{
+						"expected an undirected graph ('graph')"
+					},
+				));
+			}
+			self.advance();
+		}
+		else
+		{
+			return self.unexpected("'graph' or 'digraph'");
+		}
+		if let TokenKind::Id { .. } = self.peek().kind
+		{
+			self.id()?;
+		}
+		self.expect(TokenKind::OpenBrace)?;
+		self.statements(directed, &Attributes::new(), &Attributes::new())?;
+		self.expect(TokenKind::CloseBrace)?;
+		if self.peek().kind != TokenKind::End
+		{
+			return self.unexpected("end of input");
+		}
+		Ok(())
+	}
+
+	/// Reads statements until a closing brace, with the given default
+	/// attributes, returning the indices of the nodes mentioned.
+	fn statements<V>(
+		&mut self,
+		directed: bool,
+		node_defaults: &Attributes,
+		edge_defaults: &Attributes,
+	) -> Result<Vec<usize>, ParseError<V>>
+	{
+		let mut node_defaults = node_defaults.clone();
+		let mut edge_defaults = edge_defaults.clone();
+		let mut mentioned = Vec::new();
+
+		while self.peek().kind != TokenKind::CloseBrace
+		{
+			let kind = self.peek().kind.clone();
+			if (kind.is_keyword("node") || kind.is_keyword("edge") || kind.is_keyword("graph"))
+				&& *self.peek_second() == TokenKind::OpenBracket
+			{
+				self.advance();
+				let attributes = self.attribute_lists()?;
+				if kind.is_keyword("node")
+				{
+					node_defaults.extend(attributes);
+				}
+				else if kind.is_keyword("edge")
+				{
+					edge_defaults.extend(attributes);
+				}
+			}
+			else if let (TokenKind::Id { .. }, TokenKind::Equals) = (&kind, self.peek_second())
+			{
+				// A graph attribute
+				self.id()?;
+				self.advance();
+				self.id()?;
+			}
+			else
+			{
+				let endpoint =
+					self.endpoint(directed, &node_defaults, &edge_defaults, &mut mentioned)?;
+				let node = endpoint
+					.first()
+					.copied()
+					.filter(|_| !(kind.is_keyword("subgraph") || kind == TokenKind::OpenBrace));
+				let is_edge = self.edges_from(
+					directed,
+					endpoint,
+					&node_defaults,
+					&edge_defaults,
+					&mut mentioned,
+				)?;
+				if let (Some(node), false) = (node, is_edge)
+				{
+					if self.peek().kind == TokenKind::OpenBracket
+					{
+						let attributes = self.attribute_lists()?;
+						self.nodes[node].attributes.extend(attributes);
+					}
+				}
+			}
+			self.skip(TokenKind::Semicolon);
+		}
+		Ok(mentioned)
+	}
+
+	/// Reads a node ID or a subgraph, returning the indices of its nodes.
+	fn endpoint<V>(
+		&mut self,
+		directed: bool,
+		node_defaults: &Attributes,
+		edge_defaults: &Attributes,
+		mentioned: &mut Vec<usize>,
+	) -> Result<Vec<usize>, ParseError<V>>
+	{
+		let kind = self.peek().kind.clone();
+		let nodes = if kind.is_keyword("subgraph") || kind == TokenKind::OpenBrace
+		{
+			if kind.is_keyword("subgraph")
+			{
+				self.advance();
+				// Subgraph names are ignored
+				if let TokenKind::Id { .. } = self.peek().kind
+				{
+					self.id()?;
+				}
+			}
+			self.expect(TokenKind::OpenBrace)?;
+			let nodes = self.statements(directed, node_defaults, edge_defaults)?;
+			self.expect(TokenKind::CloseBrace)?;
+			nodes
+		}
+		else if kind.is_keyword("node") || kind.is_keyword("edge") || kind.is_keyword("graph")
+		{
+			return self.unexpected("a node ID or a subgraph");
+		}
+		else
+		{
+			let position = self.peek().position;
+			let id = self.id()?;
+			// Ports are ignored
+			if self.skip(TokenKind::Colon)
+			{
+				self.id()?;
+				if self.skip(TokenKind::Colon)
+				{
+					self.id()?;
+				}
+			}
+			vec![self.node(id, node_defaults, position)]
+		};
+		mentioned.extend(nodes.iter().copied());
+		Ok(nodes)
+	}
+
+	/// Reads any edge operators and endpoints following the given endpoint,
+	/// and the attributes of the edges, returning whether there were any.
+	fn edges_from<V>(
+		&mut self,
+		directed: bool,
+		first: Vec<usize>,
+		node_defaults: &Attributes,
+		edge_defaults: &Attributes,
+		mentioned: &mut Vec<usize>,
+	) -> Result<bool, ParseError<V>>
+	{
+		let mut endpoints = vec![first];
+		let mut positions = Vec::new();
+		loop
+		{
+			let token = self.peek();
+			let position = token.position;
+			match token.kind
+			{
+				TokenKind::DirectedEdge if !directed =>
+				{
+					return Err(position.syntax_error("'->' used in an undirected graph"));
+				},
+				TokenKind::UndirectedEdge if directed =>
+				{
+					return Err(position.syntax_error("'--' used in a directed graph"));
+				},
+				TokenKind::DirectedEdge | TokenKind::UndirectedEdge => (),
+				_ => break,
+			}
+			self.advance();
+			positions.push(position);
+			endpoints.push(self.endpoint(directed, node_defaults, edge_defaults, mentioned)?);
+		}
+		if positions.is_empty()
+		{
+			return Ok(false);
+		}
+
+		let mut attributes = edge_defaults.clone();
+		if self.peek().kind == TokenKind::OpenBracket
+		{
+			attributes.extend(self.attribute_lists()?);
+		}
+		for (pair, position) in endpoints.windows(2).zip(positions)
+		{
+			for source in pair[0].iter()
+			{
+				for sink in pair[1].iter()
+				{
+					self.edge(directed, *source, *sink, &attributes, position);
+				}
+			}
+		}
+		Ok(true)
+	}
+
+	/// Reads one or more bracketed lists of attributes.
+	fn attribute_lists<V>(&mut self) -> Result<Attributes, ParseError<V>>
+	{
+		let mut attributes = Attributes::new();
+		while self.skip(TokenKind::OpenBracket)
+		{
+			while !self.skip(TokenKind::CloseBracket)
+			{
+				let name = self.id()?;
+				self.expect(TokenKind::Equals)?;
+				let value = self.id()?;
+				attributes.insert(name, value);
+				if !self.skip(TokenKind::Comma)
+				{
+					self.skip(TokenKind::Semicolon);
+				}
+			}
+		}
+		Ok(attributes)
+	}
+
+	/// Returns the index of the node with the given ID, creating it with the
+	/// given default attributes if it doesn't exist.
+	fn node(&mut self, id: String, defaults: &Attributes, position: Position) -> usize
+	{
+		if let Some(index) = self.node_indices.get(&id)
+		{
+			return *index;
+		}
+		let index = self.nodes.len();
+		self.node_indices.insert(id.clone(), index);
+		self.nodes.push(Node {
+			id,
+			attributes: defaults.clone(),
+			position,
+		});
+		index
+	}
+
+	/// Adds an edge between the nodes at the given indices, unless the graph
+	/// is strict and already has such an edge, in which case its attributes are
+	/// updated.
+	fn edge(
+		&mut self,
+		directed: bool,
+		source: usize,
+		sink: usize,
+		attributes: &Attributes,
+		position: Position,
+	)
+	{
+		if self.strict
+		{
+			let key = if directed || source <= sink
+			{
+				(source, sink)
+			}
+			else
+			{
+				(sink, source)
+			};
+			if let Some(index) = self.edge_indices.get(&key)
+			{
+				self.edges[*index].attributes.extend(attributes.clone());
+				return;
+			}
+			self.edge_indices.insert(key, self.edges.len());
+		}
+		self.edges.push(Edge {
+			source,
+			sink,
+			attributes: attributes.clone(),
+			position,
+		});
+	}
+}
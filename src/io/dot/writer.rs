@@ -0,0 +1,306 @@
+use crate::core::{Directedness, Graph};
+use std::{
+	collections::HashMap,
+	fmt::{Display, Formatter, Result},
+	hash::Hash,
+};
+
+/// Gives the label of a vertex.
+type VertexLabel<'a, G> =
+	Box<dyn 'a + Fn(<G as Graph>::Vertex, &<G as Graph>::VertexWeight) -> String>;
+
+/// Gives the label of an edge.
+type EdgeLabel<'a, G> = Box<
+	dyn 'a + Fn(<G as Graph>::Vertex, <G as Graph>::Vertex, &<G as Graph>::EdgeWeight) -> String,
+>;
+
+/// Gives the attributes of a vertex.
+type VertexAttributes<'a, G> =
+	Box<dyn 'a + Fn(<G as Graph>::Vertex, &<G as Graph>::VertexWeight) -> Vec<(String, String)>>;
+
+/// Gives the attributes of an edge.
+type EdgeAttributes<'a, G> = Box<
+	dyn 'a
+		+ Fn(
+			<G as Graph>::Vertex,
+			<G as Graph>::Vertex,
+			&<G as Graph>::EdgeWeight,
+		) -> Vec<(String, String)>,
+>;
+
+/// Writes a graph in the [DOT](https://graphviz.org/doc/info/lang.html)
+/// language of Graphviz.
+///
+/// Directed graphs are written as a `digraph` and undirected graphs as a
+/// `graph`.
+/// Since vertices can't generally be written, each vertex is given the ID of
+/// its position in
+/// [`all_vertices`](../core/trait.Graph.html#method.all_vertices). Labels and
+/// any other [attributes](https://graphviz.org/doc/info/attrs.html), like `color`,
+/// `shape` or `style`, are given by closures.
+///
+/// Groups of vertices, like the strongly connected components found by
+/// [`TarjanScc`](../algo/struct.TarjanScc.html), can be drawn as clusters.
+///
+/// The DOT is written using the [`Display`](https://doc.rust-lang.org/std/fmt/trait.Display.html)
+/// implementation, e.g. with `to_string()` or `write!`.
+///
+/// ### Usage
+/// ```
+/// # use graphene::{
+/// # 	algo::TarjanScc,
+/// # 	common::AdjListGraph,
+/// # 	core::{property::{AddEdge, NewVertex}, Graph},
+/// # 	io::DotWriter,
+/// # };
+/// let mut graph = AdjListGraph::<&str, u32>::new();
+/// let v0 = graph.new_vertex_weighted("start").unwrap();
+/// let v1 = graph.new_vertex_weighted("end").unwrap();
+/// graph.add_edge_weighted(&v0, &v1, 1).unwrap();
+/// graph.add_edge_weighted(&v1, &v0, 2).unwrap();
+///
+/// let mut writer = DotWriter::new(&graph)
+/// 	.name("example")
+/// 	.vertex_label(|_, w| w.to_string())
+/// 	.edge_label(|_, _, w| w.to_string())
+/// 	.vertex_attributes(|v, _| {
+/// 		if v == 0 { vec![("shape".into(), "box".into())] } else { Vec::new() }
+/// 	});
+/// for (i, scc) in TarjanScc::new(&graph).enumerate()
+/// {
+/// 	writer = writer.cluster(format!("SCC {}", i), scc.all_vertices());
+/// }
+///
+/// assert_eq!(
+/// 	writer.to_string(),
+/// 	"digraph \"example\" {\n\
+/// 	\t0 [label=\"start\", shape=\"box\"];\n\
+/// 	\t1 [label=\"end\"];\n\
+/// 	\t0 -> 1 [label=\"1\"];\n\
+/// 	\t1 -> 0 [label=\"2\"];\n\
+/// 	\tsubgraph cluster_0 {\n\
+/// 	\t\tlabel=\"SCC 0\";\n\
+/// 	\t\t0;\n\
+/// 	\t\t1;\n\
+/// 	\t}\n\
+/// 	}\n"
+/// );
+/// ```
+pub struct DotWriter<'a, G: Graph>
+{
+	/// The graph to write
+	graph: &'a G,
+	/// The name of the graph, if any
+	name: Option<String>,
+	/// Gives the label of each vertex, if any
+	vertex_label: Option<VertexLabel<'a, G>>,
+	/// Gives the label of each edge, if any
+	edge_label: Option<EdgeLabel<'a, G>>,
+	/// Gives the other attributes of each vertex, if any
+	vertex_attributes: Option<VertexAttributes<'a, G>>,
+	/// Gives the other attributes of each edge, if any
+	edge_attributes: Option<EdgeAttributes<'a, G>>,
+	/// The label and vertices of each cluster
+	clusters: Vec<(String, Vec<G::Vertex>)>,
+}
+
+impl<'a, G: Graph> DotWriter<'a, G>
+{
+	/// Creates a writer of the given graph, with no labels or attributes.
+	pub fn new(graph: &'a G) -> Self
+	{
+		Self {
+			graph,
+			name: None,
+			vertex_label: None,
+			edge_label: None,
+			vertex_attributes: None,
+			edge_attributes: None,
+			clusters: Vec::new(),
+		}
+	}
+
+	/// Sets the name of the graph.
+	pub fn name(mut self, name: impl Into<String>) -> Self
+	{
+		self.name = Some(name.into());
+		self
+	}
+
+	/// Sets the label of each vertex to that given by the closure.
+	pub fn vertex_label(
+		mut self,
+		label: impl 'a + Fn(G::Vertex, &G::VertexWeight) -> String,
+	) -> Self
+	{
+		self.vertex_label = Some(Box::new(label));
+		self
+	}
+
+	/// Sets the label of each edge to that given by the closure.
+	pub fn edge_label(
+		mut self,
+		label: impl 'a + Fn(G::Vertex, G::Vertex, &G::EdgeWeight) -> String,
+	) -> Self
+	{
+		self.edge_label = Some(Box::new(label));
+		self
+	}
+
+	/// Sets the attributes of each vertex, as name and value pairs, to those
+	/// given by the closure.
+	///
+	/// Any label set using [`vertex_label`](#method.vertex_label) is written
+	/// before these attributes.
+	pub fn vertex_attributes(
+		mut self,
+		attributes: impl 'a + Fn(G::Vertex, &G::VertexWeight) -> Vec<(String, String)>,
+	) -> Self
+	{
+		self.vertex_attributes = Some(Box::new(attributes));
+		self
+	}
+
+	/// Sets the attributes of each edge, as name and value pairs, to those
+	/// given by the closure.
+	///
+	/// Any label set using [`edge_label`](#method.edge_label) is written
+	/// before these attributes.
+	pub fn edge_attributes(
+		mut self,
+		attributes: impl 'a + Fn(G::Vertex, G::Vertex, &G::EdgeWeight) -> Vec<(String, String)>,
+	) -> Self
+	{
+		self.edge_attributes = Some(Box::new(attributes));
+		self
+	}
+
+	/// Adds a cluster with the given label and vertices, which are drawn
+	/// together inside a box.
+	///
+	/// Vertices that aren't in the graph are ignored.
+	pub fn cluster(
+		mut self,
+		label: impl Into<String>,
+		vertices: impl IntoIterator<Item = G::Vertex>,
+	) -> Self
+	{
+		self.clusters
+			.push((label.into(), vertices.into_iter().collect()));
+		self
+	}
+}
+
+impl<'a, G: Graph> Display for DotWriter<'a, G>
+where
+	G::Vertex: Hash,
+{
+	fn fmt(&self, f: &mut Formatter) -> Result
+	{
+		let (kind, edge_op) = if G::Directedness::directed()
+		{
+			("digraph", "->")
+		}
+		else
+		{
+			("graph", "--")
+		};
+		match &self.name
+		{
+			Some(name) => writeln!(f, "{} {} {{", kind, quote(name))?,
+			None => writeln!(f, "{} {{", kind)?,
+		}
+
+		let mut ids = HashMap::new();
+		for (id, (v, w)) in self.graph.all_vertices_weighted().enumerate()
+		{
+			ids.insert(v, id);
+			let mut attributes = Vec::new();
+			if let Some(label) = &self.vertex_label
+			{
+				attributes.push(("label".to_string(), label(v, w)));
+			}
+			if let Some(others) = &self.vertex_attributes
+			{
+				attributes.extend(others(v, w));
+			}
+			writeln!(f, "\t{}{};", id, AttributeList(&attributes))?;
+		}
+
+		for (source, sink, w) in self.graph.all_edges()
+		{
+			let mut attributes = Vec::new();
+			if let Some(label) = &self.edge_label
+			{
+				attributes.push(("label".to_string(), label(source, sink, w)));
+			}
+			if let Some(others) = &self.edge_attributes
+			{
+				attributes.extend(others(source, sink, w));
+			}
+			writeln!(
+				f,
+				"\t{} {} {}{};",
+				ids[&source],
+				edge_op,
+				ids[&sink],
+				AttributeList(&attributes)
+			)?;
+		}
+
+		for (i, (label, vertices)) in self.clusters.iter().enumerate()
+		{
+			writeln!(f, "\tsubgraph cluster_{} {{", i)?;
+			writeln!(f, "\t\tlabel={};", quote(label))?;
+			for id in vertices.iter().filter_map(|v| ids.get(v))
+			{
+				writeln!(f, "\t\t{};", id)?;
+			}
+			writeln!(f, "\t}}")?;
+		}
+		writeln!(f, "}}")
+	}
+}
+
+/// Writes a list of attributes in brackets, or nothing if there are none.
+struct AttributeList<'a>(&'a [(String, String)]);
+
+impl<'a> Display for AttributeList<'a>
+{
+	fn fmt(&self, f: &mut Formatter) -> Result
+	{
+		if self.0.is_empty()
+		{
+			return Ok(());
+		}
+		write!(f, " [")?;
+		for (i, (name, value)) in self.0.iter().enumerate()
+		{
+			if i > 0
+			{
+				write!(f, ", ")?;
+			}
+			write!(f, "{}={}", name, quote(value))?;
+		}
+		write!(f, "]")
+	}
+}
+
+/// Returns the given string as a quoted DOT string.
+fn quote(s: &str) -> String
+{
+	let mut quoted = String::with_capacity(s.len() + 2);
+	quoted.push('"');
+	for c in s.chars()
+	{
+		match c
+		{
+			'"' => quoted.push_str("\\\""),
+			'\\' => quoted.push_str("\\\\"),
+			'\n' => quoted.push_str("\\n"),
+			c => quoted.push(c),
+		}
+	}
+	quoted.push('"');
+	quoted
+}
@@ -0,0 +1,6 @@
+//! Edge lists, with the edges separated by whitespace or as CSV.
+
+mod reader;
+mod writer;
+
+pub use self::{reader::*, writer::*};
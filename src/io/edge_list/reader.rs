@@ -0,0 +1,446 @@
+use crate::{
+	core::property::{AddEdge, NewVertex},
+	io::{whitespace_fields, Attributes, FromAttributes, NodeIds, ParseError, ReadResult},
+};
+use std::{collections::HashMap, io::BufRead};
+
+/// The most vertices added for indices by default when the number of vertices
+/// isn't declared.
+const UNDECLARED_VERTEX_LIMIT: usize = 1 << 24;
+
+/// Reads graphs from edge lists, with a line for each edge, like the datasets
+/// of [SNAP](https://snap.stanford.edu/data/) and
+/// [Konect](http://konect.cc/), or from CSV.
+///
+/// Each line has the IDs of the source and sink of an edge in two of its
+/// columns, by default the first two. The weight of the edge is created using
+/// [`FromAttributes`](trait.FromAttributes.html) from the other columns, which
+/// are named by the header, if there is one, or by their position otherwise.
+/// Columns can also be named explicitly, e.g. naming the column holding
+/// weights `weight`, which numbers are read from.
+///
+/// By default, vertices are added for each ID when it first appears.
+/// If the IDs are indices instead, vertices can be added for every index up to
+/// the largest one, or for every index below a declared number of vertices,
+/// such that vertices without edges aren't lost.
+/// Either way, the vertices are created with the default weight.
+///
+/// The input is read one line at a time, so that large files can be read
+/// without holding them in memory.
+///
+/// ### Usage
+/// ```
+/// # use graphene::{
+/// # 	common::AdjListGraph,
+/// # 	core::Graph,
+/// # 	io::EdgeListReader,
+/// # };
+/// let input = "% a weighted graph\n1 2 0.5\n2 4 1.5\n";
+/// let (graph, ids) = EdgeListReader::new()
+/// 	.column_name(2, "weight")
+/// 	.indices(1)
+/// 	.read::<AdjListGraph<(), f64>>(input.as_bytes())
+/// 	.unwrap();
+///
+/// assert_eq!(graph.all_vertices().count(), 4);
+/// assert_eq!(graph.edges_between(ids["2"], ids["4"]).next(), Some(&1.5));
+/// ```
+#[derive(Clone, Debug)]
+pub struct EdgeListReader
+{
+	/// The character separating columns, or `None` for any whitespace.
+	delimiter: Option<char>,
+	/// Whether fields may be quoted, as in CSV.
+	quoted: bool,
+	/// The prefixes of lines to ignore.
+	comments: Vec<String>,
+	/// Whether the first line names the columns.
+	header: bool,
+	source: usize,
+	sink: usize,
+	/// Names given to columns explicitly.
+	names: HashMap<usize, String>,
+	/// The first index, if the IDs are indices.
+	first_index: Option<usize>,
+	/// The declared number of vertices, if the IDs are indices.
+	vertex_count: Option<usize>,
+	/// The most vertices added for indices when the number of vertices isn't
+	/// declared.
+	vertex_limit: usize,
+}
+
+impl EdgeListReader
+{
+	/// Creates a reader of edge lists with columns separated by whitespace,
+	/// ignoring lines starting with `#` or `%`.
+	pub fn new() -> Self
+	{
+		Self {
+			delimiter: None,
+			quoted: false,
+			comments: vec!["#".into(), "%".into()],
+			header: false,
+			source: 0,
+			sink: 1,
+			names: HashMap::new(),
+			first_index: None,
+			vertex_count: None,
+			vertex_limit: UNDECLARED_VERTEX_LIMIT,
+		}
+	}
+
+	/// Creates a reader of CSV, whose first line is a header naming the
+	/// columns.
+	///
+	/// Fields may be quoted with `"`, in which case they may contain commas
+	/// and `""` is read as a quote, but not line breaks.
+	pub fn csv() -> Self
+	{
+		Self {
+			delimiter: Some(','),
+			quoted: true,
+			comments: Vec::new(),
+			header: true,
+			..Self::new()
+		}
+	}
+
+	/// Separates columns with the given character instead.
+	pub fn delimiter(mut self, delimiter: char) -> Self
+	{
+		self.delimiter = Some(delimiter);
+		self
+	}
+
+	/// Ignores lines starting with the given prefixes, instead of the
+	/// current ones.
+	pub fn comments<S: Into<String>>(mut self, prefixes: impl IntoIterator<Item = S>) -> Self
+	{
+		self.comments = prefixes.into_iter().map(Into::into).collect();
+		self
+	}
+
+	/// Sets whether the first line is a header naming the columns.
+	pub fn header(mut self, header: bool) -> Self
+	{
+		self.header = header;
+		self
+	}
+
+	/// Reads the IDs of the source and sink of each edge from the given
+	/// columns, counting from 0.
+	pub fn columns(mut self, source: usize, sink: usize) -> Self
+	{
+		self.source = source;
+		self.sink = sink;
+		self
+	}
+
+	/// Gives the column the given name in the attributes of edges, overriding
+	/// any name given by the header.
+	pub fn column_name(mut self, column: usize, name: impl Into<String>) -> Self
+	{
+		self.names.insert(column, name.into());
+		self
+	}
+
+	/// Reads the IDs as indices starting from the given index, typically 0 or
+	/// 1, adding vertices for all indices up to the largest one read.
+	///
+	/// Unless the number of vertices is declared using
+	/// [`vertex_count`](#method.vertex_count), at most 2^24 vertices are
+	/// added, or as many as set by [`vertex_limit`](#method.vertex_limit),
+	/// such that a single large index can't exhaust memory.
+	pub fn indices(mut self, first_index: usize) -> Self
+	{
+		self.first_index = Some(first_index);
+		self
+	}
+
+	/// Declares the number of vertices, if the IDs are indices.
+	///
+	/// A vertex is added for each index below the declared number of vertices,
+	/// whether or not it has edges, and larger indices are errors.
+	pub fn vertex_count(mut self, count: usize) -> Self
+	{
+		self.vertex_count = Some(count);
+		self
+	}
+
+	/// Adds at most the given number of vertices for indices when the number
+	/// of vertices isn't declared, such that larger indices are errors.
+	pub fn vertex_limit(mut self, limit: usize) -> Self
+	{
+		self.vertex_limit = limit;
+		self
+	}
+
+	/// Reads the edge list into the given graph.
+	///
+	/// Returns which vertex each ID was added as. If the IDs are indices, they
+	/// are written in the returned IDs without leading zeros or signs.
+	pub fn read_into<G>(
+		&self,
+		mut input: impl BufRead,
+		g: &mut G,
+	) -> Result<NodeIds<G>, ParseError<G::Vertex>>
+	where
+		G: NewVertex + AddEdge,
+		G::VertexWeight: Default,
+		G::EdgeWeight: FromAttributes,
+	{
+		let mut ids = NodeIds::<G>::new();
+		// The vertex of each index, counting from the first.
+		let mut vertices = Vec::new();
+		if let (Some(first_index), Some(count)) = (self.first_index, self.vertex_count)
+		{
+			for i in 0..count
+			{
+				let v = g.new_vertex_weighted(Default::default())?;
+				ids.insert((first_index + i).to_string(), v);
+				vertices.push(v);
+			}
+		}
+		let mut header = None;
+		let mut buffer = String::new();
+		let mut line = 0;
+		loop
+		{
+			buffer.clear();
+			if input.read_line(&mut buffer)? == 0
+			{
+				break;
+			}
+			line += 1;
+			let content = buffer.trim_end_matches(&['\n', '\r'][..]);
+			let trimmed = content.trim_start();
+			if trimmed.is_empty()
+				|| self
+					.comments
+					.iter()
+					.any(|prefix| trimmed.starts_with(prefix.as_str()))
+			{
+				continue;
+			}
+
+			let fields = self
+				.fields(content)
+				.map_err(|(column, message)| ParseError::syntax(line, column, message))?;
+			if self.header && header.is_none()
+			{
+				header = Some(
+					fields
+						.into_iter()
+						.map(|(_, field)| field)
+						.collect::<Vec<_>>(),
+				);
+				continue;
+			}
+
+			let end = content.chars().count() + 1;
+			let mut endpoint = |column: usize| {
+				let (field_column, id) = fields.get(column).ok_or_else(|| {
+					ParseError::syntax(line, end, format!("missing column {}", column))
+				})?;
+				self.vertex(g, &mut ids, &mut vertices, id, line, *field_column)
+			};
+			let source = endpoint(self.source)?;
+			let sink = endpoint(self.sink)?;
+
+			let is_attribute = |column: &usize| *column != self.source && *column != self.sink;
+			let attributes: Attributes = fields
+				.iter()
+				.enumerate()
+				.filter(|(column, _)| is_attribute(column))
+				.map(|(column, (_, value))| {
+					let name = self
+						.names
+						.get(&column)
+						.or_else(|| header.as_ref().and_then(|names| names.get(column)))
+						.cloned()
+						.unwrap_or_else(|| column.to_string());
+					(name, value.clone())
+				})
+				.collect();
+			// Weight errors are reported at the first attribute, or at the end of
+			// the line if there are none
+			let column = fields
+				.iter()
+				.enumerate()
+				.find(|(column, _)| is_attribute(column))
+				.map_or(end, |(_, (field_column, _))| *field_column);
+			let weight = G::EdgeWeight::from_attributes(&attributes).map_err(|message| {
+				ParseError::Weight {
+					line,
+					column,
+					message,
+				}
+			})?;
+			g.add_edge_weighted(source, sink, weight)?;
+		}
+		Ok(ids)
+	}
+
+	/// Reads the edge list into a new graph.
+	///
+	/// See [`read_into`](#method.read_into).
+	pub fn read<G>(&self, input: impl BufRead) -> ReadResult<G>
+	where
+		G: Default + NewVertex + AddEdge,
+		G::VertexWeight: Default,
+		G::EdgeWeight: FromAttributes,
+	{
+		let mut g = G::default();
+		let ids = self.read_into(input, &mut g)?;
+		Ok((g, ids))
+	}
+
+	/// Returns the vertex with the given ID, adding it (and any vertices with
+	/// lower indices) if needed.
+	///
+	/// The line and column of the ID are used to report invalid IDs.
+	fn vertex<G>(
+		&self,
+		g: &mut G,
+		ids: &mut NodeIds<G>,
+		vertices: &mut Vec<G::Vertex>,
+		id: &str,
+		line: usize,
+		column: usize,
+	) -> Result<G::Vertex, ParseError<G::Vertex>>
+	where
+		G: NewVertex,
+		G::VertexWeight: Default,
+	{
+		let first_index = match self.first_index
+		{
+			None =>
+			{
+				if let Some(v) = ids.get(id)
+				{
+					return Ok(*v);
+				}
+				let v = g.new_vertex_weighted(Default::default())?;
+				ids.insert(id.to_string(), v);
+				return Ok(v);
+			},
+			Some(first_index) => first_index,
+		};
+
+		let index: usize = id.parse().map_err(|_| {
+			ParseError::syntax(line, column, format!("expected an index, found '{}'", id))
+		})?;
+		if index < first_index
+		{
+			return Err(ParseError::syntax(
+				line,
+				column,
+				format!("index {} is below the first index {}", index, first_index),
+			));
+		}
+		let offset = index - first_index;
+		if offset >= self.vertex_count.unwrap_or(self.vertex_limit)
+		{
+			return Err(ParseError::syntax(
+				line,
+				column,
+				format!("index {} is out of bounds", index),
+			));
+		}
+		while vertices.len() <= offset
+		{
+			let v = g.new_vertex_weighted(Default::default())?;
+			ids.insert((first_index + vertices.len()).to_string(), v);
+			vertices.push(v);
+		}
+		Ok(vertices[offset])
+	}
+
+	/// Splits the given line into fields, along with the column each starts
+	/// at, or returns the column and message of a syntax error.
+	fn fields(&self, line: &str) -> Result<Vec<(usize, String)>, (usize, String)>
+	{
+		let delimiter = match self.delimiter
+		{
+			None =>
+			{
+				return Ok(whitespace_fields(line)
+					.into_iter()
+					.map(|(column, field)| (column, field.to_string()))
+					.collect())
+			},
+			Some(delimiter) => delimiter,
+		};
+
+		let mut fields = Vec::new();
+		let mut chars = line.chars().enumerate().peekable();
+		let mut start = 1;
+		let mut field = String::new();
+		while let Some((i, c)) = chars.next()
+		{
+			if c == delimiter
+			{
+				fields.push((start, field.trim().to_string()));
+				field.clear();
+				start = i + 2;
+			}
+			else if c == '"' && self.quoted && field.trim().is_empty()
+			{
+				field.clear();
+				loop
+				{
+					match chars.next()
+					{
+						None => return Err((i + 1, "unterminated quote".into())),
+						Some((_, '"')) =>
+						{
+							if let Some((_, '"')) = chars.peek()
+							{
+								chars.next();
+								field.push('"');
+							}
+							else
+							{
+								break;
+							}
+						},
+						Some((_, c)) => field.push(c),
+					}
+				}
+				while let Some((j, c)) = chars.peek()
+				{
+					if *c == delimiter
+					{
+						break;
+					}
+					if !c.is_whitespace()
+					{
+						return Err((j + 1, format!("expected a delimiter, found '{}'", c)));
+					}
+					chars.next();
+				}
+				fields.push((start, field.clone()));
+				field.clear();
+				match chars.next()
+				{
+					Some((j, _)) => start = j + 2,
+					None => return Ok(fields),
+				}
+			}
+			else
+			{
+				field.push(c);
+			}
+		}
+		fields.push((start, field.trim().to_string()));
+		Ok(fields)
+	}
+}
+
+impl Default for EdgeListReader
+{
+	fn default() -> Self
+	{
+		Self::new()
+	}
+}
@@ -0,0 +1,163 @@
+use crate::{core::Graph, io::ToAttributes};
+use std::{
+	borrow::Cow,
+	collections::{BTreeSet, HashMap},
+	fmt::{Display, Formatter, Result},
+	hash::Hash,
+};
+
+/// Writes a graph as an edge list, with a line for each edge, or as CSV.
+///
+/// Each vertex is given the index of its position in
+/// [`all_vertices`](../core/trait.Graph.html#method.all_vertices), plus the
+/// first index, which is 0 by default.
+/// Each line has the indices of the source and sink of an edge, followed by
+/// the values of the attributes given by its weight using
+/// [`ToAttributes`](trait.ToAttributes.html), ordered by name. Edges should
+/// therefore all have the same attributes.
+///
+/// Since vertices without edges aren't written, they are only kept when read
+/// by [`EdgeListReader`](struct.EdgeListReader.html) if they have lower
+/// indices than some other vertex.
+///
+/// The edge list is written using the [`Display`](https://doc.rust-lang.org/std/fmt/trait.Display.html)
+/// implementation, e.g. with `write!` to a file, in which case it isn't held
+/// in memory.
+///
+/// ### Usage
+/// ```
+/// # use graphene::{
+/// # 	common::AdjListGraph,
+/// # 	core::property::{AddEdge, NewVertex},
+/// # 	io::EdgeListWriter,
+/// # };
+/// let mut graph = AdjListGraph::<(), u32>::new();
+/// let v0 = graph.new_vertex().unwrap();
+/// let v1 = graph.new_vertex().unwrap();
+/// graph.add_edge_weighted(&v0, &v1, 7).unwrap();
+///
+/// assert_eq!(EdgeListWriter::new(&graph).first_index(1).to_string(), "1 2 7\n");
+/// assert_eq!(
+/// 	EdgeListWriter::csv(&graph).to_string(),
+/// 	"source,sink,weight\n0,1,7\n"
+/// );
+/// ```
+pub struct EdgeListWriter<'a, G: Graph>
+{
+	/// The graph to write
+	graph: &'a G,
+	delimiter: char,
+	/// Whether to write a header naming the columns, and quote fields, as in
+	/// CSV.
+	csv: bool,
+	first_index: usize,
+}
+
+impl<'a, G: Graph> EdgeListWriter<'a, G>
+{
+	/// Creates a writer of the given graph with columns separated by spaces.
+	///
+	/// Attribute values shouldn't contain whitespace.
+	pub fn new(graph: &'a G) -> Self
+	{
+		Self {
+			graph,
+			delimiter: ' ',
+			csv: false,
+			first_index: 0,
+		}
+	}
+
+	/// Creates a writer of the given graph as CSV, with a header naming the
+	/// columns `source`, `sink` and then the attribute names.
+	///
+	/// Fields are quoted when needed, and edges without some attribute have an
+	/// empty field for it.
+	pub fn csv(graph: &'a G) -> Self
+	{
+		Self {
+			delimiter: ',',
+			csv: true,
+			..Self::new(graph)
+		}
+	}
+
+	/// Separates columns with the given character instead.
+	pub fn delimiter(mut self, delimiter: char) -> Self
+	{
+		self.delimiter = delimiter;
+		self
+	}
+
+	/// Starts the indices of vertices from the given index, typically 0 or 1.
+	pub fn first_index(mut self, first_index: usize) -> Self
+	{
+		self.first_index = first_index;
+		self
+	}
+
+	/// Returns the given field, quoted if it's needed in CSV.
+	fn field<'b>(&self, field: &'b str) -> Cow<'b, str>
+	{
+		if self.csv
+			&& (field.contains(&[self.delimiter, '"', '\n', '\r'][..]) || field.trim() != field)
+		{
+			format!("\"{}\"", field.replace('"', "\"\"")).into()
+		}
+		else
+		{
+			field.into()
+		}
+	}
+}
+
+impl<'a, G> Display for EdgeListWriter<'a, G>
+where
+	G: Graph,
+	G::Vertex: Hash,
+	G::EdgeWeight: ToAttributes,
+{
+	fn fmt(&self, f: &mut Formatter) -> Result
+	{
+		let mut names = BTreeSet::new();
+		for (_, _, w) in self.graph.all_edges()
+		{
+			names.extend(w.to_attributes().into_keys());
+		}
+		if self.csv
+		{
+			write!(f, "source{}sink", self.delimiter)?;
+			for name in names.iter()
+			{
+				write!(f, "{}{}", self.delimiter, self.field(name))?;
+			}
+			writeln!(f)?;
+		}
+
+		let indices: HashMap<_, _> = self
+			.graph
+			.all_vertices()
+			.enumerate()
+			.map(|(i, v)| (v, i + self.first_index))
+			.collect();
+		for (source, sink, w) in self.graph.all_edges()
+		{
+			write!(
+				f,
+				"{}{}{}",
+				indices[&source], self.delimiter, indices[&sink]
+			)?;
+			let attributes = w.to_attributes();
+			for name in names.iter()
+			{
+				write!(f, "{}", self.delimiter)?;
+				if let Some(value) = attributes.get(name)
+				{
+					write!(f, "{}", self.field(value))?;
+				}
+			}
+			writeln!(f)?;
+		}
+		Ok(())
+	}
+}
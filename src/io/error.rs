@@ -0,0 +1,110 @@
+use crate::core::GraphError;
+use std::{
+	error::Error,
+	fmt::{Debug, Display, Formatter},
+	io,
+};
+
+/// The error returned when reading a graph fails.
+///
+/// Lines and columns count from 1.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseError<V>
+{
+	/// The input isn't valid in the format being read.
+	Syntax
+	{
+		line: usize,
+		column: usize,
+		message: String,
+	},
+
+	/// A vertex or an edge has attributes that its weight can't be created
+	/// from.
+	Weight
+	{
+		line: usize,
+		column: usize,
+		message: String,
+	},
+
+	/// The graph being read into rejected a vertex or an edge.
+	Graph(GraphError<V>),
+
+	/// The input couldn't be read.
+	Io
+	{
+		kind: io::ErrorKind,
+		message: String,
+	},
+}
+
+impl<V> ParseError<V>
+{
+	/// Constructs a [`Syntax`](#variant.Syntax) error.
+	pub fn syntax(line: usize, column: usize, message: impl Into<String>) -> Self
+	{
+		Self::Syntax {
+			line,
+			column,
+			message: message.into(),
+		}
+	}
+}
+
+impl<V> From<GraphError<V>> for ParseError<V>
+{
+	fn from(error: GraphError<V>) -> Self
+	{
+		Self::Graph(error)
+	}
+}
+
+impl<V> From<io::Error> for ParseError<V>
+{
+	fn from(error: io::Error) -> Self
+	{
+		Self::Io {
+			kind: error.kind(),
+			message: error.to_string(),
+		}
+	}
+}
+
+impl<V: Debug> Display for ParseError<V>
+{
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result
+	{
+		match self
+		{
+			Self::Syntax {
+				line,
+				column,
+				message,
+			} =>
+			{
+				write!(
+					f,
+					"Syntax error at line {}, column {}: {}",
+					line, column, message
+				)
+			},
+			Self::Weight {
+				line,
+				column,
+				message,
+			} =>
+			{
+				write!(
+					f,
+					"Invalid weight at line {}, column {}: {}",
+					line, column, message
+				)
+			},
+			Self::Graph(error) => write!(f, "{}", error),
+			Self::Io { message, .. } => write!(f, "Failed to read input: {}", message),
+		}
+	}
+}
+
+impl<V: Debug> Error for ParseError<V> {}
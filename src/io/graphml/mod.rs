@@ -0,0 +1,24 @@
+//! The [GraphML](http://graphml.graphdrawing.org/) format.
+
+mod reader;
+mod writer;
+
+pub use self::{reader::*, writer::*};
+use crate::io::AttributeType;
+
+impl AttributeType
+{
+	/// The name of the type in GraphML.
+	fn graphml_name(self) -> &'static str
+	{
+		match self
+		{
+			AttributeType::Boolean => "boolean",
+			AttributeType::Int => "int",
+			AttributeType::Long => "long",
+			AttributeType::Float => "float",
+			AttributeType::Double => "double",
+			AttributeType::String => "string",
+		}
+	}
+}
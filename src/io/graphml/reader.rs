@@ -0,0 +1,566 @@
+use crate::{
+	core::{
+		property::{AddEdge, NewVertex},
+		Directedness,
+	},
+	io::{AttributeType, Attributes, FromAttributes, NodeIds, ParseError, ReadResult},
+};
+use quick_xml::{
+	events::{BytesStart, Event},
+	Reader,
+};
+use std::collections::HashMap;
+
+/// Reads a graph in the [GraphML](http://graphml.graphdrawing.org/) format into
+/// the given graph.
+///
+/// Each node is added as a vertex, in the order they appear, and the weight of
+/// each vertex and edge is created using
+/// [`FromAttributes`](trait.FromAttributes.html) from its `<data>`, named by
+/// the `attr.name` of their `<key>`.
+/// Key defaults are used for nodes and edges without data for the key, and
+/// data for keys that declare a numeric or boolean `attr.type` must be valid
+/// for the type.
+/// Data on the graph itself, ports and unknown elements are ignored, while
+/// nested graphs and hyperedges aren't supported.
+///
+/// The `edgedefault` of the graph must be `directed` if the given graph is
+/// directed and `undirected` otherwise, and edges may not override it.
+/// Returns which vertex each node ID was added as.
+///
+/// ### Usage
+/// ```
+/// # use graphene::{
+/// # 	common::AdjListGraph,
+/// # 	core::Graph,
+/// # 	io::{read_graphml, Attributes},
+/// # };
+/// let (graph, ids) = read_graphml::<AdjListGraph<Attributes, Attributes>>(
+/// 	r#"<?xml version="1.0" encoding="UTF-8"?>
+/// 	<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+/// 		<key id="d0" for="node" attr.name="color" attr.type="string">
+/// 			<default>yellow</default>
+/// 		</key>
+/// 		<key id="d1" for="edge" attr.name="weight" attr.type="double"/>
+/// 		<graph id="G" edgedefault="directed">
+/// 			<node id="a"><data key="d0">green</data></node>
+/// 			<node id="b"/>
+/// 			<edge source="a" target="b"><data key="d1">1.5</data></edge>
+/// 			<edge source="a" target="b"/>
+/// 		</graph>
+/// 	</graphml>"#,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(graph.vertex_weight(ids["a"]).unwrap()["color"], "green");
+/// assert_eq!(graph.vertex_weight(ids["b"]).unwrap()["color"], "yellow");
+/// assert_eq!(graph.edges_between(ids["a"], ids["b"]).count(), 2);
+/// ```
+pub fn read_graphml_into<G>(input: &str, g: &mut G) -> Result<NodeIds<G>, ParseError<G::Vertex>>
+where
+	G: NewVertex + AddEdge,
+	G::VertexWeight: FromAttributes,
+	G::EdgeWeight: FromAttributes,
+{
+	let document = Document::parse(input, G::Directedness::directed())?;
+
+	let mut ids = HashMap::new();
+	for node in document.nodes
+	{
+		let weight = G::VertexWeight::from_attributes(&node.attributes)
+			.map_err(|message| weight_error(input, node.position, message))?;
+		if ids.contains_key(&node.id)
+		{
+			return Err(syntax_error(
+				input,
+				node.position,
+				format!("duplicate node ID '{}'", node.id),
+			));
+		}
+		ids.insert(node.id, g.new_vertex_weighted(weight)?);
+	}
+	for edge in document.edges
+	{
+		let weight = G::EdgeWeight::from_attributes(&edge.attributes)
+			.map_err(|message| weight_error(input, edge.position, message))?;
+		let endpoint = |id: &String| {
+			ids.get(id).copied().ok_or_else(|| {
+				syntax_error(input, edge.position, format!("unknown node ID '{}'", id))
+			})
+		};
+		let (source, sink) = (endpoint(&edge.source)?, endpoint(&edge.sink)?);
+		g.add_edge_weighted(source, sink, weight)?;
+	}
+	Ok(ids)
+}
+
+/// Reads a graph in the [GraphML](http://graphml.graphdrawing.org/) format into
+/// a new graph.
+///
+/// See [`read_graphml_into`](fn.read_graphml_into.html).
+pub fn read_graphml<G>(input: &str) -> ReadResult<G>
+where
+	G: Default + NewVertex + AddEdge,
+	G::VertexWeight: FromAttributes,
+	G::EdgeWeight: FromAttributes,
+{
+	let mut g = G::default();
+	let ids = read_graphml_into(input, &mut g)?;
+	Ok((g, ids))
+}
+
+/// Returns the line and column of the given byte offset in the input.
+fn line_and_column(input: &str, offset: usize) -> (usize, usize)
+{
+	let before = &input[..offset.min(input.len())];
+	let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+	(
+		before.matches('\n').count() + 1,
+		before[line_start..].chars().count() + 1,
+	)
+}
+
+fn syntax_error<V>(input: &str, offset: usize, message: impl Into<String>) -> ParseError<V>
+{
+	let (line, column) = line_and_column(input, offset);
+	ParseError::syntax(line, column, message)
+}
+
+fn weight_error<V>(input: &str, offset: usize, message: String) -> ParseError<V>
+{
+	let (line, column) = line_and_column(input, offset);
+	ParseError::Weight {
+		line,
+		column,
+		message,
+	}
+}
+
+/// A `<key>` declaration.
+struct Key
+{
+	/// Whether the key is for nodes, edges, or all elements.
+	domain: String,
+	name: String,
+	attribute_type: AttributeType,
+	default: Option<String>,
+}
+
+/// A node read from the input.
+struct Node
+{
+	id: String,
+	attributes: Attributes,
+	/// The byte offset of the node's start tag
+	position: usize,
+}
+
+/// An edge read from the input.
+struct Edge
+{
+	source: String,
+	sink: String,
+	attributes: Attributes,
+	/// The byte offset of the edge's start tag
+	position: usize,
+}
+
+/// The node or edge whose element is being read.
+enum Element
+{
+	Node(Node),
+	Edge(Edge),
+}
+
+impl Element
+{
+	fn attributes(&mut self) -> &mut Attributes
+	{
+		match self
+		{
+			Element::Node(node) => &mut node.attributes,
+			Element::Edge(edge) => &mut edge.attributes,
+		}
+	}
+}
+
+/// The nodes and edges of a GraphML document.
+struct Document<'a>
+{
+	input: &'a str,
+	directed: bool,
+	keys: HashMap<String, Key>,
+	/// The ID of the key being declared, whose default may be read next.
+	key: Option<String>,
+	/// The text of the key default being read, if any.
+	default: Option<String>,
+	/// Whether the graph element has been read and whether it's still open.
+	graph: Option<bool>,
+	element: Option<Element>,
+	/// The key, text and byte offset of the data element being read, if any.
+	data: Option<(String, String, usize)>,
+	nodes: Vec<Node>,
+	edges: Vec<Edge>,
+}
+
+impl<'a> Document<'a>
+{
+	/// Reads the nodes and edges of the given document.
+	fn parse<V>(input: &'a str, directed: bool) -> Result<Self, ParseError<V>>
+	{
+		let mut document = Self {
+			input,
+			directed,
+			keys: HashMap::new(),
+			key: None,
+			default: None,
+			graph: None,
+			element: None,
+			data: None,
+			nodes: Vec::new(),
+			edges: Vec::new(),
+		};
+		let mut reader = Reader::from_str(input);
+		reader.check_end_names(true);
+
+		loop
+		{
+			let position = reader.buffer_position();
+			let event = reader
+				.read_event()
+				.map_err(|e| document.error(reader.buffer_position(), e.to_string()))?;
+			match event
+			{
+				Event::Start(e) => document.start(&e, position)?,
+				Event::Empty(e) =>
+				{
+					document.start(&e, position)?;
+					document.end(e.local_name().as_ref(), position)?;
+				},
+				Event::End(e) => document.end(e.local_name().as_ref(), position)?,
+				Event::Text(text) =>
+				{
+					let text = text
+						.unescape()
+						.map_err(|e| document.error(position, e.to_string()))?;
+					document.text(&text);
+				},
+				Event::CData(text) =>
+				{
+					document.text(&String::from_utf8_lossy(&text.into_inner()));
+				},
+				Event::Eof => break,
+				_ => (),
+			}
+		}
+		if document.graph.is_none()
+		{
+			return Err(document.error(input.len(), "missing graph element"));
+		}
+		Ok(document)
+	}
+
+	fn error<V>(&self, position: usize, message: impl Into<String>) -> ParseError<V>
+	{
+		syntax_error(self.input, position, message)
+	}
+
+	/// Returns the value of the given attribute of the element, if it has it.
+	fn attribute<V>(
+		&self,
+		element: &BytesStart,
+		name: &str,
+		position: usize,
+	) -> Result<Option<String>, ParseError<V>>
+	{
+		for attribute in element.attributes()
+		{
+			let attribute = attribute.map_err(|e| self.error(position, e.to_string()))?;
+			if attribute.key.local_name().as_ref() == name.as_bytes()
+			{
+				let value = attribute
+					.unescape_value()
+					.map_err(|e| self.error(position, e.to_string()))?;
+				return Ok(Some(value.into_owned()));
+			}
+		}
+		Ok(None)
+	}
+
+	/// Returns the value of the given attribute of the element, which it must
+	/// have.
+	fn required_attribute<V>(
+		&self,
+		element: &BytesStart,
+		name: &str,
+		position: usize,
+	) -> Result<String, ParseError<V>>
+	{
+		self.attribute(element, name, position)?.ok_or_else(|| {
+			let element_name = String::from_utf8_lossy(element.local_name().as_ref()).into_owned();
+			self.error(
+				position,
+				format!("<{}> is missing the '{}' attribute", element_name, name),
+			)
+		})
+	}
+
+	fn start<V>(&mut self, element: &BytesStart, position: usize) -> Result<(), ParseError<V>>
+	{
+		match element.local_name().as_ref()
+		{
+			b"key" =>
+			{
+				let id = self.required_attribute(element, "id", position)?;
+				let name = self
+					.attribute(element, "attr.name", position)?
+					.unwrap_or_else(|| id.clone());
+				let attribute_type = match self.attribute(element, "attr.type", position)?
+				{
+					None => AttributeType::String,
+					Some(t) =>
+					{
+						parse_type(&t).ok_or_else(|| {
+							self.error(position, format!("unknown attribute type '{}'", t))
+						})?
+					},
+				};
+				let domain = self
+					.attribute(element, "for", position)?
+					.unwrap_or_else(|| "all".into());
+				self.keys.insert(
+					id.clone(),
+					Key {
+						domain,
+						name,
+						attribute_type,
+						default: None,
+					},
+				);
+				self.key = Some(id);
+			},
+			b"default" if self.key.is_some() => self.default = Some(String::new()),
+			b"graph" =>
+			{
+				if self.graph.is_some()
+				{
+					return Err(self.error(position, "nested or multiple graphs aren't supported"));
+				}
+				let edge_default = self.required_attribute(element, "edgedefault", position)?;
+				self.check_directedness(&edge_default, position)?;
+				self.graph = Some(true);
+			},
+			b"node" =>
+			{
+				self.check_in_graph(position)?;
+				self.element = Some(Element::Node(Node {
+					id: self.required_attribute(element, "id", position)?,
+					attributes: Attributes::new(),
+					position,
+				}));
+			},
+			b"edge" =>
+			{
+				self.check_in_graph(position)?;
+				if let Some(directed) = self.attribute(element, "directed", position)?
+				{
+					let edge_default = if directed == "true"
+					{
+						"directed"
+					}
+					else
+					{
+						"undirected"
+					};
+					self.check_directedness(edge_default, position)?;
+				}
+				self.element = Some(Element::Edge(Edge {
+					source: self.required_attribute(element, "source", position)?,
+					sink: self.required_attribute(element, "target", position)?,
+					attributes: Attributes::new(),
+					position,
+				}));
+			},
+			b"hyperedge" => return Err(self.error(position, "hyperedges aren't supported")),
+			b"data" =>
+			{
+				let key = self.required_attribute(element, "key", position)?;
+				if !self.keys.contains_key(&key)
+				{
+					return Err(self.error(position, format!("undeclared key '{}'", key)));
+				}
+				self.data = Some((key, String::new(), position));
+			},
+			_ => (),
+		}
+		Ok(())
+	}
+
+	fn end<V>(&mut self, name: &[u8], position: usize) -> Result<(), ParseError<V>>
+	{
+		match name
+		{
+			b"key" =>
+			{
+				if let Some(id) = self.key.take()
+				{
+					let key = &self.keys[&id];
+					if let Some(default) = &key.default
+					{
+						validate(key, default).map_err(|message| self.error(position, message))?;
+					}
+				}
+			},
+			b"default" =>
+			{
+				if let (Some(id), Some(default)) = (&self.key, self.default.take())
+				{
+					self.keys.get_mut(id).unwrap().default = Some(default);
+				}
+			},
+			b"graph" => self.graph = Some(false),
+			b"data" =>
+			{
+				if let Some((id, value, position)) = self.data.take()
+				{
+					let key = &self.keys[&id];
+					validate(key, &value).map_err(|message| self.error(position, message))?;
+					if let Some(element) = &mut self.element
+					{
+						element.attributes().insert(key.name.clone(), value);
+					}
+				}
+			},
+			b"node" | b"edge" =>
+			{
+				if let Some(mut element) = self.element.take()
+				{
+					let domain = if let Element::Node(_) = element
+					{
+						"node"
+					}
+					else
+					{
+						"edge"
+					};
+					for key in self.keys.values()
+					{
+						if let (Some(default), true) =
+							(&key.default, key.domain == domain || key.domain == "all")
+						{
+							element
+								.attributes()
+								.entry(key.name.clone())
+								.or_insert_with(|| default.clone());
+						}
+					}
+					match element
+					{
+						Element::Node(node) => self.nodes.push(node),
+						Element::Edge(edge) => self.edges.push(edge),
+					}
+				}
+			},
+			_ => (),
+		}
+		Ok(())
+	}
+
+	fn text(&mut self, text: &str)
+	{
+		if let Some((_, value, _)) = &mut self.data
+		{
+			value.push_str(text);
+		}
+		else if let Some(default) = &mut self.default
+		{
+			default.push_str(text);
+		}
+	}
+
+	fn check_in_graph<V>(&self, position: usize) -> Result<(), ParseError<V>>
+	{
+		if self.graph == Some(true) && self.element.is_none()
+		{
+			Ok(())
+		}
+		else
+		{
+			Err(self.error(position, "nodes and edges must be directly in the graph"))
+		}
+	}
+
+	fn check_directedness<V>(
+		&self,
+		edge_default: &str,
+		position: usize,
+	) -> Result<(), ParseError<V>>
+	{
+		let expected = if self.directed
+		{
+			"directed"
+		}
+		else
+		{
+			"undirected"
+		};
+		if edge_default == expected
+		{
+			Ok(())
+		}
+		else
+		{
+			Err(self.error(
+				position,
+				format!("expected {} edges, found '{}'", expected, edge_default),
+			))
+		}
+	}
+}
+
+/// Returns the attribute type with the given GraphML name.
+fn parse_type(name: &str) -> Option<AttributeType>
+{
+	[
+		AttributeType::Boolean,
+		AttributeType::Int,
+		AttributeType::Long,
+		AttributeType::Float,
+		AttributeType::Double,
+		AttributeType::String,
+	]
+	.iter()
+	.copied()
+	.find(|t| t.graphml_name() == name)
+}
+
+/// Checks that the given value is valid for the type of the given key.
+fn validate(key: &Key, value: &str) -> Result<(), String>
+{
+	let trimmed = value.trim();
+	let valid = match key.attribute_type
+	{
+		AttributeType::Boolean =>
+		{
+			trimmed.eq_ignore_ascii_case("true") || trimmed.eq_ignore_ascii_case("false")
+		},
+		AttributeType::Int => trimmed.parse::<i32>().is_ok(),
+		AttributeType::Long => trimmed.parse::<i64>().is_ok(),
+		AttributeType::Float => trimmed.parse::<f32>().is_ok(),
+		AttributeType::Double => trimmed.parse::<f64>().is_ok(),
+		AttributeType::String => true,
+	};
+	if valid
+	{
+		Ok(())
+	}
+	else
+	{
+		Err(format!(
+			"invalid {} '{}' for attribute '{}'",
+			key.attribute_type.graphml_name(),
+			value,
+			key.name
+		))
+	}
+}
@@ -0,0 +1,220 @@
+use crate::{
+	core::{Directedness, Graph},
+	io::{AttributeType, Attributes, ToAttributes},
+};
+use std::{
+	collections::{BTreeMap, HashMap},
+	fmt::{Display, Formatter, Result},
+	hash::Hash,
+};
+
+/// Writes a graph in the [GraphML](http://graphml.graphdrawing.org/) format.
+///
+/// The attributes of each vertex and edge are given by their weights using
+/// [`ToAttributes`](trait.ToAttributes.html), and a `<key>` is declared for
+/// each attribute, with the type given by
+/// [`attribute_types`](trait.ToAttributes.html#method.attribute_types).
+/// Each vertex is given the ID `n` followed by its position in
+/// [`all_vertices`](../core/trait.Graph.html#method.all_vertices).
+///
+/// Graphs written this way are read back without loss by
+/// [`read_graphml`](fn.read_graphml.html), as long as the weights also
+/// implement [`FromAttributes`](trait.FromAttributes.html) consistently.
+///
+/// The GraphML is written using the [`Display`](https://doc.rust-lang.org/std/fmt/trait.Display.html)
+/// implementation, e.g. with `to_string()` or `write!`.
+///
+/// ### Usage
+/// ```
+/// # use graphene::{
+/// # 	common::AdjListGraph,
+/// # 	core::property::{AddEdge, NewVertex},
+/// # 	io::{Attributes, GraphMlWriter},
+/// # };
+/// let mut graph = AdjListGraph::<Attributes, ()>::new();
+/// let mut weight = Attributes::new();
+/// weight.insert("name".into(), "start".into());
+/// let v0 = graph.new_vertex_weighted(weight).unwrap();
+/// let v1 = graph.new_vertex_weighted(Attributes::new()).unwrap();
+/// graph.add_edge(&v0, &v1).unwrap();
+///
+/// assert_eq!(
+/// 	GraphMlWriter::new(&graph).to_string(),
+/// 	"<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+/// 	<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n\
+/// 	\t<key id=\"v0\" for=\"node\" attr.name=\"name\" attr.type=\"string\"/>\n\
+/// 	\t<graph edgedefault=\"directed\">\n\
+/// 	\t\t<node id=\"n0\">\n\
+/// 	\t\t\t<data key=\"v0\">start</data>\n\
+/// 	\t\t</node>\n\
+/// 	\t\t<node id=\"n1\"/>\n\
+/// 	\t\t<edge source=\"n0\" target=\"n1\"/>\n\
+/// 	\t</graph>\n\
+/// 	</graphml>\n"
+/// );
+/// ```
+pub struct GraphMlWriter<'a, G: Graph>
+{
+	/// The graph to write
+	graph: &'a G,
+}
+
+impl<'a, G: Graph> GraphMlWriter<'a, G>
+{
+	/// Creates a writer of the given graph.
+	pub fn new(graph: &'a G) -> Self
+	{
+		Self { graph }
+	}
+}
+
+impl<'a, G> Display for GraphMlWriter<'a, G>
+where
+	G: Graph,
+	G::Vertex: Hash,
+	G::VertexWeight: ToAttributes,
+	G::EdgeWeight: ToAttributes,
+{
+	fn fmt(&self, f: &mut Formatter) -> Result
+	{
+		let vertices: Vec<_> = self
+			.graph
+			.all_vertices_weighted()
+			.map(|(v, w)| (v, w.to_attributes()))
+			.collect();
+		let edges: Vec<_> = self
+			.graph
+			.all_edges()
+			.map(|(source, sink, w)| (source, sink, w.to_attributes()))
+			.collect();
+		let vertex_keys = keys(
+			"v",
+			G::VertexWeight::attribute_types(),
+			vertices.iter().map(|(_, attributes)| attributes),
+		);
+		let edge_keys = keys(
+			"e",
+			G::EdgeWeight::attribute_types(),
+			edges.iter().map(|(_, _, attributes)| attributes),
+		);
+
+		writeln!(f, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>")?;
+		writeln!(
+			f,
+			"<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">"
+		)?;
+		for (domain, keys) in &[("node", &vertex_keys), ("edge", &edge_keys)]
+		{
+			for (name, (id, attribute_type)) in keys.iter()
+			{
+				writeln!(
+					f,
+					"\t<key id=\"{}\" for=\"{}\" attr.name=\"{}\" attr.type=\"{}\"/>",
+					id,
+					domain,
+					escape(name),
+					attribute_type.graphml_name()
+				)?;
+			}
+		}
+		writeln!(
+			f,
+			"\t<graph edgedefault=\"{}\">",
+			if G::Directedness::directed()
+			{
+				"directed"
+			}
+			else
+			{
+				"undirected"
+			}
+		)?;
+
+		let mut ids = HashMap::new();
+		for (i, (v, attributes)) in vertices.iter().enumerate()
+		{
+			ids.insert(*v, i);
+			write!(f, "\t\t<node id=\"n{}\"", i)?;
+			write_data(f, "node", &vertex_keys, attributes)?;
+		}
+		for (source, sink, attributes) in edges.iter()
+		{
+			write!(
+				f,
+				"\t\t<edge source=\"n{}\" target=\"n{}\"",
+				ids[source], ids[sink]
+			)?;
+			write_data(f, "edge", &edge_keys, attributes)?;
+		}
+		writeln!(f, "\t</graph>")?;
+		writeln!(f, "</graphml>")
+	}
+}
+
+/// The ID and type of the key of each attribute, by attribute name.
+type Keys = BTreeMap<String, (String, AttributeType)>;
+
+/// Returns the keys needed for the given attributes, with IDs starting with the
+/// given prefix.
+fn keys<'b>(
+	prefix: &str,
+	types: Vec<(String, AttributeType)>,
+	attributes: impl Iterator<Item = &'b Attributes>,
+) -> Keys
+{
+	let types: HashMap<_, _> = types.into_iter().collect();
+	let mut names: Vec<_> = types.keys().cloned().collect();
+	for attributes in attributes
+	{
+		names.extend(attributes.keys().cloned());
+	}
+	names.sort();
+	names.dedup();
+	names
+		.into_iter()
+		.enumerate()
+		.map(|(i, name)| {
+			let attribute_type = types.get(&name).copied().unwrap_or(AttributeType::String);
+			(name, (format!("{}{}", prefix, i), attribute_type))
+		})
+		.collect()
+}
+
+/// Finishes the element of a node or an edge whose start tag has been started,
+/// with a data element for each of the given attributes.
+fn write_data(f: &mut Formatter, element: &str, keys: &Keys, attributes: &Attributes) -> Result
+{
+	if attributes.is_empty()
+	{
+		return writeln!(f, "/>");
+	}
+	writeln!(f, ">")?;
+	for (name, (id, _)) in keys.iter()
+	{
+		if let Some(value) = attributes.get(name)
+		{
+			writeln!(f, "\t\t\t<data key=\"{}\">{}</data>", id, escape(value))?;
+		}
+	}
+	writeln!(f, "\t\t</{}>", element)
+}
+
+/// Escapes the given string for use in XML text and attribute values.
+fn escape(s: &str) -> String
+{
+	let mut escaped = String::with_capacity(s.len());
+	for c in s.chars()
+	{
+		match c
+		{
+			'&' => escaped.push_str("&amp;"),
+			'<' => escaped.push_str("&lt;"),
+			'>' => escaped.push_str("&gt;"),
+			'"' => escaped.push_str("&quot;"),
+			'\'' => escaped.push_str("&apos;"),
+			'\r' => escaped.push_str("&#13;"),
+			c => escaped.push(c),
+		}
+	}
+	escaped
+}
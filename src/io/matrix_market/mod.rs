@@ -0,0 +1,6 @@
+//! The coordinate format of [Matrix Market](https://math.nist.gov/MatrixMarket/formats.html).
+
+mod reader;
+mod writer;
+
+pub use self::{reader::*, writer::*};
@@ -0,0 +1,253 @@
+use crate::{
+	core::{
+		property::{AddEdge, NewVertex},
+		Directedness,
+	},
+	io::{whitespace_fields, Attributes, FromAttributes, NodeIds, ParseError, ReadResult},
+};
+use std::io::BufRead;
+
+/// Reads a graph from its adjacency matrix in the coordinate format of
+/// [Matrix Market](https://math.nist.gov/MatrixMarket/formats.html), like the
+/// matrices of the [SuiteSparse Matrix Collection](https://sparse.tamu.edu/).
+///
+/// A vertex is added for each row of the matrix, which must be square, and an
+/// edge for each entry, from the vertex of its row to the vertex of its
+/// column. The vertices are created with the default weight, and the weight of
+/// each edge is created using [`FromAttributes`](trait.FromAttributes.html)
+/// from the value of its entry as the `weight` attribute, which must be valid
+/// for the field of the matrix. Entries of `pattern` matrices have no
+/// attributes.
+///
+/// Undirected graphs must be read from `symmetric` matrices, whose entries
+/// are only given for one of the two vertices. Directed graphs may be read
+/// from either `general` or `symmetric` matrices, with edges in both
+/// directions for entries of the latter.
+/// Complex, skew-symmetric and hermitian matrices aren't supported.
+///
+/// The input is read one line at a time, so that large files can be read
+/// without holding them in memory.
+/// Returns which vertex each row was added as, by its index counting from 1.
+///
+/// ### Usage
+/// ```
+/// # use graphene::{
+/// # 	common::AdjListGraph,
+/// # 	core::{Graph, Undirected},
+/// # 	io::read_matrix_market,
+/// # };
+/// let input = "%%MatrixMarket matrix coordinate integer symmetric
+/// % a path
+/// 3 3 2
+/// 2 1 5
+/// 3 2 7
+/// ";
+/// let (graph, ids) =
+/// 	read_matrix_market::<AdjListGraph<(), i32, Undirected>>(input.as_bytes()).unwrap();
+///
+/// assert_eq!(graph.all_vertices().count(), 3);
+/// assert_eq!(graph.edges_between(ids["1"], ids["2"]).next(), Some(&5));
+/// assert_eq!(graph.edges_between(ids["3"], ids["2"]).next(), Some(&7));
+/// ```
+pub fn read_matrix_market_into<G>(
+	mut input: impl BufRead,
+	g: &mut G,
+) -> Result<NodeIds<G>, ParseError<G::Vertex>>
+where
+	G: NewVertex + AddEdge,
+	G::VertexWeight: Default,
+	G::EdgeWeight: FromAttributes,
+{
+	let mut buffer = String::new();
+	let mut line = 1;
+	if input.read_line(&mut buffer)? == 0
+	{
+		return Err(ParseError::syntax(1, 1, "expected a Matrix Market header"));
+	}
+	let (field, symmetric) = header(&buffer)?;
+	if !symmetric && !G::Directedness::directed()
+	{
+		return Err(ParseError::syntax(
+			1,
+			1,
+			"expected a symmetric matrix for an undirected graph",
+		));
+	}
+
+	let mut ids = NodeIds::<G>::new();
+	let mut vertices = Vec::new();
+	let mut entries = None;
+	let mut read_entries = 0;
+	loop
+	{
+		buffer.clear();
+		if input.read_line(&mut buffer)? == 0
+		{
+			break;
+		}
+		line += 1;
+		let content = buffer.trim_end_matches(&['\n', '\r'][..]);
+		if content.trim().is_empty() || content.starts_with('%')
+		{
+			continue;
+		}
+		let fields = whitespace_fields(content);
+		let end = content.chars().count() + 1;
+		let number = |i: usize, what: &str| {
+			let (column, field) = fields
+				.get(i)
+				.ok_or_else(|| ParseError::syntax(line, end, format!("expected the {}", what)))?;
+			field.parse::<usize>().map_err(|_| {
+				ParseError::syntax(
+					line,
+					*column,
+					format!("expected the {}, found '{}'", what, field),
+				)
+			})
+		};
+
+		if entries.is_none()
+		{
+			let (rows, columns) = (number(0, "rows")?, number(1, "columns")?);
+			entries = Some(number(2, "number of entries")?);
+			if rows != columns
+			{
+				return Err(ParseError::syntax(
+					line,
+					1,
+					"expected a square adjacency matrix",
+				));
+			}
+			for i in 1..=rows
+			{
+				let v = g.new_vertex_weighted(Default::default())?;
+				ids.insert(i.to_string(), v);
+				vertices.push(v);
+			}
+			continue;
+		}
+
+		let vertex = |i, what| {
+			let index = number(i, what)?;
+			if index == 0 || index > vertices.len()
+			{
+				Err(ParseError::syntax(
+					line,
+					fields[i].0,
+					format!("{} {} is out of bounds", what, index),
+				))
+			}
+			else
+			{
+				Ok(vertices[index - 1])
+			}
+		};
+		let (row, column) = (vertex(0, "row")?, vertex(1, "column")?);
+
+		let mut attributes = Attributes::new();
+		if let Some(field) = field
+		{
+			let (expected, valid): (_, fn(&str) -> bool) = if field == "integer"
+			{
+				("an integer", |value| value.parse::<i64>().is_ok())
+			}
+			else
+			{
+				("a real number", |value| value.parse::<f64>().is_ok())
+			};
+			let (column, value) = fields
+				.get(2)
+				.ok_or_else(|| ParseError::syntax(line, end, format!("expected {}", expected)))?;
+			if !valid(value)
+			{
+				return Err(ParseError::syntax(
+					line,
+					*column,
+					format!("expected {}, found '{}'", expected, value),
+				));
+			}
+			attributes.insert("weight".into(), value.to_string());
+		}
+		let weight = || {
+			G::EdgeWeight::from_attributes(&attributes).map_err(|message| {
+				ParseError::Weight {
+					line,
+					column: 1,
+					message,
+				}
+			})
+		};
+
+		g.add_edge_weighted(row, column, weight()?)?;
+		if symmetric && G::Directedness::directed() && row != column
+		{
+			g.add_edge_weighted(column, row, weight()?)?;
+		}
+		read_entries += 1;
+	}
+
+	match entries
+	{
+		None => Err(ParseError::syntax(line + 1, 1, "expected the size line")),
+		Some(entries) if entries != read_entries =>
+		{
+			Err(ParseError::syntax(
+				line + 1,
+				1,
+				format!("expected {} entries, found {}", entries, read_entries),
+			))
+		},
+		_ => Ok(ids),
+	}
+}
+
+/// Reads a graph from its adjacency matrix in the coordinate format of Matrix
+/// Market into a new graph.
+///
+/// See [`read_matrix_market_into`](fn.read_matrix_market_into.html).
+pub fn read_matrix_market<G>(input: impl BufRead) -> ReadResult<G>
+where
+	G: Default + NewVertex + AddEdge,
+	G::VertexWeight: Default,
+	G::EdgeWeight: FromAttributes,
+{
+	let mut g = G::default();
+	let ids = read_matrix_market_into(input, &mut g)?;
+	Ok((g, ids))
+}
+
+/// Reads the header line, returning the field of the entries, unless they
+/// have no values, and whether the matrix is symmetric.
+fn header<V>(line: &str) -> Result<(Option<&'static str>, bool), ParseError<V>>
+{
+	let fields = whitespace_fields(line);
+	let expect = |i: usize, expected: &[&'static str]| {
+		let (column, field) = fields
+			.get(i)
+			.copied()
+			.unwrap_or((line.trim_end().chars().count() + 1, ""));
+		expected
+			.iter()
+			.copied()
+			.find(|e| e.eq_ignore_ascii_case(field))
+			.ok_or_else(|| {
+				ParseError::syntax(
+					1,
+					column,
+					format!("expected {}, found '{}'", expected.join(" or "), field),
+				)
+			})
+	};
+
+	expect(0, &["%%MatrixMarket"])?;
+	expect(1, &["matrix"])?;
+	expect(2, &["coordinate"])?;
+	let field = match expect(3, &["real", "double", "integer", "pattern"])?
+	{
+		"pattern" => None,
+		"integer" => Some("integer"),
+		_ => Some("real"),
+	};
+	let symmetric = expect(4, &["general", "symmetric"])? == "symmetric";
+	Ok((field, symmetric))
+}
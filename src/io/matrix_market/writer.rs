@@ -0,0 +1,123 @@
+use crate::{
+	core::{Directedness, Graph},
+	io::{AttributeType, ToAttributes},
+};
+use std::{
+	collections::HashMap,
+	fmt::{Display, Formatter, Result},
+	hash::Hash,
+};
+
+/// Writes the adjacency matrix of a graph in the coordinate format of
+/// [Matrix Market](https://math.nist.gov/MatrixMarket/formats.html).
+///
+/// Each vertex is given the row and column of its position in
+/// [`all_vertices`](../core/trait.Graph.html#method.all_vertices), counting
+/// from 1, and each edge is written as an entry in the row of its source and
+/// the column of its sink. Undirected graphs are written as symmetric
+/// matrices, with each edge as an entry in the lower triangle.
+///
+/// If the edge weights declare the type of their `weight` attribute using
+/// [`ToAttributes`](trait.ToAttributes.html#method.attribute_types), as
+/// numbers do, the value of each entry is its `weight` attribute, and the
+/// matrix is an `integer` or `real` matrix. Otherwise, the matrix is a
+/// `pattern` matrix, whose entries have no values.
+///
+/// The matrix is written using the [`Display`](https://doc.rust-lang.org/std/fmt/trait.Display.html)
+/// implementation, e.g. with `write!` to a file, in which case it isn't held
+/// in memory.
+///
+/// ### Usage
+/// ```
+/// # use graphene::{
+/// # 	common::AdjListGraph,
+/// # 	core::property::{AddEdge, NewVertex},
+/// # 	io::MatrixMarketWriter,
+/// # };
+/// let mut graph = AdjListGraph::<(), f64>::new();
+/// let v0 = graph.new_vertex().unwrap();
+/// let v1 = graph.new_vertex().unwrap();
+/// graph.add_edge_weighted(&v0, &v1, 0.5).unwrap();
+///
+/// assert_eq!(
+/// 	MatrixMarketWriter::new(&graph).to_string(),
+/// 	"%%MatrixMarket matrix coordinate real general\n\
+/// 	2 2 1\n\
+/// 	1 2 0.5\n"
+/// );
+/// ```
+pub struct MatrixMarketWriter<'a, G: Graph>
+{
+	/// The graph to write
+	graph: &'a G,
+}
+
+impl<'a, G: Graph> MatrixMarketWriter<'a, G>
+{
+	/// Creates a writer of the given graph.
+	pub fn new(graph: &'a G) -> Self
+	{
+		Self { graph }
+	}
+}
+
+impl<'a, G> Display for MatrixMarketWriter<'a, G>
+where
+	G: Graph,
+	G::Vertex: Hash,
+	G::EdgeWeight: ToAttributes,
+{
+	fn fmt(&self, f: &mut Formatter) -> Result
+	{
+		let field = G::EdgeWeight::attribute_types()
+			.into_iter()
+			.find(|(name, _)| name == "weight")
+			.and_then(|(_, attribute_type)| {
+				match attribute_type
+				{
+					AttributeType::Int | AttributeType::Long => Some("integer"),
+					AttributeType::Float | AttributeType::Double => Some("real"),
+					_ => None,
+				}
+			});
+		let directed = G::Directedness::directed();
+		writeln!(
+			f,
+			"%%MatrixMarket matrix coordinate {} {}",
+			field.unwrap_or("pattern"),
+			if directed { "general" } else { "symmetric" }
+		)?;
+
+		let indices: HashMap<_, _> = self
+			.graph
+			.all_vertices()
+			.enumerate()
+			.map(|(i, v)| (v, i + 1))
+			.collect();
+		writeln!(
+			f,
+			"{} {} {}",
+			indices.len(),
+			indices.len(),
+			self.graph.all_edges().count()
+		)?;
+		for (source, sink, w) in self.graph.all_edges()
+		{
+			let (mut row, mut column) = (indices[&source], indices[&sink]);
+			if !directed && row < column
+			{
+				std::mem::swap(&mut row, &mut column);
+			}
+			write!(f, "{} {}", row, column)?;
+			if field.is_some()
+			{
+				if let Some(value) = w.to_attributes().get("weight")
+				{
+					write!(f, " {}", value)?;
+				}
+			}
+			writeln!(f)?;
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,6 @@
+//! The adjacency format of [METIS](http://glaros.dtc.umn.edu/gkhome/metis/metis/overview).
+
+mod reader;
+mod writer;
+
+pub use self::{reader::*, writer::*};
@@ -0,0 +1,299 @@
+use crate::{
+	core::{
+		property::{AddEdge, NewVertex},
+		Graph, Undirected,
+	},
+	io::{whitespace_fields, Attributes, FromAttributes, NodeIds, ParseError, ReadResult},
+};
+use std::{collections::HashMap, io::BufRead};
+
+/// Reads an undirected graph in the adjacency format of
+/// [METIS](http://glaros.dtc.umn.edu/gkhome/metis/metis/overview).
+///
+/// A vertex is added for each vertex line, and an edge for each pair of
+/// vertices listing each other, which they must do equally often and with the
+/// same weights. The weight of each vertex is created using
+/// [`FromAttributes`](trait.FromAttributes.html) from its `size` and `weight`
+/// attributes, if the format gives them. If the vertices have several weights,
+/// they are instead given as `weight1`, `weight2` and so on.
+/// The weight of each edge is created from its `weight` attribute, if the
+/// format gives it.
+///
+/// The input is read one line at a time, so that large files can be read
+/// without holding them in memory.
+/// Returns which vertex each vertex line was added as, by its number counting
+/// from 1.
+///
+/// ### Usage
+/// ```
+/// # use graphene::{
+/// # 	common::AdjListGraph,
+/// # 	core::{Graph, Undirected},
+/// # 	io::read_metis,
+/// # };
+/// let input = "% a triangle with a pendant vertex
+/// 4 4 1
+/// 2 3 3 1
+/// 1 3 3 2
+/// 1 1 2 2 4 5
+/// 3 5
+/// ";
+/// let (graph, ids) = read_metis::<AdjListGraph<(), u32, Undirected>>(input.as_bytes()).unwrap();
+///
+/// assert_eq!(graph.all_edges().count(), 4);
+/// assert_eq!(graph.edges_between(ids["4"], ids["3"]).next(), Some(&5));
+/// ```
+pub fn read_metis_into<G>(
+	mut input: impl BufRead,
+	g: &mut G,
+) -> Result<NodeIds<G>, ParseError<G::Vertex>>
+where
+	G: NewVertex + AddEdge + Graph<Directedness = Undirected>,
+	G::VertexWeight: FromAttributes,
+	G::EdgeWeight: FromAttributes,
+{
+	let mut ids = NodeIds::<G>::new();
+	let mut vertices = Vec::new();
+	// The number of vertices and edges, and whether the vertices have sizes,
+	// how many weights they have, and whether edges have weights.
+	let mut header = None;
+	// The weights of edges listed by their first vertex, but not yet by their
+	// second.
+	let mut pending: HashMap<(usize, usize), Vec<Option<String>>> = HashMap::new();
+	let mut edge_count = 0;
+
+	let mut buffer = String::new();
+	let mut line = 0;
+	loop
+	{
+		buffer.clear();
+		if input.read_line(&mut buffer)? == 0
+		{
+			break;
+		}
+		line += 1;
+		if buffer.starts_with('%')
+		{
+			continue;
+		}
+		let fields = whitespace_fields(&buffer);
+		let end = buffer.trim_end().chars().count() + 1;
+		let error = |column, message: String| ParseError::syntax(line, column, message);
+		let number = |i: usize, what: &str| {
+			let (column, field) = fields
+				.get(i)
+				.ok_or_else(|| error(end, format!("expected {}", what)))?;
+			field
+				.parse::<usize>()
+				.map_err(|_| error(*column, format!("expected {}, found '{}'", what, field)))
+		};
+
+		let (vertex_count, _, sizes, weights, edge_weights) = match header
+		{
+			None if fields.is_empty() => continue,
+			None =>
+			{
+				let format = match fields.get(2)
+				{
+					Some((_, format))
+						if format.len() <= 3 && format.chars().all(|c| c == '0' || c == '1') =>
+					{
+						format!("{:0>3}", format)
+					},
+					Some((column, format)) =>
+					{
+						return Err(error(
+							*column,
+							format!("expected a format, found '{}'", format),
+						))
+					},
+					None => "000".into(),
+				};
+				let has = |i| format.as_bytes()[i] == b'1';
+				let weights = match (has(1), fields.get(3))
+				{
+					(false, _) => 0,
+					(true, None) => 1,
+					(true, Some(_)) => number(3, "the number of vertex weights")?,
+				};
+				if fields.len() > 4
+				{
+					return Err(error(fields[4].0, "unexpected field".into()));
+				}
+				header = Some((
+					number(0, "the number of vertices")?,
+					number(1, "the number of edges")?,
+					has(0),
+					weights,
+					has(2),
+				));
+				continue;
+			},
+			Some(header) => header,
+		};
+
+		let index = vertices.len() + 1;
+		if index > vertex_count
+		{
+			if fields.is_empty()
+			{
+				continue;
+			}
+			return Err(error(
+				fields[0].0,
+				format!("expected {} vertex lines", vertex_count),
+			));
+		}
+
+		let mut attributes = Attributes::new();
+		let mut i = 0;
+		if sizes
+		{
+			attributes.insert("size".into(), number(i, "a size")?.to_string());
+			i += 1;
+		}
+		for weight in 1..=weights
+		{
+			let name = if weights == 1
+			{
+				"weight".to_string()
+			}
+			else
+			{
+				format!("weight{}", weight)
+			};
+			attributes.insert(name, number(i, "a vertex weight")?.to_string());
+			i += 1;
+		}
+		let weight = G::VertexWeight::from_attributes(&attributes).map_err(|message| {
+			ParseError::Weight {
+				line,
+				column: 1,
+				message,
+			}
+		})?;
+		let v = g.new_vertex_weighted(weight)?;
+		ids.insert(index.to_string(), v);
+		vertices.push(v);
+
+		while i < fields.len()
+		{
+			let column = fields[i].0;
+			let neighbor = number(i, "a neighbor")?;
+			i += 1;
+			let edge_weight = if edge_weights
+			{
+				i += 1;
+				Some(number(i - 1, "an edge weight")?.to_string())
+			}
+			else
+			{
+				None
+			};
+			if neighbor == 0 || neighbor > vertex_count
+			{
+				return Err(error(
+					column,
+					format!("vertex {} is out of bounds", neighbor),
+				));
+			}
+			if neighbor == index
+			{
+				return Err(error(column, "loops aren't allowed".into()));
+			}
+			if neighbor > index
+			{
+				pending
+					.entry((index, neighbor))
+					.or_default()
+					.push(edge_weight);
+				continue;
+			}
+
+			// Parallel edges may be listed in different orders by their vertices.
+			match pending.get_mut(&(neighbor, index))
+			{
+				Some(weights) if !weights.is_empty() =>
+				{
+					let position = weights
+						.iter()
+						.position(|listed| *listed == edge_weight)
+						.ok_or_else(|| {
+							error(
+								column,
+								format!("the weight differs from vertex {}", neighbor),
+							)
+						})?;
+					weights.remove(position);
+				},
+				_ =>
+				{
+					return Err(error(
+						column,
+						format!("the edge isn't listed by vertex {}", neighbor),
+					))
+				},
+			}
+			let mut attributes = Attributes::new();
+			if let Some(edge_weight) = edge_weight
+			{
+				attributes.insert("weight".into(), edge_weight);
+			}
+			let weight = G::EdgeWeight::from_attributes(&attributes).map_err(|message| {
+				ParseError::Weight {
+					line,
+					column,
+					message,
+				}
+			})?;
+			g.add_edge_weighted(vertices[neighbor - 1], v, weight)?;
+			edge_count += 1;
+		}
+	}
+
+	let error = |message: String| ParseError::syntax(line + 1, 1, message);
+	let (vertex_count, expected_edges, ..) =
+		header.ok_or_else(|| error("expected the header".into()))?;
+	if vertices.len() < vertex_count
+	{
+		return Err(error(format!(
+			"expected {} vertex lines, found {}",
+			vertex_count,
+			vertices.len()
+		)));
+	}
+	if let Some((v1, v2)) = pending
+		.iter()
+		.filter(|(_, weights)| !weights.is_empty())
+		.map(|(edge, _)| *edge)
+		.min()
+	{
+		return Err(error(format!(
+			"the edge between vertices {} and {} isn't listed by vertex {}",
+			v1, v2, v2
+		)));
+	}
+	if edge_count != expected_edges
+	{
+		return Err(error(format!(
+			"expected {} edges, found {}",
+			expected_edges, edge_count
+		)));
+	}
+	Ok(ids)
+}
+
+/// Reads an undirected graph in the adjacency format of METIS into a new
+/// graph.
+///
+/// See [`read_metis_into`](fn.read_metis_into.html).
+pub fn read_metis<G>(input: impl BufRead) -> ReadResult<G>
+where
+	G: Default + NewVertex + AddEdge + Graph<Directedness = Undirected>,
+	G::VertexWeight: FromAttributes,
+	G::EdgeWeight: FromAttributes,
+{
+	let mut g = G::default();
+	let ids = read_metis_into(input, &mut g)?;
+	Ok((g, ids))
+}
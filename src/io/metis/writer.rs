@@ -0,0 +1,139 @@
+use crate::{
+	core::{Graph, Undirected},
+	io::{AttributeType, Attributes, ToAttributes},
+};
+use std::{
+	collections::HashMap,
+	fmt::{Display, Formatter, Result},
+	hash::Hash,
+};
+
+/// Writes an undirected graph in the adjacency format of
+/// [METIS](http://glaros.dtc.umn.edu/gkhome/metis/metis/overview).
+///
+/// Each vertex is given the number of its position in
+/// [`all_vertices`](../core/trait.Graph.html#method.all_vertices), counting
+/// from 1, and has a line listing its neighbors.
+/// If the vertex or edge weights declare their `weight` attribute as an
+/// integer using
+/// [`ToAttributes`](trait.ToAttributes.html#method.attribute_types),
+/// as integers do, the weights are written too. METIS requires them to be
+/// non-negative.
+///
+/// Since METIS doesn't allow loops, they aren't written.
+///
+/// The graph is written using the [`Display`](https://doc.rust-lang.org/std/fmt/trait.Display.html)
+/// implementation, e.g. with `write!` to a file, in which case it isn't held
+/// in memory.
+///
+/// ### Usage
+/// ```
+/// # use graphene::{
+/// # 	common::AdjListGraph,
+/// # 	core::{property::{AddEdge, NewVertex}, Undirected},
+/// # 	io::MetisWriter,
+/// # };
+/// let mut graph = AdjListGraph::<(), u32, Undirected>::new();
+/// let v0 = graph.new_vertex().unwrap();
+/// let v1 = graph.new_vertex().unwrap();
+/// graph.new_vertex().unwrap();
+/// graph.add_edge_weighted(&v0, &v1, 4).unwrap();
+///
+/// assert_eq!(
+/// 	MetisWriter::new(&graph).to_string(),
+/// 	"3 1 001\n2 4\n1 4\n\n"
+/// );
+/// ```
+pub struct MetisWriter<'a, G: Graph<Directedness = Undirected>>
+{
+	/// The graph to write
+	graph: &'a G,
+}
+
+impl<'a, G: Graph<Directedness = Undirected>> MetisWriter<'a, G>
+{
+	/// Creates a writer of the given graph.
+	pub fn new(graph: &'a G) -> Self
+	{
+		Self { graph }
+	}
+}
+
+/// Returns whether weights of the given type have an integer `weight`
+/// attribute.
+fn has_integer_weight<W: ToAttributes>() -> bool
+{
+	W::attribute_types()
+		.into_iter()
+		.any(|(name, attribute_type)| {
+			name == "weight"
+				&& (attribute_type == AttributeType::Int || attribute_type == AttributeType::Long)
+		})
+}
+
+impl<'a, G> Display for MetisWriter<'a, G>
+where
+	G: Graph<Directedness = Undirected>,
+	G::Vertex: Hash,
+	G::VertexWeight: ToAttributes,
+	G::EdgeWeight: ToAttributes,
+{
+	fn fmt(&self, f: &mut Formatter) -> Result
+	{
+		let vertex_weights = has_integer_weight::<G::VertexWeight>();
+		let edge_weights = has_integer_weight::<G::EdgeWeight>();
+		let indices: HashMap<_, _> = self
+			.graph
+			.all_vertices()
+			.enumerate()
+			.map(|(i, v)| (v, i + 1))
+			.collect();
+		let edges = self
+			.graph
+			.all_edges()
+			.filter(|(source, sink, _)| source != sink)
+			.count();
+		write!(f, "{} {}", indices.len(), edges)?;
+		if vertex_weights || edge_weights
+		{
+			write!(
+				f,
+				" 0{}{}",
+				if vertex_weights { 1 } else { 0 },
+				if edge_weights { 1 } else { 0 }
+			)?;
+		}
+		writeln!(f)?;
+
+		let weight = |attributes: Attributes| {
+			attributes
+				.get("weight")
+				.cloned()
+				.unwrap_or_else(|| "1".into())
+		};
+		for (v, w) in self.graph.all_vertices_weighted()
+		{
+			let mut separator = "";
+			if vertex_weights
+			{
+				write!(f, "{}", weight(w.to_attributes()))?;
+				separator = " ";
+			}
+			for (neighbor, w) in self.graph.edges_incident_on(v)
+			{
+				if neighbor == v
+				{
+					continue;
+				}
+				write!(f, "{}{}", separator, indices[&neighbor])?;
+				separator = " ";
+				if edge_weights
+				{
+					write!(f, " {}", weight(w.to_attributes()))?;
+				}
+			}
+			writeln!(f)?;
+		}
+		Ok(())
+	}
+}
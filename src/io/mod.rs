@@ -0,0 +1,52 @@
+//! Reading and writing graphs in common file formats.
+
+mod attributes;
+mod dimacs;
+mod dot;
+mod edge_list;
+mod error;
+#[cfg(feature = "graphml")]
+mod graphml;
+mod matrix_market;
+mod metis;
+
+#[cfg(feature = "graphml")]
+pub use self::graphml::*;
+pub use self::{
+	attributes::*, dimacs::*, dot::*, edge_list::*, error::*, matrix_market::*, metis::*,
+};
+use crate::core::Graph;
+use std::collections::HashMap;
+
+/// Which vertex each node ID of a read graph was added as.
+pub type NodeIds<G> = HashMap<String, <G as Graph>::Vertex>;
+
+/// The graph read from a file, along with which of its vertices each node ID
+/// was added as.
+type ReadResult<G> = Result<(G, NodeIds<G>), ParseError<<G as Graph>::Vertex>>;
+
+/// Returns the fields of the given line that are separated by whitespace,
+/// along with the column each starts at.
+fn whitespace_fields(line: &str) -> Vec<(usize, &str)>
+{
+	let mut fields = Vec::new();
+	let mut start = None;
+	for (column, (i, c)) in line.char_indices().enumerate()
+	{
+		match (start, c.is_whitespace())
+		{
+			(None, false) => start = Some((column + 1, i)),
+			(Some((field_column, field_start)), true) =>
+			{
+				fields.push((field_column, &line[field_start..i]));
+				start = None;
+			},
+			_ => (),
+		}
+	}
+	if let Some((field_column, field_start)) = start
+	{
+		fields.push((field_column, &line[field_start..]));
+	}
+	fields
+}
@@ -228,7 +228,11 @@
 //
 //
 #![recursion_limit = "256"]
+// Code in doc comments is formatted like the rest of the code, which is
+// indented with tabs
+#![allow(clippy::tabs_in_doc_comments)]
 #[macro_use]
 pub mod core;
 pub mod algo;
 pub mod common;
+pub mod io;
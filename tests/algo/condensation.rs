@@ -0,0 +1,95 @@
+//! Tests `condensation`
+use crate::mock_graph::{arbitrary::Arb, MockGraph};
+use graphene::{
+	algo::{condensation, Dfs},
+	core::{property::VertexInGraph, Directed, Ensure, Graph},
+};
+use std::collections::{HashMap, HashSet};
+
+/// Tests that each vertex is mapped to a component whose weight contains the
+/// vertex.
+#[quickcheck]
+fn maps_each_vertex_to_its_component(Arb(graph): Arb<MockGraph<Directed>>) -> bool
+{
+	let (condensed, components) = condensation(&graph);
+
+	let member_count: usize = condensed
+		.all_vertices_weighted()
+		.map(|(_, members)| members.len())
+		.sum();
+
+	components.len() == graph.all_vertices().count()
+		&& member_count == components.len()
+		&& graph.all_vertices().all(|v| {
+			components
+				.get(&v)
+				.is_some_and(|&c| condensed.vertex_weight(c).unwrap().contains(&v))
+		})
+}
+
+/// Tests that two vertices are in the same component if and only if they can
+/// reach each other.
+#[quickcheck]
+fn components_are_strongly_connected(Arb(graph): Arb<MockGraph<Directed>>) -> bool
+{
+	let (_, components) = condensation(&graph);
+	let reachable: HashMap<_, HashSet<_>> = graph
+		.all_vertices()
+		.map(|v| {
+			let g = VertexInGraph::ensure_unvalidated(&graph, v);
+			(v, Dfs::new_simple(&g).collect())
+		})
+		.collect();
+
+	components.iter().all(|(&v1, &c1)| {
+		components.iter().all(|(&v2, &c2)| {
+			v1 == v2 || (c1 == c2) == (reachable[&v1].contains(&v2) && reachable[&v2].contains(&v1))
+		})
+	})
+}
+
+/// Tests that each edge between two components represents exactly the edges
+/// of the original graph between the members of the components.
+#[quickcheck]
+fn aggregates_edges_between_components(Arb(graph): Arb<MockGraph<Directed>>) -> bool
+{
+	let (condensed, _) = condensation(&graph);
+
+	let result = condensed.all_vertices_weighted().all(|(c1, members1)| {
+		condensed.all_vertices_weighted().all(|(c2, members2)| {
+			let mut edges: Vec<_> = condensed.edges_between(c1, c2).collect();
+			if c1 == c2
+			{
+				return edges.is_empty();
+			}
+			let expected = members1
+				.iter()
+				.flat_map(|&v1| members2.iter().map(move |&v2| (v1, v2)))
+				.map(|(v1, v2)| graph.edges_between(v1, v2).count())
+				.sum::<usize>();
+			match (edges.pop(), expected)
+			{
+				(None, 0) => true,
+				(Some(aggregated), count) =>
+				{
+					edges.is_empty()
+						&& aggregated.len() == count
+						&& aggregated
+							.iter()
+							.all(|(v1, v2)| members1.contains(v1) && members2.contains(v2))
+				},
+				_ => false,
+			}
+		})
+	});
+	result
+}
+
+/// Tests that the condensation of an empty graph is empty.
+#[test]
+fn condenses_empty_graph()
+{
+	let (condensed, components) = condensation(&MockGraph::<Directed>::empty());
+	assert!(condensed.all_vertices().next().is_none());
+	assert!(components.is_empty());
+}
@@ -0,0 +1,92 @@
+//! Tests `ContractionHierarchies`
+use crate::mock_graph::{
+	arbitrary::{Arb, TwoVerticesIn, VertexOutside},
+	MockGraph, MockVertex,
+};
+use graphene::{
+	algo::{ContractionHierarchies, DijkstraShortestPaths},
+	core::{property::VertexInGraph, Directed, Ensure, Graph},
+};
+use std::collections::HashMap;
+
+/// Calculates the shortest distances from the given vertex to all vertices
+/// reachable from it, using `DijkstraShortestPaths`.
+fn dijkstra_distances(graph: &MockGraph<Directed>, source: MockVertex) -> HashMap<MockVertex, u32>
+{
+	let mut distances = HashMap::new();
+	distances.insert(source, 0);
+	let g = VertexInGraph::ensure_unvalidated(graph, source);
+	for (so, si, w) in DijkstraShortestPaths::new(&g, |w| w.value)
+	{
+		let dist = distances[&so] + w.value;
+		distances.insert(si, dist);
+	}
+	distances
+}
+
+/// Tests that the distance between any two vertices is the same as found by
+/// `DijkstraShortestPaths`.
+#[quickcheck]
+fn distances_match_dijkstra(Arb(graph): Arb<MockGraph<Directed>>) -> bool
+{
+	let ch = ContractionHierarchies::new(&graph, |w| w.value);
+
+	graph.all_vertices().all(|source| {
+		let expected = dijkstra_distances(&graph, source);
+		graph
+			.all_vertices()
+			.all(|sink| ch.distance(source, sink) == expected.get(&sink).cloned())
+	})
+}
+
+/// Tests that any produced path is a path in the original graph, from the
+/// source to the sink, with the length reported.
+#[quickcheck]
+fn produces_valid_paths(Arb(mock): Arb<TwoVerticesIn<MockGraph<Directed>>>) -> bool
+{
+	let (source, sink) = mock.get_both();
+	let ch = ContractionHierarchies::new(&mock, |w| w.value);
+
+	if let Some((length, path)) = ch.shortest_path(source, sink)
+	{
+		let mut sum = 0;
+		for pair in path.windows(2)
+		{
+			match mock.edges_between(pair[0], pair[1]).map(|w| w.value).min()
+			{
+				Some(w) => sum += w,
+				None => return false,
+			}
+		}
+		path.first() == Some(&source) && path.last() == Some(&sink) && sum == length
+	}
+	else
+	{
+		ch.distance(source, sink).is_none()
+	}
+}
+
+/// Tests that every rank is assigned to exactly one vertex.
+#[quickcheck]
+fn ranks_are_unique(Arb(graph): Arb<MockGraph<Directed>>) -> bool
+{
+	let ch = ContractionHierarchies::new(&graph, |w| w.value);
+	let mut ranks: Vec<_> = graph.all_vertices().map(|v| ch.rank(v).unwrap()).collect();
+	ranks.sort();
+	ranks.into_iter().eq(0..graph.all_vertices().count())
+}
+
+/// Tests that queries involving vertices not in the original graph find no
+/// path.
+#[quickcheck]
+fn rejects_vertex_outside(
+	Arb(VertexOutside(graph, v)): Arb<VertexOutside<MockGraph<Directed>>>,
+) -> bool
+{
+	let ch = ContractionHierarchies::new(&graph, |w| w.value);
+
+	ch.rank(v).is_none()
+		&& graph
+			.all_vertices()
+			.all(|v2| ch.shortest_path(v, v2).is_none() && ch.shortest_path(v2, v).is_none())
+}
@@ -0,0 +1,151 @@
+//! Tests `JohnsonCycles`
+use crate::mock_graph::{arbitrary::Arb, MockGraph, MockVertex};
+use graphene::{
+	algo::JohnsonCycles,
+	core::{property::AcyclicGraph, Directed, EnsureUnloaded, Graph},
+};
+
+/// The maximum number of cycles to find by brute force before giving up.
+const CYCLE_LIMIT: usize = 50;
+
+/// Returns whether the given vertices form an elementary cycle in the graph.
+fn is_elementary_cycle(graph: &MockGraph<Directed>, cycle: &[MockVertex]) -> bool
+{
+	!cycle.is_empty()
+		&& cycle
+			.iter()
+			.enumerate()
+			.all(|(i, v)| !cycle[i + 1..].contains(v))
+		&& cycle.iter().enumerate().all(|(i, &v)| {
+			graph
+				.edges_between(v, cycle[(i + 1) % cycle.len()])
+				.next()
+				.is_some()
+		})
+}
+
+/// Rotates the given cycle such that it starts with its smallest vertex.
+fn normalize(mut cycle: Vec<MockVertex>) -> Vec<MockVertex>
+{
+	let smallest = (0..cycle.len()).min_by_key(|&i| cycle[i].value).unwrap();
+	cycle.rotate_left(smallest);
+	cycle
+}
+
+/// Counts the elementary cycles that extend the given path and only visit
+/// vertices larger than its first, by brute force.
+///
+/// Returns false if more than `limit` cycles were found, in which case the
+/// search is aborted.
+fn count_cycles(
+	graph: &MockGraph<Directed>,
+	path: &mut Vec<MockVertex>,
+	max_length: usize,
+	count: &mut usize,
+	limit: usize,
+) -> bool
+{
+	let start = path[0];
+	let mut next: Vec<_> = graph
+		.edges_sourced_in(*path.last().unwrap())
+		.map(|(v, _)| v)
+		.collect();
+	next.sort_by_key(|v| v.value);
+	next.dedup();
+	for v in next
+	{
+		if v == start
+		{
+			*count += 1;
+			if *count > limit
+			{
+				return false;
+			}
+		}
+		else if v.value > start.value && !path.contains(&v) && path.len() < max_length
+		{
+			path.push(v);
+			let within_limit = count_cycles(graph, path, max_length, count, limit);
+			path.pop();
+			if !within_limit
+			{
+				return false;
+			}
+		}
+	}
+	true
+}
+
+/// Tests that all produced cycles are elementary cycles in the graph.
+#[quickcheck]
+fn produces_elementary_cycles(Arb(graph): Arb<MockGraph<Directed>>) -> bool
+{
+	JohnsonCycles::new(&graph, None, Some(CYCLE_LIMIT)).all(|c| is_elementary_cycle(&graph, &c))
+}
+
+/// Tests that no cycle is produced twice.
+#[quickcheck]
+fn produces_unique_cycles(Arb(graph): Arb<MockGraph<Directed>>) -> bool
+{
+	let cycles: Vec<_> = JohnsonCycles::new(&graph, None, Some(CYCLE_LIMIT))
+		.map(normalize)
+		.collect();
+	cycles
+		.iter()
+		.enumerate()
+		.all(|(i, c)| !cycles[i + 1..].contains(c))
+}
+
+/// Tests that the number of cycles produced is the same as found by brute
+/// force.
+#[quickcheck]
+fn produces_all_cycles(Arb(graph): Arb<MockGraph<Directed>>) -> bool
+{
+	let mut expected = 0;
+	for v in graph.all_vertices()
+	{
+		if !count_cycles(&graph, &mut vec![v], usize::MAX, &mut expected, CYCLE_LIMIT)
+		{
+			// Too many cycles to check in reasonable time
+			return true;
+		}
+	}
+	JohnsonCycles::new_simple(&graph).count() == expected
+}
+
+/// Tests that when the length of cycles is limited, all cycles within the limit
+/// are produced and no others.
+#[quickcheck]
+fn respects_max_length(Arb(graph): Arb<MockGraph<Directed>>, max_length: u8) -> bool
+{
+	let max_length = (max_length % 5) as usize + 1;
+	let mut expected = 0;
+	for v in graph.all_vertices()
+	{
+		if !count_cycles(&graph, &mut vec![v], max_length, &mut expected, CYCLE_LIMIT)
+		{
+			// Too many cycles to check in reasonable time
+			return true;
+		}
+	}
+	let cycles: Vec<_> = JohnsonCycles::new(&graph, Some(max_length), None).collect();
+	cycles.len() == expected && cycles.iter().all(|c| c.len() <= max_length)
+}
+
+/// Tests that when the number of cycles is limited, no more than that number
+/// of cycles are produced, and fewer only if there are no more cycles.
+#[quickcheck]
+fn respects_max_count(Arb(graph): Arb<MockGraph<Directed>>, max_count: u8) -> bool
+{
+	let max_count = (max_count % 10) as usize;
+	let count = JohnsonCycles::new(&graph, None, Some(max_count)).count();
+	let available = JohnsonCycles::new(&graph, None, Some(max_count + 1)).count();
+	count == std::cmp::min(max_count, available)
+}
+
+/// Tests that cycles are found if and only if the graph isn't acyclic.
+#[quickcheck]
+fn finds_cycle_iff_cyclic(Arb(graph): Arb<MockGraph<Directed>>) -> bool
+{
+	JohnsonCycles::new_simple(&graph).next().is_some() != AcyclicGraph::validate(&&graph)
+}
@@ -1,5 +1,10 @@
 mod bfs;
+mod condensation;
+mod contraction_hierarchies;
 mod dfs;
 mod dijkstra_shortest_paths;
+mod johnson_cycles;
+mod neighborhood;
+mod scc;
 mod search;
-mod tarjan_scc;
+mod yen_shortest_paths;
@@ -0,0 +1,83 @@
+//! Tests `algo::ego_network` and `algo::reachable_subgraph`.
+
+use crate::mock_graph::{arbitrary::Arb, MockGraph, MockVertex};
+use duplicate::duplicate;
+use graphene::{
+	algo::{ego_network, path_exists, reachable_subgraph},
+	core::{
+		property::{HasVertex, VertexInGraph},
+		Directed, Graph, Undirected,
+	},
+};
+use std::collections::{HashMap, VecDeque};
+
+/// Returns the number of hops from the graph's vertex to each vertex it can
+/// reach.
+fn hop_counts<G>(graph: &G) -> HashMap<MockVertex, usize>
+where
+	G: HasVertex<Vertex = MockVertex>,
+{
+	let mut hops = HashMap::new();
+	let mut queue = VecDeque::new();
+	hops.insert(graph.get_vertex(), 0);
+	queue.push_back(graph.get_vertex());
+	while let Some(v) = queue.pop_front()
+	{
+		for (sink, _) in graph.edges_sourced_in(v)
+		{
+			if !hops.contains_key(&sink)
+			{
+				hops.insert(sink, hops[&v] + 1);
+				queue.push_back(sink);
+			}
+		}
+	}
+	hops
+}
+
+#[duplicate(
+	directedness; [ Directed ]; [ Undirected ]
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that the ego network has exactly the vertices within the given
+	/// number of hops, and all the edges between them.
+	#[quickcheck]
+	fn ego_network_within_hops(
+		Arb(graph): Arb<VertexInGraph<MockGraph<directedness>>>,
+		hops: u8,
+	) -> bool
+	{
+		let hops = (hops % 4) as usize;
+		let counts = hop_counts(&graph);
+		let ego = ego_network(&graph, hops);
+
+		graph
+			.all_vertices()
+			.all(|v| ego.contains_vertex(v) == counts.get(&v).is_some_and(|h| *h <= hops))
+			&& ego.all_edges().count()
+				== graph
+					.all_edges()
+					.filter(|(source, sink, _)| {
+						ego.contains_vertex(source) && ego.contains_vertex(sink)
+					})
+					.count()
+	}
+
+	/// Tests that the reachable subgraph has exactly the vertices with a path
+	/// from the graph's vertex.
+	#[quickcheck]
+	fn reachable_subgraph_has_reachable(
+		Arb(graph): Arb<VertexInGraph<MockGraph<directedness>>>,
+	) -> bool
+	{
+		let reachable = reachable_subgraph(&graph);
+
+		graph.all_vertices().all(|v| {
+			reachable.contains_vertex(v)
+				== (v == graph.get_vertex() || path_exists(&graph, graph.get_vertex(), v))
+		})
+	}
+}
@@ -0,0 +1,216 @@
+//! Common tests for all strongly connected component (SCC) algorithms.
+use crate::mock_graph::{arbitrary::Arb, utilities::scales_linearly, MockGraph};
+use duplicate::duplicate;
+use graphene::{
+	algo::{GabowScc, KosarajuScc, TarjanScc},
+	common::AdjListGraph,
+	core::{
+		property::{AddEdge, ConnectedGraph, NewVertex, Subgraph},
+		Directed, EnsureUnloaded, Graph,
+	},
+};
+
+/// Returns a directed path with the given number of vertices.
+fn path(len: usize) -> AdjListGraph<(), ()>
+{
+	let mut g = AdjListGraph::new();
+	let mut last = g.new_vertex().unwrap();
+	for _ in 1..len
+	{
+		let v = g.new_vertex().unwrap();
+		g.add_edge(&last, &v).unwrap();
+		last = v;
+	}
+	g
+}
+
+#[duplicate(
+	module		scc_algo_new;
+	[ tarjan ]		[ TarjanScc::new ];
+	[ gabow ]		[ GabowScc::new ];
+	[ kosaraju ]	[ KosarajuScc::new ]
+)]
+mod module
+{
+	use super::*;
+
+	/// Tests that no SCCs are produced for the empty graph.
+	#[test]
+	fn produces_nothing_for_empty_graph()
+	{
+		assert!(scc_algo_new(&MockGraph::<Directed>::empty())
+			.next()
+			.is_none());
+	}
+
+	/// Tests that no produced SCC is empty
+	#[quickcheck]
+	fn produces_non_empty_components(Arb(graph): Arb<MockGraph<Directed>>) -> bool
+	{
+		for scc in scc_algo_new(&graph)
+		{
+			if scc.all_vertices().count() == 0
+			{
+				return false;
+			}
+		}
+		true
+	}
+
+	/// Tests that any SCC returned is actually strongly connected.
+	#[quickcheck]
+	fn produces_connected_components(Arb(graph): Arb<MockGraph<Directed>>) -> bool
+	{
+		for scc in scc_algo_new(&graph)
+		{
+			if !ConnectedGraph::validate(&scc)
+			{
+				return false;
+			}
+		}
+		true
+	}
+
+	/// Tests that for any SCC pair produced, they are not strongly connected.
+	#[quickcheck]
+	fn produces_disconnected_components(Arb(graph): Arb<MockGraph<Directed>>) -> bool
+	{
+		let sccs = scc_algo_new(&graph).collect::<Vec<_>>();
+		let mut scc_iter = sccs.iter();
+
+		while let Some(scc) = scc_iter.next()
+		{
+			for scc2 in scc_iter.clone()
+			{
+				if scc.reaches(scc2).is_some()
+				{
+					if scc2.reaches(scc).is_some()
+					{
+						return false;
+					}
+				}
+			}
+		}
+		true
+	}
+
+	/// Tests that finding the SCCs of a path, each with a single vertex, takes
+	/// linear time.
+	#[test]
+	fn scales_linearly_on_path()
+	{
+		assert!(scales_linearly(2000, path, |g| {
+			scc_algo_new(g).for_each(|scc| assert_eq!(scc.all_vertices().count(), 1));
+		}));
+	}
+
+	/// Tests that all vertices are put inside some produced SCC.
+	#[quickcheck]
+	fn produces_all_vertices(Arb(graph): Arb<MockGraph<Directed>>) -> bool
+	{
+		// We simply count the vertices since we have another test
+		// for checking that no vertex is reused
+		let mut vertex_count = 0;
+		for scc in scc_algo_new(&graph)
+		{
+			vertex_count += scc.all_vertices().count();
+		}
+		vertex_count == graph.all_vertices().count()
+	}
+
+	/// Tests that all vertices in the components are from the original graph.
+	#[quickcheck]
+	fn produces_only_valid_vertices(Arb(graph): Arb<MockGraph<Directed>>) -> bool
+	{
+		for scc in scc_algo_new(&graph)
+		{
+			for v in scc.all_vertices()
+			{
+				if !graph.contains_vertex(v)
+				{
+					return false;
+				}
+			}
+		}
+		true
+	}
+
+	/// Tests that no two produced SCCs share any vertices
+	#[quickcheck]
+	fn produces_vertex_disjoint_components(Arb(graph): Arb<MockGraph<Directed>>) -> bool
+	{
+		let sccs = scc_algo_new(&graph).collect::<Vec<_>>();
+		let mut scc_iter = sccs.iter();
+
+		while let Some(scc) = scc_iter.next()
+		{
+			for scc2 in scc_iter.clone()
+			{
+				for v in scc.all_vertices()
+				{
+					if scc2.contains_vertex(v)
+					{
+						return false;
+					}
+				}
+			}
+		}
+		true
+	}
+}
+
+#[duplicate(
+	module				scc_algo_new;
+	[ tarjan_ordering ]	[ TarjanScc::new ];
+	[ gabow_ordering ]	[ GabowScc::new ]
+)]
+mod module
+{
+	use super::*;
+
+	/// Tests that the SCCs are produced in some reverse topological order.
+	/// This is a guarantee of Tarjan's and Gabow's algorithms, which means if
+	/// we don't do that, we are not implementing them correctly.
+	#[quickcheck]
+	fn produces_reverse_topological_ordering(Arb(graph): Arb<MockGraph<Directed>>) -> bool
+	{
+		// To test the ordering, we simply check that an earlier-produced component
+		// can't reach any later one.
+		let sccs = scc_algo_new(&graph).collect::<Vec<_>>();
+		let mut scc_iter = sccs.iter();
+
+		while let Some(scc) = scc_iter.next()
+		{
+			for scc2 in scc_iter.clone()
+			{
+				if scc.reaches(scc2).is_some()
+				{
+					return false;
+				}
+			}
+		}
+		true
+	}
+}
+
+/// Tests that `KosarajuScc` produces the SCCs in topological order.
+#[quickcheck]
+fn kosaraju_produces_topological_ordering(Arb(graph): Arb<MockGraph<Directed>>) -> bool
+{
+	// To test the ordering, we simply check that a later-produced component
+	// can't reach any earlier one.
+	let sccs = KosarajuScc::new(&graph).collect::<Vec<_>>();
+	let mut scc_iter = sccs.iter();
+
+	while let Some(scc) = scc_iter.next()
+	{
+		for scc2 in scc_iter.clone()
+		{
+			if scc2.reaches(scc).is_some()
+			{
+				return false;
+			}
+		}
+	}
+	true
+}
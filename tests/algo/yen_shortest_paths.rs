@@ -0,0 +1,171 @@
+//! Tests `YenShortestPaths`
+use crate::mock_graph::{
+	arbitrary::{Arb, TwoVerticesIn},
+	MockGraph, MockVertex,
+};
+use duplicate::duplicate;
+use graphene::{
+	algo::YenShortestPaths,
+	core::{Directed, Graph, Undirected},
+};
+
+/// The number of paths to take from the algorithm in each test.
+const PATH_COUNT: usize = 10;
+
+/// Returns the length of the given path, or `None` if it isn't a path in the
+/// graph.
+fn path_length<G>(graph: &G, path: &[MockVertex]) -> Option<u32>
+where
+	G: Graph<Vertex = MockVertex, EdgeWeight = crate::mock_graph::MockEdgeWeight>,
+{
+	let mut sum = 0;
+	for pair in path.windows(2)
+	{
+		sum += graph
+			.edges_between(pair[0], pair[1])
+			.map(|w| w.value)
+			.min()?;
+	}
+	Some(sum)
+}
+
+/// Finds the lengths of all simple paths from `current` to `sink` that extend
+/// the given path, by brute force.
+///
+/// Returns false if more than `limit` paths were found, in which case the
+/// search is aborted.
+fn all_path_lengths<G>(
+	graph: &G,
+	path: &mut Vec<MockVertex>,
+	sink: MockVertex,
+	lengths: &mut Vec<u32>,
+	limit: usize,
+) -> bool
+where
+	G: Graph<Vertex = MockVertex, EdgeWeight = crate::mock_graph::MockEdgeWeight>,
+{
+	let current = *path.last().unwrap();
+	if current == sink
+	{
+		lengths.push(path_length(graph, path).unwrap());
+		return lengths.len() <= limit;
+	}
+	let mut next: Vec<_> = graph
+		.edges_sourced_in(current)
+		.map(|(v, _)| v)
+		.filter(|v| !path.contains(v))
+		.collect();
+	next.dedup();
+	for v in next
+	{
+		path.push(v);
+		let within_limit = all_path_lengths(graph, path, sink, lengths, limit);
+		path.pop();
+		if !within_limit
+		{
+			return false;
+		}
+	}
+	true
+}
+
+#[duplicate(
+	directedness; [ Directed ]; [ Undirected ]
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that all paths are produced in nondecreasing order of length,
+	/// and that the reported length is correct.
+	#[quickcheck]
+	fn produces_nondecreasing_lengths(
+		Arb(mock): Arb<TwoVerticesIn<MockGraph<directedness>>>,
+	) -> bool
+	{
+		let (source, sink) = mock.get_both();
+		let mut previous = 0;
+		for (length, path) in
+			YenShortestPaths::new(&mock, source, sink, |w| w.value).take(PATH_COUNT)
+		{
+			if length < previous || path_length(&mock, &path) != Some(length)
+			{
+				return false;
+			}
+			previous = length;
+		}
+		true
+	}
+
+	/// Tests that all paths produced are simple paths from the source to the
+	/// sink.
+	#[quickcheck]
+	fn produces_simple_paths(Arb(mock): Arb<TwoVerticesIn<MockGraph<directedness>>>) -> bool
+	{
+		let (source, sink) = mock.get_both();
+		YenShortestPaths::new(&mock, source, sink, |w| w.value)
+			.take(PATH_COUNT)
+			.all(|(_, path)| {
+				path.first() == Some(&source)
+					&& path.last() == Some(&sink)
+					&& path
+						.iter()
+						.enumerate()
+						.all(|(i, v)| !path[i + 1..].contains(v))
+			})
+	}
+
+	/// Tests that no path is produced twice.
+	#[quickcheck]
+	fn produces_unique_paths(Arb(mock): Arb<TwoVerticesIn<MockGraph<directedness>>>) -> bool
+	{
+		let (source, sink) = mock.get_both();
+		let paths: Vec<_> = YenShortestPaths::new(&mock, source, sink, |w| w.value)
+			.take(PATH_COUNT)
+			.map(|(_, path)| path)
+			.collect();
+		paths
+			.iter()
+			.enumerate()
+			.all(|(i, p)| !paths[i + 1..].contains(p))
+	}
+
+	/// Tests that the lengths of the produced paths are the same as the
+	/// lengths of the shortest simple paths found by brute force.
+	#[quickcheck]
+	fn produces_shortest_paths(Arb(mock): Arb<TwoVerticesIn<MockGraph<directedness>>>) -> bool
+	{
+		let (source, sink) = mock.get_both();
+		let mut expected = Vec::new();
+		if !all_path_lengths(
+			&mock,
+			&mut vec![source],
+			sink,
+			&mut expected,
+			2 * PATH_COUNT,
+		)
+		{
+			// Too many paths to check in reasonable time
+			return true;
+		}
+		expected.sort();
+
+		YenShortestPaths::new(&mock, source, sink, |w| w.value)
+			.map(|(length, _)| length)
+			.eq(expected.into_iter())
+	}
+
+	/// Tests that once all paths have been produced, no more are.
+	#[quickcheck]
+	fn fused_when_exhausted(Arb(mock): Arb<TwoVerticesIn<MockGraph<directedness>>>) -> bool
+	{
+		let (source, sink) = mock.get_both();
+		let mut paths = YenShortestPaths::new(&mock, source, sink, |w| w.value);
+		if paths.by_ref().nth(2 * PATH_COUNT).is_some()
+		{
+			// Too many paths to exhaust in reasonable time
+			return true;
+		}
+		paths.next().is_none() && paths.next().is_none()
+	}
+}
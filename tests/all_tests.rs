@@ -7,3 +7,4 @@ mod mock_graph;
 mod algo;
 mod common;
 mod core;
+mod io;
@@ -0,0 +1,81 @@
+//! Tests the `FromIterator` and `Extend` implementations of `AdjListGraph`
+
+use crate::{
+	common::adjacency_list::adj_list_from_mock,
+	mock_graph::{arbitrary::Arb, MockEdgeWeight, MockGraph, MockVertexWeight},
+};
+use duplicate::duplicate;
+use graphene::{
+	common::AdjListGraph,
+	core::{Directed, Graph, GraphElement, Undirected},
+};
+use std::collections::HashMap;
+
+#[duplicate(
+	directedness; [ Directed ]; [ Undirected ]
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that collecting the vertices and then the edges of a graph gives
+	/// the same graph.
+	#[quickcheck]
+	fn collect_graph(Arb(mock): Arb<MockGraph<directedness>>) -> bool
+	{
+		let indices: HashMap<_, _> = mock
+			.all_vertices()
+			.enumerate()
+			.map(|(i, v)| (v, i))
+			.collect();
+		let vertices: Vec<_> = mock.all_vertices().collect();
+		let elements = vertices
+			.iter()
+			.map(|v| GraphElement::Vertex(mock.vertex_weight(v).unwrap().clone()))
+			.chain(mock.all_edges().map(|(source, sink, w)| {
+				GraphElement::Edge(indices[&source], indices[&sink], w.clone())
+			}));
+		let g: AdjListGraph<MockVertexWeight, MockEdgeWeight, directedness> = elements.collect();
+
+		g.all_vertices().count() == vertices.len()
+			&& vertices
+				.iter()
+				.all(|v| g.vertex_weight(indices[v]) == mock.vertex_weight(v))
+			&& vertices.iter().all(|source| {
+				vertices.iter().all(|sink| {
+					g.edges_between(indices[source], indices[sink]).count()
+						== mock.edges_between(source, sink).count()
+				})
+			})
+	}
+
+	/// Tests that extending a graph adds vertices after the existing ones.
+	#[quickcheck]
+	fn extend_graph(
+		Arb(mock): Arb<MockGraph<directedness>>,
+		v_weight: MockVertexWeight,
+		e_weight: MockEdgeWeight,
+	) -> bool
+	{
+		let (mut g, _) = adj_list_from_mock(&mock);
+		let count = g.all_vertices().count();
+		g.extend(vec![
+			GraphElement::Vertex(v_weight.clone()),
+			GraphElement::Edge(count, count, e_weight.clone()),
+		]);
+
+		g.all_vertices().count() == count + 1
+			&& g.vertex_weight(count) == Some(&v_weight)
+			&& g.edges_between(count, count).collect::<Vec<_>>() == vec![&e_weight]
+	}
+}
+
+/// Tests that collecting an edge to a vertex that hasn't been added panics.
+#[test]
+#[should_panic]
+fn collect_missing_vertex()
+{
+	let _: AdjListGraph<(), ()> = vec![GraphElement::Vertex(()), GraphElement::Edge(0, 1, ())]
+		.into_iter()
+		.collect();
+}
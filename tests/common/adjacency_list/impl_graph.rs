@@ -11,7 +11,7 @@ use crate::{
 use duplicate::duplicate;
 use graphene::core::{
 	property::{EdgeCount, HasVertex, RemoveEdge, RemoveVertex, VertexCount, VertexInGraph},
-	Directed, Graph, GraphMut, ReleaseUnloaded, Undirected,
+	Directed, Directedness, Graph, GraphError, GraphMut, ReleaseUnloaded, Undirected,
 };
 
 #[duplicate(
@@ -139,6 +139,54 @@ mod __
 		}
 	}
 
+	/// Tests that removing a vertex moves the following vertices down one
+	/// index, keeping their edges.
+	#[quickcheck]
+	fn remove_vertex_keeps_edges(Arb(mock): Arb<VertexInGraph<MockGraph<directedness>>>) -> bool
+	{
+		let v_remove = mock.get_vertex();
+		let mock = mock.release_all();
+		let (mut g, v_map) = adj_list_from_mock(&mock);
+		let v_removed = v_map[&v_remove];
+		// The endpoints of undirected edges are ordered, since either may be
+		// listed first
+		let ordered = |(source, sink, w)| {
+			if directedness::directed() || source <= sink
+			{
+				(source, sink, w)
+			}
+			else
+			{
+				(sink, source, w)
+			}
+		};
+		let moved = |v| {
+			let v = v_map[&v];
+			if v > v_removed
+			{
+				v - 1
+			}
+			else
+			{
+				v
+			}
+		};
+
+		g.remove_vertex(v_removed).unwrap();
+
+		mock.all_vertices()
+			.filter(|v| *v != v_remove)
+			.all(|v| g.vertex_weight(moved(v)) == mock.vertex_weight(v))
+			&& unordered_equivalent_lists_equal(
+				&mock
+					.all_edges()
+					.filter(|(source, sink, _)| *source != v_remove && *sink != v_remove)
+					.map(|(source, sink, w)| ordered((moved(source), moved(sink), w)))
+					.collect(),
+				&g.all_edges().map(ordered).collect(),
+			)
+	}
+
 	/// Tests removing an edge
 	#[quickcheck]
 	fn remove_edge(Arb(mock): Arb<EdgeIn<MockGraph<directedness>>>) -> bool
@@ -166,4 +214,14 @@ mod __
 			false
 		}
 	}
+
+	/// Tests that removing a vertex that isn't in the graph reports it
+	#[quickcheck]
+	fn remove_missing_vertex(Arb(mock): Arb<MockGraph<directedness>>) -> bool
+	{
+		let (mut g, _) = adj_list_from_mock(&mock);
+		let missing = g.all_vertices().count();
+
+		g.remove_vertex(missing) == Err(GraphError::VertexNotFound(missing))
+	}
 }
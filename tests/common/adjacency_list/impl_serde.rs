@@ -0,0 +1,50 @@
+//! Tests the `Serialize` and `Deserialize` implementations of `AdjListGraph`
+
+use crate::mock_graph::{arbitrary::Arb, MockGraph};
+use duplicate::duplicate;
+use graphene::{
+	common::AdjListGraph,
+	core::{from_graph_mapped, Directed, Graph, Undirected},
+};
+
+#[duplicate(
+	directedness; [ Directed ]; [ Undirected ]
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that deserializing a serialized graph gives the same vertices and
+	/// edges, in the same order.
+	#[quickcheck]
+	fn deserialize_serialized(Arb(mock): Arb<MockGraph<directedness>>) -> bool
+	{
+		let (g, _) = from_graph_mapped::<_, AdjListGraph<u32, u32, directedness>>(
+			&mock,
+			|w| w.value,
+			|w| w.value,
+		)
+		.unwrap();
+		let serialized = serde_json::to_string(&g).unwrap();
+		let deserialized: AdjListGraph<u32, u32, directedness> =
+			serde_json::from_str(&serialized).unwrap();
+
+		g.all_vertices_weighted()
+			.eq(deserialized.all_vertices_weighted())
+			&& g.all_edges().eq(deserialized.all_edges())
+	}
+
+	/// Tests that deserializing fails if an edge is incident on a vertex that
+	/// isn't in the graph.
+	#[test]
+	fn reject_missing_sink()
+	{
+		let result =
+			serde_json::from_str::<AdjListGraph<(), u32, directedness>>("[[null,[[1,5]]]]");
+
+		assert!(result
+			.unwrap_err()
+			.to_string()
+			.starts_with("edge incident on missing vertex 1"));
+	}
+}
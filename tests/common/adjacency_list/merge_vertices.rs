@@ -0,0 +1,161 @@
+//! Tests the `MergeVertices` implementation of `AdjListGraph`
+
+use crate::{
+	common::adjacency_list::adj_list_from_mock,
+	mock_graph::{
+		arbitrary::{Arb, EdgeIn, TwoVerticesIn},
+		MockGraph, MockT,
+	},
+};
+use duplicate::duplicate;
+use graphene::core::{
+	property::{EdgeCount, HasVertex, MergeVertices, VertexCount},
+	Directed, Directedness, Graph, GraphError, ReleaseUnloaded, Undirected,
+};
+
+/// Returns the number of loops that merging the two given vertices should
+/// result in.
+fn expected_loops<G: Graph>(g: &G, v1: G::Vertex, v2: G::Vertex) -> usize
+{
+	let mut count = g.edges_between(v1, v1).count();
+	if v1 == v2
+	{
+		return count;
+	}
+	count += g.edges_between(v2, v2).count() + g.edges_between(v1, v2).count();
+	if G::Directedness::directed()
+	{
+		count += g.edges_between(v2, v1).count();
+	}
+	count
+}
+
+/// Combines two vertex weights
+fn combine(w1: MockT, w2: MockT) -> MockT
+{
+	MockT {
+		value: w1.value.wrapping_add(w2.value),
+	}
+}
+
+#[duplicate(
+	directedness; [Directed]; [Undirected];
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that merging two distinct vertices results in a single vertex
+	/// with the combined weight, and that no edges are lost.
+	#[quickcheck]
+	fn merge_vertices(Arb(mock): Arb<TwoVerticesIn<MockGraph<directedness>>>) -> bool
+	{
+		let (v1, v2) = mock.get_both();
+		let mock = mock.0.release_all();
+		let (mut g, v_map) = adj_list_from_mock(&mock);
+
+		let merged = match g.merge_vertices(v_map[&v1], v_map[&v2], combine)
+		{
+			Ok(merged) => merged,
+			Err(_) => return false,
+		};
+
+		if v1 == v2
+		{
+			g.vertex_count() == mock.vertex_count()
+				&& g.edge_count() == mock.edge_count()
+				&& g.vertex_weight(merged) == mock.vertex_weight(v1)
+		}
+		else
+		{
+			let expected_weight = combine(
+				mock.vertex_weight(v1).unwrap().clone(),
+				mock.vertex_weight(v2).unwrap().clone(),
+			);
+
+			g.vertex_count() == mock.vertex_count() - 1
+				&& g.edge_count() == mock.edge_count()
+				&& g.vertex_weight(merged) == Some(&expected_weight)
+				&& g.edges_between(merged, merged).count() == expected_loops(&mock, v1, v2)
+		}
+	}
+
+	/// Tests that contracting an edge removes it and merges its endpoints.
+	#[quickcheck]
+	fn contract_edge(Arb(mock): Arb<EdgeIn<MockGraph<directedness>>>) -> bool
+	{
+		let source = mock.get_vertex();
+		let EdgeIn(mock, sink, _) = mock;
+		let mock = mock.release_all();
+		let (mut g, v_map) = adj_list_from_mock(&mock);
+
+		let merged = match g.contract_edge(v_map[&source], v_map[&sink], combine)
+		{
+			Ok(merged) => merged,
+			Err(_) => return false,
+		};
+		let expected_vertex_count = if source == sink
+		{
+			mock.vertex_count()
+		}
+		else
+		{
+			mock.vertex_count() - 1
+		};
+
+		g.vertex_count() == expected_vertex_count
+			&& g.edge_count() == mock.edge_count() - 1
+			&& g.edges_between(merged, merged).count() == expected_loops(&mock, source, sink) - 1
+	}
+
+	/// Tests that removing the loops of a merged vertex removes exactly the
+	/// edges that were between the merged vertices.
+	#[quickcheck]
+	fn remove_loops(Arb(mock): Arb<TwoVerticesIn<MockGraph<directedness>>>) -> bool
+	{
+		let (v1, v2) = mock.get_both();
+		let mock = mock.0.release_all();
+		let (mut g, v_map) = adj_list_from_mock(&mock);
+		if v1 == v2
+		{
+			return true;
+		}
+
+		let merged = g.merge_vertices(v_map[&v1], v_map[&v2], combine).unwrap();
+		let removed = g.remove_loops(merged).unwrap();
+
+		removed.len() == expected_loops(&mock, v1, v2)
+			&& g.edges_between(merged, merged).next().is_none()
+			&& g.edge_count() == mock.edge_count() - removed.len()
+	}
+
+	/// Tests that merging parallel edges leaves at most one edge in each
+	/// direction between the vertex and each of its neighbors.
+	#[quickcheck]
+	fn merge_parallel_edges(Arb(mock): Arb<TwoVerticesIn<MockGraph<directedness>>>) -> bool
+	{
+		let (v1, v2) = mock.get_both();
+		let mock = mock.0.release_all();
+		let (mut g, v_map) = adj_list_from_mock(&mock);
+
+		let merged = g.merge_vertices(v_map[&v1], v_map[&v2], combine).unwrap();
+		let neighbors: Vec<_> = g.vertex_neighbors(merged).collect();
+		g.merge_parallel_edges(merged, |w1, w2| combine(w1, w2))
+			.unwrap();
+
+		neighbors.into_iter().all(|neighbor| {
+			g.edges_between(merged, neighbor).count() <= 1
+				&& g.edges_between(neighbor, merged).count() <= 1
+		})
+	}
+
+	/// Tests that merging a vertex that isn't in the graph reports it
+	#[quickcheck]
+	fn merge_missing_vertex(Arb(mock): Arb<MockGraph<directedness>>) -> bool
+	{
+		let (mut g, _) = adj_list_from_mock(&mock);
+		let missing = g.all_vertices().count();
+
+		g.merge_vertices(0, missing, combine) == Err(GraphError::VertexNotFound(missing))
+	}
+}
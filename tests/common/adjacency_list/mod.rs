@@ -2,7 +2,11 @@ use crate::mock_graph::{utilities::auto_copy_from, MockEdgeWeight, MockVertex, M
 use graphene::{common::AdjListGraph, core::Graph};
 use std::collections::HashMap;
 
+mod from_iterator;
 mod impl_graph;
+#[cfg(feature = "serde")]
+mod impl_serde;
+mod merge_vertices;
 
 fn adj_list_from_mock<G>(
 	mock: &G,
@@ -1,2 +1,4 @@
 mod adjacency_list;
 mod ensured;
+#[cfg(feature = "persistent")]
+mod persistent;
@@ -0,0 +1,159 @@
+//! Tests the `Graph` implementation and the functional methods of
+//! `PersistentGraph`
+
+use crate::{
+	common::persistent::persistent_from_mock,
+	mock_graph::{
+		arbitrary::{Arb, EdgeIn, TwoVerticesIn},
+		utilities::unordered_equivalent_lists_equal,
+		MockEdgeWeight, MockGraph, MockVertexWeight,
+	},
+};
+use duplicate::duplicate;
+use graphene::core::{
+	property::{EdgeCount, HasVertex, RemoveVertex, VertexCount, VertexInGraph},
+	Directed, Graph, GraphError, GraphMut, ReleaseUnloaded, Undirected,
+};
+
+#[duplicate(
+	directedness; [Directed]; [Undirected];
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that copying a mock results in the same vertices, with the same
+	/// weights.
+	#[quickcheck]
+	fn same_vertex_weight(Arb(mock): Arb<MockGraph<directedness>>) -> bool
+	{
+		let (g, v_map) = persistent_from_mock(&mock);
+
+		unordered_equivalent_lists_equal(
+			&mock
+				.all_vertices()
+				.map(|v| (v_map[&v], mock.vertex_weight(&v)))
+				.collect(),
+			&g.all_vertices().map(|v| (v, g.vertex_weight(&v))).collect(),
+		)
+	}
+
+	/// Tests that the reference to vertex weights is the same regardless of
+	/// mutability, and that removed vertices aren't contained
+	#[quickcheck]
+	fn same_vertex_weight_mut(Arb(mock): Arb<VertexInGraph<MockGraph<directedness>>>) -> bool
+	{
+		let v = mock.get_vertex();
+		let (mut g, v_map) = persistent_from_mock(&mock.release_all());
+		let v = v_map[&v];
+
+		let same = g.vertex_weight(v).map(|w| w as *const _)
+			== g.vertex_weight_mut(v).map(|w| w as *const _);
+		let contained = g.contains_vertex(v);
+		g.remove_vertex(v).unwrap();
+
+		same && contained && !g.contains_vertex(v) && g.vertex_weight(v).is_none()
+	}
+
+	/// Tests that when we create a PersistentGraph from a MockGraph,
+	/// the edges between any two vertices are the same
+	#[quickcheck]
+	fn edges_between(Arb(mock): Arb<TwoVerticesIn<MockGraph<directedness>>>) -> bool
+	{
+		let (v1, v2) = mock.get_both();
+		let mock = mock.0.release_all();
+		let (g, v_map) = persistent_from_mock(&mock);
+
+		unordered_equivalent_lists_equal(
+			&mock.edges_between(&v1, &v2).collect(),
+			&g.edges_between(&v_map[&v1], &v_map[&v2]).collect(),
+		)
+	}
+
+	/// Tests that the edges incident on a vertex are the same as the mock's
+	#[quickcheck]
+	fn edges_incident_on(Arb(mock): Arb<VertexInGraph<MockGraph<directedness>>>) -> bool
+	{
+		let v = mock.get_vertex();
+		let mock = mock.release_all();
+		let (g, v_map) = persistent_from_mock(&mock);
+
+		g.edge_count() == mock.all_edges().count()
+			&& unordered_equivalent_lists_equal(
+				&mock
+					.edges_incident_on(v)
+					.map(|(other, w)| (v_map[&other], w))
+					.collect(),
+				&g.edges_incident_on(v_map[&v]).collect(),
+			)
+	}
+
+	/// Tests that removing a vertex removes exactly the edges incident on it
+	#[quickcheck]
+	fn remove_vertex(Arb(mock): Arb<VertexInGraph<MockGraph<directedness>>>) -> bool
+	{
+		let v_remove = mock.get_vertex();
+		let mock = mock.release_all();
+		let (mut g, v_map) = persistent_from_mock(&mock);
+
+		g.remove_vertex(v_map[&v_remove]).ok().as_ref() == mock.vertex_weight(v_remove)
+			&& g.vertex_count() == mock.vertex_count() - 1
+			&& g.edge_count() == mock.edge_count() - mock.edges_incident_on(v_remove).count()
+			&& g.all_edges().count() == g.edge_count()
+			&& g.remove_vertex(v_map[&v_remove])
+				== Err(GraphError::VertexNotFound(v_map[&v_remove]))
+	}
+
+	/// Tests that removing an edge results in a version with one less such
+	/// edge
+	#[quickcheck]
+	fn without_edge(Arb(mock): Arb<EdgeIn<MockGraph<directedness>>>) -> bool
+	{
+		let (g, v_map) = persistent_from_mock(&mock);
+		let source = v_map[&mock.get_vertex()];
+		let EdgeIn(_, sink, _) = mock;
+		let sink = v_map[&sink];
+		let edge_count = g.edges_between(source, sink).count();
+
+		match g.without_edge(source, sink)
+		{
+			Ok((removed, _)) =>
+			{
+				removed.edges_between(source, sink).count() == edge_count - 1
+					&& removed.edge_count() == g.edge_count() - 1
+					&& g.edges_between(source, sink).count() == edge_count
+			},
+			Err(_) => false,
+		}
+	}
+
+	/// Tests that new versions don't change the version they were made from
+	#[quickcheck]
+	fn versions_are_independent(
+		Arb(mock): Arb<VertexInGraph<MockGraph<directedness>>>,
+		v_weight: MockVertexWeight,
+		e_weight: MockEdgeWeight,
+	) -> bool
+	{
+		let v = mock.get_vertex();
+		let mock = mock.release_all();
+		let (g, v_map) = persistent_from_mock(&mock);
+		let v = v_map[&v];
+
+		let (added, new_v) = g.with_vertex_weighted(v_weight.clone());
+		let connected = added
+			.with_edge_weighted(v, new_v, e_weight.clone())
+			.unwrap();
+		let (removed, _) = connected.without_vertex(v).unwrap();
+
+		!g.contains_vertex(new_v)
+			&& added.vertex_weight(new_v) == Some(&v_weight)
+			&& added.edges_between(v, new_v).next().is_none()
+			&& connected.edges_between(v, new_v).next() == Some(&e_weight)
+			&& connected.edge_count() == g.edge_count() + 1
+			&& !removed.contains_vertex(v)
+			&& removed.contains_vertex(new_v)
+			&& g.vertex_count() == mock.vertex_count()
+			&& g.edge_count() == mock.edge_count()
+	}
+}
@@ -0,0 +1,19 @@
+use crate::mock_graph::{utilities::auto_copy_from, MockEdgeWeight, MockVertex, MockVertexWeight};
+use graphene::{common::PersistentGraph, core::Graph};
+use std::collections::HashMap;
+
+mod impl_graph;
+
+fn persistent_from_mock<G>(
+	mock: &G,
+) -> (
+	PersistentGraph<MockVertexWeight, MockEdgeWeight, G::Directedness>,
+	HashMap<MockVertex, usize>,
+)
+where
+	G: Graph<Vertex = MockVertex, EdgeWeight = MockEdgeWeight, VertexWeight = MockVertexWeight>,
+{
+	let mut g = PersistentGraph::new();
+	let map = auto_copy_from(&mut g, mock);
+	(g, map)
+}
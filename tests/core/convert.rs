@@ -0,0 +1,91 @@
+//! Tests `core::copy_into` and `core::from_graph`.
+
+use crate::mock_graph::{
+	arbitrary::Arb, utilities::unordered_equivalent_lists_equal, MockEdgeWeight, MockGraph,
+	MockVertexWeight,
+};
+use duplicate::duplicate;
+use graphene::{
+	common::AdjListGraph,
+	core::{
+		copy_into, from_graph, from_graph_mapped, proxy::ReverseGraph, Directed, Graph, Undirected,
+	},
+};
+
+#[duplicate(
+	directedness; [ Directed ]; [ Undirected ]
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that copying a graph gives each vertex a distinct copy with the
+	/// same weight, and copies each edge between the copies of its vertices.
+	#[quickcheck]
+	fn copy_into_same_graph(Arb(g): Arb<MockGraph<directedness>>) -> bool
+	{
+		let mut target = AdjListGraph::<MockVertexWeight, MockEdgeWeight, directedness>::new();
+		let copies = copy_into(&g, &mut target).unwrap();
+
+		target.all_vertices().count() == g.all_vertices().count()
+			&& copies.len() == g.all_vertices().count()
+			&& g.all_vertices_weighted()
+				.all(|(v, w)| target.vertex_weight(copies[&v]) == Some(w))
+			&& g.all_vertices().all(|source| {
+				g.all_vertices().all(|sink| {
+					unordered_equivalent_lists_equal(
+						&g.edges_between(source, sink).collect(),
+						&target
+							.edges_between(copies[&source], copies[&sink])
+							.collect(),
+					)
+				})
+			})
+	}
+
+	/// Tests that copying into a graph that already has vertices keeps them.
+	#[quickcheck]
+	fn copy_into_nonempty(
+		Arb(g1): Arb<MockGraph<directedness>>,
+		Arb(g2): Arb<MockGraph<directedness>>,
+	) -> bool
+	{
+		let (mut target, _) = from_graph::<_, AdjListGraph<_, _, directedness>>(&g1).unwrap();
+		copy_into(&g2, &mut target).unwrap();
+
+		target.all_vertices().count() == g1.all_vertices().count() + g2.all_vertices().count()
+			&& target.all_edges().count() == g1.all_edges().count() + g2.all_edges().count()
+	}
+
+	/// Tests that creating a graph with mapped weights maps each weight.
+	#[quickcheck]
+	fn from_graph_mapped_weights(Arb(g): Arb<MockGraph<directedness>>) -> bool
+	{
+		let (target, copies) = from_graph_mapped::<_, AdjListGraph<_, _, directedness>>(
+			&g,
+			|w| w.value / 2,
+			|w| w.value % 2,
+		)
+		.unwrap();
+
+		g.all_vertices_weighted()
+			.all(|(v, w)| target.vertex_weight(copies[&v]) == Some(&(w.value / 2)))
+			&& unordered_equivalent_lists_equal(
+				&target.all_edges().map(|(_, _, w)| *w).collect(),
+				&g.all_edges().map(|(_, _, w)| w.value % 2).collect(),
+			)
+	}
+}
+
+/// Tests that copying a view of a graph copies what the view presents.
+#[quickcheck]
+fn copy_reversed(Arb(g): Arb<MockGraph<Directed>>) -> bool
+{
+	let (target, copies) = from_graph::<_, AdjListGraph<_, _>>(&ReverseGraph::new(&g)).unwrap();
+
+	g.all_edges().all(|(source, sink, w)| {
+		target
+			.edges_between(copies[&sink], copies[&source])
+			.any(|copied| copied == w)
+	})
+}
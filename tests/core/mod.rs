@@ -1,6 +1,11 @@
 //! Testing for graphene::core.
 
+mod convert;
 mod edge;
 mod ensure;
 mod graph;
+mod operators;
+mod patch;
 mod property;
+mod proxy;
+mod transaction;
@@ -0,0 +1,51 @@
+//! Tests `core::operators::ComplementProxy` and `core::operators::complement`.
+
+use crate::mock_graph::{arbitrary::Arb, MockGraph};
+use duplicate::duplicate;
+use graphene::{
+	common::AdjListGraph,
+	core::{
+		operators::{complement, ComplementProxy},
+		property::{EdgeCount, VertexCount},
+		Directed, Graph, Undirected,
+	},
+};
+
+#[duplicate(
+	directedness; [ Directed ]; [ Undirected ]
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that the complement has an edge between two distinct vertices
+	/// exactly when the graph doesn't.
+	#[quickcheck]
+	fn complement_edges(Arb(g): Arb<MockGraph<directedness>>) -> bool
+	{
+		let proxy = ComplementProxy::new(&g);
+
+		proxy.all_vertices().count() == g.all_vertices().count()
+			&& g.all_vertices().all(|source| {
+				g.all_vertices().all(|sink| {
+					let expected = source != sink && g.edges_between(source, sink).next().is_none();
+					proxy.edges_between(source, sink).count() == expected as usize
+				})
+			})
+	}
+
+	/// Tests that materializing the complement gives a graph with the same
+	/// vertices and number of edges as the proxy.
+	#[quickcheck]
+	fn materialize(Arb(g): Arb<MockGraph<directedness>>) -> bool
+	{
+		let mut target = AdjListGraph::<_, (), directedness>::new();
+		let copies = complement(&g, &mut target).unwrap();
+		let proxy = ComplementProxy::new(&g);
+
+		target.vertex_count() == g.all_vertices().count()
+			&& target.edge_count() == proxy.all_edges().count()
+			&& g.all_vertices_weighted()
+				.all(|(v, w)| target.vertex_weight(copies[&v]) == Some(w))
+	}
+}
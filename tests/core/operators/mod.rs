@@ -0,0 +1,5 @@
+//! Tests the graph operators in `core::operators::*`
+
+mod complement;
+mod product;
+mod union;
@@ -0,0 +1,137 @@
+//! Tests `core::operators::ProductProxy` and `core::operators::product`.
+
+use crate::mock_graph::{arbitrary::Arb, MockGraph};
+use duplicate::duplicate;
+use graphene::{
+	common::AdjListGraph,
+	core::{
+		operators::{product, Product, ProductProxy},
+		property::{EdgeCount, VertexCount},
+		proxy::SubgraphProxy,
+		Directed, Directedness, Graph, Undirected,
+	},
+};
+use std::hash::Hash;
+
+/// Returns the subgraph induced by the first few vertices of the given graph.
+///
+/// Products have as many vertices as their operands combined, so this keeps
+/// the products small enough to test.
+fn small<D: Directedness>(g: &MockGraph<D>) -> SubgraphProxy<&MockGraph<D>>
+{
+	SubgraphProxy::induced(g, g.all_vertices().take(6)).unwrap()
+}
+
+/// Returns the number of edges there should be between the two given vertices
+/// of the given product of the graphs.
+fn expected_count<G: Graph>(
+	g1: &G,
+	g2: &G,
+	kind: Product,
+	(u1, u2): (G::Vertex, G::Vertex),
+	(v1, v2): (G::Vertex, G::Vertex),
+) -> usize
+{
+	let first_count = g1.edges_between(u1, v1).count();
+	let second_count = g2.edges_between(u2, v2).count();
+	let cartesian =
+		if u1 == v1 { second_count } else { 0 } + if u2 == v2 { first_count } else { 0 };
+	let tensor = first_count * second_count;
+	match kind
+	{
+		Product::Cartesian => cartesian,
+		Product::Tensor => tensor,
+		Product::Strong => cartesian + tensor,
+	}
+}
+
+/// Tests that the product has each pair of vertices and the expected number
+/// of edges between them.
+fn check_edges<G: Graph>(g1: &G, g2: &G, kind: Product) -> bool
+{
+	let proxy = ProductProxy::new(g1, g2, kind);
+
+	proxy.all_vertices().count() == g1.all_vertices().count() * g2.all_vertices().count()
+		&& proxy.all_vertices().all(|source| {
+			proxy.edges_sourced_in(source).all(|(sink, _)| {
+				proxy.edges_between(source, sink).count()
+					== expected_count(g1, g2, kind, source, sink)
+			})
+		})
+}
+
+/// Tests that the edges sourced in each vertex of the product are exactly
+/// those found by looking at every pair of vertices.
+fn check_edges_sourced_in<G: Graph>(g1: &G, g2: &G, kind: Product) -> bool
+{
+	let proxy = ProductProxy::new(g1, g2, kind);
+	let vertices: Vec<_> = proxy.all_vertices().collect();
+
+	vertices.iter().all(|source| {
+		let expected: usize = vertices
+			.iter()
+			.map(|sink| expected_count(g1, g2, kind, *source, *sink))
+			.sum();
+		proxy.edges_sourced_in(source).count() == expected
+	})
+}
+
+/// Tests that materializing the product gives a graph with the same number of
+/// vertices and edges as the proxy.
+fn check_materialize<G: Graph>(g1: &G, g2: &G, kind: Product) -> bool
+where
+	G::Vertex: Hash,
+{
+	let mut target = AdjListGraph::<(), (), G::Directedness>::new();
+	product(g1, g2, kind, &mut target).unwrap();
+	let proxy = ProductProxy::new(g1, g2, kind);
+
+	target.vertex_count() == proxy.all_vertices().count()
+		&& target.edge_count() == proxy.all_edges().count()
+}
+
+#[duplicate(
+	module			kind;
+	[ cartesian ]	[ Product::Cartesian ];
+	[ tensor ]		[ Product::Tensor ];
+	[ strong ]		[ Product::Strong ]
+)]
+mod module
+{
+	use super::*;
+
+	#[duplicate(
+		directedness; [ Directed ]; [ Undirected ]
+	)]
+	mod __
+	{
+		use super::*;
+
+		#[quickcheck]
+		fn product_edges(
+			Arb(g1): Arb<MockGraph<directedness>>,
+			Arb(g2): Arb<MockGraph<directedness>>,
+		) -> bool
+		{
+			check_edges(&small(&g1), &small(&g2), kind)
+		}
+
+		#[quickcheck]
+		fn product_edges_sourced_in(
+			Arb(g1): Arb<MockGraph<directedness>>,
+			Arb(g2): Arb<MockGraph<directedness>>,
+		) -> bool
+		{
+			check_edges_sourced_in(&small(&g1), &small(&g2), kind)
+		}
+
+		#[quickcheck]
+		fn materialize(
+			Arb(g1): Arb<MockGraph<directedness>>,
+			Arb(g2): Arb<MockGraph<directedness>>,
+		) -> bool
+		{
+			check_materialize(&small(&g1), &small(&g2), kind)
+		}
+	}
+}
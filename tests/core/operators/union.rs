@@ -0,0 +1,132 @@
+//! Tests `core::operators::{DisjointUnionProxy, UnionProxy, IntersectionProxy}`
+//! and their materializing functions.
+
+use crate::mock_graph::{arbitrary::Arb, MockGraph};
+use duplicate::duplicate;
+use graphene::{
+	common::AdjListGraph,
+	core::{
+		operators::{
+			disjoint_union, intersection, union, DisjointUnionProxy, DisjointVertex,
+			IntersectionProxy, UnionProxy,
+		},
+		property::{EdgeCount, VertexCount},
+		Directed, Graph, Undirected,
+	},
+};
+
+#[duplicate(
+	directedness; [ Directed ]; [ Undirected ]
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that the disjoint union has the vertices and edges of both graphs,
+	/// and no edges between them.
+	#[quickcheck]
+	fn disjoint_union_has_both(
+		Arb(g1): Arb<MockGraph<directedness>>,
+		Arb(g2): Arb<MockGraph<directedness>>,
+	) -> bool
+	{
+		let proxy = DisjointUnionProxy::new(&g1, &g2);
+
+		proxy.all_vertices().count() == g1.all_vertices().count() + g2.all_vertices().count()
+			&& proxy.all_edges().count() == g1.all_edges().count() + g2.all_edges().count()
+			&& g1.all_edges().all(|(source, sink, _)| {
+				proxy
+					.edges_between(DisjointVertex::First(source), DisjointVertex::First(sink))
+					.count() == g1.edges_between(source, sink).count()
+			}) && g2.all_edges().all(|(source, sink, _)| {
+			proxy
+				.edges_between(DisjointVertex::Second(source), DisjointVertex::Second(sink))
+				.count() == g2.edges_between(source, sink).count()
+		}) && g1.all_vertices().all(|v1| {
+			g2.all_vertices()
+				.all(|v2| !proxy.neighbors(DisjointVertex::First(v1), DisjointVertex::Second(v2)))
+		})
+	}
+
+	/// Tests that the union has the vertices of either graph, and the edges of
+	/// the first graph, or the second if the first has none between two
+	/// vertices.
+	#[quickcheck]
+	fn union_has_either(
+		Arb(g1): Arb<MockGraph<directedness>>,
+		Arb(g2): Arb<MockGraph<directedness>>,
+	) -> bool
+	{
+		let proxy = UnionProxy::new(&g1, &g2);
+
+		proxy
+			.all_vertices()
+			.all(|v| g1.contains_vertex(v) || g2.contains_vertex(v))
+			&& g1.all_vertices().all(|v| proxy.contains_vertex(v))
+			&& g2.all_vertices().all(|v| proxy.contains_vertex(v))
+			&& proxy.all_vertices().all(|source| {
+				proxy.all_vertices().all(|sink| {
+					let expected = match g1.edges_between(source, sink).count()
+					{
+						0 => g2.edges_between(source, sink).count(),
+						count => count,
+					};
+					proxy.edges_between(source, sink).count() == expected
+				})
+			})
+	}
+
+	/// Tests that the intersection has the vertices of both graphs, and the
+	/// edges of the first graph where both graphs have edges.
+	#[quickcheck]
+	fn intersection_has_both(
+		Arb(g1): Arb<MockGraph<directedness>>,
+		Arb(g2): Arb<MockGraph<directedness>>,
+	) -> bool
+	{
+		let proxy = IntersectionProxy::new(&g1, &g2);
+
+		g1.all_vertices()
+			.all(|v| proxy.contains_vertex(v) == g2.contains_vertex(v))
+			&& proxy.all_vertices().all(|v| g1.contains_vertex(v))
+			&& proxy.all_vertices().all(|source| {
+				proxy.all_vertices().all(|sink| {
+					let expected = match g2.edges_between(source, sink).count()
+					{
+						0 => 0,
+						_ => g1.edges_between(source, sink).count(),
+					};
+					proxy.edges_between(source, sink).count() == expected
+				})
+			})
+	}
+
+	/// Tests that materializing the operators gives graphs with the same
+	/// number of vertices and edges as their proxies.
+	#[quickcheck]
+	fn materialize(
+		Arb(g1): Arb<MockGraph<directedness>>,
+		Arb(g2): Arb<MockGraph<directedness>>,
+	) -> bool
+	{
+		let mut disjoint = AdjListGraph::<_, _, directedness>::new();
+		disjoint_union(&g1, &g2, &mut disjoint).unwrap();
+		let mut unioned = AdjListGraph::<_, _, directedness>::new();
+		let copies = union(&g1, &g2, &mut unioned).unwrap();
+		let mut intersected = AdjListGraph::<_, _, directedness>::new();
+		intersection(&g1, &g2, &mut intersected).unwrap();
+
+		let union_proxy = UnionProxy::new(&g1, &g2);
+		let intersection_proxy = IntersectionProxy::new(&g1, &g2);
+
+		disjoint.vertex_count() == g1.all_vertices().count() + g2.all_vertices().count()
+			&& disjoint.edge_count() == g1.all_edges().count() + g2.all_edges().count()
+			&& unioned.vertex_count() == union_proxy.all_vertices().count()
+			&& unioned.edge_count() == union_proxy.all_edges().count()
+			&& union_proxy
+				.all_vertices_weighted()
+				.all(|(v, w)| unioned.vertex_weight(copies[&v]) == Some(w))
+			&& intersected.vertex_count() == intersection_proxy.all_vertices().count()
+			&& intersected.edge_count() == intersection_proxy.all_edges().count()
+	}
+}
@@ -0,0 +1,146 @@
+//! Tests `core::diff` and `core::apply_patch`.
+
+use crate::mock_graph::{
+	arbitrary::{Arb, EdgeIn},
+	MockEdgeWeight, MockGraph, MockVertexWeight,
+};
+use duplicate::duplicate;
+use graphene::{
+	common::AdjListGraph,
+	core::{
+		apply_patch, diff,
+		property::{AddEdge, HasVertex, NewVertex, RemoveEdge, RemoveVertex, VertexInGraph},
+		Directed, Graph, GraphMut, GraphPatch, ReleaseUnloaded, Undirected,
+	},
+};
+
+#[duplicate(
+	directedness; [ Directed ]; [ Undirected ]
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that a graph has no differences with itself.
+	#[quickcheck]
+	fn diff_self_is_empty(Arb(g): Arb<MockGraph<directedness>>) -> bool
+	{
+		diff(&g, &g).is_empty()
+	}
+
+	/// Tests that applying the difference between two versions of a graph
+	/// turns the first into the second.
+	#[quickcheck]
+	fn apply_diff(
+		Arb(g): Arb<EdgeIn<MockGraph<directedness>>>,
+		v_weight: MockVertexWeight,
+		e_weight: MockEdgeWeight,
+	) -> bool
+	{
+		let source = g.get_vertex();
+		let EdgeIn(g, sink, _) = g;
+		let mut old = g.release_all();
+		let mut new = old.clone();
+
+		new.remove_edge(source, sink).unwrap();
+		let added = new.new_vertex_weighted(v_weight.clone()).unwrap();
+		new.add_edge_weighted(source, added, e_weight).unwrap();
+		let changed = *new.vertex_weight(sink).unwrap() != v_weight;
+		*new.vertex_weight_mut(sink).unwrap() = v_weight;
+
+		let patch = diff(&old, &new);
+		let expected = patch.added_vertices.len() == 1
+			&& patch.added_edges.len() == 1
+			&& patch.removed_edges.len() == 1
+			&& patch.changed_vertices.len() == changed as usize;
+		let new_vertices = apply_patch(&mut old, patch).unwrap();
+
+		expected && new_vertices == vec![added] && diff(&old, &new).is_empty()
+	}
+
+	/// Tests that removing a vertex also removes its edges in the patch.
+	#[quickcheck]
+	fn apply_vertex_removal(Arb(g): Arb<VertexInGraph<MockGraph<directedness>>>) -> bool
+	{
+		let v = g.get_vertex();
+		let mut old = g.release_all();
+		let mut new = old.clone();
+		new.remove_vertex(v).unwrap();
+
+		let patch = diff(&old, &new);
+		let expected = patch.removed_vertices == vec![v]
+			&& patch.removed_edges.len() == old.edges_incident_on(v).count()
+			&& patch.added_edges.is_empty();
+		apply_patch(&mut old, patch).unwrap();
+
+		expected && diff(&old, &new).is_empty()
+	}
+}
+
+/// Returns the edges of the graph, with their vertices given by their weights.
+fn weighted_edges(g: &AdjListGraph<u32, u32>) -> Vec<(u32, u32, u32)>
+{
+	let mut edges: Vec<_> = g
+		.all_edges()
+		.map(|(source, sink, w)| {
+			(
+				*g.vertex_weight(source).unwrap(),
+				*g.vertex_weight(sink).unwrap(),
+				*w,
+			)
+		})
+		.collect();
+	edges.sort();
+	edges
+}
+
+/// Tests applying a patch to a graph that renumbers its vertices when one is
+/// removed.
+#[test]
+fn apply_renumbered_vertices()
+{
+	let mut g = AdjListGraph::<u32, u32>::new();
+	let v: Vec<_> = (0..5).map(|w| g.new_vertex_weighted(w).unwrap()).collect();
+	g.add_edge_weighted(v[1], v[4], 14).unwrap();
+	g.add_edge_weighted(v[2], v[3], 23).unwrap();
+	g.add_edge_weighted(v[3], v[4], 34).unwrap();
+
+	let patch = GraphPatch {
+		added_vertices: vec![(5, 5)],
+		removed_vertices: vec![v[0], v[2]],
+		changed_vertices: vec![(v[3], 33)],
+		added_edges: vec![(v[1], v[3], 13), (5, v[4], 54)],
+		removed_edges: vec![(v[2], v[3], 23)],
+	};
+	let new_vertices = apply_patch(&mut g, patch).unwrap();
+
+	assert_eq!(
+		g.all_vertex_weights().copied().collect::<Vec<_>>(),
+		vec![1, 33, 4, 5]
+	);
+	assert_eq!(new_vertices, vec![3]);
+	assert_eq!(
+		weighted_edges(&g),
+		vec![(1, 4, 14), (1, 33, 13), (5, 4, 54), (33, 4, 34)]
+	);
+}
+
+/// Tests that deserializing a serialized patch gives the same patch.
+#[cfg(feature = "serde")]
+#[test]
+fn deserialize_serialized()
+{
+	let patch: GraphPatch<usize, u32, u32> = GraphPatch {
+		added_vertices: vec![(3, 30)],
+		removed_vertices: vec![0],
+		changed_vertices: vec![(1, 10)],
+		added_edges: vec![(1, 3, 13)],
+		removed_edges: vec![(0, 1, 1)],
+	};
+
+	let serialized = serde_json::to_string(&patch).unwrap();
+	assert_eq!(
+		serde_json::from_str::<GraphPatch<_, _, _>>(&serialized).unwrap(),
+		patch
+	);
+}
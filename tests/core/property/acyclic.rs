@@ -7,9 +7,10 @@ use crate::mock_graph::{
 use duplicate::duplicate;
 use graphene::core::{
 	property::{Acyclic, AcyclicGraph, AddEdge, HasVertex, NoLoops, RemoveEdge},
-	Directed, EnsureUnloaded, Graph, ReleaseUnloaded, Undirected,
+	Directed, Directedness, EnsureUnloaded, Graph, GraphError, ReleaseUnloaded, Undirected,
 };
 use static_assertions::assert_impl_all;
+use std::collections::HashSet;
 
 #[duplicate(
 	directedness; [ Directed ]; [ Undirected ]
@@ -33,6 +34,49 @@ mod __
 		!AcyclicGraph::validate(&g.0)
 	}
 
+	/// Tests that diagnosing a cyclic graph gives a cycle in it.
+	#[quickcheck]
+	fn diagnose_cyclic(g: Arb<CyclicGraph<directedness>>) -> bool
+	{
+		let g = g.0;
+		match AcyclicGraph::diagnose(&g)
+		{
+			Err(GraphError::PropertyViolated {
+				property: "Acyclic",
+				witness,
+			}) =>
+			{
+				// Undirected cycles of two vertices need two edges between them
+				let min_edges = if witness.len() == 2 && !directedness::directed()
+				{
+					2
+				}
+				else
+				{
+					1
+				};
+				let distinct = witness.iter().collect::<HashSet<_>>().len() == witness.len();
+				distinct
+					&& witness.iter().enumerate().all(|(i, v)| {
+						let next = witness[(i + 1) % witness.len()];
+						g.edges_between(v, next).count() >= min_edges
+					})
+			},
+			_ => false,
+		}
+	}
+
+	/// Tests that ensuring a cyclic graph reports the violated property.
+	#[quickcheck]
+	fn ensure_cyclic_reports_property(g: Arb<CyclicGraph<directedness>>) -> bool
+	{
+		match AcyclicGraph::ensure(g.0)
+		{
+			Err(GraphError::PropertyViolated { property, .. }) => property == "Acyclic",
+			_ => false,
+		}
+	}
+
 	/// Tests that a AcyclicGraph accepts adding an edge that doesn't
 	/// result in a cycle
 	#[quickcheck]
@@ -64,7 +108,11 @@ mod __
 
 		let mut g = AcyclicGraph::ensure_unvalidated(graph.release_all());
 
-		g.add_edge_weighted(v2, v1, weight).is_err() && g.all_edges().count() == edge_count
+		let expected = GraphError::PropertyViolated {
+			property: "Acyclic",
+			witness: vec![v2, v1],
+		};
+		g.add_edge_weighted(v2, v1, weight) == Err(expected) && g.all_edges().count() == edge_count
 	}
 
 	assert_impl_all!(AcyclicGraph<MockGraph<directedness>>: Acyclic, NoLoops);
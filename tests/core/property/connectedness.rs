@@ -5,12 +5,16 @@ use crate::mock_graph::{
 	MockDirectedness, MockEdgeWeight, MockGraph, MockVertexWeight,
 };
 use duplicate::duplicate;
-use graphene::core::{
-	property::{
-		AddEdge, Connected, ConnectedGraph, HasVertex, NewVertex, RemoveEdge, RemoveVertex,
-		Unilateral, UnilateralGraph, VertexInGraph, Weak, WeakGraph,
+use graphene::{
+	algo::path_exists,
+	core::{
+		property::{
+			AddEdge, Connected, ConnectedGraph, HasVertex, NewVertex, RemoveEdge, RemoveVertex,
+			Unilateral, UnilateralGraph, VertexInGraph, Weak, WeakGraph,
+		},
+		proxy::UndirectedProxy,
+		Directed, EnsureUnloaded, Graph, GraphError, ReleaseUnloaded, Undirected,
 	},
-	Directed, EnsureUnloaded, ReleaseUnloaded, Undirected,
 };
 use static_assertions::assert_impl_all;
 
@@ -226,6 +230,83 @@ mod module
 	}
 }
 
+#[duplicate(
+	module					directedness;
+	[diagnose_directed]		[Directed];
+	[diagnose_undirected]	[Undirected]
+)]
+mod module
+{
+	use super::*;
+
+	/// Tests that diagnosing an unconnected graph gives two vertices, such
+	/// that the first can't reach the second.
+	#[quickcheck]
+	fn diagnose_unconnected(Arb(g): Arb<UnconnectedGraph<directedness>>) -> bool
+	{
+		let g = g.release_all();
+		match ConnectedGraph::diagnose(&g)
+		{
+			Err(GraphError::PropertyViolated {
+				property: "Connected",
+				witness,
+			}) => witness.len() == 2 && !path_exists(&g, witness[0], witness[1]),
+			_ => false,
+		}
+	}
+}
+
+/// Tests that diagnosing a directed graph that isn't unilaterally connected
+/// gives two vertices, such that neither can reach the other.
+#[quickcheck]
+fn diagnose_unilateral(Arb(g): Arb<MockGraph<Directed>>) -> bool
+{
+	match UnilateralGraph::diagnose(&g)
+	{
+		Ok(()) =>
+		{
+			g.all_vertices().all(|v1| {
+				g.all_vertices()
+					.all(|v2| v1 == v2 || path_exists(&g, v1, v2) || path_exists(&g, v2, v1))
+			})
+		},
+		Err(GraphError::PropertyViolated {
+			property: "Unilateral",
+			witness,
+		}) =>
+		{
+			witness.len() == 2
+				&& !path_exists(&g, witness[0], witness[1])
+				&& !path_exists(&g, witness[1], witness[0])
+		},
+		_ => false,
+	}
+}
+
+/// Tests that diagnosing a directed graph that isn't weakly connected gives two
+/// vertices, such that there is no path between them even when ignoring edge
+/// directions.
+#[quickcheck]
+fn diagnose_weak(Arb(g): Arb<MockGraph<Directed>>) -> bool
+{
+	let undirected = UndirectedProxy::new(&g);
+	match WeakGraph::diagnose(&g)
+	{
+		Ok(()) =>
+		{
+			g.all_vertices().all(|v1| {
+				g.all_vertices()
+					.all(|v2| v1 == v2 || path_exists(&undirected, v1, v2))
+			})
+		},
+		Err(GraphError::PropertyViolated {
+			property: "Weak",
+			witness,
+		}) => witness.len() == 2 && !path_exists(&undirected, witness[0], witness[1]),
+		_ => false,
+	}
+}
+
 // Test that all Connected graphs are also unilateral and weak.
 assert_impl_all!(ConnectedGraph<MockGraph<MockDirectedness>>: Connected, Unilateral, Weak);
 
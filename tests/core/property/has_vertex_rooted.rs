@@ -9,7 +9,7 @@ use graphene::core::{
 	property::{
 		HasVertex, HasVertexGraph, NewVertex, RemoveVertex, Rooted, RootedGraph, VertexInGraph,
 	},
-	Directed, Undirected,
+	Directed, GraphError, Undirected,
 };
 
 #[duplicate(
@@ -90,6 +90,18 @@ mod __
 			!GraphStruct::validate(&g.0, &g.1)
 		}
 
+		/// Tests that diagnosing a vertex not in the graph gives it as the
+		/// witness.
+		#[quickcheck]
+		fn diagnose_not_in_graph(Arb(g): Arb<VertexOutside<MockGraph<directedness>>>) -> bool
+		{
+			match GraphStruct::diagnose(&g.0, &g.1)
+			{
+				Err(GraphError::PropertyViolated { witness, .. }) => witness == vec![g.1],
+				_ => false,
+			}
+		}
+
 		/// Tests that can remove a vertex if its not the one guaranteed by
 		/// the graph
 		#[quickcheck]
@@ -0,0 +1,304 @@
+//! Tests `core::property::HistoryGraph`.
+
+use crate::mock_graph::{
+	arbitrary::{Arb, EdgeIn, TwoReachableVerticesIn},
+	utilities::unordered_equivalent_lists_equal,
+	MockEdgeWeight, MockGraph, MockT, MockVertex, MockVertexWeight,
+};
+use duplicate::duplicate;
+use graphene::{
+	common::AdjListGraph,
+	core::{
+		property::{
+			AcyclicGraph, AddEdge, HasVertex, History, HistoryGraph, NewVertex, RemoveEdge,
+			RemoveVertex, VertexInGraph,
+		},
+		Directed, Directedness, EnsureUnloaded, Graph, GraphError, ReleaseUnloaded, Undirected,
+	},
+};
+
+/// Returns the edges of the graph, with the endpoints of undirected edges
+/// ordered.
+fn edges<D: Directedness>(
+	g: &impl Graph<Directedness = D, Vertex = MockVertex, EdgeWeight = MockEdgeWeight>,
+) -> Vec<(usize, usize, MockT)>
+{
+	g.all_edges()
+		.map(|(source, sink, w)| {
+			if D::directed() || source.value <= sink.value
+			{
+				(source.value, sink.value, w.clone())
+			}
+			else
+			{
+				(sink.value, source.value, w.clone())
+			}
+		})
+		.collect()
+}
+
+/// Returns the vertex weights and edge weights of the graph.
+fn weights(
+	g: &impl Graph<VertexWeight = MockVertexWeight, EdgeWeight = MockEdgeWeight>,
+) -> (Vec<MockT>, Vec<MockT>)
+{
+	(
+		g.all_vertex_weights().cloned().collect(),
+		g.all_edges().map(|(_, _, w)| w.clone()).collect(),
+	)
+}
+
+/// Returns whether the two graphs have the same vertex and edge weights.
+fn equal_weights(
+	(vertices1, edges1): &(Vec<MockT>, Vec<MockT>),
+	(vertices2, edges2): &(Vec<MockT>, Vec<MockT>),
+) -> bool
+{
+	unordered_equivalent_lists_equal(vertices1, vertices2)
+		&& unordered_equivalent_lists_equal(edges1, edges2)
+}
+
+#[duplicate(
+	directedness; [ Directed ]; [ Undirected ]
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that undoing edge mutations restores the graph, and that redoing
+	/// them mutates it again.
+	#[quickcheck]
+	fn undo_redo_edges(Arb(g): Arb<EdgeIn<MockGraph<directedness>>>, weight: MockEdgeWeight)
+		-> bool
+	{
+		let source = g.get_vertex();
+		let EdgeIn(g, sink, _) = g;
+		let mut g = HistoryGraph::ensure(g.release_all()).unwrap();
+		let before = edges(&g);
+
+		g.remove_edge(source, sink).unwrap();
+		g.add_edge_weighted(sink, source, weight).unwrap();
+		let after = edges(&g);
+
+		let undone = g.undo() == Ok(true) && g.undo() == Ok(true) && g.undo() == Ok(false);
+		let restored = unordered_equivalent_lists_equal(&edges(&g), &before);
+		let redone = g.redo() == Ok(true) && g.redo() == Ok(true) && g.redo() == Ok(false);
+
+		undone && restored && redone && unordered_equivalent_lists_equal(&edges(&g), &after)
+	}
+
+	/// Tests that undoing the removal of a vertex restores it and its edges.
+	#[quickcheck]
+	fn undo_redo_vertex_removal(Arb(g): Arb<VertexInGraph<MockGraph<directedness>>>) -> bool
+	{
+		let v = g.get_vertex();
+		let mut g = HistoryGraph::ensure(g.release_all()).unwrap();
+		let before = weights(&g);
+
+		g.remove_vertex(v).unwrap();
+		let after = weights(&g);
+
+		g.undo().unwrap();
+		let restored = equal_weights(&weights(&g), &before);
+		g.redo().unwrap();
+
+		restored && equal_weights(&weights(&g), &after)
+	}
+
+	/// Tests that undoing a vertex removal remaps the history to the restored
+	/// vertex.
+	#[quickcheck]
+	fn undo_remaps_vertex(
+		Arb(g): Arb<VertexInGraph<MockGraph<directedness>>>,
+		v_weight: MockVertexWeight,
+		e_weight: MockEdgeWeight,
+	) -> bool
+	{
+		let v = g.get_vertex();
+		let mut g = HistoryGraph::ensure(g.release_all()).unwrap();
+		let before = weights(&g);
+
+		let new_v = g.new_vertex_weighted(v_weight).unwrap();
+		g.add_edge_weighted(v, new_v, e_weight).unwrap();
+		g.remove_vertex(new_v).unwrap();
+		let after = weights(&g);
+
+		// Undoing the removal restores the vertex, which the following undos
+		// must refer to
+		let undone = (0..3).all(|_| g.undo() == Ok(true));
+		let restored = equal_weights(&weights(&g), &before);
+		let redone = (0..3).all(|_| g.redo() == Ok(true));
+
+		undone && restored && redone && equal_weights(&weights(&g), &after)
+	}
+
+	/// Tests reverting to checkpoints in either direction.
+	#[quickcheck]
+	fn revert_to_checkpoint(
+		Arb(g): Arb<MockGraph<directedness>>,
+		v_weight: MockVertexWeight,
+		e_weight: MockEdgeWeight,
+	) -> bool
+	{
+		let mut g = HistoryGraph::ensure(g).unwrap();
+		let start = weights(&g);
+		g.checkpoint("start");
+
+		let v = g.new_vertex_weighted(v_weight).unwrap();
+		g.add_edge_weighted(v, v, e_weight.clone()).unwrap();
+		g.add_edge_weighted(v, v, e_weight).unwrap();
+		let end = weights(&g);
+		g.checkpoint("end");
+
+		g.revert_to("start").unwrap();
+		let at_start =
+			equal_weights(&weights(&g), &start) && !g.contains_vertex(v) && !g.can_undo();
+		g.revert_to("end").unwrap();
+
+		at_start && equal_weights(&weights(&g), &end) && !g.can_redo()
+	}
+
+	/// Tests that mutating after undoing discards the undone mutations and the
+	/// checkpoints after them.
+	#[quickcheck]
+	fn mutation_discards_redo(
+		Arb(g): Arb<MockGraph<directedness>>,
+		v_weight: MockVertexWeight,
+	) -> bool
+	{
+		let mut g = HistoryGraph::ensure(g).unwrap();
+		g.checkpoint("start");
+		g.new_vertex_weighted(v_weight.clone()).unwrap();
+		g.checkpoint("one vertex");
+
+		g.undo().unwrap();
+		g.new_vertex_weighted(v_weight).unwrap();
+
+		!g.can_redo()
+			&& g.revert_to("one vertex") == Err(GraphError::Unsupported)
+			&& g.revert_to("start").is_ok()
+	}
+
+	/// Tests that undoing and redoing is performed through the underlying
+	/// ensurers.
+	#[quickcheck]
+	fn undo_through_ensurer(
+		Arb(g): Arb<VertexInGraph<AcyclicGraph<MockGraph<directedness>>>>,
+	) -> bool
+	{
+		let v = g.get_vertex();
+		let mut g = HistoryGraph::ensure(g.release()).unwrap();
+		let before = weights(&g);
+
+		g.remove_vertex(v).unwrap();
+		let undone = g.undo() == Ok(true);
+		let graph = g.release_all();
+
+		undone && equal_weights(&weights(&graph), &before) && AcyclicGraph::ensure(graph).is_ok()
+	}
+
+	/// Tests that an undo that would violate the property of an ensurer
+	/// wrapping the history graph is refused.
+	#[quickcheck]
+	fn outer_ensurer_refuses_undo(
+		Arb(g): Arb<TwoReachableVerticesIn<AcyclicGraph<MockGraph<directedness>>>>,
+		weight: MockEdgeWeight,
+	) -> bool
+	{
+		let (v1, v2) = g.0.get_both();
+		let mut g = HistoryGraph::ensure(g.release_all()).unwrap();
+
+		// The cycle is made before the graph is ensured to be acyclic
+		g.add_edge_weighted(v2, v1, weight.clone()).unwrap();
+		g.remove_edge_where_weight(v2, v1, |w| *w == weight)
+			.unwrap();
+		let mut g = AcyclicGraph::ensure(g).unwrap();
+		let before = edges(&g);
+
+		let refused = match g.undo()
+		{
+			Err(GraphError::PropertyViolated { property, .. }) => property == "Acyclic",
+			_ => false,
+		};
+
+		refused
+			&& unordered_equivalent_lists_equal(&edges(&g), &before)
+			&& g.can_undo()
+			&& !g.can_redo()
+	}
+}
+
+/// Tests undoing and redoing on a graph that renumbers its vertices when one
+/// is removed.
+#[test]
+fn undo_redo_renumbered_vertices()
+{
+	let mut g = HistoryGraph::ensure(AdjListGraph::<u32, u32>::new()).unwrap();
+	let v0 = g.new_vertex_weighted(0).unwrap();
+	let v1 = g.new_vertex_weighted(1).unwrap();
+	let v2 = g.new_vertex_weighted(2).unwrap();
+	g.add_edge_weighted(v1, v2, 12).unwrap();
+	g.add_edge_weighted(v2, v0, 20).unwrap();
+	// The vertices after the removed one move down one index
+	g.remove_vertex(v0).unwrap();
+
+	let edges = |g: &HistoryGraph<AdjListGraph<u32, u32>>| {
+		let mut edges: Vec<_> = g
+			.all_edges()
+			.map(|(source, sink, w)| {
+				(
+					*g.vertex_weight(source).unwrap(),
+					*g.vertex_weight(sink).unwrap(),
+					*w,
+				)
+			})
+			.collect();
+		edges.sort();
+		edges
+	};
+	let after = edges(&g);
+
+	assert_eq!(g.undo(), Ok(true));
+	assert_eq!(edges(&g), vec![(1, 2, 12), (2, 0, 20)]);
+	assert_eq!(g.undo(), Ok(true));
+	assert_eq!(g.undo(), Ok(true));
+	assert_eq!(edges(&g), vec![]);
+	assert_eq!(g.all_vertices().count(), 3);
+
+	assert_eq!(g.redo(), Ok(true));
+	assert_eq!(g.redo(), Ok(true));
+	assert_eq!(g.redo(), Ok(true));
+	assert_eq!(edges(&g), after);
+	assert_eq!(after, vec![(1, 2, 12)]);
+}
+
+/// Tests that an ensurer wrapping a history graph that renumbers its vertices
+/// validates undos and redos.
+#[test]
+fn outer_ensurer_renumbered_vertices()
+{
+	let mut g = HistoryGraph::ensure(AdjListGraph::<u32, ()>::new()).unwrap();
+	let v0 = g.new_vertex_weighted(0).unwrap();
+	let v1 = g.new_vertex_weighted(1).unwrap();
+	let v2 = g.new_vertex_weighted(2).unwrap();
+	g.add_edge(v1, v2).unwrap();
+	g.add_edge(v2, v1).unwrap();
+	g.checkpoint("cyclic");
+	g.remove_edge(v2, v1).unwrap();
+	let mut g = AcyclicGraph::ensure(g).unwrap();
+	// The vertices after the removed one move down one index
+	g.remove_vertex(v0).unwrap();
+	g.checkpoint("removed");
+
+	// Restoring the removed vertex is fine, but restoring the cycle isn't
+	assert_eq!(g.undo(), Ok(true));
+	assert!(g.undo().is_err());
+	assert!(g.revert_to("cyclic").is_err());
+
+	g.revert_to("removed").unwrap();
+	let (v1, v2) = (0, 1);
+	assert_eq!(g.all_vertices().count(), 2);
+	assert_eq!(g.vertex_weight(v1), Some(&1));
+	assert_eq!(g.edges_between(v1, v2).count(), 1);
+	assert_eq!(g.edges_between(v2, v1).count(), 0);
+}
@@ -0,0 +1,122 @@
+//! Tests the `Serialize` and `Deserialize` implementations of ensurers
+
+use crate::mock_graph::{
+	arbitrary::{Arb, CyclicGraph},
+	utilities::unordered_equivalent_lists_equal,
+	MockGraph,
+};
+use duplicate::duplicate;
+use graphene::{
+	common::AdjListGraph,
+	core::{
+		from_graph_mapped,
+		property::{AcyclicGraph, HasVertex, Rooted, RootedGraph, VertexInGraph},
+		Directed, Ensure, Graph, ReleaseUnloaded, Undirected,
+	},
+};
+
+#[duplicate(
+	directedness; [ Directed ]; [ Undirected ]
+)]
+mod __
+{
+	use super::*;
+
+	type TestGraph = AdjListGraph<u32, u32, directedness>;
+
+	/// Copies the given mock graph, returning the copy of the given vertex.
+	fn copy(
+		mock: &MockGraph<directedness>,
+		v: <MockGraph<directedness> as Graph>::Vertex,
+	) -> (TestGraph, usize)
+	{
+		let (g, map) = from_graph_mapped(mock, |w| w.value, |w| w.value).unwrap();
+		(g, map[&v])
+	}
+
+	/// Tests that deserializing a serialized acyclic graph gives an acyclic
+	/// graph with the same edges.
+	#[quickcheck]
+	fn accept_acyclic(Arb(mock): Arb<AcyclicGraph<MockGraph<directedness>>>) -> bool
+	{
+		let (g, _) =
+			from_graph_mapped::<_, TestGraph>(&mock.release_all(), |w| w.value, |w| w.value)
+				.unwrap();
+		let serialized = serde_json::to_string(&g).unwrap();
+
+		match serde_json::from_str::<AcyclicGraph<TestGraph>>(&serialized)
+		{
+			Ok(deserialized) =>
+			{
+				unordered_equivalent_lists_equal(
+					&g.all_edges().collect(),
+					&deserialized.all_edges().collect(),
+				)
+			},
+			Err(_) => false,
+		}
+	}
+
+	/// Tests that deserializing a cyclic graph as an acyclic graph fails,
+	/// naming the property and a cycle witnessing its violation.
+	#[quickcheck]
+	fn reject_cyclic(Arb(mock): Arb<CyclicGraph<directedness>>) -> bool
+	{
+		let (g, _) = from_graph_mapped::<_, TestGraph>(&mock.0, |w| w.value, |w| w.value).unwrap();
+		let serialized = serde_json::to_string(&g).unwrap();
+		let expected = match AcyclicGraph::ensure(g, ())
+		{
+			Err(error) => error.to_string(),
+			Ok(_) => return false,
+		};
+
+		serde_json::from_str::<AcyclicGraph<TestGraph>>(&serialized).is_err_and(|error| {
+			error.to_string() == expected
+				&& expected.starts_with("Property 'Acyclic' violated by [")
+		})
+	}
+
+	/// Tests that deserializing a serialized rooted graph keeps its root.
+	#[quickcheck]
+	fn keep_root(Arb(mock): Arb<VertexInGraph<MockGraph<directedness>>>) -> bool
+	{
+		let root = mock.get_vertex();
+		let (g, root) = copy(&mock.release_all(), root);
+		let rooted = RootedGraph::ensure(g, root).unwrap();
+		let serialized = serde_json::to_string(&rooted).unwrap();
+
+		serde_json::from_str::<RootedGraph<TestGraph>>(&serialized)
+			.unwrap()
+			.root() == root
+			&& serde_json::from_str::<VertexInGraph<TestGraph>>(&serialized)
+				.unwrap()
+				.get_vertex()
+				== root
+	}
+
+	/// Tests that deserializing a vertex that isn't in the graph fails.
+	#[quickcheck]
+	fn reject_missing_vertex(Arb(mock): Arb<MockGraph<directedness>>) -> bool
+	{
+		let (g, _) = from_graph_mapped::<_, TestGraph>(&mock, |w| w.value, |w| w.value).unwrap();
+		let missing = g.all_vertices().count();
+		let serialized = serde_json::to_string(&(g, missing)).unwrap();
+
+		let expected = format!("Property 'VertexInGraph' violated by [{}]", missing);
+		serde_json::from_str::<VertexInGraph<TestGraph>>(&serialized)
+			.is_err_and(|error| error.to_string() == expected)
+			&& serde_json::from_str::<RootedGraph<TestGraph>>(&serialized).is_err()
+	}
+
+	/// Tests that deserializing a stack of ensurers validates every level.
+	#[quickcheck]
+	fn reject_inner_level(Arb(mock): Arb<CyclicGraph<directedness>>) -> bool
+	{
+		let v = mock.0.all_vertices().next().unwrap();
+		let (g, v) = copy(&mock.0, v);
+		let serialized = serde_json::to_string(&(g, v)).unwrap();
+
+		serde_json::from_str::<VertexInGraph<TestGraph>>(&serialized).is_ok()
+			&& serde_json::from_str::<RootedGraph<AcyclicGraph<TestGraph>>>(&serialized).is_err()
+	}
+}
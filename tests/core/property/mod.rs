@@ -3,4 +3,8 @@
 mod acyclic;
 mod connectedness;
 mod has_vertex_rooted;
+mod history;
+#[cfg(feature = "serde")]
+mod impl_serde;
+mod observed;
 mod unique;
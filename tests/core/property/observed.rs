@@ -0,0 +1,161 @@
+//! Tests `core::property::ObservedGraph`.
+
+use crate::mock_graph::{
+	arbitrary::{Arb, EdgeIn},
+	MockEdgeWeight, MockGraph, MockT, MockVertex, MockVertexWeight,
+};
+use duplicate::duplicate;
+use graphene::core::{
+	property::{
+		AddEdge, GraphEvent, HasVertex, NewVertex, ObservedGraph, RemoveEdge, RemoveVertex,
+		VertexInGraph,
+	},
+	Directed, Directedness, EnsureUnloaded, Graph, GraphMut, ReleaseUnloaded, Undirected,
+};
+use std::{cell::RefCell, rc::Rc};
+
+/// The name, vertices, and weight of an observed event.
+type Recorded = (&'static str, Vec<MockVertex>, MockT);
+
+/// Observes the given graph, returning the events it is notified of.
+fn observe<D: Directedness>(
+	graph: MockGraph<D>,
+) -> (ObservedGraph<MockGraph<D>>, Rc<RefCell<Vec<Recorded>>>)
+{
+	let mut observed = ObservedGraph::ensure(graph).unwrap();
+	let events = Rc::new(RefCell::new(Vec::new()));
+	let recorder = events.clone();
+	observed.add_listener(move |event| {
+		recorder.borrow_mut().push(match event
+		{
+			GraphEvent::VertexAdded(v, w) => ("VertexAdded", vec![v], w.clone()),
+			GraphEvent::VertexRemoved(v, w) => ("VertexRemoved", vec![v], w.clone()),
+			GraphEvent::EdgeAdded(so, si, w) => ("EdgeAdded", vec![so, si], w.clone()),
+			GraphEvent::EdgeRemoved(so, si, w) => ("EdgeRemoved", vec![so, si], w.clone()),
+			GraphEvent::VertexWeightMutable(v, w) => ("VertexWeightMutable", vec![v], w.clone()),
+			GraphEvent::EdgeWeightMutable(so, si, w) =>
+			{
+				("EdgeWeightMutable", vec![so, si], w.clone())
+			},
+		})
+	});
+	(observed, events)
+}
+
+#[duplicate(
+	directedness; [ Directed ]; [ Undirected ]
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that listeners are notified of added and removed vertices and
+	/// edges, in order.
+	#[quickcheck]
+	fn notifies_additions_and_removals(
+		Arb(g): Arb<VertexInGraph<MockGraph<directedness>>>,
+		v_weight: MockVertexWeight,
+		e_weight: MockEdgeWeight,
+	) -> bool
+	{
+		let v = g.get_vertex();
+		let (mut g, events) = observe(g.release_all());
+
+		let new_v = g.new_vertex_weighted(v_weight.clone()).unwrap();
+		g.add_edge_weighted(v, new_v, e_weight.clone()).unwrap();
+		g.remove_edge(v, new_v).unwrap();
+		g.remove_vertex(new_v).unwrap();
+
+		let expected = vec![
+			("VertexAdded", vec![new_v], v_weight.clone()),
+			("EdgeAdded", vec![v, new_v], e_weight.clone()),
+			("EdgeRemoved", vec![v, new_v], e_weight),
+			("VertexRemoved", vec![new_v], v_weight),
+		];
+		let result = *events.borrow() == expected;
+		result
+	}
+
+	/// Tests that listeners aren't notified of failed mutations.
+	#[quickcheck]
+	fn ignores_failed_mutations(Arb(g): Arb<EdgeIn<MockGraph<directedness>>>) -> bool
+	{
+		let source = g.get_vertex();
+		let EdgeIn(g, sink, _) = g;
+		let (mut g, events) = observe(g.release_all());
+
+		g.remove_vertex(source).unwrap();
+		let count = events.borrow().len();
+		let failed = g.remove_vertex(source).is_err() && g.remove_edge(source, sink).is_err();
+
+		let result = failed && events.borrow().len() == count;
+		result
+	}
+
+	/// Tests that removing a vertex notifies the removal of each incident edge
+	/// before the removal of the vertex.
+	#[quickcheck]
+	fn notifies_incident_edges_removed(Arb(g): Arb<EdgeIn<MockGraph<directedness>>>) -> bool
+	{
+		let v = g.get_vertex();
+		let g = g.release_all();
+		let v_weight = g.vertex_weight(v).unwrap().clone();
+		let mut incident: Vec<_> = g
+			.edges_sourced_in(v)
+			.map(|(si, w)| ("EdgeRemoved", vec![v, si], w.clone()))
+			.collect();
+		if directedness::directed()
+		{
+			incident.extend(
+				g.edges_sinked_in(v)
+					.filter(|(so, _)| *so != v)
+					.map(|(so, w)| ("EdgeRemoved", vec![so, v], w.clone())),
+			);
+		}
+		incident.push(("VertexRemoved", vec![v], v_weight));
+		let (mut g, events) = observe(g);
+
+		g.remove_vertex(v).unwrap();
+
+		let result = *events.borrow() == incident;
+		result
+	}
+
+	/// Tests that listeners aren't notified of the removal of the edges
+	/// incident on a vertex the underlying graph refuses to remove.
+	#[quickcheck]
+	fn ignores_refused_vertex_removal(Arb(g): Arb<EdgeIn<MockGraph<directedness>>>) -> bool
+	{
+		let v = g.get_vertex();
+		let mut g = ObservedGraph::ensure(g.release()).unwrap();
+		let events = Rc::new(RefCell::new(0));
+		let counter = events.clone();
+		g.add_listener(move |_| *counter.borrow_mut() += 1);
+
+		let refused = g.remove_vertex(v).is_err();
+
+		let result = refused && *events.borrow() == 0 && g.contains_vertex(v);
+		result
+	}
+
+	/// Tests that listeners are notified of each weight handed out mutably.
+	#[quickcheck]
+	fn notifies_mutable_weights(Arb(g): Arb<EdgeIn<MockGraph<directedness>>>) -> bool
+	{
+		let source = g.get_vertex();
+		let EdgeIn(g, sink, _) = g;
+		let (mut g, events) = observe(g.release_all());
+
+		let vertex_count = g.all_vertices_weighted_mut().count();
+		let edge_count = g.edges_between_mut(source, sink).count();
+
+		let events = events.borrow();
+		events.len() == vertex_count + edge_count
+			&& events[..vertex_count]
+				.iter()
+				.all(|(name, _, _)| *name == "VertexWeightMutable")
+			&& events[vertex_count..]
+				.iter()
+				.all(|(name, vs, _)| *name == "EdgeWeightMutable" && *vs == vec![source, sink])
+	}
+}
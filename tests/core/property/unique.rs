@@ -6,7 +6,7 @@ use crate::mock_graph::{
 use duplicate::duplicate;
 use graphene::core::{
 	property::{AddEdge, HasVertex, NewVertex, UniqueGraph, VertexInGraph},
-	Directed, EnsureUnloaded, Graph, ReleaseUnloaded, Undirected,
+	Directed, EnsureUnloaded, Graph, GraphError, ReleaseUnloaded, Undirected,
 };
 
 #[duplicate(
@@ -30,6 +30,30 @@ mod __
 		!UniqueGraph::validate(&g.0)
 	}
 
+	/// Tests that diagnosing a non-unique graph gives the endpoints of a
+	/// duplicate edge.
+	#[quickcheck]
+	fn diagnose_non_unique(g: Arb<NonUniqueGraph<directedness>>) -> bool
+	{
+		match UniqueGraph::diagnose(&g.0)
+		{
+			Err(GraphError::PropertyViolated {
+				property: "Unique",
+				witness,
+			}) => witness.len() == 2 && g.0.edges_between(witness[0], witness[1]).count() >= 2,
+			_ => false,
+		}
+	}
+
+	/// Tests that ensuring a non-unique graph fails with the same error as
+	/// diagnosing it.
+	#[quickcheck]
+	fn ensure_non_unique_diagnosed(g: Arb<NonUniqueGraph<directedness>>) -> bool
+	{
+		let diagnosed = UniqueGraph::diagnose(&g.0);
+		UniqueGraph::ensure(g.0).err() == diagnosed.err()
+	}
+
 	/// Tests that a UniqueGraph accepts adding a non-duplicate edge
 	#[quickcheck]
 	fn accept_add_edge(
@@ -55,6 +79,10 @@ mod __
 	{
 		let source = g.get_vertex();
 		let EdgeIn(mut g, sink, _) = g;
-		g.add_edge_weighted(source, sink, weight).is_err()
+		let expected = GraphError::PropertyViolated {
+			property: "Unique",
+			witness: vec![source, sink],
+		};
+		g.add_edge_weighted(source, sink, weight) == Err(expected)
 	}
 }
@@ -0,0 +1,115 @@
+//! Tests `core::proxy::ContractedProxy`.
+
+use crate::mock_graph::{
+	arbitrary::{Arb, TwoVerticesIn},
+	MockGraph,
+};
+use duplicate::duplicate;
+use graphene::core::{
+	proxy::ContractedProxy, Directed, Directedness, Graph, GraphError, ReleaseUnloaded, Undirected,
+};
+
+#[duplicate(
+	directedness; [ Directed ]; [ Undirected ]
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that merging two distinct vertices hides the second one, keeps
+	/// the weight of the first, and turns the edges between them into loops.
+	#[quickcheck]
+	fn merge_vertices(Arb(mock): Arb<TwoVerticesIn<MockGraph<directedness>>>) -> bool
+	{
+		let (v1, v2) = mock.get_both();
+		let g = mock.0.release_all();
+		let mut proxy = ContractedProxy::new(&g);
+
+		let merged = proxy.merge_vertices(v1, v2).unwrap();
+		let mut expected_loops = g.edges_between(v1, v1).count();
+		if v1 != v2
+		{
+			expected_loops += g.edges_between(v2, v2).count() + g.edges_between(v1, v2).count();
+			if directedness::directed()
+			{
+				expected_loops += g.edges_between(v2, v1).count();
+			}
+		}
+		let expected_vertex_count = g.all_vertices().count() - if v1 == v2 { 0 } else { 1 };
+
+		merged == v1
+			&& proxy.representative(v2) == v1
+			&& proxy.all_vertices().count() == expected_vertex_count
+			&& proxy.vertex_weight(merged) == g.vertex_weight(v1)
+			&& proxy.edges_between(merged, merged).count() == expected_loops
+			&& proxy.all_edges().count() == g.all_edges().count()
+	}
+
+	/// Tests that the edges between a merged vertex and any other vertex are
+	/// the edges between the other vertex and either of the merged vertices.
+	#[quickcheck]
+	fn merged_edges_between(Arb(mock): Arb<TwoVerticesIn<MockGraph<directedness>>>) -> bool
+	{
+		let (v1, v2) = mock.get_both();
+		let g = mock.0.release_all();
+		let mut proxy = ContractedProxy::new(&g);
+		proxy.merge_vertices(v1, v2).unwrap();
+
+		let others: Vec<_> = g.all_vertices().filter(|v| *v != v1 && *v != v2).collect();
+		others.into_iter().all(|v| {
+			let expected_out = g.edges_between(v1, v).count()
+				+ if v1 == v2
+				{
+					0
+				}
+				else
+				{
+					g.edges_between(v2, v).count()
+				};
+			let expected_in = g.edges_between(v, v1).count()
+				+ if v1 == v2
+				{
+					0
+				}
+				else
+				{
+					g.edges_between(v, v2).count()
+				};
+
+			proxy.edges_between(v1, v).count() == expected_out
+				&& proxy.edges_between(v, v1).count() == expected_in
+		})
+	}
+
+	/// Tests that contracting a missing edge reports it and merges nothing.
+	#[quickcheck]
+	fn contract_missing_edge(Arb(mock): Arb<TwoVerticesIn<MockGraph<directedness>>>) -> bool
+	{
+		let (v1, v2) = mock.get_both();
+		let g = mock.0.release_all();
+		let mut proxy = ContractedProxy::new(&g);
+
+		if g.edges_between(v1, v2).next().is_some()
+		{
+			proxy.contract_edge(v1, v2) == Ok(v1)
+		}
+		else
+		{
+			proxy.contract_edge(v1, v2) == Err(GraphError::EdgeNotFound(v1, v2))
+				&& proxy.all_vertices().count() == g.all_vertices().count()
+		}
+	}
+
+	/// Tests that merging a vertex that has already been merged into another
+	/// reports it as missing.
+	#[quickcheck]
+	fn merge_hidden_vertex(Arb(mock): Arb<TwoVerticesIn<MockGraph<directedness>>>) -> bool
+	{
+		let (v1, v2) = mock.get_both();
+		let g = mock.0.release_all();
+		let mut proxy = ContractedProxy::new(&g);
+		proxy.merge_vertices(v1, v2).unwrap();
+
+		v1 == v2 || proxy.merge_vertices(v2, v1) == Err(GraphError::VertexNotFound(v2))
+	}
+}
@@ -0,0 +1,111 @@
+//! Tests `core::proxy::EdgeProxyGraph`.
+
+use crate::mock_graph::{
+	arbitrary::{Arb, EdgeIn},
+	MockGraph,
+};
+use duplicate::duplicate;
+use graphene::{
+	common::AdjListGraph,
+	core::{
+		property::{AddEdge, HasVertex, NewVertex, RemoveVertex},
+		proxy::EdgeProxyGraph,
+		Directed, Directedness, Graph, ReleaseUnloaded, Undirected,
+	},
+};
+
+#[duplicate(
+	directedness; [ Directed ]; [ Undirected ]
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that removing an edge through the proxy hides exactly one edge,
+	/// whichever direction it is removed and listed in.
+	#[quickcheck]
+	fn removes_one_edge(Arb(g): Arb<EdgeIn<MockGraph<directedness>>>, reverse: bool) -> bool
+	{
+		let (source, sink, weight) = (g.get_vertex(), g.1, g.2.clone());
+		let graph = g.0.release_all();
+		let mut proxy = EdgeProxyGraph::new(&graph);
+		let (so, si) = if reverse && !directedness::directed()
+		{
+			(sink, source)
+		}
+		else
+		{
+			(source, sink)
+		};
+		proxy
+			.discard_edge_where_weight(so, si, |w| w.value == weight.value)
+			.unwrap();
+
+		let hidden = |a, b| {
+			((a, b) == (source, sink) || (!directedness::directed() && (a, b) == (sink, source)))
+				as usize
+		};
+
+		[(source, sink), (sink, source)].iter().all(|&(a, b)| {
+			proxy.edges_between(a, b).count() + hidden(a, b) == graph.edges_between(a, b).count()
+				&& proxy.edges_sourced_in(a).filter(|(u, _)| *u == b).count() + hidden(a, b)
+					== graph.edges_sourced_in(a).filter(|(u, _)| *u == b).count()
+		}) && proxy.all_edges().count() + 1 == graph.all_edges().count()
+	}
+}
+
+/// Tests that removing one of several edges with zero-sized weights only hides
+/// one of them.
+#[test]
+fn removes_one_of_identical_edges()
+{
+	let mut graph = AdjListGraph::<(), ()>::new();
+	let v0 = graph.new_vertex().unwrap();
+	let v1 = graph.new_vertex().unwrap();
+	graph.add_edge(&v0, &v1).unwrap();
+	graph.add_edge(&v0, &v1).unwrap();
+
+	let mut proxy = EdgeProxyGraph::new(&graph);
+	proxy.discard_edge_where_weight(v0, v1, |_| true).unwrap();
+	assert_eq!(proxy.edges_between(v0, v1).count(), 1);
+	assert_eq!(proxy.edges_sourced_in(v0).count(), 1);
+
+	proxy.discard_edge_where_weight(v0, v1, |_| true).unwrap();
+	assert_eq!(proxy.edges_between(v0, v1).count(), 0);
+	assert!(proxy.discard_edge_where_weight(v0, v1, |_| true).is_err());
+	assert_eq!(graph.edges_between(v0, v1).count(), 2);
+}
+
+/// Tests that the removed and added edges follow the vertices the underlying
+/// graph renumbers when removing a vertex.
+#[test]
+fn remove_renumbered_vertex()
+{
+	let mut graph = AdjListGraph::<(), u32>::new();
+	let v0 = graph.new_vertex().unwrap();
+	let v1 = graph.new_vertex().unwrap();
+	let v2 = graph.new_vertex().unwrap();
+	graph.add_edge_weighted(&v1, &v2, 1).unwrap();
+	graph.add_edge_weighted(&v1, &v2, 2).unwrap();
+	graph.add_edge_weighted(&v0, &v1, 3).unwrap();
+
+	let mut proxy = EdgeProxyGraph::new(&mut graph);
+	proxy
+		.discard_edge_where_weight(v1, v2, |w| *w == 1)
+		.unwrap();
+	proxy.add_edge_weighted(&v2, &v1, 4).unwrap();
+	proxy.remove_vertex(v0).unwrap();
+
+	// The first vertex is removed, so the others move down one
+	assert_eq!(proxy.edges_between(0, 1).collect::<Vec<_>>(), vec![&2]);
+	assert_eq!(proxy.edges_between(1, 0).collect::<Vec<_>>(), vec![&4]);
+	assert_eq!(proxy.all_edges().count(), 2);
+}
+
+/// Tests that the proxy can be sent to and shared between threads.
+#[test]
+fn send_and_sync()
+{
+	fn assert_send_sync<T: Send + Sync>() {}
+	assert_send_sync::<EdgeProxyGraph<&AdjListGraph<(), u32>>>();
+}
@@ -0,0 +1,78 @@
+//! Tests `core::proxy::VertexFilterProxy` and `core::proxy::EdgeFilterProxy`.
+
+use crate::mock_graph::{
+	arbitrary::Arb, utilities::unordered_equivalent_lists_equal, MockEdgeWeight, MockGraph,
+	MockVertex,
+};
+use duplicate::duplicate;
+use graphene::core::{
+	proxy::{EdgeFilterProxy, VertexFilterProxy},
+	Directed, Directedness, Graph, Undirected,
+};
+
+/// Returns the given edges, with the endpoints of undirected edges ordered.
+fn ordered<'a, D: Directedness>(
+	edges: impl Iterator<Item = (MockVertex, MockVertex, &'a MockEdgeWeight)>,
+) -> Vec<(usize, usize, &'a MockEdgeWeight)>
+{
+	edges
+		.map(|(source, sink, w)| {
+			if D::directed() || source.value <= sink.value
+			{
+				(source.value, sink.value, w)
+			}
+			else
+			{
+				(sink.value, source.value, w)
+			}
+		})
+		.collect()
+}
+
+#[duplicate(
+	directedness; [ Directed ]; [ Undirected ]
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that the vertex filter shows exactly the vertices satisfying the
+	/// predicate, and the edges between them.
+	#[quickcheck]
+	fn vertex_filter(Arb(g): Arb<MockGraph<directedness>>, threshold: u32) -> bool
+	{
+		let visible = |v| g.vertex_weight(v).unwrap().value >= threshold;
+		let expected_vertices: Vec<_> = g.all_vertices().filter(|v| visible(*v)).collect();
+		let expected_edges = ordered::<directedness>(
+			g.all_edges()
+				.filter(|(source, sink, _)| visible(*source) && visible(*sink)),
+		);
+
+		let proxy = VertexFilterProxy::new(&g, |w| w.value >= threshold);
+
+		unordered_equivalent_lists_equal(&proxy.all_vertices().collect(), &expected_vertices)
+			&& unordered_equivalent_lists_equal(
+				&ordered::<directedness>(proxy.all_edges()),
+				&expected_edges,
+			)
+	}
+
+	/// Tests that the edge filter shows all vertices, and exactly the edges
+	/// satisfying the predicate.
+	#[quickcheck]
+	fn edge_filter(Arb(g): Arb<MockGraph<directedness>>, threshold: u32) -> bool
+	{
+		let expected_edges =
+			ordered::<directedness>(g.all_edges().filter(|(_, _, w)| w.value >= threshold));
+
+		let proxy = EdgeFilterProxy::new(&g, |w| w.value >= threshold);
+
+		unordered_equivalent_lists_equal(
+			&proxy.all_vertices().collect(),
+			&g.all_vertices().collect(),
+		) && unordered_equivalent_lists_equal(
+			&ordered::<directedness>(proxy.all_edges()),
+			&expected_edges,
+		)
+	}
+}
@@ -0,0 +1,67 @@
+//! Tests `core::proxy::LineGraphProxy`.
+
+use crate::mock_graph::{arbitrary::Arb, utilities::unordered_equivalent_lists_equal, MockGraph};
+use duplicate::duplicate;
+use graphene::core::{proxy::LineGraphProxy, Directed, Graph, Undirected};
+
+#[duplicate(
+	directedness; [ Directed ]; [ Undirected ]
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that the vertices of the line graph are the edges of the
+	/// underlying graph, weighted by the edge weights.
+	#[quickcheck]
+	fn vertices_are_edges(Arb(g): Arb<MockGraph<directedness>>) -> bool
+	{
+		let line = LineGraphProxy::new(&g);
+
+		unordered_equivalent_lists_equal(
+			&line.all_vertex_weights().collect(),
+			&g.all_edges().map(|(_, _, w)| w).collect(),
+		) && line
+			.all_vertices_weighted()
+			.all(|((source, sink, i), w)| g.edges_between(source, sink).nth(i) == Some(w))
+	}
+}
+
+/// Tests that the directed line graph has an edge for each pair of an edge
+/// sinked in a vertex and an edge sourced in it.
+#[quickcheck]
+fn directed_edge_count(Arb(g): Arb<MockGraph<Directed>>) -> bool
+{
+	let line = LineGraphProxy::new(&g);
+	let expected: usize = g
+		.all_vertices()
+		.map(|v| g.edges_sinked_in(v).count() * g.edges_sourced_in(v).count())
+		.sum();
+
+	line.all_edges().count() == expected
+}
+
+/// Tests that the undirected line graph has an edge for each pair of distinct
+/// edges sharing an endpoint.
+#[quickcheck]
+fn undirected_edge_count(Arb(g): Arb<MockGraph<Undirected>>) -> bool
+{
+	let line = LineGraphProxy::new(&g);
+	let edges: Vec<_> = g
+		.all_edges()
+		.map(|(source, sink, _)| (source, sink))
+		.collect();
+	let mut expected = 0;
+	for (i, (source1, sink1)) in edges.iter().enumerate()
+	{
+		for (source2, sink2) in edges[i + 1..].iter()
+		{
+			if source1 == source2 || source1 == sink2 || sink1 == source2 || sink1 == sink2
+			{
+				expected += 1;
+			}
+		}
+	}
+
+	line.all_edges().count() == expected
+}
@@ -0,0 +1,100 @@
+//! Tests `core::proxy::MapWeightsProxy`.
+
+use crate::mock_graph::{
+	arbitrary::Arb, utilities::unordered_equivalent_lists_equal, MockGraph, MockVertex,
+};
+use duplicate::duplicate;
+use graphene::core::{proxy::MapWeightsProxy, Directed, Directedness, Graph, Undirected};
+
+/// Returns the given edges with their weights, with the endpoints of
+/// undirected edges ordered.
+fn ordered<'a, D: Directedness>(
+	edges: impl Iterator<Item = (MockVertex, MockVertex, &'a u32)>,
+) -> Vec<(usize, usize, u32)>
+{
+	edges
+		.map(|(source, sink, w)| {
+			if D::directed() || source.value <= sink.value
+			{
+				(source.value, sink.value, *w)
+			}
+			else
+			{
+				(sink.value, source.value, *w)
+			}
+		})
+		.collect()
+}
+
+#[duplicate(
+	directedness; [ Directed ]; [ Undirected ]
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that each vertex of the proxy has the mapped weight of the same
+	/// vertex in the underlying graph.
+	#[quickcheck]
+	fn maps_vertex_weights(Arb(g): Arb<MockGraph<directedness>>) -> bool
+	{
+		let proxy = MapWeightsProxy::new(&g, |w| w.value / 2, |w| w.value);
+
+		proxy.all_vertices().count() == g.all_vertices().count()
+			&& g.all_vertices_weighted()
+				.all(|(v, w)| proxy.vertex_weight(v) == Some(&(w.value / 2)))
+	}
+
+	/// Tests that the proxy has the same edges as the underlying graph, with
+	/// mapped weights.
+	#[quickcheck]
+	fn maps_edge_weights(Arb(g): Arb<MockGraph<directedness>>) -> bool
+	{
+		let proxy = MapWeightsProxy::new(&g, |_| (), |w| w.value / 2);
+		let expected: Vec<_> = g
+			.all_edges()
+			.map(|(source, sink, w)| (source, sink, w.value / 2))
+			.collect();
+
+		unordered_equivalent_lists_equal(
+			&ordered::<directedness>(proxy.all_edges()),
+			&ordered::<directedness>(expected.iter().map(|(source, sink, w)| (*source, *sink, w))),
+		)
+	}
+
+	/// Tests that the proxy lists its vertices and edges in the same order as
+	/// the underlying graph.
+	#[quickcheck]
+	fn same_order(Arb(g): Arb<MockGraph<directedness>>) -> bool
+	{
+		let proxy = MapWeightsProxy::new(&g, |w| w.value, |w| w.value);
+
+		proxy
+			.all_vertices_weighted()
+			.map(|(v, w)| (v, *w))
+			.eq(g.all_vertices_weighted().map(|(v, w)| (v, w.value)))
+			&& proxy
+				.all_edges()
+				.map(|(source, sink, w)| (source, sink, *w))
+				.eq(g
+					.all_edges()
+					.map(|(source, sink, w)| (source, sink, w.value)))
+	}
+
+	/// Tests that the edges between any two vertices of the proxy are the
+	/// mapped edges between them in the underlying graph.
+	#[quickcheck]
+	fn maps_edges_between(Arb(g): Arb<MockGraph<directedness>>) -> bool
+	{
+		let proxy = MapWeightsProxy::new(&g, |_| (), |w| w.value / 2);
+
+		g.all_vertices().all(|source| {
+			g.all_vertices().all(|sink| {
+				unordered_equivalent_lists_equal(
+					&proxy.edges_between(source, sink).copied().collect(),
+					&g.edges_between(source, sink).map(|w| w.value / 2).collect(),
+				)
+			})
+		})
+	}
+}
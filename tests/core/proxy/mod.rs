@@ -0,0 +1,9 @@
+//! Tests the proxy graphs in `core::proxy::*`
+
+mod contracted_proxy;
+mod edge_proxy;
+mod filter_proxy;
+mod line_graph_proxy;
+mod map_weights_proxy;
+mod quotient_proxy;
+mod subgraph_proxy;
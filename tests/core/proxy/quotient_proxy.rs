@@ -0,0 +1,71 @@
+//! Tests `core::proxy::QuotientProxy`.
+
+use crate::mock_graph::{
+	arbitrary::Arb, utilities::unordered_equivalent_lists_equal, MockGraph, MockVertex,
+};
+use duplicate::duplicate;
+use graphene::core::{proxy::QuotientProxy, Directed, Graph, Undirected};
+use std::collections::HashSet;
+
+/// The class of each vertex in the tests
+fn class(v: MockVertex) -> usize
+{
+	v.value % 3
+}
+
+#[duplicate(
+	directedness; [ Directed ]; [ Undirected ]
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that the quotient has a vertex for each class and that each
+	/// vertex is a member of its class.
+	#[quickcheck]
+	fn vertices_are_classes(Arb(g): Arb<MockGraph<directedness>>) -> bool
+	{
+		let quotient = QuotientProxy::new(&g, class);
+		let classes: HashSet<_> = g.all_vertices().map(class).collect();
+
+		quotient.all_vertices().count() == classes.len()
+			&& g.all_vertices()
+				.all(|v| quotient.members(class(v)).contains(&v))
+	}
+
+	/// Tests that the quotient has each edge of the underlying graph exactly
+	/// once.
+	#[quickcheck]
+	fn same_edges(Arb(g): Arb<MockGraph<directedness>>) -> bool
+	{
+		let quotient = QuotientProxy::new(&g, class);
+
+		unordered_equivalent_lists_equal(
+			&quotient.all_edges().map(|(_, _, w)| w).collect(),
+			&g.all_edges().map(|(_, _, w)| w).collect(),
+		)
+	}
+}
+
+/// Tests that the edges between two classes of a directed quotient are the
+/// edges from any member of the first to any member of the second.
+#[quickcheck]
+fn directed_edges_between(Arb(g): Arb<MockGraph<Directed>>) -> bool
+{
+	let quotient = QuotientProxy::new(&g, class);
+	let classes: Vec<_> = quotient.all_vertices().collect();
+
+	classes.iter().all(|source| {
+		classes.iter().all(|sink| {
+			let expected = g
+				.all_edges()
+				.filter(|(so, si, _)| class(*so) == *source && class(*si) == *sink)
+				.map(|(_, _, w)| w)
+				.collect();
+			unordered_equivalent_lists_equal(
+				&quotient.edges_between(source, sink).collect(),
+				&expected,
+			)
+		})
+	})
+}
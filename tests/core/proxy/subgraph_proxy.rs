@@ -0,0 +1,72 @@
+//! Tests `core::proxy::SubgraphProxy`.
+
+use crate::mock_graph::{arbitrary::Arb, MockGraph};
+use duplicate::duplicate;
+use graphene::{
+	common::AdjListGraph,
+	core::{
+		property::{EdgeCount, Subgraph, VertexCount},
+		proxy::SubgraphProxy,
+		Directed, Graph, Undirected,
+	},
+};
+
+#[duplicate(
+	directedness; [ Directed ]; [ Undirected ]
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that the induced subgraph has the given vertices, all the edges
+	/// between them, and the edges leaving them as exit edges.
+	#[quickcheck]
+	fn induced(Arb(g): Arb<MockGraph<directedness>>, threshold: u32) -> bool
+	{
+		let vertices: Vec<_> = g
+			.all_vertices_weighted()
+			.filter(|(_, w)| w.value >= threshold)
+			.map(|(v, _)| v)
+			.collect();
+		let induced = SubgraphProxy::induced(&g, vertices.clone()).unwrap();
+
+		let inside = |v| vertices.contains(&v);
+		let expected_exits = g
+			.all_vertices()
+			.filter(|v| inside(*v))
+			.flat_map(|v| g.edges_sourced_in(v).map(move |(sink, _)| (v, sink)))
+			.filter(|(_, sink)| !inside(*sink))
+			.count();
+
+		induced.all_vertices().count() == vertices.len()
+			&& induced.all_edges().count()
+				== g.all_edges()
+					.filter(|(source, sink, _)| inside(*source) && inside(*sink))
+					.count()
+			&& induced.exit_edges().count() == expected_exits
+	}
+
+	/// Tests that copying a subgraph gives a graph with the same vertices and
+	/// edges.
+	#[quickcheck]
+	fn copy_into(Arb(g): Arb<MockGraph<directedness>>, threshold: u32) -> bool
+	{
+		let vertices = g
+			.all_vertices_weighted()
+			.filter(|(_, w)| w.value >= threshold)
+			.map(|(v, _)| v);
+		let induced = SubgraphProxy::induced(&g, vertices).unwrap();
+		let mut copy = AdjListGraph::<_, _, directedness>::new();
+		let copies = induced.copy_into(&mut copy).unwrap();
+
+		copy.vertex_count() == induced.all_vertices().count()
+			&& copy.edge_count() == induced.all_edges().count()
+			&& induced
+				.all_vertices_weighted()
+				.all(|(v, w)| copy.vertex_weight(copies[&v]) == Some(w))
+			&& induced.all_edges().all(|(source, sink, w)| {
+				copy.edges_between(copies[&source], copies[&sink])
+					.any(|copy_w| copy_w == w)
+			})
+	}
+}
@@ -0,0 +1,280 @@
+//! Tests `core::Transaction`.
+
+use crate::mock_graph::{
+	arbitrary::{Arb, TwoReachableVerticesIn},
+	MockEdgeWeight, MockGraph, MockVertexWeight,
+};
+use duplicate::duplicate;
+use graphene::{
+	common::AdjListGraph,
+	core::{
+		property::{
+			AcyclicGraph, AddEdge, ConnectedGraph, HasVertex, History, HistoryGraph, NewVertex,
+			RemoveEdge, RemoveVertex, UniqueGraph, VertexInGraph,
+		},
+		proxy::ProxyVertex::Underlying,
+		Directed, Ensure, Graph, GraphError, Release, Undirected,
+	},
+};
+
+#[duplicate(
+	directedness; [ Directed ]; [ Undirected ]
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that committing applies new vertices and edges to the graph.
+	#[quickcheck]
+	fn commit_adds(
+		Arb(mut g): Arb<VertexInGraph<MockGraph<directedness>>>,
+		v_weight: MockVertexWeight,
+		e_weight: MockEdgeWeight,
+	) -> bool
+	{
+		let v = g.get_vertex();
+		let vertex_count = g.all_vertices().count();
+		let edge_count = g.all_edges().count();
+
+		let mut transaction = g.transaction();
+		let new_v = transaction.new_vertex_weighted(v_weight.clone()).unwrap();
+		transaction
+			.add_edge_weighted(Underlying(v), new_v, e_weight.clone())
+			.unwrap();
+		let new_v = transaction.commit().unwrap()[0];
+
+		g.all_vertices().count() == vertex_count + 1
+			&& g.all_edges().count() == edge_count + 1
+			&& g.vertex_weight(new_v) == Some(&v_weight)
+			&& g.edges_between(v, new_v).next() == Some(&e_weight)
+	}
+
+	/// Tests that committing removes vertices from the graph.
+	#[quickcheck]
+	fn commit_removes_vertex(Arb(mut g): Arb<UniqueGraph<MockGraph<directedness>>>) -> bool
+	{
+		let v = match g.all_vertices().next()
+		{
+			Some(v) => v,
+			None => return true,
+		};
+		let vertex_count = g.all_vertices().count();
+
+		let mut transaction = g.transaction();
+		transaction.remove_vertex(Underlying(v)).unwrap();
+		transaction.commit().unwrap();
+
+		!g.contains_vertex(v) && g.all_vertices().count() == vertex_count - 1
+	}
+
+	/// Tests that a transaction removing the vertex guaranteed by the graph is
+	/// rejected.
+	#[quickcheck]
+	fn reject_removing_guaranteed_vertex(
+		Arb(mut g): Arb<VertexInGraph<MockGraph<directedness>>>,
+	) -> bool
+	{
+		let v = g.get_vertex();
+		let vertex_count = g.all_vertices().count();
+
+		let mut transaction = g.transaction();
+		transaction.remove_vertex(Underlying(v)).unwrap();
+
+		transaction.commit()
+			== Err(GraphError::PropertyViolated {
+				property: "VertexInGraph",
+				witness: vec![Underlying(v)],
+			}) && g.contains_vertex(v)
+			&& g.all_vertices().count() == vertex_count
+	}
+
+	/// Tests that a transaction accepts removing an edge of a connected graph
+	/// if it is replaced.
+	#[quickcheck]
+	fn accept_replaced_edge(Arb(mut g): Arb<ConnectedGraph<MockGraph<directedness>>>) -> bool
+	{
+		let (source, sink, weight) = match g.all_edges().next()
+		{
+			Some((source, sink, weight)) => (source, sink, weight.clone()),
+			None => return true,
+		};
+		let edge_count = g.edges_between(source, sink).count();
+
+		let mut transaction = g.transaction();
+		transaction
+			.remove_edge(Underlying(source), Underlying(sink))
+			.unwrap();
+		transaction
+			.add_edge_weighted(Underlying(source), Underlying(sink), weight)
+			.unwrap();
+
+		transaction.commit().is_ok() && g.edges_between(source, sink).count() == edge_count
+	}
+
+	/// Tests that a transaction accepts removing an edge of a unique graph and
+	/// adding it again.
+	#[quickcheck]
+	fn accept_readded_edge(Arb(mut g): Arb<UniqueGraph<MockGraph<directedness>>>) -> bool
+	{
+		let (source, sink, weight) = match g.all_edges().next()
+		{
+			Some((source, sink, weight)) => (source, sink, weight.clone()),
+			None => return true,
+		};
+
+		let mut transaction = g.transaction();
+		transaction
+			.remove_edge(Underlying(source), Underlying(sink))
+			.unwrap();
+		transaction
+			.add_edge_weighted(Underlying(source), Underlying(sink), weight.clone())
+			.unwrap();
+
+		transaction.commit().is_ok()
+			&& g.edges_between(source, sink).collect::<Vec<_>>() == vec![&weight]
+	}
+
+	/// Tests that removing an edge added by the transaction removes that edge
+	/// and not one already in the graph.
+	#[quickcheck]
+	fn remove_added_edge(Arb(mut g): Arb<MockGraph<directedness>>) -> bool
+	{
+		let (source, sink) = match g.all_edges().next()
+		{
+			Some((source, sink, _)) => (source, sink),
+			None => return true,
+		};
+		let before: Vec<_> = g.edges_between(source, sink).cloned().collect();
+		let weight = MockEdgeWeight {
+			value: before.iter().map(|w| w.value).max().unwrap() + 1,
+		};
+
+		let mut transaction = g.transaction();
+		transaction
+			.add_edge_weighted(Underlying(source), Underlying(sink), weight.clone())
+			.unwrap();
+		transaction
+			.remove_edge_where_weight(Underlying(source), Underlying(sink), |w| *w == weight)
+			.unwrap();
+		transaction.commit().unwrap();
+
+		g.edges_between(source, sink).cloned().collect::<Vec<_>>() == before
+	}
+
+	/// Tests that a transaction that would violate the property applies none
+	/// of its mutations.
+	#[quickcheck]
+	fn reject_leaves_graph_unchanged(
+		Arb(g): Arb<TwoReachableVerticesIn<AcyclicGraph<MockGraph<directedness>>>>,
+		v_weight: MockVertexWeight,
+		e_weight: MockEdgeWeight,
+	) -> bool
+	{
+		let (v1, v2) = g.0.get_both();
+		let mut g = (g.0).0;
+		let vertex_count = g.all_vertices().count();
+		let edge_count = g.all_edges().count();
+
+		let mut transaction = g.transaction();
+		transaction.new_vertex_weighted(v_weight).unwrap();
+		transaction
+			.add_edge_weighted(Underlying(v2), Underlying(v1), e_weight)
+			.unwrap();
+
+		let rejected = match transaction.commit()
+		{
+			Err(GraphError::PropertyViolated { property, .. }) => property == "Acyclic",
+			_ => false,
+		};
+		rejected && g.all_vertices().count() == vertex_count && g.all_edges().count() == edge_count
+	}
+}
+
+/// Tests that vertex removals follow the vertices a graph renumbers when one
+/// is removed.
+#[test]
+fn removals_follow_renumbering()
+{
+	let mut g = AdjListGraph::<u32, ()>::new();
+	let v0 = g.new_vertex_weighted(0).unwrap();
+	g.new_vertex_weighted(1).unwrap();
+	let v2 = g.new_vertex_weighted(2).unwrap();
+	let v3 = g.new_vertex_weighted(3).unwrap();
+	g.add_edge(v0, v2).unwrap();
+
+	let mut transaction = g.transaction();
+	// Removing v0 renumbers the later vertices
+	transaction.remove_vertex(Underlying(v0)).unwrap();
+	transaction.remove_vertex(Underlying(v2)).unwrap();
+	let v4 = transaction.new_vertex_weighted(4).unwrap();
+	transaction.add_edge(Underlying(v3), v4).unwrap();
+	let added = transaction.commit().unwrap();
+
+	let weights = |g: &AdjListGraph<u32, ()>| {
+		g.all_vertices_weighted()
+			.map(|(_, w)| *w)
+			.collect::<Vec<_>>()
+	};
+	assert_eq!(weights(&g), vec![1, 3, 4]);
+	assert_eq!(g.vertex_weight(added[0]), Some(&4));
+	let edges: Vec<_> = g
+		.all_edges()
+		.map(|(source, sink, _)| {
+			(
+				*g.vertex_weight(source).unwrap(),
+				*g.vertex_weight(sink).unwrap(),
+			)
+		})
+		.collect();
+	assert_eq!(edges, vec![(3, 4)]);
+}
+
+/// Tests that the mutations of a committed transaction are recorded by a
+/// history graph under the ensurer the transaction is on.
+#[test]
+fn commit_recorded_by_history()
+{
+	let mut g = HistoryGraph::ensure(AdjListGraph::<u32, ()>::new(), ()).unwrap();
+	let v0 = g.new_vertex_weighted(0).unwrap();
+	let v1 = g.new_vertex_weighted(1).unwrap();
+	g.add_edge(v0, v1).unwrap();
+	g.add_edge(v1, v0).unwrap();
+	g.checkpoint("before");
+	let mut g = ConnectedGraph::ensure(g, ()).unwrap();
+
+	let mut transaction = g.transaction();
+	transaction
+		.remove_edge(Underlying(v0), Underlying(v1))
+		.unwrap();
+	let v2 = transaction.new_vertex_weighted(2).unwrap();
+	transaction.add_edge(Underlying(v0), v2).unwrap();
+	transaction.add_edge(v2, Underlying(v1)).unwrap();
+	transaction.commit().unwrap();
+
+	// One edge removed, one vertex and two edges added
+	assert_eq!(g.checkpoint_distance("before"), Some(4));
+	// Undoing one mutation at a time would disconnect the graph
+	let (mut g, _) = g.release();
+	g.revert_to("before").unwrap();
+	assert_eq!(g.all_vertices().count(), 2);
+	assert_eq!(g.edges_between(v0, v1).count(), 1);
+	assert_eq!(g.edges_between(v1, v0).count(), 1);
+}
+
+/// Tests a transaction on an ensurer of a borrowed graph.
+#[test]
+fn borrowed_base()
+{
+	let mut g = AdjListGraph::<u32, ()>::new();
+	let v0 = g.new_vertex_weighted(0).unwrap();
+	let v1 = g.new_vertex_weighted(1).unwrap();
+	g.add_edge(v0, v1).unwrap();
+
+	let mut borrowed = VertexInGraph::ensure(&mut g, v0).unwrap();
+	let mut transaction = borrowed.transaction();
+	transaction.remove_vertex(Underlying(v1)).unwrap();
+	transaction.commit().unwrap();
+
+	assert_eq!(borrowed.get_vertex(), v0);
+	assert_eq!(g.all_vertex_weights().collect::<Vec<_>>(), vec![&0]);
+}
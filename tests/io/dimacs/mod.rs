@@ -0,0 +1,4 @@
+//! Tests reading and writing DIMACS.
+
+mod reader;
+mod writer;
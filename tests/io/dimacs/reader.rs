@@ -0,0 +1,179 @@
+//! Tests `io::read_dimacs`, `io::read_dimacs_into` and
+//! `io::read_dimacs_max_flow`.
+
+use crate::mock_graph::{
+	arbitrary::Arb, utilities::unordered_equivalent_lists_equal, MockEdgeWeight, MockGraph,
+};
+use duplicate::duplicate;
+use graphene::{
+	common::AdjListGraph,
+	core::{
+		from_graph_mapped,
+		property::{HasVertex, Rooted, VertexInGraph},
+		Directed, Graph, Undirected,
+	},
+	io::{
+		read_dimacs, read_dimacs_into, read_dimacs_max_flow, Attributes, DimacsProblem,
+		DimacsWriter, ParseError,
+	},
+};
+
+#[duplicate(
+	directedness weight map_weight problem;
+	[ Directed ] [ u32 ] [ |w: &MockEdgeWeight| w.value ] [ ShortestPath ];
+	[ Undirected ] [ () ] [ |_: &MockEdgeWeight| () ] [ Edge ]
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that reading a written graph gives the same graph.
+	#[quickcheck]
+	fn read_written(Arb(mock): Arb<MockGraph<directedness>>) -> bool
+	{
+		let (g, _) = from_graph_mapped::<_, AdjListGraph<(), weight, directedness>>(
+			&mock,
+			|_| (),
+			map_weight,
+		)
+		.unwrap();
+		let written = DimacsWriter::new(&g).to_string();
+		let (read, ids, read_problem) =
+			read_dimacs::<AdjListGraph<(), weight, directedness>>(written.as_bytes()).unwrap();
+		let vertices: Vec<_> = g.all_vertices().collect();
+		let id = |i: usize| ids[&(i + 1).to_string()];
+
+		read_problem == DimacsProblem::problem
+			&& read.all_vertices().count() == vertices.len()
+			&& vertices.iter().enumerate().all(|(i, source)| {
+				vertices.iter().enumerate().all(|(j, sink)| {
+					unordered_equivalent_lists_equal(
+						&read.edges_between(id(i), id(j)).collect(),
+						&g.edges_between(source, sink).collect(),
+					)
+				})
+			})
+	}
+}
+
+/// Tests that reading a written maximum flow problem gives a flow network
+/// with the same source and sink.
+#[quickcheck]
+fn read_written_max_flow(Arb(mock): Arb<VertexInGraph<MockGraph<Directed>>>) -> bool
+{
+	let (g, copies) =
+		from_graph_mapped::<_, AdjListGraph<(), u32>>(&mock, |_| (), |w| w.value).unwrap();
+	let source = copies[&mock.get_vertex()];
+	let sink = g.all_vertices().last().unwrap();
+	let written = DimacsWriter::new(&g).max_flow(source, sink).to_string();
+	let (network, ids) = read_dimacs_max_flow::<AdjListGraph<(), u32>>(written.as_bytes()).unwrap();
+	let index = |v| g.all_vertices().position(|v2| v2 == v).unwrap() + 1;
+
+	network.root() == ids[&index(source).to_string()]
+		&& network.get_vertex() == ids[&index(sink).to_string()]
+		&& network.all_edges().count() == g.all_edges().count()
+}
+
+/// Tests reading supplies, lower bounds, capacities and costs of a
+/// minimum-cost flow problem.
+#[test]
+fn min_cost_flow()
+{
+	let (g, ids, problem) = read_dimacs::<AdjListGraph<Attributes, Attributes>>(
+		"c supplies\np min 3 2\nn 1 4\nn 3 -4\nc arcs\na 1 2 0 4 3\na 2 3 1 5 -1\n".as_bytes(),
+	)
+	.unwrap();
+
+	assert_eq!(problem, DimacsProblem::MinCostFlow);
+	assert_eq!(g.vertex_weight(ids["1"]).unwrap()["supply"], "4");
+	assert_eq!(g.vertex_weight(ids["2"]).unwrap()["supply"], "0");
+	assert_eq!(g.vertex_weight(ids["3"]).unwrap()["supply"], "-4");
+	let arc = g.edges_between(ids["2"], ids["3"]).next().unwrap();
+	assert_eq!(arc["low"], "1");
+	assert_eq!(arc["capacity"], "5");
+	assert_eq!(arc["cost"], "-1");
+}
+
+/// Tests that CNF formulas are read as their primal graph.
+#[test]
+fn cnf()
+{
+	let mut g = AdjListGraph::<(), (), Undirected>::new();
+	let (ids, problem) = read_dimacs_into(
+		"c example\np cnf 4 3\n1 -2 0\n2 -1\n3 0 -4 0\n%\n0\n".as_bytes(),
+		&mut g,
+	)
+	.unwrap();
+
+	assert_eq!(problem, DimacsProblem::Cnf);
+	assert_eq!(g.all_vertices().count(), 4);
+	assert_eq!(g.all_edges().count(), 3);
+	assert!(g.neighbors(ids["1"], ids["2"]));
+	assert!(g.neighbors(ids["1"], ids["3"]));
+	assert!(g.neighbors(ids["2"], ids["3"]));
+	assert!(!g.neighbors(ids["3"], ids["4"]));
+}
+
+/// Tests that invalid problems are reported where the error is.
+#[test]
+fn errors()
+{
+	let read = |input: &str| read_dimacs::<AdjListGraph<(), u32>>(input.as_bytes()).map(|_| ());
+
+	assert_eq!(
+		read("a 1 2 3\n"),
+		Err(ParseError::syntax(1, 1, "expected the problem line"))
+	);
+	assert_eq!(
+		read("p tsp 2 1\n"),
+		Err(ParseError::syntax(
+			1,
+			3,
+			"expected sp or max or min or edge or col or cnf, found 'tsp'"
+		))
+	);
+	assert_eq!(
+		read("p edge 2 1\n"),
+		Err(ParseError::syntax(
+			1,
+			3,
+			"'edge' problems must be read into undirected graphs"
+		))
+	);
+	assert_eq!(
+		read("p sp 2 1\na 1 3 1\n"),
+		Err(ParseError::syntax(2, 5, "node 3 is out of bounds"))
+	);
+	assert_eq!(
+		read("p sp 2 1\na 1 2\n"),
+		Err(ParseError::syntax(2, 6, "expected weight"))
+	);
+	assert_eq!(
+		read("p sp 2 2\na 1 2 1\n"),
+		Err(ParseError::syntax(3, 1, "expected 2 edges, found 1"))
+	);
+	assert_eq!(
+		read("p max 2 1\nn 1 s\na 1 2 1\nn 2 t\n"),
+		Err(ParseError::syntax(
+			4,
+			1,
+			"nodes must be described before arcs"
+		))
+	);
+	assert_eq!(
+		read("p max 2 0\nn 1 s\n"),
+		Err(ParseError::syntax(3, 1, "missing sink"))
+	);
+	assert_eq!(
+		read("p sp 2 1\na 1 2 -1\n"),
+		Err(ParseError::Weight {
+			line: 2,
+			column: 1,
+			message: "invalid weight '-1'".into()
+		})
+	);
+	assert_eq!(
+		read_dimacs_max_flow::<AdjListGraph<(), u32>>("p sp 2 0\n".as_bytes()).map(|_| ()),
+		Err(ParseError::syntax(1, 3, "expected max, found 'sp'"))
+	);
+}
@@ -0,0 +1,48 @@
+//! Tests `io::DimacsWriter`.
+
+use graphene::{
+	common::AdjListGraph,
+	core::{
+		property::{AddEdge, NewVertex},
+		Undirected,
+	},
+	io::{Attributes, DimacsWriter},
+};
+
+/// Tests writing a minimum-cost flow problem, with missing attributes being 0.
+#[test]
+fn min_cost_flow()
+{
+	let mut g = AdjListGraph::<Attributes, Attributes>::new();
+	let mut supply = Attributes::new();
+	supply.insert("supply".into(), "3".into());
+	let v0 = g.new_vertex_weighted(supply).unwrap();
+	let v1 = g.new_vertex_weighted(Attributes::new()).unwrap();
+	let mut arc = Attributes::new();
+	arc.insert("capacity".into(), "5".into());
+	arc.insert("cost".into(), "2".into());
+	g.add_edge_weighted(&v0, &v1, arc).unwrap();
+
+	assert_eq!(
+		DimacsWriter::new(&g).min_cost_flow().to_string(),
+		"p min 2 1\nn 1 3\na 1 2 0 5 2\n"
+	);
+}
+
+/// Tests that edges without weights are written with length 1, and that
+/// undirected graphs are written as edge problems.
+#[test]
+fn without_weights()
+{
+	let mut g = AdjListGraph::<(), ()>::new();
+	let v0 = g.new_vertex().unwrap();
+	g.add_edge(&v0, &v0).unwrap();
+	assert_eq!(DimacsWriter::new(&g).to_string(), "p sp 1 1\na 1 1 1\n");
+
+	let mut g = AdjListGraph::<(), (), Undirected>::new();
+	let v0 = g.new_vertex().unwrap();
+	let v1 = g.new_vertex().unwrap();
+	g.add_edge(&v1, &v0).unwrap();
+	let written = DimacsWriter::new(&g).to_string();
+	assert!(written == "p edge 2 1\ne 1 2\n" || written == "p edge 2 1\ne 2 1\n");
+}
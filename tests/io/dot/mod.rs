@@ -0,0 +1,4 @@
+//! Tests reading and writing DOT.
+
+mod reader;
+mod writer;
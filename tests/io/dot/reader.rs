@@ -0,0 +1,185 @@
+//! Tests `io::read_dot` and `io::read_dot_into`.
+
+use crate::mock_graph::{arbitrary::Arb, utilities::unordered_equivalent_lists_equal, MockGraph};
+use duplicate::duplicate;
+use graphene::{
+	common::AdjListGraph,
+	core::{Directed, Graph, Undirected},
+	io::{read_dot, read_dot_into, Attributes, DotWriter, FromAttributes, ParseError},
+};
+
+/// A weight read from the `w` attribute.
+#[derive(Debug, PartialEq)]
+struct Number(u32);
+
+impl FromAttributes for Number
+{
+	fn from_attributes(attributes: &Attributes) -> Result<Self, String>
+	{
+		attributes
+			.get("w")
+			.ok_or_else(|| "missing w".to_string())?
+			.parse()
+			.map(Number)
+			.map_err(|_| "invalid w".to_string())
+	}
+}
+
+#[duplicate(
+	directedness; [ Directed ]; [ Undirected ]
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that reading a written graph gives the same graph.
+	#[quickcheck]
+	fn read_written(Arb(g): Arb<MockGraph<directedness>>) -> bool
+	{
+		let dot = DotWriter::new(&g)
+			.vertex_attributes(|_, w| vec![("w".to_string(), w.value.to_string())])
+			.edge_attributes(|_, _, w| vec![("w".to_string(), w.value.to_string())])
+			.to_string();
+		let (read, ids) = read_dot::<AdjListGraph<Number, Number, directedness>>(&dot).unwrap();
+		let vertices: Vec<_> = g.all_vertices().collect();
+
+		read.all_vertices().count() == vertices.len()
+			&& vertices.iter().enumerate().all(|(i, v)| {
+				read.vertex_weight(ids[&i.to_string()]).map(|w| w.0)
+					== g.vertex_weight(v).map(|w| w.value)
+			}) && vertices.iter().enumerate().all(|(i, source)| {
+			vertices.iter().enumerate().all(|(j, sink)| {
+				unordered_equivalent_lists_equal(
+					&read
+						.edges_between(ids[&i.to_string()], ids[&j.to_string()])
+						.map(|w| w.0)
+						.collect(),
+					&g.edges_between(source, sink).map(|w| w.value).collect(),
+				)
+			})
+		})
+	}
+}
+
+/// Tests edge chains, subgraphs as endpoints and the scoping of default
+/// attributes.
+#[test]
+fn edge_chains_and_subgraphs()
+{
+	let (g, ids) = read_dot::<AdjListGraph<Attributes, Attributes>>(
+		"strict digraph G {
+			edge [color=red]
+			a -> b -> c [style=bold];
+			subgraph cluster { node [shape=box]; edge [color=blue]; d; e -> d }
+			a -> { d; e } ;
+			a -> b [weight = 2]
+			f
+			{}
+		}",
+	)
+	.unwrap();
+
+	assert_eq!(g.all_vertices().count(), 6);
+	assert_eq!(g.all_edges().count(), 5);
+	assert_eq!(g.vertex_weight(ids["d"]).unwrap()["shape"], "box");
+	assert!(g.vertex_weight(ids["f"]).unwrap().get("shape").is_none());
+
+	let ab = g.edges_between(ids["a"], ids["b"]).next().unwrap();
+	assert_eq!(ab["style"], "bold");
+	assert_eq!(ab["weight"], "2");
+	assert_eq!(
+		g.edges_between(ids["e"], ids["d"]).next().unwrap()["color"],
+		"blue"
+	);
+	assert_eq!(
+		g.edges_between(ids["a"], ids["e"]).next().unwrap()["color"],
+		"red"
+	);
+}
+
+/// Tests IDs that are quoted, concatenated, HTML or numerals, along with
+/// comments and ports.
+#[test]
+fn ids_and_comments()
+{
+	let (g, ids) = read_dot::<AdjListGraph<Attributes, (), Undirected>>(
+		"# preprocessor output
+		graph {
+			// line comment
+			\"a \\\"quoted\\\"\" + \" id\" [label=<<b>bold</b>>]; /* block
+			comment */
+			-1.5:port:n -- \"a \\\"quoted\\\" id\"
+		}",
+	)
+	.unwrap();
+
+	assert_eq!(g.all_vertices().count(), 2);
+	assert_eq!(
+		g.vertex_weight(ids["a \"quoted\" id"]).unwrap()["label"],
+		"<b>bold</b>"
+	);
+	assert!(g.neighbors(ids["-1.5"], ids["a \"quoted\" id"]));
+}
+
+/// Tests that syntax errors report where they are.
+#[test]
+fn syntax_errors()
+{
+	let read = |dot| read_dot::<AdjListGraph<(), ()>>(dot).map(|_| ());
+
+	assert_eq!(
+		read("digraph {\n\ta -> ;\n}"),
+		Err(ParseError::syntax(2, 7, "expected an ID, found ';'"))
+	);
+	assert_eq!(
+		read("digraph {\n\ta -- b\n}"),
+		Err(ParseError::syntax(2, 4, "'--' used in a directed graph"))
+	);
+	assert_eq!(
+		read("graph { }"),
+		Err(ParseError::syntax(
+			1,
+			1,
+			"expected a directed graph ('digraph')"
+		))
+	);
+	assert_eq!(
+		read("digraph { a [label=\"open }"),
+		Err(ParseError::syntax(1, 20, "unterminated string"))
+	);
+	assert_eq!(
+		read("digraph { a } b"),
+		Err(ParseError::syntax(
+			1,
+			15,
+			"expected end of input, found 'b'"
+		))
+	);
+}
+
+/// Tests that attributes that a weight can't be created from are reported
+/// where the vertex or edge first appears.
+#[test]
+fn weight_errors()
+{
+	let mut g = AdjListGraph::<Number, Number>::new();
+
+	assert_eq!(
+		read_dot_into("digraph {\n a [w=1]\n b [w=x] }", &mut g),
+		Err(ParseError::Weight {
+			line: 3,
+			column: 2,
+			message: "invalid w".into()
+		})
+	);
+
+	let mut g = AdjListGraph::<Number, Number>::new();
+	assert_eq!(
+		read_dot_into("digraph { a [w=1]; a -> a }", &mut g),
+		Err(ParseError::Weight {
+			line: 1,
+			column: 22,
+			message: "missing w".into()
+		})
+	);
+}
@@ -0,0 +1,104 @@
+//! Tests `io::DotWriter`.
+
+use crate::mock_graph::{arbitrary::Arb, MockGraph};
+use duplicate::duplicate;
+use graphene::{
+	common::AdjListGraph,
+	core::{
+		property::{AddEdge, NewVertex},
+		Directed, Directedness, Graph, Undirected,
+	},
+	io::DotWriter,
+};
+
+#[duplicate(
+	directedness; [ Directed ]; [ Undirected ]
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that the graph is written with the right kind, and with a
+	/// statement for each vertex and each edge.
+	#[quickcheck]
+	fn writes_vertices_and_edges(Arb(g): Arb<MockGraph<directedness>>) -> bool
+	{
+		let dot = DotWriter::new(&g).to_string();
+		let lines: Vec<_> = dot.lines().collect();
+		let (header, edge_op) = if directedness::directed()
+		{
+			("digraph {", " -> ")
+		}
+		else
+		{
+			("graph {", " -- ")
+		};
+		let vertex_count = g.all_vertices().count();
+		let edge_count = g.all_edges().count();
+
+		lines.len() == vertex_count + edge_count + 2
+			&& lines[0] == header
+			&& lines[1..=vertex_count]
+				.iter()
+				.enumerate()
+				.all(|(i, line)| *line == format!("\t{};", i))
+			&& lines[vertex_count + 1..lines.len() - 1]
+				.iter()
+				.all(|line| line.contains(edge_op))
+			&& lines[lines.len() - 1] == "}"
+	}
+
+	/// Tests that each vertex is written with its label and attributes.
+	#[quickcheck]
+	fn writes_vertex_attributes(Arb(g): Arb<MockGraph<directedness>>) -> bool
+	{
+		let dot = DotWriter::new(&g)
+			.vertex_label(|_, w| w.value.to_string())
+			.vertex_attributes(|_, _| vec![("color".to_string(), "red".to_string())])
+			.to_string();
+
+		g.all_vertex_weights().enumerate().all(|(i, w)| {
+			dot.contains(&format!(
+				"\t{} [label=\"{}\", color=\"red\"];\n",
+				i, w.value
+			))
+		})
+	}
+
+	/// Tests that each edge is written with its label.
+	#[quickcheck]
+	fn writes_edge_labels(Arb(g): Arb<MockGraph<directedness>>) -> bool
+	{
+		let dot = DotWriter::new(&g)
+			.edge_label(|_, _, w| format!("w{}", w.value))
+			.to_string();
+
+		g.all_edges()
+			.all(|(_, _, w)| dot.contains(&format!(" [label=\"w{}\"];\n", w.value)))
+	}
+}
+
+/// Tests that labels are escaped and that clusters only contain vertices in
+/// the graph.
+#[test]
+fn escapes_and_clusters()
+{
+	let mut g = AdjListGraph::<&str, (), Undirected>::new();
+	let v0 = g.new_vertex_weighted("say \"hi\"\\n").unwrap();
+	let v1 = g.new_vertex_weighted("two\nlines").unwrap();
+	g.add_edge(&v0, &v1).unwrap();
+
+	let dot = DotWriter::new(&g)
+		.name("g")
+		.vertex_label(|_, w| w.to_string())
+		.edge_attributes(|_, _, _| vec![("style".to_string(), "dashed".to_string())])
+		.cluster("both", vec![v1, v0, 7])
+		.to_string();
+
+	assert_eq!(
+		dot,
+		"graph \"g\" {\n\t0 [label=\"say \\\"hi\\\"\\\\n\"];\n\t1 [label=\"two\\nlines\"];\n\t0 \
+		 -- 1 [style=\"dashed\"];\n\tsubgraph cluster_0 \
+		 {\n\t\tlabel=\"both\";\n\t\t1;\n\t\t0;\n\t}\n}\n"
+	);
+}
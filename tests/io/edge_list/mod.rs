@@ -0,0 +1,4 @@
+//! Tests reading and writing edge lists.
+
+mod reader;
+mod writer;
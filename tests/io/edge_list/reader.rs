@@ -0,0 +1,219 @@
+//! Tests `io::EdgeListReader`.
+
+use crate::mock_graph::{arbitrary::Arb, utilities::unordered_equivalent_lists_equal, MockGraph};
+use duplicate::duplicate;
+use graphene::{
+	common::AdjListGraph,
+	core::{from_graph_mapped, Directed, Graph, Undirected},
+	io::{Attributes, EdgeListReader, EdgeListWriter, ParseError},
+};
+
+#[duplicate(
+	directedness; [ Directed ]; [ Undirected ]
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that reading a written edge list gives the same edges, with
+	/// vertices kept up to the last one with an edge.
+	#[quickcheck]
+	fn read_written(Arb(mock): Arb<MockGraph<directedness>>, csv: bool) -> bool
+	{
+		let (g, _) =
+			from_graph_mapped::<_, AdjListGraph<(), u32, directedness>>(&mock, |_| (), |w| w.value)
+				.unwrap();
+		let (written, reader) = if csv
+		{
+			(
+				EdgeListWriter::csv(&g).first_index(1).to_string(),
+				EdgeListReader::csv().indices(1),
+			)
+		}
+		else
+		{
+			(
+				EdgeListWriter::new(&g).to_string(),
+				EdgeListReader::new().column_name(2, "weight").indices(0),
+			)
+		};
+		let (read, ids) = reader
+			.read::<AdjListGraph<(), u32, directedness>>(written.as_bytes())
+			.unwrap();
+		let first_index = if csv { 1 } else { 0 };
+		let vertices: Vec<_> = g.all_vertices().collect();
+		let id = |i: usize| ids.get(&(i + first_index).to_string()).copied();
+
+		read.all_vertices().count() == ids.len()
+			&& vertices.iter().enumerate().all(|(i, source)| {
+				vertices.iter().enumerate().all(|(j, sink)| {
+					let expected = g.edges_between(source, sink).collect();
+					match (id(i), id(j))
+					{
+						(Some(read_source), Some(read_sink)) =>
+						{
+							unordered_equivalent_lists_equal(
+								&read.edges_between(read_source, read_sink).collect(),
+								&expected,
+							)
+						},
+						_ => expected.is_empty(),
+					}
+				})
+			})
+	}
+}
+
+/// Tests reading IDs that aren't indices, with comments, blank lines and
+/// custom columns.
+#[test]
+fn ids_and_columns()
+{
+	let (g, ids) = EdgeListReader::new()
+		.delimiter('\t')
+		.comments(vec!["//"])
+		.columns(2, 0)
+		.column_name(1, "label")
+		.read::<AdjListGraph<(), Attributes>>(
+			"// sink label source extra\n\nb\tfirst\ta\tx\r\n  \na\tsecond\tc\ty\n".as_bytes(),
+		)
+		.unwrap();
+
+	assert_eq!(g.all_vertices().count(), 3);
+	let ab = g.edges_between(ids["a"], ids["b"]).next().unwrap();
+	assert_eq!(ab["label"], "first");
+	assert_eq!(ab["3"], "x");
+	assert_eq!(ab.len(), 2);
+	assert_eq!(
+		g.edges_between(ids["c"], ids["a"]).next().unwrap()["3"],
+		"y"
+	);
+}
+
+/// Tests reading CSV with a header and quoted fields.
+#[test]
+fn csv()
+{
+	let (g, ids) = EdgeListReader::csv()
+		.read::<AdjListGraph<(), Attributes, Undirected>>(
+			"from,to,note,weight\n\"x, y\", z ,\"say \"\"hi\"\"\",\n z ,w,, 2\n".as_bytes(),
+		)
+		.unwrap();
+
+	assert_eq!(g.all_vertices().count(), 3);
+	let xyz = g.edges_between(ids["x, y"], ids["z"]).next().unwrap();
+	assert_eq!(xyz["note"], "say \"hi\"");
+	assert_eq!(xyz["weight"], "");
+	let zw = g.edges_between(ids["w"], ids["z"]).next().unwrap();
+	assert_eq!(zw["note"], "");
+	assert_eq!(zw["weight"], "2");
+}
+
+/// Tests that indices add vertices up to the largest index read.
+#[test]
+fn indices()
+{
+	let (g, ids) = EdgeListReader::new()
+		.indices(1)
+		.read::<AdjListGraph<(), ()>>("% one edge\n3 002\n".as_bytes())
+		.unwrap();
+
+	assert_eq!(g.all_vertices().count(), 3);
+	assert_eq!(ids.len(), 3);
+	assert!(g.edges_between(ids["3"], ids["2"]).next().is_some());
+	assert!(g.edges_between(ids["1"], ids["2"]).next().is_none());
+}
+
+/// Tests that a declared number of vertices adds vertices without edges.
+#[test]
+fn declared_vertex_count()
+{
+	let (g, ids) = EdgeListReader::new()
+		.indices(0)
+		.vertex_count(4)
+		.read::<AdjListGraph<(), ()>>("0 1\n".as_bytes())
+		.unwrap();
+
+	assert_eq!(g.all_vertices().count(), 4);
+	assert_eq!(ids.len(), 4);
+	assert!(g.edges_between(ids["0"], ids["1"]).next().is_some());
+	assert!(g.edges_between(ids["2"], ids["3"]).next().is_none());
+}
+
+/// Tests that invalid lines are reported where the error is.
+#[test]
+fn errors()
+{
+	let read = |reader: EdgeListReader, input: &str| {
+		reader
+			.read::<AdjListGraph<(), u32>>(input.as_bytes())
+			.map(|_| ())
+	};
+
+	let weighted = || EdgeListReader::new().column_name(2, "weight");
+
+	assert_eq!(
+		read(weighted(), "# comment\n1 2 3\n4\n"),
+		Err(ParseError::syntax(3, 2, "missing column 1"))
+	);
+	assert_eq!(
+		read(weighted().indices(1), "1 2 3\n0 1 3\n"),
+		Err(ParseError::syntax(
+			2,
+			1,
+			"index 0 is below the first index 1"
+		))
+	);
+	assert_eq!(
+		read(weighted().indices(1).vertex_count(3), "1 2 3\n2 4 3\n"),
+		Err(ParseError::syntax(2, 3, "index 4 is out of bounds"))
+	);
+	assert_eq!(
+		read(weighted().indices(1), "1 4294967295 3\n"),
+		Err(ParseError::syntax(
+			1,
+			3,
+			"index 4294967295 is out of bounds"
+		))
+	);
+	assert_eq!(
+		read(weighted().indices(1).vertex_limit(3), "1 2 3\n2 4 3\n"),
+		Err(ParseError::syntax(2, 3, "index 4 is out of bounds"))
+	);
+	assert_eq!(
+		read(weighted().indices(0), "1  x 3\n"),
+		Err(ParseError::syntax(1, 4, "expected an index, found 'x'"))
+	);
+	assert_eq!(
+		read(EdgeListReader::csv(), "a,b,weight\n1,\"2,3\n"),
+		Err(ParseError::syntax(2, 3, "unterminated quote"))
+	);
+	assert_eq!(
+		read(EdgeListReader::csv(), "a,b,weight\n1,\"2\"x,3\n"),
+		Err(ParseError::syntax(2, 6, "expected a delimiter, found 'x'"))
+	);
+	assert_eq!(
+		read(EdgeListReader::new(), "1 2 3\n"),
+		Err(ParseError::Weight {
+			line: 1,
+			column: 5,
+			message: "missing weight".into()
+		})
+	);
+	assert_eq!(
+		read(EdgeListReader::new(), "1 2\n"),
+		Err(ParseError::Weight {
+			line: 1,
+			column: 4,
+			message: "missing weight".into()
+		})
+	);
+	assert_eq!(
+		read(EdgeListReader::csv(), "a,b,weight\n1,2,-3\n"),
+		Err(ParseError::Weight {
+			line: 2,
+			column: 5,
+			message: "invalid weight '-3'".into()
+		})
+	);
+}
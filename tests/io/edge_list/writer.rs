@@ -0,0 +1,69 @@
+//! Tests `io::EdgeListWriter`.
+
+use graphene::{
+	common::AdjListGraph,
+	core::{
+		property::{AddEdge, NewVertex},
+		Undirected,
+	},
+	io::{Attributes, EdgeListWriter},
+};
+
+/// Tests writing edges without weights, and with a custom delimiter and first
+/// index.
+#[test]
+fn without_weights()
+{
+	let mut g = AdjListGraph::<(), (), Undirected>::new();
+	let v0 = g.new_vertex().unwrap();
+	let v1 = g.new_vertex().unwrap();
+	g.new_vertex().unwrap();
+	g.add_edge(&v0, &v1).unwrap();
+	g.add_edge(&v1, &v1).unwrap();
+
+	let mut lines: Vec<_> = EdgeListWriter::new(&g)
+		.delimiter('\t')
+		.first_index(1)
+		.to_string()
+		.lines()
+		.map(String::from)
+		.collect();
+	lines.sort();
+	let first = if lines[0] == "1\t2" { "1\t2" } else { "2\t1" };
+
+	assert_eq!(lines, vec![first, "2\t2"]);
+	assert_eq!(
+		EdgeListWriter::csv(&g).to_string().lines().next(),
+		Some("source,sink")
+	);
+}
+
+/// Tests that CSV has a column for each attribute, with fields quoted when
+/// needed.
+#[test]
+fn csv_attributes()
+{
+	let mut g = AdjListGraph::<(), Attributes>::new();
+	let v0 = g.new_vertex().unwrap();
+	let v1 = g.new_vertex().unwrap();
+	let mut weight = Attributes::new();
+	weight.insert("note".into(), "say \"hi\", ok".into());
+	weight.insert("b".into(), " padded".into());
+	g.add_edge_weighted(&v0, &v1, weight).unwrap();
+	let mut weight = Attributes::new();
+	weight.insert("a".into(), "1".into());
+	g.add_edge_weighted(&v1, &v0, weight).unwrap();
+
+	let csv = EdgeListWriter::csv(&g).to_string();
+	let mut lines: Vec<_> = csv.lines().collect();
+	lines[1..].sort();
+
+	assert_eq!(
+		lines,
+		vec![
+			"source,sink,a,b,note",
+			"0,1,,\" padded\",\"say \"\"hi\"\", ok\"",
+			"1,0,1,,",
+		]
+	);
+}
@@ -0,0 +1,4 @@
+//! Tests reading and writing GraphML.
+
+mod reader;
+mod writer;
@@ -0,0 +1,258 @@
+//! Tests `io::read_graphml` and `io::read_graphml_into`.
+
+use crate::mock_graph::{arbitrary::Arb, utilities::unordered_equivalent_lists_equal, MockGraph};
+use duplicate::duplicate;
+use graphene::{
+	common::AdjListGraph,
+	core::{from_graph_mapped, Directed, Graph, Undirected},
+	io::{
+		read_graphml, read_graphml_into, AttributeType, Attributes, FromAttributes, GraphMlWriter,
+		ParseError, ToAttributes,
+	},
+};
+
+/// A weight with a typed number and a free-form label.
+#[derive(Debug, Clone, PartialEq)]
+struct Weight
+{
+	number: i64,
+	label: String,
+}
+
+impl ToAttributes for Weight
+{
+	fn to_attributes(&self) -> Attributes
+	{
+		let mut attributes = Attributes::new();
+		attributes.insert("number".into(), self.number.to_string());
+		attributes.insert("label".into(), self.label.clone());
+		attributes
+	}
+
+	fn attribute_types() -> Vec<(String, AttributeType)>
+	{
+		vec![("number".into(), AttributeType::Long)]
+	}
+}
+
+impl FromAttributes for Weight
+{
+	fn from_attributes(attributes: &Attributes) -> Result<Self, String>
+	{
+		Ok(Self {
+			number: attributes
+				.get("number")
+				.ok_or_else(|| "missing number".to_string())?
+				.parse()
+				.map_err(|_| "invalid number".to_string())?,
+			label: attributes.get("label").cloned().unwrap_or_default(),
+		})
+	}
+}
+
+/// Returns a label that needs escaping in XML.
+fn label(value: u64) -> String
+{
+	format!("<'{}'> & \"{}\"\n", value, value)
+}
+
+#[duplicate(
+	directedness; [ Directed ]; [ Undirected ]
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that reading a written graph gives the same graph, including
+	/// parallel edges.
+	#[quickcheck]
+	fn read_written(Arb(mock): Arb<MockGraph<directedness>>) -> bool
+	{
+		let (g, _) = from_graph_mapped::<_, AdjListGraph<Weight, Weight, directedness>>(
+			&mock,
+			|w| {
+				Weight {
+					number: w.value as i64,
+					label: label(w.value as u64),
+				}
+			},
+			|w| {
+				Weight {
+					number: -(w.value as i64),
+					label: label(w.value as u64),
+				}
+			},
+		)
+		.unwrap();
+		let graphml = GraphMlWriter::new(&g).to_string();
+		let (read, ids) =
+			read_graphml::<AdjListGraph<Weight, Weight, directedness>>(&graphml).unwrap();
+		let vertices: Vec<_> = g.all_vertices().collect();
+		let id = |i: usize| ids[&format!("n{}", i)];
+
+		read.all_vertices().count() == vertices.len()
+			&& vertices
+				.iter()
+				.enumerate()
+				.all(|(i, v)| read.vertex_weight(id(i)) == g.vertex_weight(v))
+			&& vertices.iter().enumerate().all(|(i, source)| {
+				vertices.iter().enumerate().all(|(j, sink)| {
+					unordered_equivalent_lists_equal(
+						&read.edges_between(id(i), id(j)).collect(),
+						&g.edges_between(source, sink).collect(),
+					)
+				})
+			})
+	}
+}
+
+/// Tests that key names, types and defaults are used, and that unknown
+/// elements and graph data are ignored.
+#[test]
+fn keys_and_defaults()
+{
+	let (g, ids) = read_graphml::<AdjListGraph<Attributes, Attributes>>(
+		r#"<?xml version="1.0"?>
+		<!-- comment -->
+		<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+			<key id="k0" for="all" attr.name="shared"><default>everywhere</default></key>
+			<key id="k1" for="node" attr.type="boolean"><default>false</default></key>
+			<key id="k2" for="edge" attr.name="cost" attr.type="int"/>
+			<key id="k3" for="graph" attr.name="title"/>
+			<graph id="G" edgedefault="directed">
+				<desc>ignored</desc>
+				<data key="k3">my graph</data>
+				<node id="a"><data key="k1">true</data><port name="p"/></node>
+				<node id="b"><data key="k0"><![CDATA[<raw> & text]]></data></node>
+				<edge id="e" source="a" target="b" directed="true">
+					<data key="k2"> 12 </data>
+				</edge>
+			</graph>
+		</graphml>"#,
+	)
+	.unwrap();
+
+	assert_eq!(g.all_vertices().count(), 2);
+	let a = g.vertex_weight(ids["a"]).unwrap();
+	assert_eq!(a["k1"], "true");
+	assert_eq!(a["shared"], "everywhere");
+	let b = g.vertex_weight(ids["b"]).unwrap();
+	assert_eq!(b["k1"], "false");
+	assert_eq!(b["shared"], "<raw> & text");
+
+	let edge = g.edges_between(ids["a"], ids["b"]).next().unwrap();
+	assert_eq!(edge["cost"], " 12 ");
+	assert_eq!(edge["shared"], "everywhere");
+	assert!(edge.get("k1").is_none());
+}
+
+/// Tests reading an undirected graph with parallel edges and a loop.
+#[test]
+fn undirected_parallel_edges()
+{
+	let mut g = AdjListGraph::<(), (), Undirected>::new();
+	let ids = read_graphml_into(
+		"<graphml><graph edgedefault=\"undirected\">
+			<node id=\"a\"/><node id=\"b\"/>
+			<edge source=\"a\" target=\"b\"/>
+			<edge source=\"b\" target=\"a\" directed=\"false\"/>
+			<edge source=\"b\" target=\"b\"/>
+		</graph></graphml>",
+		&mut g,
+	)
+	.unwrap();
+
+	assert_eq!(g.edges_between(ids["a"], ids["b"]).count(), 2);
+	assert_eq!(g.edges_between(ids["b"], ids["b"]).count(), 1);
+	assert_eq!(g.all_edges().count(), 3);
+}
+
+/// Tests that invalid documents are reported where the error is.
+#[test]
+fn syntax_errors()
+{
+	let read = |graphml| read_graphml::<AdjListGraph<(), ()>>(graphml).map(|_| ());
+
+	assert_eq!(
+		read("<graphml>\n\t<graph edgedefault=\"undirected\"/>\n</graphml>"),
+		Err(ParseError::syntax(
+			2,
+			2,
+			"expected directed edges, found 'undirected'"
+		))
+	);
+	assert_eq!(
+		read(
+			"<graphml><graph edgedefault=\"directed\">\n<edge source=\"a\" target=\"b\" \
+			 directed=\"false\"/></graph></graphml>"
+		),
+		Err(ParseError::syntax(
+			2,
+			1,
+			"expected directed edges, found 'undirected'"
+		))
+	);
+	assert_eq!(
+		read(
+			"<graphml><graph edgedefault=\"directed\">\n<node id=\"a\"/>\n<edge source=\"a\" \
+			 target=\"b\"/></graph></graphml>"
+		),
+		Err(ParseError::syntax(3, 1, "unknown node ID 'b'"))
+	);
+	assert_eq!(
+		read(
+			"<graphml><graph edgedefault=\"directed\">\n<node id=\"a\"/><node \
+			 id=\"a\"/></graph></graphml>"
+		),
+		Err(ParseError::syntax(2, 15, "duplicate node ID 'a'"))
+	);
+	assert_eq!(
+		read(
+			"<graphml><graph edgedefault=\"directed\"><node id=\"a\"><data \
+			 key=\"x\"/></node></graph></graphml>"
+		),
+		Err(ParseError::syntax(1, 53, "undeclared key 'x'"))
+	);
+	assert_eq!(
+		read(
+			"<graphml>\n<key id=\"x\" attr.type=\"int\"/>\n<graph \
+			 edgedefault=\"directed\">\n<node id=\"a\"><data \
+			 key=\"x\">1.5</data></node></graph></graphml>"
+		),
+		Err(ParseError::syntax(
+			4,
+			14,
+			"invalid int '1.5' for attribute 'x'"
+		))
+	);
+	assert_eq!(
+		read("<graphml><graph edgedefault=\"directed\"><hyperedge/></graph></graphml>"),
+		Err(ParseError::syntax(1, 40, "hyperedges aren't supported"))
+	);
+	assert_eq!(
+		read("<graphml></graphml>"),
+		Err(ParseError::syntax(1, 20, "missing graph element"))
+	);
+	assert!(read("<graphml><graph edgedefault=\"directed\"></graphml>").is_err());
+}
+
+/// Tests that data that a weight can't be created from is reported where the
+/// node or edge starts.
+#[test]
+fn weight_errors()
+{
+	let mut g = AdjListGraph::<Weight, Weight>::new();
+
+	assert_eq!(
+		read_graphml_into(
+			"<graphml><key id=\"k\" attr.name=\"number\"/><graph edgedefault=\"directed\">\n<node \
+			 id=\"a\"><data key=\"k\">1</data></node>\n<node id=\"b\"/></graph></graphml>",
+			&mut g
+		),
+		Err(ParseError::Weight {
+			line: 3,
+			column: 1,
+			message: "missing number".into()
+		})
+	);
+}
@@ -0,0 +1,95 @@
+//! Tests `io::GraphMlWriter`.
+
+use crate::mock_graph::{arbitrary::Arb, MockGraph};
+use duplicate::duplicate;
+use graphene::{
+	common::AdjListGraph,
+	core::{
+		from_graph_mapped,
+		property::{AddEdge, NewVertex},
+		Directed, Directedness, Graph, Undirected,
+	},
+	io::{AttributeType, Attributes, GraphMlWriter, ToAttributes},
+};
+
+/// A weight whose attribute is declared as an integer.
+#[derive(Debug, Clone)]
+struct Number(i32);
+
+impl ToAttributes for Number
+{
+	fn to_attributes(&self) -> Attributes
+	{
+		let mut attributes = Attributes::new();
+		attributes.insert("w".into(), self.0.to_string());
+		attributes
+	}
+
+	fn attribute_types() -> Vec<(String, AttributeType)>
+	{
+		vec![("w".into(), AttributeType::Int)]
+	}
+}
+
+#[duplicate(
+	directedness; [ Directed ]; [ Undirected ]
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that the graph is written with the right default edge direction,
+	/// and with an element for each vertex and each edge.
+	#[quickcheck]
+	fn writes_vertices_and_edges(Arb(mock): Arb<MockGraph<directedness>>) -> bool
+	{
+		let (g, _) =
+			from_graph_mapped::<_, AdjListGraph<(), (), directedness>>(&mock, |_| (), |_| ())
+				.unwrap();
+		let graphml = GraphMlWriter::new(&g).to_string();
+		let edge_default = if directedness::directed()
+		{
+			"\t<graph edgedefault=\"directed\">"
+		}
+		else
+		{
+			"\t<graph edgedefault=\"undirected\">"
+		};
+		let lines: Vec<_> = graphml.lines().collect();
+
+		lines.contains(&edge_default)
+			&& lines.iter().filter(|l| l.contains("<node ")).count() == g.all_vertices().count()
+			&& lines.iter().filter(|l| l.contains("<edge ")).count() == g.all_edges().count()
+			&& !lines.iter().any(|l| l.contains("<key "))
+	}
+}
+
+/// Tests that keys are declared with their types and that data is escaped.
+#[test]
+fn keys_and_escaping()
+{
+	let mut g = AdjListGraph::<Attributes, Number, Undirected>::new();
+	let mut weight = Attributes::new();
+	weight.insert("a & b".into(), "<\"x\">".into());
+	let v0 = g.new_vertex_weighted(weight).unwrap();
+	let v1 = g.new_vertex_weighted(Attributes::new()).unwrap();
+	g.add_edge_weighted(v0, v1, Number(-3)).unwrap();
+
+	assert_eq!(
+		GraphMlWriter::new(&g).to_string(),
+		"<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+		 <graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n\
+		 \t<key id=\"v0\" for=\"node\" attr.name=\"a &amp; b\" attr.type=\"string\"/>\n\
+		 \t<key id=\"e0\" for=\"edge\" attr.name=\"w\" attr.type=\"int\"/>\n\
+		 \t<graph edgedefault=\"undirected\">\n\
+		 \t\t<node id=\"n0\">\n\
+		 \t\t\t<data key=\"v0\">&lt;&quot;x&quot;&gt;</data>\n\
+		 \t\t</node>\n\
+		 \t\t<node id=\"n1\"/>\n\
+		 \t\t<edge source=\"n0\" target=\"n1\">\n\
+		 \t\t\t<data key=\"e0\">-3</data>\n\
+		 \t\t</edge>\n\
+		 \t</graph>\n\
+		 </graphml>\n"
+	);
+}
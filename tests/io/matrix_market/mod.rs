@@ -0,0 +1,4 @@
+//! Tests reading and writing Matrix Market.
+
+mod reader;
+mod writer;
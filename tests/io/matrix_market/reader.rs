@@ -0,0 +1,149 @@
+//! Tests `io::read_matrix_market` and `io::read_matrix_market_into`.
+
+use crate::mock_graph::{arbitrary::Arb, utilities::unordered_equivalent_lists_equal, MockGraph};
+use duplicate::duplicate;
+use graphene::{
+	common::AdjListGraph,
+	core::{from_graph_mapped, Directed, Graph, Undirected},
+	io::{read_matrix_market, read_matrix_market_into, MatrixMarketWriter, ParseError},
+};
+
+#[duplicate(
+	directedness; [ Directed ]; [ Undirected ]
+)]
+mod __
+{
+	use super::*;
+
+	/// Tests that reading a written matrix gives the same graph.
+	#[quickcheck]
+	fn read_written(Arb(mock): Arb<MockGraph<directedness>>) -> bool
+	{
+		let (g, _) = from_graph_mapped::<_, AdjListGraph<(), i64, directedness>>(
+			&mock,
+			|_| (),
+			|w| w.value as i64 - 1000,
+		)
+		.unwrap();
+		let written = MatrixMarketWriter::new(&g).to_string();
+		let (read, ids) =
+			read_matrix_market::<AdjListGraph<(), i64, directedness>>(written.as_bytes()).unwrap();
+		let vertices: Vec<_> = g.all_vertices().collect();
+		let id = |i: usize| ids[&(i + 1).to_string()];
+
+		read.all_vertices().count() == vertices.len()
+			&& vertices.iter().enumerate().all(|(i, source)| {
+				vertices.iter().enumerate().all(|(j, sink)| {
+					unordered_equivalent_lists_equal(
+						&read.edges_between(id(i), id(j)).collect(),
+						&g.edges_between(source, sink).collect(),
+					)
+				})
+			})
+	}
+}
+
+/// Tests that entries of symmetric matrices give edges in both directions in
+/// directed graphs, and that pattern matrices have no values.
+#[test]
+fn symmetric_pattern()
+{
+	let (g, ids) = read_matrix_market::<AdjListGraph<(), ()>>(
+		"%%matrixmarket MATRIX Coordinate Pattern Symmetric\n%\n\n4 4 3\n2 1\n3 3\n4 2\n"
+			.as_bytes(),
+	)
+	.unwrap();
+
+	assert_eq!(g.all_vertices().count(), 4);
+	assert_eq!(g.all_edges().count(), 5);
+	assert!(g.edges_between(ids["1"], ids["2"]).next().is_some());
+	assert!(g.edges_between(ids["2"], ids["1"]).next().is_some());
+	assert_eq!(g.edges_between(ids["3"], ids["3"]).count(), 1);
+}
+
+/// Tests that real values are read as the weight.
+#[test]
+fn real_values()
+{
+	let mut g = AdjListGraph::<(), f64>::new();
+	let ids = read_matrix_market_into(
+		"%%MatrixMarket matrix coordinate real general\n2 2 2\n1 2 1.5e2\n2 1 -0.25\n".as_bytes(),
+		&mut g,
+	)
+	.unwrap();
+
+	assert_eq!(g.edges_between(ids["1"], ids["2"]).next(), Some(&150.0));
+	assert_eq!(g.edges_between(ids["2"], ids["1"]).next(), Some(&-0.25));
+}
+
+/// Tests that invalid matrices are reported where the error is.
+#[test]
+fn errors()
+{
+	let read =
+		|input: &str| read_matrix_market::<AdjListGraph<(), i32>>(input.as_bytes()).map(|_| ());
+	let read_undirected = |input: &str| {
+		read_matrix_market::<AdjListGraph<(), i32, Undirected>>(input.as_bytes()).map(|_| ())
+	};
+
+	assert_eq!(
+		read("%%MatrixMarket matrix array real general\n"),
+		Err(ParseError::syntax(
+			1,
+			23,
+			"expected coordinate, found 'array'"
+		))
+	);
+	assert_eq!(
+		read("%%MatrixMarket matrix coordinate complex general\n"),
+		Err(ParseError::syntax(
+			1,
+			34,
+			"expected real or double or integer or pattern, found 'complex'"
+		))
+	);
+	assert_eq!(
+		read_undirected("%%MatrixMarket matrix coordinate integer general\n"),
+		Err(ParseError::syntax(
+			1,
+			1,
+			"expected a symmetric matrix for an undirected graph"
+		))
+	);
+	assert_eq!(
+		read("%%MatrixMarket matrix coordinate integer general\n2 3 0\n"),
+		Err(ParseError::syntax(
+			2,
+			1,
+			"expected a square adjacency matrix"
+		))
+	);
+	assert_eq!(
+		read("%%MatrixMarket matrix coordinate integer general\n2 2 1\n1 3 4\n"),
+		Err(ParseError::syntax(3, 3, "column 3 is out of bounds"))
+	);
+	assert_eq!(
+		read("%%MatrixMarket matrix coordinate integer general\n2 2 1\n1 2 4.5\n"),
+		Err(ParseError::syntax(3, 5, "expected an integer, found '4.5'"))
+	);
+	assert_eq!(
+		read("%%MatrixMarket matrix coordinate integer general\n2 2 1\n1 2\n"),
+		Err(ParseError::syntax(3, 4, "expected an integer"))
+	);
+	assert_eq!(
+		read("%%MatrixMarket matrix coordinate integer general\n2 2 2\n1 2 1\n"),
+		Err(ParseError::syntax(4, 1, "expected 2 entries, found 1"))
+	);
+	assert_eq!(
+		read("%%MatrixMarket matrix coordinate integer general\n% no size\n"),
+		Err(ParseError::syntax(3, 1, "expected the size line"))
+	);
+	assert_eq!(
+		read("%%MatrixMarket matrix coordinate integer general\n2 2 1\n1 2 9999999999\n"),
+		Err(ParseError::Weight {
+			line: 3,
+			column: 1,
+			message: "invalid weight '9999999999'".into()
+		})
+	);
+}
@@ -0,0 +1,54 @@
+//! Tests `io::MatrixMarketWriter`.
+
+use graphene::{
+	common::AdjListGraph,
+	core::{
+		property::{AddEdge, NewVertex},
+		Undirected,
+	},
+	io::{Attributes, MatrixMarketWriter},
+};
+
+/// Tests that undirected graphs are written as symmetric matrices, with the
+/// entries in the lower triangle.
+#[test]
+fn undirected_symmetric()
+{
+	let mut g = AdjListGraph::<(), i32, Undirected>::new();
+	let v0 = g.new_vertex().unwrap();
+	let v1 = g.new_vertex().unwrap();
+	let v2 = g.new_vertex().unwrap();
+	g.add_edge_weighted(&v0, &v1, 3).unwrap();
+	g.add_edge_weighted(&v2, &v1, -4).unwrap();
+
+	let written = MatrixMarketWriter::new(&g).to_string();
+	let mut lines: Vec<_> = written.lines().collect();
+	lines[2..].sort();
+
+	assert_eq!(
+		lines,
+		vec![
+			"%%MatrixMarket matrix coordinate integer symmetric",
+			"3 3 2",
+			"2 1 3",
+			"3 2 -4",
+		]
+	);
+}
+
+/// Tests that weights without a typed `weight` attribute are written as a
+/// pattern matrix.
+#[test]
+fn pattern()
+{
+	let mut g = AdjListGraph::<(), Attributes>::new();
+	let v0 = g.new_vertex().unwrap();
+	let mut weight = Attributes::new();
+	weight.insert("weight".into(), "1".into());
+	g.add_edge_weighted(&v0, &v0, weight).unwrap();
+
+	assert_eq!(
+		MatrixMarketWriter::new(&g).to_string(),
+		"%%MatrixMarket matrix coordinate pattern general\n1 1 1\n1 1\n"
+	);
+}
@@ -0,0 +1,4 @@
+//! Tests reading and writing METIS.
+
+mod reader;
+mod writer;
@@ -0,0 +1,117 @@
+//! Tests `io::read_metis` and `io::read_metis_into`.
+
+use crate::mock_graph::{arbitrary::Arb, MockGraph};
+use graphene::{
+	common::AdjListGraph,
+	core::{from_graph_mapped, Graph, Undirected},
+	io::{read_metis, read_metis_into, Attributes, MetisWriter, ParseError},
+};
+
+/// Tests that reading a written graph gives the same graph without its loops.
+#[quickcheck]
+fn read_written(Arb(mock): Arb<MockGraph<Undirected>>) -> bool
+{
+	let (g, _) =
+		from_graph_mapped::<_, AdjListGraph<u32, u32, Undirected>>(&mock, |w| w.value, |w| w.value)
+			.unwrap();
+	let written = MetisWriter::new(&g).to_string();
+	let (read, ids) = read_metis::<AdjListGraph<u32, u32, Undirected>>(written.as_bytes()).unwrap();
+	let vertices: Vec<_> = g.all_vertices().collect();
+	let id = |i: usize| ids[&(i + 1).to_string()];
+
+	read.all_vertices().count() == vertices.len()
+		&& vertices
+			.iter()
+			.enumerate()
+			.all(|(i, v)| read.vertex_weight(id(i)) == g.vertex_weight(v))
+		&& vertices.iter().enumerate().all(|(i, source)| {
+			vertices.iter().enumerate().all(|(j, sink)| {
+				let mut expected: Vec<_> = if i == j
+				{
+					Vec::new()
+				}
+				else
+				{
+					g.edges_between(source, sink).collect()
+				};
+				let mut read_weights: Vec<_> = read.edges_between(id(i), id(j)).collect();
+				expected.sort();
+				read_weights.sort();
+				expected == read_weights
+			})
+		})
+}
+
+/// Tests reading vertex sizes and multiple vertex weights, along with comments
+/// and isolated vertices.
+#[test]
+fn sizes_and_weights()
+{
+	let mut g = AdjListGraph::<Attributes, (), Undirected>::new();
+	let ids = read_metis_into(
+		"% header\n3 1 110 2\n1 5 6 2\n% comment\n2 7 8 1\n3 9 10\n\n".as_bytes(),
+		&mut g,
+	)
+	.unwrap();
+
+	assert_eq!(g.all_vertices().count(), 3);
+	assert!(g.neighbors(ids["1"], ids["2"]));
+	let v2 = g.vertex_weight(ids["2"]).unwrap();
+	assert_eq!(v2["size"], "2");
+	assert_eq!(v2["weight1"], "7");
+	assert_eq!(v2["weight2"], "8");
+	assert!(g.vertex_weight(ids["3"]).unwrap().get("weight").is_none());
+}
+
+/// Tests that invalid graphs are reported where the error is.
+#[test]
+fn errors()
+{
+	let read =
+		|input: &str| read_metis::<AdjListGraph<(), (), Undirected>>(input.as_bytes()).map(|_| ());
+
+	assert_eq!(
+		read("2 1 002\n"),
+		Err(ParseError::syntax(1, 5, "expected a format, found '002'"))
+	);
+	assert_eq!(
+		read("2 1\n1\n"),
+		Err(ParseError::syntax(2, 1, "loops aren't allowed"))
+	);
+	assert_eq!(
+		read("2 1\n2\n3\n"),
+		Err(ParseError::syntax(3, 1, "vertex 3 is out of bounds"))
+	);
+	assert_eq!(
+		read("3 1\n\n1\n\n"),
+		Err(ParseError::syntax(
+			3,
+			1,
+			"the edge isn't listed by vertex 1"
+		))
+	);
+	assert_eq!(
+		read("2 1\n2\n\n"),
+		Err(ParseError::syntax(
+			4,
+			1,
+			"the edge between vertices 1 and 2 isn't listed by vertex 2"
+		))
+	);
+	assert_eq!(
+		read("2 2\n2\n1\n"),
+		Err(ParseError::syntax(4, 1, "expected 2 edges, found 1"))
+	);
+	assert_eq!(
+		read("3 0\n\n"),
+		Err(ParseError::syntax(3, 1, "expected 3 vertex lines, found 1"))
+	);
+	assert_eq!(
+		read("1 0\n\n5\n"),
+		Err(ParseError::syntax(3, 1, "expected 1 vertex lines"))
+	);
+	assert_eq!(
+		read_metis::<AdjListGraph<(), u32, Undirected>>("2 1 1\n2 3\n1 4\n".as_bytes()).map(|_| ()),
+		Err(ParseError::syntax(3, 1, "the weight differs from vertex 1"))
+	);
+}
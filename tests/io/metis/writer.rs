@@ -0,0 +1,29 @@
+//! Tests `io::MetisWriter`.
+
+use graphene::{
+	common::AdjListGraph,
+	core::{
+		property::{AddEdge, NewVertex},
+		Undirected,
+	},
+	io::MetisWriter,
+};
+
+/// Tests that vertex weights are written before the neighbors, and that loops
+/// aren't written.
+#[test]
+fn vertex_weights_and_loops()
+{
+	let mut g = AdjListGraph::<i32, (), Undirected>::new();
+	let v0 = g.new_vertex_weighted(3).unwrap();
+	let v1 = g.new_vertex_weighted(5).unwrap();
+	g.add_edge(&v0, &v1).unwrap();
+	g.add_edge(&v1, &v1).unwrap();
+
+	assert_eq!(MetisWriter::new(&g).to_string(), "2 1 010\n3 2\n5 1\n");
+
+	let mut g = AdjListGraph::<(), (), Undirected>::new();
+	let v0 = g.new_vertex().unwrap();
+	g.add_edge(&v0, &v0).unwrap();
+	assert_eq!(MetisWriter::new(&g).to_string(), "1 0\n\n");
+}
@@ -0,0 +1,9 @@
+//! Tests `graphene::io`.
+
+mod dimacs;
+mod dot;
+mod edge_list;
+#[cfg(feature = "graphml")]
+mod graphml;
+mod matrix_market;
+mod metis;
@@ -75,7 +75,9 @@ where
 		{
 			panic!("Vertex not in graph: '{:?}'", v2);
 		}
-		Self(VertexInGraph::ensure(g, v1).unwrap(), v2, PhantomData)
+		let g = VertexInGraph::ensure(g, v1)
+			.unwrap_or_else(|_| panic!("Vertex not in graph: '{:?}'", v1));
+		Self(g, v2, PhantomData)
 	}
 
 	pub fn get_both(&self) -> (MockVertex, MockVertex)
@@ -191,7 +193,8 @@ where
 			// Shrink by making both vertices the same
 			result.push(Self(self.0.clone(), self.get_vertex(), PhantomData));
 			result.push(Self(
-				VertexInGraph::ensure(self.0.clone().release(), self.1).unwrap(),
+				VertexInGraph::ensure(self.0.clone().release(), self.1)
+					.unwrap_or_else(|_| panic!("Vertex not in graph: '{:?}'", self.1)),
 				self.1,
 				PhantomData,
 			));
@@ -3,7 +3,7 @@ use graphene::{
 	base_graph,
 	core::{
 		property::{AddEdge, EdgeCount, NewVertex, RemoveEdge, RemoveVertex, VertexCount},
-		Directedness, Edge, Graph, GraphMut,
+		Directedness, Edge, Graph, GraphError, GraphMut,
 	},
 };
 use std::{
@@ -260,7 +260,10 @@ impl<D: Directedness> GraphMut for MockGraph<D>
 
 impl<D: Directedness> NewVertex for MockGraph<D>
 {
-	fn new_vertex_weighted(&mut self, w: Self::VertexWeight) -> Result<Self::Vertex, ()>
+	fn new_vertex_weighted(
+		&mut self,
+		w: Self::VertexWeight,
+	) -> Result<Self::Vertex, GraphError<Self::Vertex>>
 	{
 		if self.vertices.insert(self.next_id, w).is_some()
 		{
@@ -278,8 +281,10 @@ impl<D: Directedness> NewVertex for MockGraph<D>
 }
 impl<D: Directedness> RemoveVertex for MockGraph<D>
 {
-	fn remove_vertex(&mut self, mock_v: impl Borrow<Self::Vertex>)
-		-> Result<Self::VertexWeight, ()>
+	fn remove_vertex(
+		&mut self,
+		mock_v: impl Borrow<Self::Vertex>,
+	) -> Result<Self::VertexWeight, GraphError<Self::Vertex>>
 	{
 		let v = mock_v.borrow().value;
 		if let Some(weight) = self.vertices.remove(&v)
@@ -290,7 +295,7 @@ impl<D: Directedness> RemoveVertex for MockGraph<D>
 		}
 		else
 		{
-			Err(())
+			Err(GraphError::VertexNotFound(*mock_v.borrow()))
 		}
 	}
 }
@@ -302,7 +307,7 @@ impl<D: Directedness> AddEdge for MockGraph<D>
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		weight: Self::EdgeWeight,
-	) -> Result<(), ()>
+	) -> Result<(), GraphError<Self::Vertex>>
 	{
 		let source = source.borrow().value;
 		let sink = sink.borrow().value;
@@ -312,9 +317,13 @@ impl<D: Directedness> AddEdge for MockGraph<D>
 			self.validate_is_graph();
 			Ok(())
 		}
+		else if !self.vertices.contains_key(&source)
+		{
+			Err(GraphError::VertexNotFound(MockVertex { value: source }))
+		}
 		else
 		{
-			Err(())
+			Err(GraphError::VertexNotFound(MockVertex { value: sink }))
 		}
 	}
 }
@@ -326,7 +335,7 @@ impl<D: Directedness> RemoveEdge for MockGraph<D>
 		source: impl Borrow<Self::Vertex>,
 		sink: impl Borrow<Self::Vertex>,
 		f: F,
-	) -> Result<Self::EdgeWeight, ()>
+	) -> Result<Self::EdgeWeight, GraphError<Self::Vertex>>
 	where
 		F: Fn(&Self::EdgeWeight) -> bool,
 	{
@@ -344,7 +353,7 @@ impl<D: Directedness> RemoveEdge for MockGraph<D>
 		}
 		else
 		{
-			Err(())
+			Err(GraphError::EdgeNotFound(*source.borrow(), *sink.borrow()))
 		}
 	}
 }
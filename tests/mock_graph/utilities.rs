@@ -3,7 +3,7 @@ use graphene::core::{
 	property::{AddEdge, NewVertex},
 	Directedness, Graph,
 };
-use std::{borrow::Borrow, collections::HashMap};
+use std::{borrow::Borrow, collections::HashMap, time::Instant};
 #[macro_export]
 macro_rules! holds_if{
 	{
@@ -117,7 +117,7 @@ where
 	{
 		let new_v = g
 			.new_vertex_weighted(mock.vertex_weight(&v).unwrap().clone())
-			.unwrap();
+			.unwrap_or_else(|_| panic!("Couldn't add vertex"));
 		vertex_map.insert(v, new_v.clone());
 
 		// Insert all edge to/from the finished vertices
@@ -126,14 +126,14 @@ where
 			for e_w in mock.edges_between(&v, v_done)
 			{
 				g.add_edge_weighted(new_v.borrow(), new_v_done.borrow(), e_w.clone())
-					.unwrap();
+					.unwrap_or_else(|_| panic!("Couldn't add edge"));
 			}
 			if G::Directedness::directed() && *v_done != v
 			{
 				for e_w in mock.edges_between(v_done, &v)
 				{
 					g.add_edge_weighted(new_v_done.borrow(), new_v.borrow(), e_w.clone())
-						.unwrap();
+						.unwrap_or_else(|_| panic!("Couldn't add edge"));
 				}
 			}
 		}
@@ -141,3 +141,26 @@ where
 
 	vertex_map
 }
+
+/// Returns whether the time `run` takes on the input made by `setup` grows at
+/// most linearly with the given size, by comparing it with an input 4 times
+/// larger.
+///
+/// Linear algorithms take around 4 times longer on the larger input and
+/// quadratic ones around 16 times, so anything below 8 times is accepted.
+/// The fastest of a few runs is used, to tolerate noise.
+pub fn scales_linearly<T>(size: usize, setup: impl Fn(usize) -> T, run: impl Fn(&T)) -> bool
+{
+	let fastest = |size| {
+		let input = setup(size);
+		(0..3)
+			.map(|_| {
+				let start = Instant::now();
+				run(&input);
+				start.elapsed()
+			})
+			.min()
+			.unwrap()
+	};
+	fastest(4 * size) < fastest(size) * 8
+}